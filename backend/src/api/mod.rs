@@ -4,7 +4,7 @@ pub mod ws;
 
 use axum::{
     extract::{Json, Path, Query, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     routing::{delete, get, post, put},
     Router,
@@ -16,15 +16,19 @@ use std::sync::Arc;
 use std::path::PathBuf;
 
 use crate::auth::{AuthUser, OptionalAuthUser};
-use crate::db::Database;
+use crate::db::{Database, LeaderboardFilter};
+use crate::engine::analysis;
+use crate::engine::player::ApiVersion;
 use crate::engine::server::{self, GameResult, GameServer, PlayerEntry};
 use crate::engine::world::World;
+use crate::league::League;
 use crate::metrics;
 use crate::queue::run_game_completion;
 use crate::rate_limit::{RateLimitType, RateLimiter};
 use crate::tournament::{
-    generate_round_robin_pairings, generate_single_elimination_bracket, generate_swiss_pairings,
-    total_rounds, TournamentFormat,
+    bracket_games, double_elimination_state, generate_round_robin_pairings,
+    generate_single_elimination_bracket, generate_swiss_pairings, swiss_standings, total_rounds,
+    TournamentFormat,
 };
 
 // ── Request types ─────────────────────────────────────────────────────
@@ -39,6 +43,8 @@ pub struct CreateBotRequest {
 pub struct UpdateBotRequest {
     pub name: String,
     pub description: Option<String>,
+    /// Opt the bot's active version into ladder matchmaking.
+    pub ladder: Option<bool>,
 }
 
 #[derive(Deserialize)]
@@ -71,6 +77,12 @@ pub struct MapParamsRequest {
     pub width: Option<usize>,
     pub height: Option<usize>,
     pub num_food_spots: Option<usize>,
+    /// `none`, `mirror_horizontal`, `mirror_vertical`, `rotational` or `radial`.
+    pub symmetry: Option<String>,
+    /// Number of folds for `radial` symmetry (2-8, default 4).
+    pub radial_folds: Option<usize>,
+    /// Regenerate until the map's unfairness (0-1) is at most this.
+    pub max_unfairness: Option<f64>,
 }
 
 #[derive(Deserialize)]
@@ -104,6 +116,34 @@ pub struct ChallengeRequest {
     pub format: Option<String>,
     pub headless: Option<bool>,
     pub map: Option<String>,
+    /// Count the result towards ratings (default false). The caller must own
+    /// the challenging bot, and both versions must be their bot's active version.
+    pub ranked: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateMapRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub visibility: Option<String>,
+    /// Map contents in the JSON or ASCII map format.
+    pub data: String,
+    /// `"json"` or `"ascii"`. Detected from `data` when omitted.
+    pub format: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateMapRequest {
+    pub description: Option<String>,
+    pub visibility: Option<String>,
+    /// New map contents; stored as the next version when present.
+    pub data: Option<String>,
+    pub format: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GetMapParams {
+    pub version: Option<i32>,
 }
 
 #[derive(Deserialize)]
@@ -112,6 +152,86 @@ pub struct PaginationParams {
     pub offset: Option<i64>,
 }
 
+#[derive(Deserialize)]
+pub struct RatingHistoryParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// Also report the rating as of this timestamp or date.
+    pub at: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateLadderRequest {
+    pub paused: Option<bool>,
+    /// Replace the ranked map pool. Empty means generated random maps.
+    pub maps: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+pub struct RecomputeParams {
+    /// Report what would change without writing anything.
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct LeaderboardParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// 1v1: "elo" (default) or "glicko"; FFA: "points" (default) or "skill";
+    /// 2v2: "elo" (default) or "skill".
+    pub sort: Option<String>,
+    pub hide_provisional: Option<bool>,
+    /// "newcomer" or "open"; all leagues when absent.
+    pub league: Option<String>,
+    /// "all" (default), "week" or "month": only count ranked games from the
+    /// last 7 or 30 days.
+    pub period: Option<String>,
+    /// Owner's username.
+    pub owner: Option<String>,
+    /// Only count ranked games played on this map.
+    pub map: Option<String>,
+    pub active_only: Option<bool>,
+    /// "version" (default) lists every version, "bot" only each bot's best.
+    pub group: Option<String>,
+}
+
+impl LeaderboardParams {
+    /// The board's filter, or a message for a 400 response.
+    fn filter(&self) -> Result<LeaderboardFilter, &'static str> {
+        let league = match self.league.as_deref().map(League::from_str_name) {
+            None => None,
+            Some(Some(l)) => Some(l),
+            Some(None) => return Err("league must be 'newcomer' or 'open'"),
+        };
+        let period_days = match self.period.as_deref().unwrap_or("all") {
+            "all" => None,
+            "week" => Some(7),
+            "month" => Some(30),
+            _ => return Err("period must be 'all', 'week' or 'month'"),
+        };
+        let per_bot = match self.group.as_deref().unwrap_or("version") {
+            "version" => false,
+            "bot" => true,
+            _ => return Err("group must be 'version' or 'bot'"),
+        };
+        Ok(LeaderboardFilter {
+            hide_provisional: self.hide_provisional.unwrap_or(false),
+            league,
+            period_days,
+            map: self.map.clone().filter(|m| !m.is_empty()),
+            owner: self.owner.clone().filter(|o| !o.is_empty()),
+            active_only: self.active_only.unwrap_or(false),
+            per_bot,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RolloverParams {
+    /// Length of the new season in days.
+    pub days: Option<i64>,
+}
+
 #[derive(Deserialize)]
 pub struct MatchListParams {
     pub limit: Option<i64>,
@@ -178,6 +298,42 @@ fn internal_error(e: sqlx::Error) -> impl IntoResponse {
     json_error(StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
 }
 
+/// Whether a write failed on a UNIQUE constraint, i.e. lost a race with a
+/// concurrent request writing the same key.
+fn is_unique_violation(e: &sqlx::Error) -> bool {
+    matches!(e, sqlx::Error::Database(db) if db.is_unique_violation())
+}
+
+/// JSON response tagged with an ETag of its body; a bare 304 when the
+/// request's `If-None-Match` already holds that tag.
+fn json_with_etag<T: Serialize>(headers: &HeaderMap, value: &T) -> axum::response::Response {
+    let body = match serde_json::to_vec(value) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!("Serialization error: {e}");
+            return json_error(StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+                .into_response();
+        }
+    };
+    let tag = crate::leaderboard::etag(&body);
+    let fresh = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| crate::leaderboard::etag_matches(v, &tag));
+    // no-cache: clients may store the body but must revalidate it
+    let caching = [(header::ETAG, tag), (header::CACHE_CONTROL, "no-cache".to_string())];
+    if fresh {
+        return (StatusCode::NOT_MODIFIED, caching).into_response();
+    }
+    (
+        StatusCode::OK,
+        caching,
+        [(header::CONTENT_TYPE, "application/json")],
+        body,
+    )
+        .into_response()
+}
+
 // ── Router ────────────────────────────────────────────────────────────
 
 pub fn router(
@@ -195,7 +351,9 @@ pub fn router(
 
     Router::new()
         // Maps
-        .route("/api/maps", get(list_maps))
+        .route("/api/maps", get(list_maps).post(create_map))
+        .route("/api/maps/{name}", get(get_map).put(update_map))
+        .route("/api/maps/{name}/analysis", get(get_map_analysis))
         // Bots
         .route("/api/bots", get(list_bots).post(create_bot))
         .route(
@@ -211,6 +369,10 @@ pub fn router(
             "/api/bots/{bot_id}/versions/{version_id}",
             get(get_bot_version).put(update_bot_version),
         )
+        .route(
+            "/api/bots/{bot_id}/versions/{version_id}/rating-history",
+            get(get_rating_history),
+        )
         .route("/api/bots/{id}/active-version", put(set_active_version))
         .route("/api/bots/{id}/stats", get(get_bot_stats))
         // Matches
@@ -246,12 +408,21 @@ pub fn router(
         // Tournament results
         .route("/api/tournaments/{id}/results", get(get_tournament_results))
         .route("/api/tournaments/{id}/matches", get(get_tournament_matches))
+        .route("/api/tournaments/{id}/bracket", get(get_tournament_bracket))
         // Tournament run
         .route("/api/tournaments/{id}/run", post(run_tournament))
         // Leaderboards
         .route("/api/leaderboards/1v1", get(leaderboard_1v1))
         .route("/api/leaderboards/ffa", get(leaderboard_ffa))
         .route("/api/leaderboards/2v2", get(leaderboard_2v2))
+        .route("/api/admin/ratings/glicko/backfill", post(backfill_glicko))
+        .route("/api/admin/ratings/recompute", post(recompute_ratings))
+        .route("/api/admin/leaderboards/rebuild", post(rebuild_leaderboards))
+        .route("/api/admin/ladder", get(get_ladder).put(update_ladder))
+        // Seasons
+        .route("/api/seasons", get(list_seasons))
+        .route("/api/seasons/{id}/leaderboard", get(season_leaderboard))
+        .route("/api/admin/seasons/rollover", post(rollover_season))
         // Teams
         .route("/api/teams", get(list_teams).post(create_team))
         .route(
@@ -359,7 +530,7 @@ async fn update_bot(
         Err(e) => return internal_error(e).into_response(),
     }
     let description = req.description.unwrap_or_default();
-    match state.db.update_bot(id, &req.name, &description).await {
+    match state.db.update_bot(id, &req.name, &description, req.ladder).await {
         Ok(Some(bot)) => (StatusCode::OK, Json(json!(bot))).into_response(),
         Ok(None) => json_error(StatusCode::NOT_FOUND, "Bot not found").into_response(),
         Err(e) => internal_error(e).into_response(),
@@ -428,6 +599,9 @@ async fn create_bot_version(
         Ok(None) => return json_error(StatusCode::NOT_FOUND, "Bot not found").into_response(),
         Err(e) => return internal_error(e).into_response(),
     }
+    if let Err(e) = ApiVersion::detect(&req.code).check() {
+        return json_error(StatusCode::BAD_REQUEST, &e).into_response();
+    }
     match state
         .db
         .create_bot_version(bot_id, &req.code)
@@ -452,6 +626,53 @@ async fn get_bot_version(
     }
 }
 
+/// 1v1 Elo over time for one version, with its peak and optionally its
+/// rating as of a given date.
+async fn get_rating_history(
+    State(state): State<AppState>,
+    Path((bot_id, version_id)): Path<(i64, i64)>,
+    Query(params): Query<RatingHistoryParams>,
+) -> impl IntoResponse {
+    let version = match state.db.get_bot_version(bot_id, version_id).await {
+        Ok(Some(v)) => v,
+        Ok(None) => return json_error(StatusCode::NOT_FOUND, "Bot version not found").into_response(),
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let limit = params.limit.unwrap_or(500).clamp(1, 1000);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let history = match state.db.list_rating_history(version_id, limit, offset).await {
+        Ok(h) => h,
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let peak = match state.db.version_peak_rating(version_id).await {
+        Ok(p) => p.unwrap_or(version.elo_1v1),
+        Err(e) => return internal_error(e).into_response(),
+    };
+    if let Some(at) = &params.at {
+        if crate::db::normalize_timestamp(at).is_none() {
+            return json_error(StatusCode::BAD_REQUEST, "Invalid 'at' timestamp").into_response();
+        }
+    }
+    let rating_at = match &params.at {
+        Some(at) => match state.db.version_rating_at(version_id, at).await {
+            Ok(r) => Some(r.unwrap_or(version.elo_1v1)),
+            Err(e) => return internal_error(e).into_response(),
+        },
+        None => None,
+    };
+    (
+        StatusCode::OK,
+        Json(json!({
+            "version_id": version_id,
+            "rating": version.elo_1v1,
+            "peak": peak,
+            "rating_at": rating_at,
+            "history": history,
+        })),
+    )
+        .into_response()
+}
+
 // ── Bot version management handlers ──────────────────────────────────
 
 async fn update_bot_version(
@@ -546,6 +767,13 @@ async fn get_bot_stats(
                         "elo_rating": v.elo_rating,
                         "elo_1v1": v.elo_1v1,
                         "elo_peak": v.elo_peak,
+                        "glicko_rating": v.glicko_rating.round() as i32,
+                        "glicko_confidence": v.glicko().confidence().round() as i32,
+                        "glicko_provisional": v.glicko().is_provisional(),
+                        "ffa_skill": v.ffa_mu.round() as i32,
+                        "ffa_skill_confidence": v.ffa_skill().confidence().round() as i32,
+                        "team_skill": v.team_mu.round() as i32,
+                        "team_skill_confidence": v.team_skill().confidence().round() as i32,
                         "games_played": v.games_played,
                         "wins": v.wins,
                         "losses": v.losses,
@@ -669,12 +897,15 @@ async fn list_tournaments(State(state): State<AppState>) -> impl IntoResponse {
 
 async fn create_tournament(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Json(req): Json<CreateTournamentRequest>,
 ) -> impl IntoResponse {
     if req.name.is_empty() {
         return json_error(StatusCode::BAD_REQUEST, "name is required").into_response();
     }
+    if let Err(resp) = check_map_access(&state, req.map.as_deref(), auth.0.sub).await {
+        return resp;
+    }
     let map = req.map.unwrap_or_else(|| "random".to_string());
     match state.db.create_tournament(&req.name, &map).await {
         Ok(tournament) => (StatusCode::CREATED, Json(json!(tournament))).into_response(),
@@ -692,10 +923,13 @@ async fn get_tournament(State(state): State<AppState>, Path(id): Path<i64>) -> i
 
 async fn update_tournament(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Path(id): Path<i64>,
     Json(req): Json<UpdateTournamentRequest>,
 ) -> impl IntoResponse {
+    if let Err(resp) = check_map_access(&state, req.map.as_deref(), auth.0.sub).await {
+        return resp;
+    }
     // Validate format if provided
     if let Some(ref fmt) = req.format {
         if crate::tournament::TournamentFormat::from_str_name(fmt).is_none() {
@@ -740,6 +974,68 @@ async fn get_tournament_standings(
     }
 }
 
+/// Double-elimination bracket: each match with its bracket (winners, losers,
+/// grand final, reset) and every entrant's losses and final placement.
+async fn get_tournament_bracket(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let tournament = match state.db.get_tournament(id).await {
+        Ok(Some(t)) => t,
+        Ok(None) => {
+            return json_error(StatusCode::NOT_FOUND, "Tournament not found").into_response()
+        }
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let Some(TournamentFormat::DoubleElimination { bracket_reset }) =
+        TournamentFormat::from_str_name(&tournament.format)
+    else {
+        return json_error(
+            StatusCode::BAD_REQUEST,
+            "Brackets are only available for double elimination tournaments",
+        )
+        .into_response();
+    };
+    let entries = match state.db.list_tournament_entries(id).await {
+        Ok(e) => e,
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let details = match state.db.get_tournament_matches_detail(id).await {
+        Ok(d) => d,
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let seeds: Vec<i64> = entries.iter().map(|e| e.bot_version_id).collect();
+    let bracket = double_elimination_state(&seeds, &bracket_games(&details), bracket_reset);
+    let standings: Vec<serde_json::Value> = bracket
+        .entrants
+        .iter()
+        .map(|e| {
+            let name = entries
+                .iter()
+                .find(|en| en.bot_version_id == e.bot_version_id)
+                .and_then(|en| en.bot_name.clone());
+            json!({
+                "bot_version_id": e.bot_version_id,
+                "bot_name": name,
+                "seed": e.seed,
+                "losses": e.losses,
+                "eliminated_round": e.eliminated_round,
+                "placement": e.placement,
+            })
+        })
+        .collect();
+    (
+        StatusCode::OK,
+        Json(json!({
+            "format": tournament.format,
+            "champion": bracket.champion,
+            "standings": standings,
+            "matches": bracket.matches,
+        })),
+    )
+        .into_response()
+}
+
 // ── Tournament entry handlers ─────────────────────────────────────────
 
 async fn list_tournament_entries(
@@ -886,6 +1182,15 @@ async fn run_tournament(
         .into_response();
     }
 
+    // Only a tournament that hasn't started can be run, whatever its format
+    match state.db.start_tournament(tournament_id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return json_error(StatusCode::CONFLICT, "Tournament already started").into_response()
+        }
+        Err(e) => return internal_error(e).into_response(),
+    }
+
     // Parse tournament format
     let format =
         TournamentFormat::from_str_name(&tournament.format).unwrap_or(TournamentFormat::RoundRobin);
@@ -896,9 +1201,18 @@ async fn run_tournament(
     let pairings = match &format {
         TournamentFormat::RoundRobin => generate_round_robin_pairings(&version_ids),
         TournamentFormat::SingleElimination => generate_single_elimination_bracket(&version_ids),
+        TournamentFormat::DoubleElimination { bracket_reset } => {
+            double_elimination_state(&version_ids, &[], *bracket_reset).next_round
+        }
         TournamentFormat::Swiss { .. } => {
-            // First round: no standings yet
-            generate_swiss_pairings(&version_ids, &[], 0)
+            // First round: no standings yet, so entry order decides
+            let swiss = generate_swiss_pairings(&swiss_standings(&version_ids, &[], &[]));
+            if let Some(bye) = swiss.bye {
+                if let Err(e) = state.db.add_tournament_bye(tournament_id, bye, 1).await {
+                    return internal_error(e).into_response();
+                }
+            }
+            swiss.pairs
         }
     };
 
@@ -925,8 +1239,9 @@ async fn run_tournament(
             _ => continue,
         };
 
-        // Create match in DB
-        let m = match state.db.create_match("1v1", &tournament.map).await {
+        // Create match in DB. Tournament games are unranked: entries aren't
+        // limited to the caller's bots, so they must not move ratings.
+        let m = match state.db.create_match("1v1", &tournament.map, false).await {
             Ok(m) => m,
             Err(_) => continue,
         };
@@ -946,13 +1261,6 @@ async fn run_tournament(
         match_ids.push(m.id);
     }
 
-    // Update tournament status
-    let _ = state
-        .db
-        .update_tournament_status(tournament_id, "running")
-        .await;
-    let _ = state.db.update_tournament_round(tournament_id, 1).await;
-
     (
         StatusCode::OK,
         Json(json!({
@@ -969,8 +1277,31 @@ async fn run_tournament(
 
 // ── Map handlers ─────────────────────────────────────────────────────
 
-async fn list_maps(State(state): State<AppState>) -> impl IntoResponse {
+async fn list_maps(State(state): State<AppState>, auth: OptionalAuthUser) -> impl IntoResponse {
     let mut maps = server::list_maps(&state.maps_dir);
+    let viewer_id = auth.0.map(|c| c.sub);
+    match state.db.list_custom_maps(viewer_id).await {
+        Ok(custom) => {
+            for m in custom {
+                if maps.iter().any(|f| f.name == m.name) {
+                    continue;
+                }
+                let description = if m.description.is_empty() {
+                    format!("{}x{} custom map", m.width, m.height)
+                } else {
+                    m.description
+                };
+                maps.push(server::MapInfo {
+                    name: m.name,
+                    width: m.width as usize,
+                    height: m.height as usize,
+                    description,
+                });
+            }
+            maps.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        Err(e) => return internal_error(e).into_response(),
+    }
     // Prepend pseudo-entries for random options
     maps.insert(
         0,
@@ -993,8 +1324,305 @@ async fn list_maps(State(state): State<AppState>) -> impl IntoResponse {
     (StatusCode::OK, Json(json!(maps))).into_response()
 }
 
+/// Map names that refer to generated maps rather than stored ones.
+const RESERVED_MAP_NAMES: [&str; 3] = ["random", "random_pool", "default"];
+
+/// Parse uploaded map contents in the given (or detected) format and check
+/// that the result is playable.
+fn parse_map_upload(data: &str, format: Option<&str>) -> Result<World, String> {
+    use crate::engine::config::{MAP_MAX_SIZE, MAX_MAP_UNFAIRNESS};
+
+    let is_json = match format {
+        Some("json") => true,
+        Some("ascii") => false,
+        Some(other) => return Err(format!("Unknown map format '{}'", other)),
+        None => data.trim_start().starts_with('{'),
+    };
+    let world = if is_json {
+        // Check dimensions before allocating the tile grid
+        #[derive(Deserialize)]
+        struct MapSize {
+            width: usize,
+            height: usize,
+        }
+        let size: MapSize =
+            serde_json::from_str(data).map_err(|e| format!("Invalid map JSON: {}", e))?;
+        if size.width > MAP_MAX_SIZE || size.height > MAP_MAX_SIZE {
+            return Err(format!("Map must be at most {MAP_MAX_SIZE}x{MAP_MAX_SIZE}"));
+        }
+        World::from_json(data)?
+    } else {
+        World::from_ascii(data)?
+    };
+    world.validate_playable()?;
+    let unfairness = analysis::analyze(&world).unfairness;
+    if unfairness > MAX_MAP_UNFAIRNESS {
+        return Err(format!(
+            "Map is too unfair: slot scores differ by {:.2} (max {:.2})",
+            unfairness, MAX_MAP_UNFAIRNESS
+        ));
+    }
+    Ok(world)
+}
+
+fn validate_visibility(visibility: &str) -> Result<(), &'static str> {
+    if visibility == "public" || visibility == "private" {
+        Ok(())
+    } else {
+        Err("visibility must be 'public' or 'private'")
+    }
+}
+
+/// Look up a custom map for a viewer. Private maps are only visible to their owner.
+async fn find_visible_custom_map(
+    state: &AppState,
+    name: &str,
+    viewer_id: Option<i64>,
+) -> Result<Option<crate::db::CustomMap>, sqlx::Error> {
+    Ok(state
+        .db
+        .get_custom_map_by_name(name)
+        .await?
+        .filter(|m| m.visibility == "public" || Some(m.owner_id) == viewer_id))
+}
+
+async fn create_map(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(req): Json<CreateMapRequest>,
+) -> impl IntoResponse {
+    let valid_name = !req.name.is_empty()
+        && req.name.len() <= 64
+        && req
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid_name {
+        return json_error(
+            StatusCode::BAD_REQUEST,
+            "name must be 1-64 characters of letters, digits, '_' or '-'",
+        )
+        .into_response();
+    }
+    let visibility = req.visibility.unwrap_or_else(|| "public".to_string());
+    if let Err(msg) = validate_visibility(&visibility) {
+        return json_error(StatusCode::BAD_REQUEST, msg).into_response();
+    }
+    if RESERVED_MAP_NAMES.contains(&req.name.as_str())
+        || server::list_maps(&state.maps_dir).iter().any(|m| m.name == req.name)
+    {
+        return json_error(StatusCode::CONFLICT, "A map with that name already exists")
+            .into_response();
+    }
+    match state.db.get_custom_map_by_name(&req.name).await {
+        Ok(Some(_)) => {
+            return json_error(StatusCode::CONFLICT, "A map with that name already exists")
+                .into_response()
+        }
+        Ok(None) => {}
+        Err(e) => return internal_error(e).into_response(),
+    }
+
+    let mut world = match parse_map_upload(&req.data, req.format.as_deref()) {
+        Ok(w) => w,
+        Err(e) => {
+            return json_error(StatusCode::BAD_REQUEST, &format!("Invalid map: {}", e))
+                .into_response()
+        }
+    };
+    world.metadata.insert("name".to_string(), req.name.clone());
+
+    let description = req.description.unwrap_or_default();
+    match state
+        .db
+        .create_custom_map(
+            auth.0.sub,
+            &req.name,
+            &description,
+            &visibility,
+            (world.width as i32, world.height as i32),
+            &world.to_json(),
+        )
+        .await
+    {
+        Ok(map) => (StatusCode::CREATED, Json(json!(map))).into_response(),
+        // Lost a race with another upload of the same name
+        Err(e) if is_unique_violation(&e) => {
+            json_error(StatusCode::CONFLICT, "A map with that name already exists").into_response()
+        }
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+async fn get_map(
+    State(state): State<AppState>,
+    auth: OptionalAuthUser,
+    Path(name): Path<String>,
+    Query(params): Query<GetMapParams>,
+) -> impl IntoResponse {
+    let viewer_id = auth.0.map(|c| c.sub);
+    let map = match find_visible_custom_map(&state, &name, viewer_id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => return json_error(StatusCode::NOT_FOUND, "Map not found").into_response(),
+        Err(e) => return internal_error(e).into_response(),
+    };
+    match state.db.get_custom_map_version(map.id, params.version).await {
+        Ok(Some(v)) => (
+            StatusCode::OK,
+            Json(json!({
+                "map": map,
+                "version": v.version,
+                "data": v.data,
+            })),
+        )
+            .into_response(),
+        Ok(None) => json_error(StatusCode::NOT_FOUND, "Map version not found").into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+async fn update_map(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(name): Path<String>,
+    Json(req): Json<UpdateMapRequest>,
+) -> impl IntoResponse {
+    let map = match state.db.get_custom_map_by_name(&name).await {
+        Ok(Some(m)) => m,
+        Ok(None) => return json_error(StatusCode::NOT_FOUND, "Map not found").into_response(),
+        Err(e) => return internal_error(e).into_response(),
+    };
+    if map.owner_id != auth.0.sub {
+        return json_error(StatusCode::FORBIDDEN, "You do not own this map").into_response();
+    }
+    let visibility = req.visibility.unwrap_or(map.visibility);
+    if let Err(msg) = validate_visibility(&visibility) {
+        return json_error(StatusCode::BAD_REQUEST, msg).into_response();
+    }
+
+    // Validate new contents before changing anything
+    let world = match req.data.as_deref() {
+        Some(data) => match parse_map_upload(data, req.format.as_deref()) {
+            Ok(mut w) => {
+                w.metadata.insert("name".to_string(), map.name.clone());
+                Some(w)
+            }
+            Err(e) => {
+                return json_error(StatusCode::BAD_REQUEST, &format!("Invalid map: {}", e))
+                    .into_response()
+            }
+        },
+        None => None,
+    };
+
+    let description = req.description.unwrap_or(map.description);
+    let data = world.as_ref().map(|w| w.to_json());
+    let contents = world
+        .as_ref()
+        .zip(data.as_deref())
+        .map(|(w, data)| ((w.width as i32, w.height as i32), data));
+    match state
+        .db
+        .update_custom_map(map.id, &description, &visibility, contents)
+        .await
+    {
+        Ok(Some(map)) => (StatusCode::OK, Json(json!(map))).into_response(),
+        Ok(None) => json_error(StatusCode::NOT_FOUND, "Map not found").into_response(),
+        Err(e) if is_unique_violation(&e) => json_error(
+            StatusCode::CONFLICT,
+            "The map was changed by another request; try again",
+        )
+        .into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+async fn get_map_analysis(
+    State(state): State<AppState>,
+    auth: OptionalAuthUser,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let world = match server::load_map(&state.maps_dir, &name) {
+        Ok(w) => w,
+        Err(_) => {
+            let viewer_id = auth.0.map(|c| c.sub);
+            match find_visible_custom_map(&state, &name, viewer_id).await {
+                Ok(Some(_)) => match load_custom_map(&state.db, &name).await {
+                    Ok(Some(w)) => w,
+                    Ok(None) => {
+                        return json_error(StatusCode::NOT_FOUND, "Map not found").into_response()
+                    }
+                    Err(e) => {
+                        return json_error(StatusCode::INTERNAL_SERVER_ERROR, &e).into_response()
+                    }
+                },
+                Ok(None) => return json_error(StatusCode::NOT_FOUND, "Map not found").into_response(),
+                Err(e) => return internal_error(e).into_response(),
+            }
+        }
+    };
+    (StatusCode::OK, Json(json!(analysis::analyze(&world)))).into_response()
+}
+
+/// Reject the request if `map` names a private custom map the user doesn't own.
+async fn check_map_access(
+    state: &AppState,
+    map: Option<&str>,
+    user_id: i64,
+) -> Result<(), axum::response::Response> {
+    let Some(map) = map else {
+        return Ok(());
+    };
+    let (name, _) = split_map_version(map);
+    match state.db.get_custom_map_by_name(name).await {
+        Ok(Some(m)) if m.visibility != "public" && m.owner_id != user_id => {
+            Err(json_error(StatusCode::FORBIDDEN, "This map is private").into_response())
+        }
+        Ok(_) => Ok(()),
+        Err(e) => Err(internal_error(e).into_response()),
+    }
+}
+
+/// Split a custom map reference `name@version` into its parts.
+fn split_map_version(map: &str) -> (&str, Option<i32>) {
+    match map.rsplit_once('@') {
+        Some((name, v)) => match v.parse() {
+            Ok(v) => (name, Some(v)),
+            Err(_) => (map, None),
+        },
+        None => (map, None),
+    }
+}
+
+/// Load a custom map from the database. `name@version` pins a specific version.
+async fn load_custom_map(db: &Database, map: &str) -> Result<Option<World>, String> {
+    let (name, version) = split_map_version(map);
+    let custom = db
+        .get_custom_map_by_name(name)
+        .await
+        .map_err(|e| format!("Failed to load map '{}': {}", name, e))?;
+    let Some(custom) = custom else {
+        return Ok(None);
+    };
+    let data = db
+        .get_custom_map_version(custom.id, version)
+        .await
+        .map_err(|e| format!("Failed to load map '{}': {}", name, e))?
+        .ok_or_else(|| format!("Map '{}' has no version {}", name, version.unwrap_or(0)))?;
+    World::from_json(&data.data).map(Some)
+}
+
 /// Resolve an optional map name to a World.
-pub fn resolve_map(maps_dir: &std::path::Path, map: &Option<String>, map_params: Option<&MapParamsRequest>) -> Result<World, String> {
+///
+/// Map files in `maps_dir` take precedence; other names are looked up among
+/// the user-uploaded maps in the database.
+pub async fn resolve_map(
+    db: &Database,
+    maps_dir: &std::path::Path,
+    map: &Option<String>,
+    map_params: Option<&MapParamsRequest>,
+) -> Result<World, String> {
+    use crate::engine::symmetry::MapSymmetry;
     use crate::engine::world::RandomMapParams;
     use rand::seq::SliceRandom;
     match map.as_deref() {
@@ -1004,8 +1632,15 @@ pub fn resolve_map(maps_dir: &std::path::Path, map: &Option<String>, map_params:
                 if let Some(w) = mp.width { params.width = w.clamp(20, 150); }
                 if let Some(h) = mp.height { params.height = h.clamp(20, 150); }
                 if let Some(f) = mp.num_food_spots { params.num_food_spots = f.clamp(1, 200); }
+                if let Some(ref name) = mp.symmetry {
+                    params.symmetry = MapSymmetry::from_str_name(name, mp.radial_folds)
+                        .ok_or_else(|| format!("Unknown symmetry '{}'", name))?;
+                }
+            }
+            match map_params.and_then(|mp| mp.max_unfairness) {
+                Some(max) => Ok(analysis::generate_fair_random(&params, max)),
+                None => Ok(World::generate_random(params)),
             }
-            Ok(World::generate_random(params))
         }
         Some("random_pool") => {
             let available = server::list_maps(maps_dir);
@@ -1013,12 +1648,17 @@ pub fn resolve_map(maps_dir: &std::path::Path, map: &Option<String>, map_params:
                 // Fall back to generated if no map files exist
                 Ok(World::generate_random(RandomMapParams::default()))
             } else {
-                let mut rng = rand::thread_rng();
-                let chosen = available.choose(&mut rng).unwrap();
-                server::load_map(maps_dir, &chosen.name)
+                let chosen = {
+                    let mut rng = rand::thread_rng();
+                    available.choose(&mut rng).unwrap().name.clone()
+                };
+                server::load_map(maps_dir, &chosen)
             }
         }
-        Some(name) => server::load_map(maps_dir, name),
+        Some(name) => match server::load_map(maps_dir, name) {
+            Ok(world) => Ok(world),
+            Err(file_err) => load_custom_map(db, name).await?.ok_or(file_err),
+        },
     }
 }
 
@@ -1026,36 +1666,219 @@ pub fn resolve_map(maps_dir: &std::path::Path, map: &Option<String>, map_params:
 
 async fn leaderboard_1v1(
     State(state): State<AppState>,
-    Query(params): Query<PaginationParams>,
+    headers: HeaderMap,
+    Query(params): Query<LeaderboardParams>,
 ) -> impl IntoResponse {
     let limit = params.limit.unwrap_or(50).min(100);
     let offset = params.offset.unwrap_or(0).max(0);
-    match state.db.leaderboard_1v1(limit, offset).await {
-        Ok(entries) => (StatusCode::OK, Json(json!(entries))).into_response(),
+    let sort = params.sort.as_deref().unwrap_or("elo");
+    if sort != "elo" && sort != "glicko" {
+        return json_error(StatusCode::BAD_REQUEST, "sort must be 'elo' or 'glicko'").into_response();
+    }
+    let filter = match params.filter() {
+        Ok(f) => f,
+        Err(msg) => return json_error(StatusCode::BAD_REQUEST, msg).into_response(),
+    };
+    match state.db.leaderboard_1v1(limit, offset, sort, &filter).await {
+        Ok(entries) => json_with_etag(&headers, &entries),
         Err(e) => internal_error(e).into_response(),
     }
 }
 
 async fn leaderboard_ffa(
     State(state): State<AppState>,
-    Query(params): Query<PaginationParams>,
+    headers: HeaderMap,
+    Query(params): Query<LeaderboardParams>,
 ) -> impl IntoResponse {
     let limit = params.limit.unwrap_or(50).min(100);
     let offset = params.offset.unwrap_or(0).max(0);
-    match state.db.leaderboard_ffa(limit, offset).await {
-        Ok(entries) => (StatusCode::OK, Json(json!(entries))).into_response(),
+    let sort = params.sort.as_deref().unwrap_or("points");
+    if sort != "points" && sort != "skill" {
+        return json_error(StatusCode::BAD_REQUEST, "sort must be 'points' or 'skill'").into_response();
+    }
+    let filter = match params.filter() {
+        Ok(f) => f,
+        Err(msg) => return json_error(StatusCode::BAD_REQUEST, msg).into_response(),
+    };
+    match state.db.leaderboard_ffa(limit, offset, sort, &filter).await {
+        Ok(entries) => json_with_etag(&headers, &entries),
         Err(e) => internal_error(e).into_response(),
     }
 }
 
 async fn leaderboard_2v2(
     State(state): State<AppState>,
-    Query(params): Query<PaginationParams>,
+    headers: HeaderMap,
+    Query(params): Query<LeaderboardParams>,
 ) -> impl IntoResponse {
     let limit = params.limit.unwrap_or(50).min(100);
     let offset = params.offset.unwrap_or(0).max(0);
-    match state.db.leaderboard_2v2(limit, offset).await {
-        Ok(entries) => (StatusCode::OK, Json(json!(entries))).into_response(),
+    let sort = params.sort.as_deref().unwrap_or("elo");
+    if sort != "elo" && sort != "skill" {
+        return json_error(StatusCode::BAD_REQUEST, "sort must be 'elo' or 'skill'").into_response();
+    }
+    let filter = match params.filter() {
+        Ok(f) => f,
+        Err(msg) => return json_error(StatusCode::BAD_REQUEST, msg).into_response(),
+    };
+    match state.db.leaderboard_2v2(limit, offset, sort, &filter).await {
+        Ok(entries) => json_with_etag(&headers, &entries),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+// ── Season handlers ──────────────────────────────────────────────────
+
+async fn list_seasons(State(state): State<AppState>) -> impl IntoResponse {
+    match state.db.list_seasons().await {
+        Ok(seasons) => (StatusCode::OK, Json(json!(seasons))).into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+/// Final standings of a past season, or the live ones of the active season.
+async fn season_leaderboard(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(params): Query<LeaderboardParams>,
+) -> impl IntoResponse {
+    let limit = params.limit.unwrap_or(50).min(100);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let season = match state.db.get_season(id).await {
+        Ok(Some(s)) => s,
+        Ok(None) => return json_error(StatusCode::NOT_FOUND, "Season not found").into_response(),
+        Err(e) => return internal_error(e).into_response(),
+    };
+    match state.db.season_standings(&season, limit, offset).await {
+        Ok(entries) => json_with_etag(&headers, &entries),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+/// End the active season now and start the next one, lasting `?days=`
+/// (default `season::DEFAULT_SEASON_DAYS`).
+async fn rollover_season(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Query(params): Query<RolloverParams>,
+) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    let days = params.days.unwrap_or(crate::season::DEFAULT_SEASON_DAYS);
+    if days <= 0 {
+        return json_error(StatusCode::BAD_REQUEST, "days must be positive").into_response();
+    }
+    match state.db.rollover_season(days).await {
+        Ok(season) => (StatusCode::OK, Json(json!(season))).into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+/// Replay 1v1 match history to recompute every version's Glicko-2 rating.
+async fn backfill_glicko(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    match state.db.backfill_glicko().await {
+        Ok(rated) => (StatusCode::OK, Json(json!({ "versions_rated": rated }))).into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+async fn ladder_status(state: &AppState) -> axum::response::Response {
+    let maps = match state.db.list_ranked_maps().await {
+        Ok(m) => m,
+        Err(e) => return internal_error(e).into_response(),
+    };
+    let candidates = match state.db.ladder_candidates().await {
+        Ok(c) => c,
+        Err(e) => return internal_error(e).into_response(),
+    };
+    (
+        StatusCode::OK,
+        Json(json!({
+            "paused": crate::matchmaker::is_paused(),
+            "maps": maps,
+            "eligible_versions": candidates.len(),
+        })),
+    )
+        .into_response()
+}
+
+/// Ladder matchmaker state: pause switch, ranked map pool, eligible versions.
+async fn get_ladder(State(state): State<AppState>, auth: AuthUser) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    ladder_status(&state).await
+}
+
+/// Pause or resume the ladder matchmaker and/or replace the ranked map pool.
+async fn update_ladder(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(req): Json<UpdateLadderRequest>,
+) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    if let Some(maps) = &req.maps {
+        let files = server::list_maps(&state.maps_dir);
+        for name in maps {
+            if name == "random" || name == "random_pool" || files.iter().any(|f| &f.name == name) {
+                continue;
+            }
+            match state.db.get_custom_map_by_name(name).await {
+                Ok(Some(m)) if m.visibility == "public" => {}
+                Ok(_) => {
+                    return json_error(
+                        StatusCode::BAD_REQUEST,
+                        &format!("Map '{name}' not found or not public"),
+                    )
+                    .into_response();
+                }
+                Err(e) => return internal_error(e).into_response(),
+            }
+        }
+        if let Err(e) = state.db.set_ranked_maps(maps).await {
+            return internal_error(e).into_response();
+        }
+    }
+    if let Some(paused) = req.paused {
+        crate::matchmaker::set_paused(paused);
+    }
+    ladder_status(&state).await
+}
+
+/// Reset all ratings and replay every finished match. `?dry_run=true` only
+/// reports how the leaderboard would change.
+async fn recompute_ratings(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Query(params): Query<RecomputeParams>,
+) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    match state.db.recompute_ratings(params.dry_run.unwrap_or(false)).await {
+        Ok(report) => (StatusCode::OK, Json(report)).into_response(),
+        Err(e) => internal_error(e).into_response(),
+    }
+}
+
+/// Rebuild the materialized leaderboard stats from match history now
+/// instead of waiting for the periodic rebuild.
+async fn rebuild_leaderboards(State(state): State<AppState>, auth: AuthUser) -> impl IntoResponse {
+    if auth.0.role != "admin" {
+        return json_error(StatusCode::FORBIDDEN, "Admin access required").into_response();
+    }
+    match state.db.rebuild_leaderboard_stats().await {
+        Ok(rows) => (StatusCode::OK, Json(json!({ "rows": rows }))).into_response(),
         Err(e) => internal_error(e).into_response(),
     }
 }
@@ -1068,8 +1891,20 @@ async fn validate_lua(
 ) -> impl IntoResponse {
     let result = tokio::task::spawn_blocking(move || {
         let lua = mlua::Lua::new();
+        let api = ApiVersion::detect(&req.code);
         match lua.load(&req.code).set_name("user_bot").into_function() {
-            Ok(_) => serde_json::json!({ "valid": true }),
+            Ok(_) => match api.check() {
+                Ok(warning) => serde_json::json!({
+                    "valid": true,
+                    "api_type": api.style.as_str(),
+                    "api_level": api.level,
+                    "warnings": warning.into_iter().collect::<Vec<_>>(),
+                }),
+                Err(e) => {
+                    metrics::BOT_VALIDATION_FAILURES_TOTAL.inc();
+                    serde_json::json!({ "valid": false, "error": e })
+                }
+            },
             Err(e) => {
                 metrics::BOT_VALIDATION_FAILURES_TOTAL.inc();
                 serde_json::json!({ "valid": false, "error": e.to_string() })
@@ -1145,9 +1980,12 @@ async fn mark_notification_read(
 
 async fn start_game(
     State(state): State<AppState>,
-    _auth: AuthUser,
+    auth: AuthUser,
     Json(req): Json<StartGameRequest>,
 ) -> impl IntoResponse {
+    if let Err(resp) = check_map_access(&state, req.map.as_deref(), auth.0.sub).await {
+        return resp;
+    }
     let headless_early = req.headless.unwrap_or(false);
     if !headless_early && state.game_server.is_running() {
         return json_error(StatusCode::CONFLICT, "A game is already running").into_response();
@@ -1186,11 +2024,12 @@ async fn start_game(
         bot_version_ids.push(p.bot_version_id);
         players.push(PlayerEntry {
             name,
+            api: ApiVersion::from_stored(&version.code, version.api_level),
             code: version.code,
         });
     }
 
-    let world = match resolve_map(&state.maps_dir, &req.map, req.map_params.as_ref()) {
+    let world = match resolve_map(&state.db, &state.maps_dir, &req.map, req.map_params.as_ref()).await {
         Ok(w) => w,
         Err(e) => {
             return json_error(StatusCode::BAD_REQUEST, &format!("Invalid map: {}", e))
@@ -1201,7 +2040,8 @@ async fn start_game(
     // Create match record in DB
     let map_name = req.map.clone().unwrap_or_else(|| "random".to_string());
     let format = if players.len() == 2 { "1v1" } else { "ffa" };
-    let m = match state.db.create_match(format, &map_name).await {
+    // Hand-picked games are never rated; ranked games go through challenges
+    let m = match state.db.create_match(format, &map_name, false).await {
         Ok(m) => m,
         Err(e) => return internal_error(e).into_response(),
     };
@@ -1366,6 +2206,9 @@ async fn list_my_matches(
 
 // ── Challenge handler ────────────────────────────────────────────────
 
+/// Ranked games allowed per day between the same two bots.
+const MAX_RANKED_PAIR_GAMES_PER_DAY: i64 = 5;
+
 async fn create_challenge(
     State(state): State<AppState>,
     auth: AuthUser,
@@ -1382,6 +2225,9 @@ async fn create_challenge(
         return json_error(StatusCode::BAD_REQUEST, "format must be '1v1' or 'ffa'")
             .into_response();
     }
+    if let Err(resp) = check_map_access(&state, req.map.as_deref(), user_id).await {
+        return resp;
+    }
 
     // Validate both bot versions exist
     let version_a = match state.db.get_bot_version_by_id(req.bot_version_id).await {
@@ -1422,6 +2268,84 @@ async fn create_challenge(
         _ => format!("Bot v{}", version_b.version),
     };
 
+    let ranked = req.ranked.unwrap_or(false);
+    if ranked {
+        if version_a.bot_id == version_b.bot_id {
+            return json_error(
+                StatusCode::BAD_REQUEST,
+                "A bot cannot play a ranked game against itself",
+            )
+            .into_response();
+        }
+        // Only the challenger's owner can put it into rated games
+        match state.db.get_bot(version_a.bot_id).await {
+            Ok(Some(b)) if b.owner_id == Some(user_id) => {}
+            Ok(_) => {
+                return json_error(
+                    StatusCode::FORBIDDEN,
+                    "Ranked challenges must be issued with your own bot",
+                )
+                .into_response();
+            }
+            Err(e) => return internal_error(e).into_response(),
+        }
+        for v in [&version_a, &version_b] {
+            match state.db.get_bot(v.bot_id).await {
+                Ok(Some(b)) if b.active_version_id == Some(v.id) => {}
+                Ok(_) => {
+                    return json_error(
+                        StatusCode::BAD_REQUEST,
+                        &format!(
+                            "Ranked challenges require active versions; bot version {} is not active",
+                            v.id
+                        ),
+                    )
+                    .into_response();
+                }
+                Err(e) => return internal_error(e).into_response(),
+            }
+        }
+    }
+
+    // Ranked games across leagues are rated only for the Newcomer side,
+    // whichever side issued the challenge
+    let mut rated_sides = (true, true);
+    if ranked {
+        rated_sides = match (
+            state.db.bot_league(version_a.bot_id).await,
+            state.db.bot_league(version_b.bot_id).await,
+        ) {
+            (Ok(a), Ok(b)) => crate::league::rated_sides(a, b),
+            (Err(e), _) | (_, Err(e)) => return internal_error(e).into_response(),
+        };
+    }
+
+    // Ranked games between the same two bots are capped so a pairing can't be
+    // farmed. Counted from the matches table, before any of the user's quota is
+    // spent, and again when the match is created.
+    let pair_capped = ranked && !crate::config::is_local_mode();
+    let pair_cap_error = || {
+        json_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            &format!(
+                "Rate limit exceeded: max {MAX_RANKED_PAIR_GAMES_PER_DAY} ranked games \
+                 per day between the same two bots"
+            ),
+        )
+        .into_response()
+    };
+    if pair_capped {
+        match state
+            .db
+            .count_ranked_pair_matches(version_a.bot_id, version_b.bot_id, 1)
+            .await
+        {
+            Ok(n) if n >= MAX_RANKED_PAIR_GAMES_PER_DAY => return pair_cap_error(),
+            Ok(_) => {}
+            Err(e) => return internal_error(e).into_response(),
+        }
+    }
+
     // Check rate limits
     let limit_type = if headless {
         RateLimitType::HeadlessChallenges
@@ -1438,32 +2362,49 @@ async fn create_challenge(
             .rate_limiter
             .check_limit(user_id, RateLimitType::LiveGames)
         {
+            state.rate_limiter.release(user_id, limit_type);
             return json_error(StatusCode::TOO_MANY_REQUESTS, &e.to_string()).into_response();
         }
     }
 
-    // Create match record in DB
+    // Create the match record with its participants; a failure refunds the
+    // user's slots
     let map_name = req.map.clone().unwrap_or_else(|| "random".to_string());
-    let m = match state.db.create_match(&format, &map_name).await {
-        Ok(m) => m,
-        Err(e) => return internal_error(e).into_response(),
+    let participants = [
+        (req.bot_version_id, rated_sides.0),
+        (req.opponent_bot_version_id, rated_sides.1),
+    ];
+    let created = if pair_capped {
+        state
+            .db
+            .create_capped_ranked_match(
+                &format,
+                &map_name,
+                &participants,
+                (version_a.bot_id, version_b.bot_id),
+                MAX_RANKED_PAIR_GAMES_PER_DAY,
+            )
+            .await
+    } else {
+        state
+            .db
+            .create_match_with_participants(&format, &map_name, ranked, &participants, None)
+            .await
+            .map(Some)
+    };
+    let m = match created {
+        Ok(Some(m)) => m,
+        other => {
+            state.rate_limiter.release(user_id, limit_type);
+            if !headless {
+                state.rate_limiter.release(user_id, RateLimitType::LiveGames);
+            }
+            return match other {
+                Err(e) => internal_error(e).into_response(),
+                _ => pair_cap_error(),
+            };
+        }
     };
-
-    // Add participants
-    if let Err(e) = state
-        .db
-        .add_match_participant(m.id, req.bot_version_id, 0)
-        .await
-    {
-        return internal_error(e).into_response();
-    }
-    if let Err(e) = state
-        .db
-        .add_match_participant(m.id, req.opponent_bot_version_id, 1)
-        .await
-    {
-        return internal_error(e).into_response();
-    }
 
     if headless {
         // Queue headless challenge via DB
@@ -1518,7 +2459,7 @@ async fn create_challenge(
     }
 
     // Resolve map
-    let world = match resolve_map(&state.maps_dir, &req.map, None) {
+    let world = match resolve_map(&state.db, &state.maps_dir, &req.map, None).await {
         Ok(w) => w,
         Err(e) => {
             return json_error(StatusCode::BAD_REQUEST, &format!("Invalid map: {}", e))
@@ -1529,10 +2470,12 @@ async fn create_challenge(
     let players = vec![
         PlayerEntry {
             name: bot_a_name.clone(),
+            api: ApiVersion::from_stored(&version_a.code, version_a.api_level),
             code: version_a.code,
         },
         PlayerEntry {
             name: bot_b_name.clone(),
+            api: ApiVersion::from_stored(&version_b.code, version_b.api_level),
             code: version_b.code,
         },
     ];
@@ -270,6 +270,7 @@ pub struct UserPublic {
     pub email: String,
     pub display_name: Option<String>,
     pub role: String,
+    pub league: String,
     pub created_at: String,
 }
 
@@ -341,6 +342,7 @@ pub async fn register(
                         email: user.email,
                         display_name: user.display_name,
                         role: user.role,
+                        league: user.league,
                         created_at: user.created_at,
                     },
                 })),
@@ -439,6 +441,7 @@ pub async fn login(
                 email: user.email,
                 display_name: user.display_name,
                 role: user.role,
+                league: user.league,
                 created_at: user.created_at,
             },
         })),
@@ -469,6 +472,7 @@ pub async fn update_profile(
                 email: user.email,
                 display_name: user.display_name,
                 role: user.role,
+                league: user.league,
                 created_at: user.created_at,
             })),
         )
@@ -527,6 +531,7 @@ pub async fn local_login(State(db): State<Arc<Database>>) -> impl IntoResponse {
                         email: user.email,
                         display_name: user.display_name,
                         role: user.role,
+                        league: user.league,
                         created_at: user.created_at,
                     },
                 })),
@@ -570,6 +575,7 @@ pub async fn me(AuthUser(claims): AuthUser, State(db): State<Arc<Database>>) ->
                 email: user.email,
                 display_name: user.display_name,
                 role: user.role,
+                league: user.league,
                 created_at: user.created_at,
             })),
         )
@@ -20,6 +20,20 @@ pub struct Config {
     pub worker_count: usize,
     /// Interval in milliseconds between queue polls.
     pub queue_poll_ms: u64,
+    /// Seconds between ladder matchmaking rounds.
+    pub ladder_interval_secs: u64,
+    /// Ranked matches the ladder schedules per round (0 disables it).
+    pub ladder_matches_per_round: usize,
+    /// The ladder stops scheduling while this many games are pending.
+    pub ladder_max_pending: i64,
+    /// Start with the ladder matchmaker paused.
+    pub ladder_paused: bool,
+    /// Length of a competitive season in days (0 disables seasons).
+    pub season_days: i64,
+    /// Recompute all ratings from match history, print the report and exit.
+    pub recompute_ratings: bool,
+    /// With `recompute_ratings`, only report what would change.
+    pub dry_run: bool,
 }
 
 impl Config {
@@ -31,10 +45,17 @@ impl Config {
     /// - `MAPS_DIR` - Path to maps directory (default: `../data/maps`)
     /// - `INFON_LOCAL_MODE` - Set to `true` to enable local mode
     /// - `STATIC_DIR` - Path to frontend dist directory for static file serving
+    /// - `INFON_LADDER_INTERVAL_SECS` - Seconds between ladder rounds (default: 300)
+    /// - `INFON_LADDER_MATCHES_PER_ROUND` - Ladder matches per round, 0 disables (default: 4)
+    /// - `INFON_LADDER_MAX_PENDING` - Skip ladder rounds at this queue depth (default: 20)
+    /// - `INFON_LADDER_PAUSED` - Set to `true` to start with the ladder paused
+    /// - `INFON_SEASON_DAYS` - Season length in days, 0 disables seasons (default: 90)
     ///
     /// CLI flags:
     /// - `--local` - Enable local mode (same as `INFON_LOCAL_MODE=true`)
     /// - `--port <PORT>` - Override the port
+    /// - `--recompute-ratings` - Replay match history into fresh ratings and exit
+    /// - `--dry-run` - With `--recompute-ratings`, print the diff without writing
     pub fn load() -> Self {
         let args: Vec<String> = std::env::args().collect();
 
@@ -68,6 +89,33 @@ impl Config {
             .and_then(|v| v.parse().ok())
             .unwrap_or(1000);
 
+        let ladder_interval_secs = std::env::var("INFON_LADDER_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(300);
+
+        let ladder_matches_per_round = std::env::var("INFON_LADDER_MATCHES_PER_ROUND")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(4);
+
+        let ladder_max_pending = std::env::var("INFON_LADDER_MAX_PENDING")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(20);
+
+        let ladder_paused = std::env::var("INFON_LADDER_PAUSED")
+            .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+            .unwrap_or(false);
+
+        let season_days = std::env::var("INFON_SEASON_DAYS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(crate::season::DEFAULT_SEASON_DAYS);
+
+        let recompute_ratings = args.contains(&"--recompute-ratings".to_string());
+        let dry_run = args.contains(&"--dry-run".to_string());
+
         Config {
             database_url,
             port,
@@ -76,6 +124,13 @@ impl Config {
             static_dir,
             worker_count,
             queue_poll_ms,
+            ladder_interval_secs,
+            ladder_matches_per_round,
+            ladder_max_pending,
+            ladder_paused,
+            season_days,
+            recompute_ratings,
+            dry_run,
         }
     }
 
@@ -13,6 +13,36 @@ where
     serializer.serialize_bool(*val != 0)
 }
 
+/// Conflict clause adding a new result to an existing `leaderboard_stats` row.
+const LEADERBOARD_UPSERT: &str = "ON CONFLICT (format, entry_id, day, map) DO UPDATE SET
+    games_played = leaderboard_stats.games_played + excluded.games_played,
+    wins = leaderboard_stats.wins + excluded.wins,
+    losses = leaderboard_stats.losses + excluded.losses,
+    points = leaderboard_stats.points + excluded.points";
+
+/// Data migration replaying history into the Glicko-2 columns added after
+/// versions had already played.
+pub const GLICKO_BACKFILL: &str = "glicko_backfill";
+
+
+/// Parse a timestamp or bare date (ISO `T` or space separated, optional
+/// fraction and offset, the latter converted to UTC) into the database's
+/// `YYYY-MM-DD HH:MM:SS` text format so it compares correctly as a string.
+pub fn normalize_timestamp(at: &str) -> Option<String> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime};
+    let at = at.trim();
+    let utc = DateTime::parse_from_rfc3339(at)
+        .or_else(|_| DateTime::parse_from_str(at, "%Y-%m-%d %H:%M:%S%.f%#z"))
+        .map(|t| t.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(at, "%Y-%m-%dT%H:%M:%S%.f"))
+        .or_else(|_| NaiveDateTime::parse_from_str(at, "%Y-%m-%d %H:%M:%S%.f"))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(at, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap())
+        })
+        .ok()?;
+    Some(utc.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct User {
     pub id: i64,
@@ -23,6 +53,8 @@ pub struct User {
     pub avatar_url: Option<String>,
     pub bio: Option<String>,
     pub role: String,
+    /// "newcomer" or "open" (see `crate::league`).
+    pub league: String,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -35,6 +67,9 @@ pub struct Bot {
     pub owner_id: Option<i64>,
     pub visibility: String,
     pub active_version_id: Option<i64>,
+    /// Opted into automatic ladder matches (see `crate::matchmaker`).
+    #[serde(serialize_with = "serialize_int_as_bool")]
+    pub ladder: i32,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -62,6 +97,8 @@ pub struct BotVersion {
     pub version: i32,
     pub code: String,
     pub api_type: String,
+    /// Lua API level the code was written against (see `engine::player::ApiVersion`).
+    pub api_level: i32,
     /// Stored as INTEGER (0/1) for cross-database compatibility with the Any driver.
     /// Serialized as boolean for API consumers.
     #[serde(serialize_with = "serialize_int_as_bool")]
@@ -72,12 +109,27 @@ pub struct BotVersion {
     pub elo_rating: i32,
     pub elo_1v1: i32,
     pub elo_peak: i32,
+    /// Glicko-2 1v1 rating, kept alongside `elo_1v1` (see `crate::glicko`).
+    pub glicko_rating: f64,
+    pub glicko_rd: f64,
+    pub glicko_volatility: f64,
+    /// Weng-Lin skill from FFA games (see `crate::openskill`).
+    pub ffa_mu: f64,
+    pub ffa_sigma: f64,
+    /// Weng-Lin skill from 2v2 games, as a member of any team.
+    pub team_mu: f64,
+    pub team_sigma: f64,
     pub games_played: i32,
     pub wins: i32,
     pub losses: i32,
     pub draws: i32,
     pub ffa_placement_points: i32,
     pub ffa_games: i32,
+    /// Results of unranked games, kept out of `games_played` and the ratings.
+    pub unranked_games: i32,
+    pub unranked_wins: i32,
+    pub unranked_losses: i32,
+    pub unranked_draws: i32,
     pub creatures_spawned: i32,
     pub creatures_killed: i32,
     pub creatures_lost: i32,
@@ -85,6 +137,30 @@ pub struct BotVersion {
     pub created_at: String,
 }
 
+impl BotVersion {
+    pub fn glicko(&self) -> crate::glicko::Glicko2Rating {
+        crate::glicko::Glicko2Rating {
+            rating: self.glicko_rating,
+            deviation: self.glicko_rd,
+            volatility: self.glicko_volatility,
+        }
+    }
+
+    pub fn ffa_skill(&self) -> crate::openskill::SkillRating {
+        crate::openskill::SkillRating {
+            mu: self.ffa_mu,
+            sigma: self.ffa_sigma,
+        }
+    }
+
+    pub fn team_skill(&self) -> crate::openskill::SkillRating {
+        crate::openskill::SkillRating {
+            mu: self.team_mu,
+            sigma: self.team_sigma,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Match {
     pub id: i64,
@@ -92,6 +168,9 @@ pub struct Match {
     pub map: String,
     pub status: String,
     pub winner_bot_version_id: Option<i64>,
+    /// Whether the result counts towards ratings and leaderboards.
+    #[serde(serialize_with = "serialize_int_as_bool")]
+    pub ranked: i32,
     pub created_at: String,
     pub finished_at: Option<String>,
 }
@@ -109,6 +188,10 @@ pub struct MatchParticipant {
     pub creatures_spawned: i32,
     pub creatures_killed: i32,
     pub creatures_lost: i32,
+    /// 0 when this side of a ranked match doesn't count for ratings (an Open
+    /// bot challenged by a newcomer).
+    #[serde(serialize_with = "serialize_int_as_bool")]
+    pub rated: i32,
     pub bot_name: Option<String>,
     pub owner_name: Option<String>,
 }
@@ -133,7 +216,14 @@ pub struct TournamentStanding {
     pub total_score: i64,
     pub matches_played: i32,
     pub wins: i32,
+    pub draws: i32,
     pub losses: i32,
+    pub byes: i32,
+    /// Swiss score: 1 per win or bye, 0.5 per draw.
+    pub points: f64,
+    pub buchholz: f64,
+    pub sonneborn_berger: f64,
+    pub head_to_head: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -194,11 +284,114 @@ pub struct LeaderboardEntry {
     pub bot_name: String,
     pub version: i32,
     pub owner_username: String,
+    /// League of the owner ("newcomer" or "open").
+    pub league: String,
     pub rating: i32,
     pub games_played: i32,
     pub wins: i32,
     pub losses: i32,
     pub win_rate: f64,
+    /// Half-width of the interval around the skill rating: 95% Glicko-2 for
+    /// 1v1, 3 sigma Weng-Lin for FFA and 2v2.
+    #[sqlx(default)]
+    pub confidence: Option<i32>,
+    /// Too few games for the skill rating to be trusted.
+    #[sqlx(default)]
+    #[serde(serialize_with = "serialize_int_as_bool")]
+    pub provisional: i32,
+    /// Elo gained or lost over the window, on windowed 1v1 Elo boards.
+    #[sqlx(default)]
+    pub rating_change: Option<i32>,
+}
+
+/// Which games and rows a leaderboard covers. The default is the all-time
+/// board listing every version.
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardFilter {
+    /// Drop rows whose skill rating is provisional.
+    pub hide_provisional: bool,
+    pub league: Option<crate::league::League>,
+    /// Only count ranked games finished in the last this many days.
+    pub period_days: Option<i64>,
+    /// Only count ranked games played on this map.
+    pub map: Option<String>,
+    /// Owner's username.
+    pub owner: Option<String>,
+    /// Only each bot's active version (each team's latest version on 2v2).
+    pub active_only: bool,
+    /// One row per bot (per team on 2v2): its best-ranked version.
+    pub per_bot: bool,
+}
+
+impl LeaderboardFilter {
+    /// Results come from match history instead of the all-time totals kept
+    /// on each version. Ratings are always the current ones.
+    pub fn is_scoped(&self) -> bool {
+        self.period_days.is_some() || self.map.is_some()
+    }
+}
+
+/// Board-specific SQL for `Database::version_leaderboard`.
+struct VersionBoard<'a> {
+    format: &'a str,
+    rating: &'a str,
+    sort_key: &'a str,
+    confidence: &'a str,
+    /// Deviation/sigma column compared against `provisional_limit`.
+    uncertainty: &'a str,
+    provisional_limit: f64,
+    /// Extra `... AS rating_change,` column.
+    rating_change: Option<&'a str>,
+}
+
+/// A competitive season; the active one has no `finished_at`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Season {
+    pub id: i64,
+    pub name: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub finished_at: Option<String>,
+    pub created_at: String,
+    /// Top of the archived standings, once the season is over.
+    #[sqlx(default)]
+    pub champion_bot_name: Option<String>,
+    #[sqlx(default)]
+    pub champion_owner: Option<String>,
+    #[sqlx(default)]
+    pub champion_rating: Option<i32>,
+}
+
+/// A version's place on a season's 1v1 board. Games, wins and peak only
+/// count ranked 1v1 games played during the season.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SeasonStanding {
+    pub rank: i64,
+    pub bot_version_id: i64,
+    pub bot_name: String,
+    pub version: i32,
+    pub owner_username: String,
+    /// Elo at the end of the season (current Elo for the active one).
+    pub rating: i32,
+    pub peak_rating: i32,
+    pub games_played: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+}
+
+/// One rated 1v1 match, or season reset, for a bot version.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RatingHistoryEntry {
+    pub id: i64,
+    pub bot_version_id: i64,
+    /// `None` for a season reset, which also has no opponent.
+    pub match_id: Option<i64>,
+    pub rating_before: i32,
+    pub rating_after: i32,
+    pub opponent_version_id: Option<i64>,
+    pub opponent_rating: Option<i32>,
+    pub created_at: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -294,9 +487,38 @@ pub struct GameQueueStatus {
     pub total: i64,
 }
 
+/// A user-uploaded map. The tile data lives in `map_versions`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustomMap {
+    pub id: i64,
+    pub owner_id: i64,
+    pub name: String,
+    pub description: String,
+    pub visibility: String,
+    pub width: i32,
+    pub height: i32,
+    pub version: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// One stored revision of a custom map, in the JSON map format.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustomMapVersion {
+    pub id: i64,
+    pub map_id: i64,
+    pub version: i32,
+    pub data: String,
+    pub created_at: String,
+}
+
 pub struct Database {
     pool: AnyPool,
     is_postgres: bool,
+    /// Held shared by a game completion while it reads and writes ratings,
+    /// and exclusively by the jobs that rewrite all of them, so a completion
+    /// can't write back ratings it read before a reset or replay.
+    rating_updates: tokio::sync::RwLock<()>,
 }
 
 impl Database {
@@ -317,7 +539,11 @@ impl Database {
             .max_connections(max_conn)
             .connect(database_url)
             .await?;
-        let db = Self { pool, is_postgres };
+        let db = Self {
+            pool,
+            is_postgres,
+            rating_updates: tokio::sync::RwLock::new(()),
+        };
         db.run_migrations().await?;
         Ok(db)
     }
@@ -354,11 +580,14 @@ impl Database {
                 owner_id BIGINT REFERENCES users(id),
                 visibility TEXT NOT NULL DEFAULT 'public',
                 active_version_id BIGINT,
+                ladder INTEGER NOT NULL DEFAULT 0,
                 created_at TEXT NOT NULL DEFAULT (now()::text),
                 updated_at TEXT NOT NULL DEFAULT (now()::text)
             )
         "#).await?;
 
+        let _ = self.exec("ALTER TABLE bots ADD COLUMN ladder INTEGER NOT NULL DEFAULT 0").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS bot_versions (
                 id BIGSERIAL PRIMARY KEY,
@@ -366,17 +595,29 @@ impl Database {
                 version INTEGER NOT NULL,
                 code TEXT NOT NULL,
                 api_type TEXT NOT NULL DEFAULT 'oo',
+                api_level INTEGER NOT NULL DEFAULT 1,
                 is_archived INTEGER NOT NULL DEFAULT 0,
                 is_faulty INTEGER NOT NULL DEFAULT 0,
                 elo_rating INTEGER NOT NULL DEFAULT 1500,
                 elo_1v1 INTEGER NOT NULL DEFAULT 1500,
                 elo_peak INTEGER NOT NULL DEFAULT 1500,
+                glicko_rating DOUBLE PRECISION NOT NULL DEFAULT 1500,
+                glicko_rd DOUBLE PRECISION NOT NULL DEFAULT 350,
+                glicko_volatility DOUBLE PRECISION NOT NULL DEFAULT 0.06,
+                ffa_mu DOUBLE PRECISION NOT NULL DEFAULT 1500,
+                ffa_sigma DOUBLE PRECISION NOT NULL DEFAULT 500,
+                team_mu DOUBLE PRECISION NOT NULL DEFAULT 1500,
+                team_sigma DOUBLE PRECISION NOT NULL DEFAULT 500,
                 games_played INTEGER NOT NULL DEFAULT 0,
                 wins INTEGER NOT NULL DEFAULT 0,
                 losses INTEGER NOT NULL DEFAULT 0,
                 draws INTEGER NOT NULL DEFAULT 0,
                 ffa_placement_points INTEGER NOT NULL DEFAULT 0,
                 ffa_games INTEGER NOT NULL DEFAULT 0,
+                unranked_games INTEGER NOT NULL DEFAULT 0,
+                unranked_wins INTEGER NOT NULL DEFAULT 0,
+                unranked_losses INTEGER NOT NULL DEFAULT 0,
+                unranked_draws INTEGER NOT NULL DEFAULT 0,
                 creatures_spawned INTEGER NOT NULL DEFAULT 0,
                 creatures_killed INTEGER NOT NULL DEFAULT 0,
                 creatures_lost INTEGER NOT NULL DEFAULT 0,
@@ -386,6 +627,21 @@ impl Database {
             )
         "#).await?;
 
+        // Versions stored before API levels existed were written against level 1
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN api_level INTEGER NOT NULL DEFAULT 1").await;
+        // Glicko-2 columns; existing versions are seeded by `backfill_glicko`
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN glicko_rating DOUBLE PRECISION NOT NULL DEFAULT 1500").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN glicko_rd DOUBLE PRECISION NOT NULL DEFAULT 350").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN glicko_volatility DOUBLE PRECISION NOT NULL DEFAULT 0.06").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN ffa_mu DOUBLE PRECISION NOT NULL DEFAULT 1500").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN ffa_sigma DOUBLE PRECISION NOT NULL DEFAULT 500").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN team_mu DOUBLE PRECISION NOT NULL DEFAULT 1500").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN team_sigma DOUBLE PRECISION NOT NULL DEFAULT 500").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN unranked_games INTEGER NOT NULL DEFAULT 0").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN unranked_wins INTEGER NOT NULL DEFAULT 0").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN unranked_losses INTEGER NOT NULL DEFAULT 0").await;
+        let _ = self.exec("ALTER TABLE bot_versions ADD COLUMN unranked_draws INTEGER NOT NULL DEFAULT 0").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS matches (
                 id BIGSERIAL PRIMARY KEY,
@@ -393,11 +649,15 @@ impl Database {
                 map TEXT NOT NULL DEFAULT 'random',
                 status TEXT NOT NULL DEFAULT 'pending',
                 winner_bot_version_id BIGINT,
+                ranked INTEGER NOT NULL DEFAULT 1,
                 created_at TEXT NOT NULL DEFAULT (now()::text),
                 finished_at TEXT
             )
         "#).await?;
 
+        // Matches played before the flag existed all counted towards ratings
+        let _ = self.exec("ALTER TABLE matches ADD COLUMN ranked INTEGER NOT NULL DEFAULT 1").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS match_participants (
                 id BIGSERIAL PRIMARY KEY,
@@ -410,10 +670,13 @@ impl Database {
                 elo_after INTEGER,
                 creatures_spawned INTEGER NOT NULL DEFAULT 0,
                 creatures_killed INTEGER NOT NULL DEFAULT 0,
-                creatures_lost INTEGER NOT NULL DEFAULT 0
+                creatures_lost INTEGER NOT NULL DEFAULT 0,
+                rated INTEGER NOT NULL DEFAULT 1
             )
         "#).await?;
 
+        let _ = self.exec("ALTER TABLE match_participants ADD COLUMN rated INTEGER NOT NULL DEFAULT 1").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS tournaments (
                 id BIGSERIAL PRIMARY KEY,
@@ -460,6 +723,18 @@ impl Database {
             )
         "#).await?;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS tournament_byes (
+                id BIGSERIAL PRIMARY KEY,
+                tournament_id BIGINT NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+                bot_version_id BIGINT NOT NULL REFERENCES bot_versions(id),
+                round INTEGER NOT NULL,
+                UNIQUE(tournament_id, round)
+            )
+        "#).await?;
+        // Tables created before the constraint get it as an index
+        let _ = self.exec("CREATE UNIQUE INDEX IF NOT EXISTS idx_tournament_byes_round ON tournament_byes(tournament_id, round)").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS teams (
                 id BIGSERIAL PRIMARY KEY,
@@ -486,6 +761,30 @@ impl Database {
             )
         "#).await?;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS rating_history (
+                id BIGSERIAL PRIMARY KEY,
+                bot_version_id BIGINT NOT NULL REFERENCES bot_versions(id) ON DELETE CASCADE,
+                match_id BIGINT REFERENCES matches(id) ON DELETE CASCADE,
+                rating_before INTEGER NOT NULL,
+                rating_after INTEGER NOT NULL,
+                opponent_version_id BIGINT,
+                opponent_rating INTEGER,
+                created_at TEXT NOT NULL DEFAULT (now()::text)
+            )
+        "#).await?;
+
+        // Season resets are recorded without a match or opponent
+        let _ = self.exec("ALTER TABLE rating_history ALTER COLUMN match_id DROP NOT NULL").await;
+        let _ = self
+            .exec("ALTER TABLE rating_history ALTER COLUMN opponent_rating DROP NOT NULL")
+            .await;
+
+        self.exec(r#"
+            CREATE INDEX IF NOT EXISTS idx_rating_history_version
+            ON rating_history(bot_version_id, created_at)
+        "#).await?;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS replays (
                 id BIGSERIAL PRIMARY KEY,
@@ -558,6 +857,91 @@ impl Database {
         // Add map_params column to existing game_queue tables
         let _ = self.exec("ALTER TABLE game_queue ADD COLUMN map_params TEXT").await;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS maps (
+                id BIGSERIAL PRIMARY KEY,
+                owner_id BIGINT NOT NULL REFERENCES users(id),
+                name TEXT UNIQUE NOT NULL,
+                description TEXT NOT NULL DEFAULT '',
+                visibility TEXT NOT NULL DEFAULT 'public',
+                width INTEGER NOT NULL,
+                height INTEGER NOT NULL,
+                version INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL DEFAULT (now()::text),
+                updated_at TEXT NOT NULL DEFAULT (now()::text)
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS ranked_maps (
+                name TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL DEFAULT (now()::text)
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS seasons (
+                id BIGSERIAL PRIMARY KEY,
+                name TEXT NOT NULL,
+                starts_at TEXT NOT NULL,
+                ends_at TEXT NOT NULL,
+                finished_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (now()::text)
+            )
+        "#).await?;
+
+        // Names are copied so past standings survive deleted bots
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS season_standings (
+                id BIGSERIAL PRIMARY KEY,
+                season_id BIGINT NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+                rank BIGINT NOT NULL,
+                bot_version_id BIGINT NOT NULL,
+                bot_name TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                owner_username TEXT NOT NULL,
+                rating INTEGER NOT NULL,
+                peak_rating INTEGER NOT NULL,
+                games_played INTEGER NOT NULL,
+                wins INTEGER NOT NULL,
+                losses INTEGER NOT NULL,
+                draws INTEGER NOT NULL
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS leaderboard_stats (
+                format TEXT NOT NULL,
+                entry_id BIGINT NOT NULL,
+                day TEXT NOT NULL,
+                map TEXT NOT NULL,
+                games_played INTEGER NOT NULL DEFAULT 0,
+                wins INTEGER NOT NULL DEFAULT 0,
+                losses INTEGER NOT NULL DEFAULT 0,
+                points INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (format, entry_id, day, map)
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS map_versions (
+                id BIGSERIAL PRIMARY KEY,
+                map_id BIGINT NOT NULL REFERENCES maps(id) ON DELETE CASCADE,
+                version INTEGER NOT NULL,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (now()::text),
+                UNIQUE(map_id, version)
+            )
+        "#).await?;
+
+        // One-off data migrations that have run, so startup doesn't repeat them
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS data_migrations (
+                name TEXT PRIMARY KEY,
+                applied_at TEXT NOT NULL DEFAULT (now()::text)
+            )
+        "#).await?;
+
         Ok(())
     }
 
@@ -585,6 +969,7 @@ impl Database {
                 owner_id INTEGER REFERENCES users(id),
                 visibility TEXT NOT NULL DEFAULT 'public',
                 active_version_id INTEGER,
+                ladder INTEGER NOT NULL DEFAULT 0,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now'))
             )
@@ -594,6 +979,7 @@ impl Database {
         let _ = self.exec("ALTER TABLE bots ADD COLUMN owner_id INTEGER REFERENCES users(id)").await;
         let _ = self.exec("ALTER TABLE bots ADD COLUMN visibility TEXT NOT NULL DEFAULT 'public'").await;
         let _ = self.exec("ALTER TABLE bots ADD COLUMN active_version_id INTEGER").await;
+        let _ = self.exec("ALTER TABLE bots ADD COLUMN ladder INTEGER NOT NULL DEFAULT 0").await;
 
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS bot_versions (
@@ -602,17 +988,29 @@ impl Database {
                 version INTEGER NOT NULL,
                 code TEXT NOT NULL,
                 api_type TEXT NOT NULL DEFAULT 'oo',
+                api_level INTEGER NOT NULL DEFAULT 1,
                 is_archived INTEGER NOT NULL DEFAULT 0,
                 is_faulty INTEGER NOT NULL DEFAULT 0,
                 elo_rating INTEGER NOT NULL DEFAULT 1500,
                 elo_1v1 INTEGER NOT NULL DEFAULT 1500,
                 elo_peak INTEGER NOT NULL DEFAULT 1500,
+                glicko_rating REAL NOT NULL DEFAULT 1500.0,
+                glicko_rd REAL NOT NULL DEFAULT 350.0,
+                glicko_volatility REAL NOT NULL DEFAULT 0.06,
+                ffa_mu REAL NOT NULL DEFAULT 1500.0,
+                ffa_sigma REAL NOT NULL DEFAULT 500.0,
+                team_mu REAL NOT NULL DEFAULT 1500.0,
+                team_sigma REAL NOT NULL DEFAULT 500.0,
                 games_played INTEGER NOT NULL DEFAULT 0,
                 wins INTEGER NOT NULL DEFAULT 0,
                 losses INTEGER NOT NULL DEFAULT 0,
                 draws INTEGER NOT NULL DEFAULT 0,
                 ffa_placement_points INTEGER NOT NULL DEFAULT 0,
                 ffa_games INTEGER NOT NULL DEFAULT 0,
+                unranked_games INTEGER NOT NULL DEFAULT 0,
+                unranked_wins INTEGER NOT NULL DEFAULT 0,
+                unranked_losses INTEGER NOT NULL DEFAULT 0,
+                unranked_draws INTEGER NOT NULL DEFAULT 0,
                 creatures_spawned INTEGER NOT NULL DEFAULT 0,
                 creatures_killed INTEGER NOT NULL DEFAULT 0,
                 creatures_lost INTEGER NOT NULL DEFAULT 0,
@@ -624,17 +1022,29 @@ impl Database {
 
         // Add new columns to existing bot_versions if missing
         for col in &[
+            "api_level INTEGER NOT NULL DEFAULT 1",
             "is_archived INTEGER NOT NULL DEFAULT 0",
             "is_faulty INTEGER NOT NULL DEFAULT 0",
             "elo_rating INTEGER NOT NULL DEFAULT 1500",
             "elo_1v1 INTEGER NOT NULL DEFAULT 1500",
             "elo_peak INTEGER NOT NULL DEFAULT 1500",
+            "glicko_rating REAL NOT NULL DEFAULT 1500.0",
+            "glicko_rd REAL NOT NULL DEFAULT 350.0",
+            "glicko_volatility REAL NOT NULL DEFAULT 0.06",
+            "ffa_mu REAL NOT NULL DEFAULT 1500.0",
+            "ffa_sigma REAL NOT NULL DEFAULT 500.0",
+            "team_mu REAL NOT NULL DEFAULT 1500.0",
+            "team_sigma REAL NOT NULL DEFAULT 500.0",
             "games_played INTEGER NOT NULL DEFAULT 0",
             "wins INTEGER NOT NULL DEFAULT 0",
             "losses INTEGER NOT NULL DEFAULT 0",
             "draws INTEGER NOT NULL DEFAULT 0",
             "ffa_placement_points INTEGER NOT NULL DEFAULT 0",
             "ffa_games INTEGER NOT NULL DEFAULT 0",
+            "unranked_games INTEGER NOT NULL DEFAULT 0",
+            "unranked_wins INTEGER NOT NULL DEFAULT 0",
+            "unranked_losses INTEGER NOT NULL DEFAULT 0",
+            "unranked_draws INTEGER NOT NULL DEFAULT 0",
             "creatures_spawned INTEGER NOT NULL DEFAULT 0",
             "creatures_killed INTEGER NOT NULL DEFAULT 0",
             "creatures_lost INTEGER NOT NULL DEFAULT 0",
@@ -650,11 +1060,15 @@ impl Database {
                 map TEXT NOT NULL DEFAULT 'random',
                 status TEXT NOT NULL DEFAULT 'pending',
                 winner_bot_version_id INTEGER,
+                ranked INTEGER NOT NULL DEFAULT 1,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 finished_at TEXT
             )
         "#).await?;
 
+        // Matches played before the flag existed all counted towards ratings
+        let _ = self.exec("ALTER TABLE matches ADD COLUMN ranked INTEGER NOT NULL DEFAULT 1").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS match_participants (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -667,10 +1081,13 @@ impl Database {
                 elo_after INTEGER,
                 creatures_spawned INTEGER NOT NULL DEFAULT 0,
                 creatures_killed INTEGER NOT NULL DEFAULT 0,
-                creatures_lost INTEGER NOT NULL DEFAULT 0
+                creatures_lost INTEGER NOT NULL DEFAULT 0,
+                rated INTEGER NOT NULL DEFAULT 1
             )
         "#).await?;
 
+        let _ = self.exec("ALTER TABLE match_participants ADD COLUMN rated INTEGER NOT NULL DEFAULT 1").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS tournaments (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -723,6 +1140,18 @@ impl Database {
             )
         "#).await?;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS tournament_byes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                tournament_id INTEGER NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+                bot_version_id INTEGER NOT NULL REFERENCES bot_versions(id),
+                round INTEGER NOT NULL,
+                UNIQUE(tournament_id, round)
+            )
+        "#).await?;
+        // Tables created before the constraint get it as an index
+        let _ = self.exec("CREATE UNIQUE INDEX IF NOT EXISTS idx_tournament_byes_round ON tournament_byes(tournament_id, round)").await;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS teams (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -749,6 +1178,53 @@ impl Database {
             )
         "#).await?;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS rating_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                bot_version_id INTEGER NOT NULL REFERENCES bot_versions(id) ON DELETE CASCADE,
+                match_id INTEGER REFERENCES matches(id) ON DELETE CASCADE,
+                rating_before INTEGER NOT NULL,
+                rating_after INTEGER NOT NULL,
+                opponent_version_id INTEGER,
+                opponent_rating INTEGER,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+        "#).await?;
+
+        // Season resets are recorded without a match or opponent. SQLite can't
+        // drop NOT NULL, so rebuild a table created with it.
+        let strict: Option<(i32,)> = sqlx::query_as(
+            "SELECT \"notnull\" FROM pragma_table_info('rating_history') WHERE name = 'match_id'",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        if strict.is_some_and(|(notnull,)| notnull != 0) {
+            let mut tx = self.pool.begin().await?;
+            for sql in [
+                r#"CREATE TABLE rating_history_rebuilt (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    bot_version_id INTEGER NOT NULL REFERENCES bot_versions(id) ON DELETE CASCADE,
+                    match_id INTEGER REFERENCES matches(id) ON DELETE CASCADE,
+                    rating_before INTEGER NOT NULL,
+                    rating_after INTEGER NOT NULL,
+                    opponent_version_id INTEGER,
+                    opponent_rating INTEGER,
+                    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+                )"#,
+                "INSERT INTO rating_history_rebuilt SELECT id, bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at FROM rating_history",
+                "DROP TABLE rating_history",
+                "ALTER TABLE rating_history_rebuilt RENAME TO rating_history",
+            ] {
+                sqlx::query(sql).execute(&mut *tx).await?;
+            }
+            tx.commit().await?;
+        }
+
+        self.exec(r#"
+            CREATE INDEX IF NOT EXISTS idx_rating_history_version
+            ON rating_history(bot_version_id, created_at)
+        "#).await?;
+
         self.exec(r#"
             CREATE TABLE IF NOT EXISTS replays (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -832,6 +1308,91 @@ impl Database {
         // Add map_params column to existing game_queue tables
         let _ = self.exec("ALTER TABLE game_queue ADD COLUMN map_params TEXT").await;
 
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS maps (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                owner_id INTEGER NOT NULL REFERENCES users(id),
+                name TEXT UNIQUE NOT NULL,
+                description TEXT NOT NULL DEFAULT '',
+                visibility TEXT NOT NULL DEFAULT 'public',
+                width INTEGER NOT NULL,
+                height INTEGER NOT NULL,
+                version INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS ranked_maps (
+                name TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS seasons (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                starts_at TEXT NOT NULL,
+                ends_at TEXT NOT NULL,
+                finished_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+        "#).await?;
+
+        // Names are copied so past standings survive deleted bots
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS season_standings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                season_id INTEGER NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+                rank INTEGER NOT NULL,
+                bot_version_id INTEGER NOT NULL,
+                bot_name TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                owner_username TEXT NOT NULL,
+                rating INTEGER NOT NULL,
+                peak_rating INTEGER NOT NULL,
+                games_played INTEGER NOT NULL,
+                wins INTEGER NOT NULL,
+                losses INTEGER NOT NULL,
+                draws INTEGER NOT NULL
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS leaderboard_stats (
+                format TEXT NOT NULL,
+                entry_id BIGINT NOT NULL,
+                day TEXT NOT NULL,
+                map TEXT NOT NULL,
+                games_played INTEGER NOT NULL DEFAULT 0,
+                wins INTEGER NOT NULL DEFAULT 0,
+                losses INTEGER NOT NULL DEFAULT 0,
+                points INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (format, entry_id, day, map)
+            )
+        "#).await?;
+
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS map_versions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                map_id INTEGER NOT NULL REFERENCES maps(id) ON DELETE CASCADE,
+                version INTEGER NOT NULL,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                UNIQUE(map_id, version)
+            )
+        "#).await?;
+
+        // One-off data migrations that have run, so startup doesn't repeat them
+        self.exec(r#"
+            CREATE TABLE IF NOT EXISTS data_migrations (
+                name TEXT PRIMARY KEY,
+                applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+        "#).await?;
+
         Ok(())
     }
 
@@ -845,6 +1406,42 @@ impl Database {
         }
     }
 
+    /// SQL expression for the timestamp `days` days ago, comparable with
+    /// `now_expr` timestamps.
+    fn days_ago_expr(&self, days: i64) -> String {
+        if self.is_postgres {
+            format!("(now() - interval '{days} days')::text")
+        } else {
+            format!("datetime('now', '-{days} days')")
+        }
+    }
+
+    /// SQL expression for the league of the owner joined as `users` (see
+    /// `crate::league`): Newcomer while the account is younger than
+    /// `NEWCOMER_DAYS`, Open after that and for bots without an owner.
+    fn league_expr(&self, users: &str) -> String {
+        format!(
+            "CASE WHEN {users}.created_at > {} THEN 'newcomer' ELSE 'open' END",
+            self.days_ago_expr(crate::league::NEWCOMER_DAYS)
+        )
+    }
+
+    /// `AND` clause keeping only leaderboard rows of `league` (on the owner join `u`).
+    fn league_filter(&self, league: Option<crate::league::League>) -> String {
+        match league {
+            Some(l) => format!("AND {} = '{}'", self.league_expr("u"), l.as_str()),
+            None => String::new(),
+        }
+    }
+
+    /// Columns of a `User` row, with the league derived from the account age.
+    fn user_columns(&self) -> String {
+        format!(
+            "id, username, email, password_hash, display_name, avatar_url, bio, role, {} AS league, created_at, updated_at",
+            self.league_expr("users")
+        )
+    }
+
     // ── User CRUD ─────────────────────────────────────────────────────
 
     pub async fn create_user(
@@ -854,9 +1451,10 @@ impl Database {
         password_hash: &str,
         display_name: &str,
     ) -> Result<User, sqlx::Error> {
-        let row = sqlx::query_as::<_, User>(
-            "INSERT INTO users (username, email, password_hash, display_name) VALUES ($1, $2, $3, $4) RETURNING id, username, email, password_hash, display_name, avatar_url, bio, role, created_at, updated_at",
-        )
+        let row = sqlx::query_as::<_, User>(&format!(
+            "INSERT INTO users (username, email, password_hash, display_name) VALUES ($1, $2, $3, $4) RETURNING {}",
+            self.user_columns()
+        ))
         .bind(username)
         .bind(email)
         .bind(password_hash)
@@ -867,9 +1465,10 @@ impl Database {
     }
 
     pub async fn get_user(&self, id: i64) -> Result<Option<User>, sqlx::Error> {
-        let row = sqlx::query_as::<_, User>(
-            "SELECT id, username, email, password_hash, display_name, avatar_url, bio, role, created_at, updated_at FROM users WHERE id = $1",
-        )
+        let row = sqlx::query_as::<_, User>(&format!(
+            "SELECT {} FROM users WHERE id = $1",
+            self.user_columns()
+        ))
         .bind(id)
         .fetch_optional(&self.pool)
         .await?;
@@ -877,9 +1476,10 @@ impl Database {
     }
 
     pub async fn get_user_by_username(&self, username: &str) -> Result<Option<User>, sqlx::Error> {
-        let row = sqlx::query_as::<_, User>(
-            "SELECT id, username, email, password_hash, display_name, avatar_url, bio, role, created_at, updated_at FROM users WHERE username = $1",
-        )
+        let row = sqlx::query_as::<_, User>(&format!(
+            "SELECT {} FROM users WHERE username = $1",
+            self.user_columns()
+        ))
         .bind(username)
         .fetch_optional(&self.pool)
         .await?;
@@ -909,6 +1509,18 @@ impl Database {
         self.get_user(id).await
     }
 
+    /// League a bot plays in: its owner's, or Open for ownerless bots.
+    pub async fn bot_league(&self, bot_id: i64) -> Result<crate::league::League, sqlx::Error> {
+        let league: Option<String> = sqlx::query_scalar(&format!(
+            "SELECT {} FROM bots b LEFT JOIN users u ON u.id = b.owner_id WHERE b.id = $1",
+            self.league_expr("u")
+        ))
+        .bind(bot_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(crate::league::League::of_owner(league.as_deref()))
+    }
+
     // ── Bot CRUD ──────────────────────────────────────────────────────
 
     pub async fn create_bot(
@@ -918,7 +1530,7 @@ impl Database {
         owner_id: Option<i64>,
     ) -> Result<Bot, sqlx::Error> {
         let row = sqlx::query_as::<_, Bot>(
-            "INSERT INTO bots (name, description, owner_id) VALUES ($1, $2, $3) RETURNING id, name, description, owner_id, visibility, active_version_id, created_at, updated_at",
+            "INSERT INTO bots (name, description, owner_id) VALUES ($1, $2, $3) RETURNING id, name, description, owner_id, visibility, active_version_id, ladder, created_at, updated_at",
         )
         .bind(name)
         .bind(description)
@@ -930,7 +1542,7 @@ impl Database {
 
     pub async fn list_bots(&self) -> Result<Vec<Bot>, sqlx::Error> {
         let rows =
-            sqlx::query_as::<_, Bot>("SELECT id, name, description, owner_id, visibility, active_version_id, created_at, updated_at FROM bots ORDER BY id")
+            sqlx::query_as::<_, Bot>("SELECT id, name, description, owner_id, visibility, active_version_id, ladder, created_at, updated_at FROM bots ORDER BY id")
                 .fetch_all(&self.pool)
                 .await?;
         Ok(rows)
@@ -938,7 +1550,7 @@ impl Database {
 
     pub async fn list_bots_by_owner(&self, owner_id: i64) -> Result<Vec<Bot>, sqlx::Error> {
         let rows = sqlx::query_as::<_, Bot>(
-            "SELECT id, name, description, owner_id, visibility, active_version_id, created_at, updated_at FROM bots WHERE owner_id = $1 ORDER BY id",
+            "SELECT id, name, description, owner_id, visibility, active_version_id, ladder, created_at, updated_at FROM bots WHERE owner_id = $1 ORDER BY id",
         )
         .bind(owner_id)
         .fetch_all(&self.pool)
@@ -1015,7 +1627,7 @@ impl Database {
 
     pub async fn get_bot(&self, id: i64) -> Result<Option<Bot>, sqlx::Error> {
         let row = sqlx::query_as::<_, Bot>(
-            "SELECT id, name, description, owner_id, visibility, active_version_id, created_at, updated_at FROM bots WHERE id = $1",
+            "SELECT id, name, description, owner_id, visibility, active_version_id, ladder, created_at, updated_at FROM bots WHERE id = $1",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -1023,19 +1635,42 @@ impl Database {
         Ok(row)
     }
 
+    /// Rename and describe a bot, and opt it in or out of the ladder when
+    /// `ladder` is given, all in one statement.
     pub async fn update_bot(
         &self,
         id: i64,
         name: &str,
         description: &str,
+        ladder: Option<bool>,
     ) -> Result<Option<Bot>, sqlx::Error> {
         let sql = format!(
-            "UPDATE bots SET name = $1, description = $2, updated_at = {} WHERE id = $3",
+            "UPDATE bots SET name = $1, description = $2, ladder = COALESCE($3, ladder), updated_at = {} WHERE id = $4",
             self.now_expr()
         );
         let result: AnyQueryResult = sqlx::query(&sql)
             .bind(name)
             .bind(description)
+            .bind(ladder.map(|l| l as i32))
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        self.get_bot(id).await
+    }
+
+    /// Opt a bot in or out of ladder matchmaking.
+    pub async fn set_bot_ladder(&self, id: i64, ladder: bool) -> Result<Option<Bot>, sqlx::Error> {
+        let sql = format!(
+            "UPDATE bots SET ladder = $1, updated_at = {} WHERE id = $2",
+            self.now_expr()
+        );
+        let result: AnyQueryResult = sqlx::query(&sql)
+            .bind(ladder as i32)
             .bind(id)
             .execute(&self.pool)
             .await?;
@@ -1107,12 +1742,18 @@ impl Database {
             1500
         };
 
+        // Record the API style and level the code was written against, so
+        // later API changes don't break it
+        let api = crate::engine::player::ApiVersion::detect(code);
+
         let row = sqlx::query_as::<_, BotVersion>(
-            "INSERT INTO bot_versions (bot_id, version, code, elo_rating, elo_1v1, elo_peak) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, bot_id, version, code, api_type, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, games_played, wins, losses, draws, ffa_placement_points, ffa_games, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at",
+            "INSERT INTO bot_versions (bot_id, version, code, api_type, api_level, elo_rating, elo_1v1, elo_peak) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id, bot_id, version, code, api_type, api_level, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, glicko_rating, glicko_rd, glicko_volatility, ffa_mu, ffa_sigma, team_mu, team_sigma, games_played, wins, losses, draws, ffa_placement_points, ffa_games, unranked_games, unranked_wins, unranked_losses, unranked_draws, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at",
         )
         .bind(bot_id)
         .bind(next_version)
         .bind(code)
+        .bind(api.style.as_str())
+        .bind(api.level as i32)
         .bind(starting_elo)
         .bind(starting_elo)
         .bind(starting_elo)
@@ -1123,7 +1764,7 @@ impl Database {
 
     pub async fn list_bot_versions(&self, bot_id: i64) -> Result<Vec<BotVersion>, sqlx::Error> {
         let rows = sqlx::query_as::<_, BotVersion>(
-            "SELECT id, bot_id, version, code, api_type, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, games_played, wins, losses, draws, ffa_placement_points, ffa_games, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE bot_id = $1 ORDER BY version",
+            "SELECT id, bot_id, version, code, api_type, api_level, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, glicko_rating, glicko_rd, glicko_volatility, ffa_mu, ffa_sigma, team_mu, team_sigma, games_played, wins, losses, draws, ffa_placement_points, ffa_games, unranked_games, unranked_wins, unranked_losses, unranked_draws, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE bot_id = $1 ORDER BY version",
         )
         .bind(bot_id)
         .fetch_all(&self.pool)
@@ -1137,7 +1778,7 @@ impl Database {
         version_id: i64,
     ) -> Result<Option<BotVersion>, sqlx::Error> {
         let row = sqlx::query_as::<_, BotVersion>(
-            "SELECT id, bot_id, version, code, api_type, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, games_played, wins, losses, draws, ffa_placement_points, ffa_games, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE bot_id = $1 AND id = $2",
+            "SELECT id, bot_id, version, code, api_type, api_level, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, glicko_rating, glicko_rd, glicko_volatility, ffa_mu, ffa_sigma, team_mu, team_sigma, games_played, wins, losses, draws, ffa_placement_points, ffa_games, unranked_games, unranked_wins, unranked_losses, unranked_draws, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE bot_id = $1 AND id = $2",
         )
         .bind(bot_id)
         .bind(version_id)
@@ -1152,7 +1793,7 @@ impl Database {
         version_id: i64,
     ) -> Result<Option<BotVersion>, sqlx::Error> {
         let row = sqlx::query_as::<_, BotVersion>(
-            "SELECT id, bot_id, version, code, api_type, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, games_played, wins, losses, draws, ffa_placement_points, ffa_games, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE id = $1",
+            "SELECT id, bot_id, version, code, api_type, api_level, is_archived, is_faulty, elo_rating, elo_1v1, elo_peak, glicko_rating, glicko_rd, glicko_volatility, ffa_mu, ffa_sigma, team_mu, team_sigma, games_played, wins, losses, draws, ffa_placement_points, ffa_games, unranked_games, unranked_wins, unranked_losses, unranked_draws, creatures_spawned, creatures_killed, creatures_lost, total_score, created_at FROM bot_versions WHERE id = $1",
         )
         .bind(version_id)
         .fetch_optional(&self.pool)
@@ -1207,7 +1848,7 @@ impl Database {
 
     pub async fn get_active_version(&self, bot_id: i64) -> Result<Option<BotVersion>, sqlx::Error> {
         let row = sqlx::query_as::<_, BotVersion>(
-            "SELECT bv.id, bv.bot_id, bv.version, bv.code, bv.api_type, bv.is_archived, bv.is_faulty, bv.elo_rating, bv.elo_1v1, bv.elo_peak, bv.games_played, bv.wins, bv.losses, bv.draws, bv.ffa_placement_points, bv.ffa_games, bv.creatures_spawned, bv.creatures_killed, bv.creatures_lost, bv.total_score, bv.created_at FROM bot_versions bv JOIN bots b ON b.active_version_id = bv.id WHERE b.id = $1",
+            "SELECT bv.id, bv.bot_id, bv.version, bv.code, bv.api_type, bv.api_level, bv.is_archived, bv.is_faulty, bv.elo_rating, bv.elo_1v1, bv.elo_peak, bv.glicko_rating, bv.glicko_rd, bv.glicko_volatility, bv.ffa_mu, bv.ffa_sigma, bv.team_mu, bv.team_sigma, bv.games_played, bv.wins, bv.losses, bv.draws, bv.ffa_placement_points, bv.ffa_games, bv.unranked_games, bv.unranked_wins, bv.unranked_losses, bv.unranked_draws, bv.creatures_spawned, bv.creatures_killed, bv.creatures_lost, bv.total_score, bv.created_at FROM bot_versions bv JOIN bots b ON b.active_version_id = bv.id WHERE b.id = $1",
         )
         .bind(bot_id)
         .fetch_optional(&self.pool)
@@ -1217,23 +1858,128 @@ impl Database {
 
     // ── Match Recording ──────────────────────────────────────────────
 
-    pub async fn create_match(&self, format: &str, map: &str) -> Result<Match, sqlx::Error> {
+    /// Create a running match. Only `ranked` matches change ratings and
+    /// count towards the leaderboards.
+    pub async fn create_match(
+        &self,
+        format: &str,
+        map: &str,
+        ranked: bool,
+    ) -> Result<Match, sqlx::Error> {
         let row = sqlx::query_as::<_, Match>(
-            "INSERT INTO matches (format, map, status) VALUES ($1, $2, 'running') RETURNING id, format, map, status, winner_bot_version_id, created_at, finished_at",
+            "INSERT INTO matches (format, map, status, ranked) VALUES ($1, $2, 'running', $3) RETURNING id, format, map, status, winner_bot_version_id, ranked, created_at, finished_at",
         )
         .bind(format)
         .bind(map)
+        .bind(ranked as i32)
         .fetch_one(&self.pool)
         .await?;
         Ok(row)
     }
 
-    /// Mark any matches still in 'running' status as 'abandoned'.
-    /// Called at startup to clean up orphaned matches from prior server runs.
-    pub async fn cleanup_orphaned_matches(&self) -> Result<u64, sqlx::Error> {
-        let sql = format!(
-            "UPDATE matches SET status = 'abandoned', finished_at = {} WHERE status = 'running'",
-            self.now_expr()
+    /// Create a match with its participants, given as `(bot_version_id,
+    /// rated)` in slot order, and with `enqueue` (`(map, priority)`) its queue
+    /// job, all in one transaction.
+    pub async fn create_match_with_participants(
+        &self,
+        format: &str,
+        map: &str,
+        ranked: bool,
+        participants: &[(i64, bool)],
+        enqueue: Option<(Option<&str>, i32)>,
+    ) -> Result<Match, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let row = Self::insert_match_with_participants(
+            &mut tx,
+            format,
+            map,
+            ranked,
+            participants,
+            enqueue,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(row)
+    }
+
+    /// Create a ranked match between two bots like `create_match_with_participants`,
+    /// unless they already have `max_per_day` ranked matches in the last day.
+    /// The count and the insert share a transaction holding the matches write
+    /// lock, so concurrent challenges can't overshoot the cap. `None` if capped.
+    pub async fn create_capped_ranked_match(
+        &self,
+        format: &str,
+        map: &str,
+        participants: &[(i64, bool)],
+        (bot_a, bot_b): (i64, i64),
+        max_per_day: i64,
+    ) -> Result<Option<Match>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let lock = if self.is_postgres {
+            "LOCK TABLE matches IN SHARE ROW EXCLUSIVE MODE"
+        } else {
+            "UPDATE matches SET id = id WHERE 0 = 1"
+        };
+        sqlx::query(lock).execute(&mut *tx).await?;
+        let (count,): (i64,) = sqlx::query_as(&self.ranked_pair_count_sql(1))
+            .bind(bot_a)
+            .bind(bot_b)
+            .fetch_one(&mut *tx)
+            .await?;
+        if count >= max_per_day {
+            return Ok(None);
+        }
+        let row =
+            Self::insert_match_with_participants(&mut tx, format, map, true, participants, None)
+                .await?;
+        tx.commit().await?;
+        Ok(Some(row))
+    }
+
+    async fn insert_match_with_participants(
+        tx: &mut sqlx::Transaction<'_, sqlx::Any>,
+        format: &str,
+        map: &str,
+        ranked: bool,
+        participants: &[(i64, bool)],
+        enqueue: Option<(Option<&str>, i32)>,
+    ) -> Result<Match, sqlx::Error> {
+        let row = sqlx::query_as::<_, Match>(
+            "INSERT INTO matches (format, map, status, ranked) VALUES ($1, $2, 'running', $3) RETURNING id, format, map, status, winner_bot_version_id, ranked, created_at, finished_at",
+        )
+        .bind(format)
+        .bind(map)
+        .bind(ranked as i32)
+        .fetch_one(&mut **tx)
+        .await?;
+        for (slot, (bot_version_id, rated)) in participants.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO match_participants (match_id, bot_version_id, player_slot, rated) VALUES ($1, $2, $3, $4)",
+            )
+            .bind(row.id)
+            .bind(bot_version_id)
+            .bind(slot as i32)
+            .bind(*rated as i32)
+            .execute(&mut **tx)
+            .await?;
+        }
+        if let Some((queue_map, priority)) = enqueue {
+            sqlx::query("INSERT INTO game_queue (match_id, map, priority) VALUES ($1, $2, $3)")
+                .bind(row.id)
+                .bind(queue_map)
+                .bind(priority)
+                .execute(&mut **tx)
+                .await?;
+        }
+        Ok(row)
+    }
+
+    /// Mark any matches still in 'running' status as 'abandoned'.
+    /// Called at startup to clean up orphaned matches from prior server runs.
+    pub async fn cleanup_orphaned_matches(&self) -> Result<u64, sqlx::Error> {
+        let sql = format!(
+            "UPDATE matches SET status = 'abandoned', finished_at = {} WHERE status = 'running'",
+            self.now_expr()
         );
         let result: AnyQueryResult = sqlx::query(&sql)
             .execute(&self.pool)
@@ -1269,7 +2015,7 @@ impl Database {
 
     pub async fn get_match(&self, id: i64) -> Result<Option<Match>, sqlx::Error> {
         let row = sqlx::query_as::<_, Match>(
-            "SELECT id, format, map, status, winner_bot_version_id, created_at, finished_at FROM matches WHERE id = $1",
+            "SELECT id, format, map, status, winner_bot_version_id, ranked, created_at, finished_at FROM matches WHERE id = $1",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -1283,7 +2029,7 @@ impl Database {
         offset: i64,
     ) -> Result<Vec<Match>, sqlx::Error> {
         let rows = sqlx::query_as::<_, Match>(
-            "SELECT id, format, map, status, winner_bot_version_id, created_at, finished_at FROM matches ORDER BY id DESC LIMIT $1 OFFSET $2",
+            "SELECT id, format, map, status, winner_bot_version_id, ranked, created_at, finished_at FROM matches ORDER BY id DESC LIMIT $1 OFFSET $2",
         )
         .bind(limit)
         .bind(offset)
@@ -1306,7 +2052,7 @@ impl Database {
         let needs_join = bot_id.is_some() || user_id.is_some() || username.is_some();
 
         let mut sql = String::from(
-            "SELECT DISTINCT m.id, m.format, m.map, m.status, m.winner_bot_version_id, m.created_at, m.finished_at FROM matches m",
+            "SELECT DISTINCT m.id, m.format, m.map, m.status, m.winner_bot_version_id, m.ranked, m.created_at, m.finished_at FROM matches m",
         );
 
         if needs_join {
@@ -1422,7 +2168,7 @@ impl Database {
         offset: i64,
     ) -> Result<Vec<Match>, sqlx::Error> {
         let rows = sqlx::query_as::<_, Match>(
-            "SELECT DISTINCT m.id, m.format, m.map, m.status, m.winner_bot_version_id, m.created_at, m.finished_at \
+            "SELECT DISTINCT m.id, m.format, m.map, m.status, m.winner_bot_version_id, m.ranked, m.created_at, m.finished_at \
              FROM matches m \
              JOIN match_participants mp ON mp.match_id = m.id \
              JOIN bot_versions bv ON bv.id = mp.bot_version_id \
@@ -1446,7 +2192,7 @@ impl Database {
         player_slot: i32,
     ) -> Result<MatchParticipant, sqlx::Error> {
         let row = sqlx::query_as::<_, MatchParticipant>(
-            "INSERT INTO match_participants (match_id, bot_version_id, player_slot) VALUES ($1, $2, $3) RETURNING id, match_id, bot_version_id, player_slot, final_score, placement, elo_before, elo_after, creatures_spawned, creatures_killed, creatures_lost, NULL AS bot_name, NULL AS owner_name",
+            "INSERT INTO match_participants (match_id, bot_version_id, player_slot) VALUES ($1, $2, $3) RETURNING id, match_id, bot_version_id, player_slot, final_score, placement, elo_before, elo_after, creatures_spawned, creatures_killed, creatures_lost, rated, NULL AS bot_name, NULL AS owner_name",
         )
         .bind(match_id)
         .bind(bot_version_id)
@@ -1488,7 +2234,7 @@ impl Database {
         match_id: i64,
     ) -> Result<Vec<MatchParticipant>, sqlx::Error> {
         let rows = sqlx::query_as::<_, MatchParticipant>(
-            "SELECT mp.id, mp.match_id, mp.bot_version_id, mp.player_slot, mp.final_score, mp.placement, mp.elo_before, mp.elo_after, mp.creatures_spawned, mp.creatures_killed, mp.creatures_lost, b.name AS bot_name, u.username AS owner_name FROM match_participants mp LEFT JOIN bot_versions bv ON bv.id = mp.bot_version_id LEFT JOIN bots b ON b.id = bv.bot_id LEFT JOIN users u ON u.id = b.owner_id WHERE mp.match_id = $1 ORDER BY mp.player_slot",
+            "SELECT mp.id, mp.match_id, mp.bot_version_id, mp.player_slot, mp.final_score, mp.placement, mp.elo_before, mp.elo_after, mp.creatures_spawned, mp.creatures_killed, mp.creatures_lost, mp.rated, b.name AS bot_name, u.username AS owner_name FROM match_participants mp LEFT JOIN bot_versions bv ON bv.id = mp.bot_version_id LEFT JOIN bots b ON b.id = bv.bot_id LEFT JOIN users u ON u.id = b.owner_id WHERE mp.match_id = $1 ORDER BY mp.player_slot",
         )
         .bind(match_id)
         .fetch_all(&self.pool)
@@ -1498,24 +2244,838 @@ impl Database {
 
     // ── Elo & Stats Updates ──────────────────────────────────────────
 
+    /// Set a version's current Elo. `elo_peak` is not touched here; it is
+    /// derived from `rating_history` by `record_rating_history`.
     pub async fn update_version_elo(
         &self,
         version_id: i64,
         new_elo: i32,
     ) -> Result<bool, sqlx::Error> {
-        // GREATEST works on PostgreSQL; for SQLite MAX works in this context too
-        let max_fn = if self.is_postgres { "GREATEST" } else { "MAX" };
+        let result: AnyQueryResult =
+            sqlx::query("UPDATE bot_versions SET elo_rating = $1, elo_1v1 = $2 WHERE id = $3")
+                .bind(new_elo)
+                .bind(new_elo)
+                .bind(version_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    // ── Rating History ───────────────────────────────────────────────
+
+    /// Record one rated 1v1 match for a version and refresh its peak.
+    pub async fn record_rating_history(
+        &self,
+        version_id: i64,
+        match_id: i64,
+        rating_before: i32,
+        rating_after: i32,
+        opponent_version_id: Option<i64>,
+        opponent_rating: i32,
+    ) -> Result<RatingHistoryEntry, sqlx::Error> {
+        let row = sqlx::query_as::<_, RatingHistoryEntry>(
+            "INSERT INTO rating_history (bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at",
+        )
+        .bind(version_id)
+        .bind(match_id)
+        .bind(rating_before)
+        .bind(rating_after)
+        .bind(opponent_version_id)
+        .bind(opponent_rating)
+        .fetch_one(&self.pool)
+        .await?;
+        self.refresh_peak_rating(version_id).await?;
+        Ok(row)
+    }
+
+    /// Store the version's peak from its history in `bot_versions.elo_peak`.
+    async fn refresh_peak_rating(&self, version_id: i64) -> Result<(), sqlx::Error> {
+        if let Some(peak) = self.version_peak_rating(version_id).await? {
+            sqlx::query("UPDATE bot_versions SET elo_peak = $1 WHERE id = $2")
+                .bind(peak)
+                .bind(version_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// A version's rated matches and season resets, oldest first.
+    pub async fn list_rating_history(
+        &self,
+        version_id: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<RatingHistoryEntry>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, RatingHistoryEntry>(
+            "SELECT id, bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at FROM rating_history WHERE bot_version_id = $1 ORDER BY created_at, id LIMIT $2 OFFSET $3",
+        )
+        .bind(version_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Highest rating the version has held, including its starting rating.
+    /// `None` if it has no rated matches.
+    pub async fn version_peak_rating(&self, version_id: i64) -> Result<Option<i32>, sqlx::Error> {
+        let peak: Option<i32> = sqlx::query_scalar(
+            r#"SELECT MAX(r) FROM (
+                   SELECT rating_before AS r FROM rating_history WHERE bot_version_id = $1
+                   UNION ALL
+                   SELECT rating_after AS r FROM rating_history WHERE bot_version_id = $1
+               ) ratings"#,
+        )
+        .bind(version_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(peak)
+    }
+
+    /// The version's rating as of `at` (any timestamp `normalize_timestamp`
+    /// accepts; a bare date means the start of that day): the rating after its
+    /// last match before then, or its starting rating if it hadn't played yet.
+    /// `None` if it has no rated matches at all.
+    pub async fn version_rating_at(
+        &self,
+        version_id: i64,
+        at: &str,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        let at = normalize_timestamp(at)
+            .ok_or_else(|| sqlx::Error::Protocol(format!("invalid timestamp: {at}")))?;
+        let before = if self.is_postgres {
+            "created_at::timestamptz <= $2::timestamp AT TIME ZONE 'UTC'"
+        } else {
+            "created_at <= $2"
+        };
+        let last: Option<(i32,)> = sqlx::query_as(&format!(
+            "SELECT rating_after FROM rating_history WHERE bot_version_id = $1 AND {before} ORDER BY created_at DESC, id DESC LIMIT 1"
+        ))
+        .bind(version_id)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some((rating,)) = last {
+            return Ok(Some(rating));
+        }
+        let first: Option<(i32,)> = sqlx::query_as(
+            "SELECT rating_before FROM rating_history WHERE bot_version_id = $1 ORDER BY created_at, id LIMIT 1",
+        )
+        .bind(version_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(first.map(|(rating,)| rating))
+    }
+
+    /// Seed `rating_history` from the Elo before/after already stored on 1v1
+    /// match participants. Does nothing once the table has rows. Returns the
+    /// number of rows inserted.
+    pub async fn seed_rating_history(&self) -> Result<u64, sqlx::Error> {
+        let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM rating_history")
+            .fetch_one(&self.pool)
+            .await?;
+        if existing > 0 {
+            return Ok(0);
+        }
+        let result: AnyQueryResult = sqlx::query(
+            r#"INSERT INTO rating_history (bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at)
+               SELECT mp.bot_version_id, mp.match_id, mp.elo_before, mp.elo_after, op.bot_version_id, op.elo_before, m.finished_at
+               FROM match_participants mp
+               JOIN matches m ON m.id = mp.match_id
+               JOIN match_participants op ON op.match_id = mp.match_id AND op.id <> mp.id
+               WHERE m.format = '1v1' AND m.status = 'finished' AND m.ranked = 1 AND m.finished_at IS NOT NULL
+                 AND mp.elo_before > 0 AND mp.elo_after > 0 AND op.elo_before > 0
+               ORDER BY m.finished_at, m.id"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        let versions: Vec<(i64,)> =
+            sqlx::query_as("SELECT DISTINCT bot_version_id FROM rating_history")
+                .fetch_all(&self.pool)
+                .await?;
+        for (version_id,) in versions {
+            self.refresh_peak_rating(version_id).await?;
+        }
+        Ok(result.rows_affected())
+    }
+
+    pub async fn update_version_glicko(
+        &self,
+        version_id: i64,
+        rating: &crate::glicko::Glicko2Rating,
+    ) -> Result<bool, sqlx::Error> {
+        let result: AnyQueryResult = sqlx::query(
+            "UPDATE bot_versions SET glicko_rating = $1, glicko_rd = $2, glicko_volatility = $3 WHERE id = $4",
+        )
+        .bind(rating.rating)
+        .bind(rating.deviation)
+        .bind(rating.volatility)
+        .bind(version_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn update_version_ffa_skill(
+        &self,
+        version_id: i64,
+        skill: &crate::openskill::SkillRating,
+    ) -> Result<bool, sqlx::Error> {
+        let result: AnyQueryResult =
+            sqlx::query("UPDATE bot_versions SET ffa_mu = $1, ffa_sigma = $2 WHERE id = $3")
+                .bind(skill.mu)
+                .bind(skill.sigma)
+                .bind(version_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn update_version_team_skill(
+        &self,
+        version_id: i64,
+        skill: &crate::openskill::SkillRating,
+    ) -> Result<bool, sqlx::Error> {
+        let result: AnyQueryResult =
+            sqlx::query("UPDATE bot_versions SET team_mu = $1, team_sigma = $2 WHERE id = $3")
+                .bind(skill.mu)
+                .bind(skill.sigma)
+                .bind(version_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Finished ranked 1v1 results as `(version_a, version_b, winner)`, oldest first.
+    pub async fn list_1v1_results(&self) -> Result<Vec<(i64, i64, Option<i64>)>, sqlx::Error> {
+        let rows: Vec<(i64, i64, Option<i64>)> = sqlx::query_as(
+            r#"SELECT a.bot_version_id, b.bot_version_id, m.winner_bot_version_id
+               FROM matches m
+               JOIN match_participants a ON a.match_id = m.id
+               JOIN match_participants b ON b.match_id = m.id AND b.player_slot > a.player_slot
+               WHERE m.format = '1v1' AND m.status = 'finished' AND m.ranked = 1
+               ORDER BY m.finished_at, m.id"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Whether the one-off data migration `name` (e.g. `GLICKO_BACKFILL`) has
+    /// already run.
+    pub async fn data_migration_applied(&self, name: &str) -> Result<bool, sqlx::Error> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT name FROM data_migrations WHERE name = $1")
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(row.is_some())
+    }
+
+    /// Recompute every version's Glicko-2 rating by replaying the rating
+    /// history through `crate::recompute`, leaving the other ratings alone.
+    /// Runs in one transaction under the ratings write lock and records the
+    /// `GLICKO_BACKFILL` data migration, so startup only does it once.
+    /// Returns the number of versions with rated 1v1 games.
+    pub async fn backfill_glicko(&self) -> Result<usize, sqlx::Error> {
+        let _rewrite = self.rating_updates.write().await;
+        let mut tx = self.pool.begin().await?;
+        self.lock_ratings(&mut tx).await?;
+        let result = self.replay_history(&mut tx).await?;
+
+        let start = crate::glicko::Glicko2Rating::default();
+        let mut rated = 0;
+        for (version_id, v) in &result.versions {
+            sqlx::query(
+                "UPDATE bot_versions SET glicko_rating = $1, glicko_rd = $2, glicko_volatility = $3 WHERE id = $4",
+            )
+            .bind(v.glicko.rating)
+            .bind(v.glicko.deviation)
+            .bind(v.glicko.volatility)
+            .bind(version_id)
+            .execute(&mut *tx)
+            .await?;
+            rated += usize::from(v.glicko != start);
+        }
+        sqlx::query("INSERT INTO data_migrations (name) VALUES ($1) ON CONFLICT (name) DO NOTHING")
+            .bind(GLICKO_BACKFILL)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(rated)
+    }
+
+    // ── Ladder ───────────────────────────────────────────────────────
+
+    /// Active versions of bots opted into the ladder, skipping faulty and
+    /// archived ones and those already in an unfinished ranked match (one
+    /// whose queue job hasn't failed for good).
+    pub async fn ladder_candidates(
+        &self,
+    ) -> Result<Vec<crate::matchmaker::LadderCandidate>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, crate::matchmaker::LadderCandidate>(&format!(
+            r#"SELECT bv.id AS bot_version_id, bv.bot_id, bv.elo_1v1 AS rating, bv.games_played,
+                      {} AS league
+               FROM bots b
+               JOIN bot_versions bv ON bv.id = b.active_version_id
+               LEFT JOIN users u ON u.id = b.owner_id
+               WHERE b.ladder = 1 AND bv.is_faulty = 0 AND bv.is_archived = 0
+                 AND NOT EXISTS (
+                     SELECT 1 FROM match_participants mp
+                     JOIN matches m ON m.id = mp.match_id
+                     WHERE mp.bot_version_id = bv.id AND m.ranked = 1 AND m.status = 'running'
+                       AND NOT EXISTS (
+                           SELECT 1 FROM game_queue q WHERE q.match_id = m.id AND q.status = 'failed'
+                       )
+                 )
+               ORDER BY bv.id"#,
+            self.league_expr("u")
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Version pairs of the latest `limit` ranked 1v1 matches, including ones
+    /// still queued or running.
+    pub async fn recent_ranked_pairs(&self, limit: i64) -> Result<Vec<(i64, i64)>, sqlx::Error> {
+        let rows: Vec<(i64, i64)> = sqlx::query_as(
+            r#"SELECT a.bot_version_id, b.bot_version_id
+               FROM (SELECT id FROM matches WHERE ranked = 1 AND format = '1v1' ORDER BY id DESC LIMIT $1) m
+               JOIN match_participants a ON a.match_id = m.id
+               JOIN match_participants b ON b.match_id = m.id AND b.player_slot > a.player_slot"#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Ranked matches of the last `days` days with a version of each of the
+    /// two bots, including ones still queued or running.
+    pub async fn count_ranked_pair_matches(
+        &self,
+        bot_a: i64,
+        bot_b: i64,
+        days: i64,
+    ) -> Result<i64, sqlx::Error> {
+        let (count,): (i64,) = sqlx::query_as(&self.ranked_pair_count_sql(days))
+            .bind(bot_a)
+            .bind(bot_b)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count)
+    }
+
+    /// Count query behind `count_ranked_pair_matches`, binding the bots as `$1` and `$2`.
+    fn ranked_pair_count_sql(&self, days: i64) -> String {
+        format!(
+            r#"SELECT COUNT(*) FROM matches m
+               WHERE m.ranked = 1 AND m.status <> 'abandoned' AND m.created_at >= {}
+                 AND EXISTS (SELECT 1 FROM match_participants p JOIN bot_versions v ON v.id = p.bot_version_id
+                             WHERE p.match_id = m.id AND v.bot_id = $1)
+                 AND EXISTS (SELECT 1 FROM match_participants p JOIN bot_versions v ON v.id = p.bot_version_id
+                             WHERE p.match_id = m.id AND v.bot_id = $2)"#,
+            self.days_ago_expr(days)
+        )
+    }
+
+    /// Maps the ladder picks from. Empty means generated random maps.
+    pub async fn list_ranked_maps(&self) -> Result<Vec<String>, sqlx::Error> {
+        let rows: Vec<(String,)> = sqlx::query_as("SELECT name FROM ranked_maps ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(name,)| name).collect())
+    }
+
+    /// Replace the ranked map pool.
+    pub async fn set_ranked_maps(&self, names: &[String]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM ranked_maps")
+            .execute(&mut *tx)
+            .await?;
+        for name in names {
+            sqlx::query("INSERT INTO ranked_maps (name) VALUES ($1)")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // ── Seasons ──────────────────────────────────────────────────────
+
+    /// Standings of the season that started at `$1`, computed from its
+    /// ranked 1v1 matches and rating history.
+    fn live_season_standings_sql(&self, paged: bool) -> String {
+        format!(
+            r#"
+            SELECT
+                ROW_NUMBER() OVER (ORDER BY bv.elo_1v1 DESC, bv.id) AS rank,
+                bv.id AS bot_version_id,
+                b.name AS bot_name,
+                bv.version,
+                COALESCE(u.username, 'anonymous') AS owner_username,
+                bv.elo_1v1 AS rating,
+                COALESCE(
+                    (SELECT MAX(rh.rating_after) FROM rating_history rh
+                     WHERE rh.bot_version_id = bv.id AND rh.created_at >= $1),
+                    bv.elo_1v1
+                ) AS peak_rating,
+                s.games_played,
+                s.wins,
+                s.losses,
+                s.draws
+            FROM (
+                SELECT
+                    mp.bot_version_id,
+                    CAST(COUNT(*) AS INTEGER) AS games_played,
+                    CAST(SUM(CASE WHEN m.winner_bot_version_id = mp.bot_version_id THEN 1 ELSE 0 END) AS INTEGER) AS wins,
+                    CAST(SUM(CASE WHEN m.winner_bot_version_id <> mp.bot_version_id THEN 1 ELSE 0 END) AS INTEGER) AS losses,
+                    CAST(SUM(CASE WHEN m.winner_bot_version_id IS NULL THEN 1 ELSE 0 END) AS INTEGER) AS draws
+                FROM match_participants mp
+                JOIN matches m ON m.id = mp.match_id
+                WHERE m.status = 'finished' AND m.ranked = 1 AND mp.rated = 1
+                  AND m.format = '1v1' AND m.finished_at >= $1
+                GROUP BY mp.bot_version_id
+            ) s
+            JOIN bot_versions bv ON bv.id = s.bot_version_id
+            JOIN bots b ON b.id = bv.bot_id
+            LEFT JOIN users u ON u.id = b.owner_id
+            WHERE bv.is_archived = 0
+            ORDER BY bv.elo_1v1 DESC, bv.id
+            {}
+            "#,
+            if paged { "LIMIT $2 OFFSET $3" } else { "" }
+        )
+    }
+
+    /// Seasons newest first, with the champion of each finished one.
+    pub async fn list_seasons(&self) -> Result<Vec<Season>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, Season>(
+            r#"SELECT s.id, s.name, s.starts_at, s.ends_at, s.finished_at, s.created_at,
+                      st.bot_name AS champion_bot_name, st.owner_username AS champion_owner,
+                      st.rating AS champion_rating
+               FROM seasons s
+               LEFT JOIN season_standings st ON st.season_id = s.id AND st.rank = 1
+               ORDER BY s.id DESC"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_season(&self, id: i64) -> Result<Option<Season>, sqlx::Error> {
+        let row = sqlx::query_as::<_, Season>(
+            "SELECT id, name, starts_at, ends_at, finished_at, created_at FROM seasons WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn active_season(&self) -> Result<Option<Season>, sqlx::Error> {
+        let row = sqlx::query_as::<_, Season>(
+            "SELECT id, name, starts_at, ends_at, finished_at, created_at FROM seasons WHERE finished_at IS NULL ORDER BY id DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// The active season, if its end date has passed.
+    pub async fn expired_season(&self) -> Result<Option<Season>, sqlx::Error> {
         let sql = format!(
-            "UPDATE bot_versions SET elo_rating = $1, elo_1v1 = $2, elo_peak = {max_fn}(elo_peak, $3) WHERE id = $4"
+            "SELECT id, name, starts_at, ends_at, finished_at, created_at FROM seasons WHERE finished_at IS NULL AND ends_at <= {} ORDER BY id DESC LIMIT 1",
+            self.now_expr()
         );
-        let result: AnyQueryResult = sqlx::query(&sql)
-            .bind(new_elo)
-            .bind(new_elo)
-            .bind(new_elo)
-            .bind(version_id)
-            .execute(&self.pool)
+        let row = sqlx::query_as::<_, Season>(&sql)
+            .fetch_optional(&self.pool)
             .await?;
-        Ok(result.rows_affected() > 0)
+        Ok(row)
+    }
+
+    /// Archived standings of a finished season, or live ones for the active season.
+    pub async fn season_standings(
+        &self,
+        season: &Season,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<SeasonStanding>, sqlx::Error> {
+        if season.finished_at.is_some() {
+            let rows = sqlx::query_as::<_, SeasonStanding>(
+                "SELECT rank, bot_version_id, bot_name, version, owner_username, rating, peak_rating, games_played, wins, losses, draws FROM season_standings WHERE season_id = $1 ORDER BY rank LIMIT $2 OFFSET $3",
+            )
+            .bind(season.id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+            return Ok(rows);
+        }
+        let rows = sqlx::query_as::<_, SeasonStanding>(&self.live_season_standings_sql(true))
+            .bind(&season.starts_at)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows)
+    }
+
+    /// End the active season (if any) and start the next one, lasting
+    /// `length_days`. The final standings are archived, then every rating is
+    /// pulled halfway back to its starting value, like `elo::soft_reset_elo`
+    /// does for new versions, and the Elo change is recorded in
+    /// `rating_history` at the season's `finished_at`, where
+    /// `crate::recompute` replays it. Deviations are left alone. All in one
+    /// transaction under the ratings write lock, so no game can finish
+    /// halfway through and concurrent rollovers run one after the other.
+    pub async fn rollover_season(&self, length_days: i64) -> Result<Season, sqlx::Error> {
+        self.rollover(length_days, false)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    /// Like `rollover_season`, but only if there is no active season or it
+    /// has ended, checked under the same lock. `None` if nothing was due, e.g.
+    /// because another rollover just started a new season.
+    pub async fn rollover_due_season(
+        &self,
+        length_days: i64,
+    ) -> Result<Option<Season>, sqlx::Error> {
+        self.rollover(length_days, true).await
+    }
+
+    async fn rollover(
+        &self,
+        length_days: i64,
+        only_if_due: bool,
+    ) -> Result<Option<Season>, sqlx::Error> {
+        let ends_at = if self.is_postgres {
+            format!("(now() + interval '{length_days} days')::text")
+        } else {
+            format!("datetime('now', '+{length_days} days')")
+        };
+        let _rewrite = self.rating_updates.write().await;
+        let mut tx = self.pool.begin().await?;
+        self.lock_ratings(&mut tx).await?;
+
+        let active = sqlx::query_as::<_, Season>(&format!(
+            "SELECT id, name, starts_at, ends_at, finished_at, created_at FROM seasons WHERE finished_at IS NULL ORDER BY id DESC LIMIT 1{}",
+            if self.is_postgres { " FOR UPDATE" } else { "" }
+        ))
+        .fetch_optional(&mut *tx)
+        .await?;
+        if only_if_due {
+            if let Some(season) = &active {
+                let sql = format!(
+                    "SELECT COUNT(*) FROM seasons WHERE id = $1 AND ends_at <= {}",
+                    self.now_expr()
+                );
+                let (ended,): (i64,) =
+                    sqlx::query_as(&sql).bind(season.id).fetch_one(&mut *tx).await?;
+                if ended == 0 {
+                    return Ok(None);
+                }
+            }
+        }
+        if let Some(season) = active {
+            let standings =
+                sqlx::query_as::<_, SeasonStanding>(&self.live_season_standings_sql(false))
+                    .bind(&season.starts_at)
+                    .fetch_all(&mut *tx)
+                    .await?;
+            for st in &standings {
+                sqlx::query(
+                    "INSERT INTO season_standings (season_id, rank, bot_version_id, bot_name, version, owner_username, rating, peak_rating, games_played, wins, losses, draws) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+                )
+                .bind(season.id)
+                .bind(st.rank)
+                .bind(st.bot_version_id)
+                .bind(&st.bot_name)
+                .bind(st.version)
+                .bind(&st.owner_username)
+                .bind(st.rating)
+                .bind(st.peak_rating)
+                .bind(st.games_played)
+                .bind(st.wins)
+                .bind(st.losses)
+                .bind(st.draws)
+                .execute(&mut *tx)
+                .await?;
+            }
+            let finished_at: String = sqlx::query_scalar(&format!(
+                "UPDATE seasons SET finished_at = {} WHERE id = $1 RETURNING finished_at",
+                self.now_expr()
+            ))
+            .bind(season.id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                r#"INSERT INTO rating_history (bot_version_id, rating_before, rating_after, created_at)
+                   SELECT id, elo_1v1, (elo_1v1 + $1) / 2, $2 FROM bot_versions
+                   WHERE elo_1v1 <> $1
+                   ORDER BY id"#,
+            )
+            .bind(crate::elo::STARTING_ELO)
+            .bind(&finished_at)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                r#"UPDATE bot_versions SET
+                       elo_rating = (elo_rating + $1) / 2,
+                       elo_1v1 = (elo_1v1 + $1) / 2,
+                       elo_peak = CASE WHEN (elo_1v1 + $1) / 2 > elo_peak
+                                       THEN (elo_1v1 + $1) / 2 ELSE elo_peak END,
+                       glicko_rating = (glicko_rating + $2) / 2,
+                       ffa_mu = (ffa_mu + $3) / 2,
+                       team_mu = (team_mu + $3) / 2"#,
+            )
+            .bind(crate::elo::STARTING_ELO)
+            .bind(crate::glicko::STARTING_RATING)
+            .bind(crate::openskill::STARTING_MU)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query("UPDATE team_versions SET elo_rating = (elo_rating + $1) / 2")
+                .bind(crate::elo::STARTING_ELO)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM seasons")
+            .fetch_one(&mut *tx)
+            .await?;
+        let next = sqlx::query_as::<_, Season>(&format!(
+            "INSERT INTO seasons (name, starts_at, ends_at) VALUES ($1, {}, {ends_at}) RETURNING id, name, starts_at, ends_at, finished_at, created_at",
+            self.now_expr()
+        ))
+        .bind(format!("Season {}", count + 1))
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(Some(next))
+    }
+
+    // ── Rating Recomputation ─────────────────────────────────────────
+
+    /// Reset every version's ratings and replay all finished ranked matches
+    /// through the current rating code (see `crate::recompute`). With
+    /// `dry_run` nothing is written and the report shows how the 1v1
+    /// leaderboard would change; otherwise versions, team versions,
+    /// participants' Elo and `rating_history` are rewritten in the same
+    /// transaction the matches are read in, which holds the write lock
+    /// throughout so no game can finish between the read and the commit.
+    pub async fn recompute_ratings(
+        &self,
+        dry_run: bool,
+    ) -> Result<crate::recompute::RecomputeReport, sqlx::Error> {
+        use crate::recompute::VersionChange;
+
+        let _rewrite = if dry_run {
+            None
+        } else {
+            Some(self.rating_updates.write().await)
+        };
+        let mut tx = self.pool.begin().await?;
+        if !dry_run {
+            self.lock_ratings(&mut tx).await?;
+        }
+        let result = self.replay_history(&mut tx).await?;
+
+        let current: Vec<(i64, String, i32, i32, f64, i32, i32)> = sqlx::query_as(
+            "SELECT bv.id, b.name, bv.version, bv.elo_1v1, bv.glicko_rating, bv.games_played, bv.is_archived FROM bot_versions bv JOIN bots b ON b.id = bv.bot_id",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // Ranks on the 1v1 Elo board, which lists unarchived versions with games
+        let listed: Vec<_> = current.iter().filter(|c| c.6 == 0 && c.5 > 0).collect();
+        let ranks_before = crate::recompute::rank(
+            &listed.iter().map(|c| (c.0, c.3)).collect::<Vec<_>>(),
+        );
+        let ranks_after = crate::recompute::rank(
+            &listed
+                .iter()
+                .map(|c| (c.0, result.versions.get(&c.0).map_or(c.3, |v| v.elo)))
+                .collect::<Vec<_>>(),
+        );
+        let mut changes: Vec<VersionChange> = current
+            .iter()
+            .filter_map(|(id, name, version, elo, glicko, _, _)| {
+                let after = result.versions.get(id)?;
+                let change = VersionChange {
+                    bot_version_id: *id,
+                    bot_name: name.clone(),
+                    version: *version,
+                    elo_before: *elo,
+                    elo_after: after.elo,
+                    glicko_before: glicko.round() as i32,
+                    glicko_after: after.glicko.rating.round() as i32,
+                    rank_before: ranks_before.get(id).copied(),
+                    rank_after: ranks_after.get(id).copied(),
+                };
+                let changed = change.elo_before != change.elo_after
+                    || change.glicko_before != change.glicko_after
+                    || change.rank_before != change.rank_after;
+                changed.then_some(change)
+            })
+            .collect();
+        changes.sort_by_key(|c| {
+            (std::cmp::Reverse((c.elo_after - c.elo_before).abs()), c.bot_version_id)
+        });
+
+        let report = crate::recompute::RecomputeReport {
+            dry_run,
+            matches_replayed: result.matches_replayed,
+            versions_rated: result.versions.len(),
+            changes,
+        };
+        if dry_run {
+            return Ok(report);
+        }
+
+        for (id, v) in &result.versions {
+            sqlx::query(
+                r#"UPDATE bot_versions SET elo_rating = $1, elo_1v1 = $2, elo_peak = $3,
+                       glicko_rating = $4, glicko_rd = $5, glicko_volatility = $6,
+                       ffa_mu = $7, ffa_sigma = $8, team_mu = $9, team_sigma = $10,
+                       ffa_placement_points = $11, ffa_games = $12
+                   WHERE id = $13"#,
+            )
+            .bind(v.elo)
+            .bind(v.elo)
+            .bind(v.elo_peak)
+            .bind(v.glicko.rating)
+            .bind(v.glicko.deviation)
+            .bind(v.glicko.volatility)
+            .bind(v.ffa_skill.mu)
+            .bind(v.ffa_skill.sigma)
+            .bind(v.team_skill.mu)
+            .bind(v.team_skill.sigma)
+            .bind(v.ffa_placement_points)
+            .bind(v.ffa_games)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        for (id, elo) in &result.team_elo {
+            sqlx::query("UPDATE team_versions SET elo_rating = $1 WHERE id = $2")
+                .bind(elo)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (participant_id, before, after) in &result.participant_elo {
+            sqlx::query("UPDATE match_participants SET elo_before = $1, elo_after = $2 WHERE id = $3")
+                .bind(before)
+                .bind(after)
+                .bind(participant_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        sqlx::query("DELETE FROM rating_history")
+            .execute(&mut *tx)
+            .await?;
+        for h in &result.history {
+            sqlx::query(
+                "INSERT INTO rating_history (bot_version_id, match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(h.bot_version_id)
+            .bind(h.match_id)
+            .bind(h.rating_before)
+            .bind(h.rating_after)
+            .bind(h.opponent_version_id)
+            .bind(h.opponent_rating)
+            .bind(&h.created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(report)
+    }
+
+    /// Hold off the bulk rating rewrites (season rollover, recompute, Glicko
+    /// backfill) while a finished game's ratings are read and written.
+    pub async fn rating_update_guard(&self) -> tokio::sync::RwLockReadGuard<'_, ()> {
+        self.rating_updates.read().await
+    }
+
+    /// Take the write lock over everything a finished game updates, so no
+    /// game can complete between a replay's read and its commit. SQLite takes
+    /// the database write lock on a transaction's first write.
+    async fn lock_ratings(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Any>,
+    ) -> Result<(), sqlx::Error> {
+        let lock = if self.is_postgres {
+            "LOCK TABLE matches, match_participants, bot_versions, team_versions IN EXCLUSIVE MODE"
+        } else {
+            "UPDATE bot_versions SET id = id WHERE 0 = 1"
+        };
+        sqlx::query(lock).execute(&mut **tx).await?;
+        Ok(())
+    }
+
+    /// Load the full rating history inside `tx` and replay it through
+    /// `crate::recompute::replay`.
+    async fn replay_history(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Any>,
+    ) -> Result<crate::recompute::Recomputed, sqlx::Error> {
+        use crate::recompute::{ReplayMatch, ReplayParticipant, ReplayTeamVersion, ReplayVersion};
+
+        let versions = sqlx::query_as::<_, ReplayVersion>(
+            "SELECT id, bot_id, version, created_at FROM bot_versions",
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+        let team_versions = sqlx::query_as::<_, ReplayTeamVersion>(
+            "SELECT id, bot_version_a, bot_version_b, created_at FROM team_versions",
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+        let match_rows: Vec<(i64, String, Option<i64>, String)> = sqlx::query_as(
+            "SELECT id, format, winner_bot_version_id, finished_at FROM matches WHERE status = 'finished' AND ranked = 1 AND finished_at IS NOT NULL ORDER BY finished_at, id",
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+        let participant_rows = sqlx::query_as::<_, ReplayParticipant>(
+            r#"SELECT mp.id, mp.match_id, mp.bot_version_id, mp.player_slot, mp.final_score, mp.rated
+               FROM match_participants mp
+               JOIN matches m ON m.id = mp.match_id
+               WHERE m.status = 'finished' AND m.ranked = 1
+               ORDER BY mp.match_id, mp.player_slot"#,
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+        let mut by_match: std::collections::HashMap<i64, Vec<ReplayParticipant>> =
+            std::collections::HashMap::new();
+        for p in participant_rows {
+            by_match.entry(p.match_id).or_default().push(p);
+        }
+        let matches: Vec<ReplayMatch> = match_rows
+            .into_iter()
+            .map(|(id, format, winner_bot_version_id, finished_at)| ReplayMatch {
+                participants: by_match.remove(&id).unwrap_or_default(),
+                id,
+                format,
+                winner_bot_version_id,
+                finished_at,
+            })
+            .collect();
+
+        let season_resets: Vec<String> = sqlx::query_scalar(
+            "SELECT finished_at FROM seasons WHERE finished_at IS NOT NULL ORDER BY finished_at, id",
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Ok(crate::recompute::replay(&versions, &team_versions, &matches, &season_resets))
     }
 
     pub async fn update_version_stats(
@@ -1545,6 +3105,26 @@ impl Database {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Count an unranked game separately from the ranked record.
+    pub async fn update_version_unranked_stats(
+        &self,
+        version_id: i64,
+        won: bool,
+        lost: bool,
+        draw: bool,
+    ) -> Result<bool, sqlx::Error> {
+        let result: AnyQueryResult = sqlx::query(
+            "UPDATE bot_versions SET unranked_games = unranked_games + 1, unranked_wins = unranked_wins + $1, unranked_losses = unranked_losses + $2, unranked_draws = unranked_draws + $3 WHERE id = $4",
+        )
+        .bind(won as i32)
+        .bind(lost as i32)
+        .bind(draw as i32)
+        .bind(version_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn update_version_ffa_stats(
         &self,
         version_id: i64,
@@ -1616,6 +3196,18 @@ impl Database {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Move a tournament from "created" to "running" at round 1. `false` if
+    /// it isn't "created" (e.g. already started), so it only starts once.
+    pub async fn start_tournament(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let result: AnyQueryResult = sqlx::query(
+            "UPDATE tournaments SET status = 'running', current_round = 1 WHERE id = $1 AND status = 'created'",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn update_tournament_round(&self, id: i64, round: i32) -> Result<bool, sqlx::Error> {
         let result: AnyQueryResult = sqlx::query("UPDATE tournaments SET current_round = $1 WHERE id = $2")
             .bind(round)
@@ -1655,7 +3247,7 @@ impl Database {
         tournament_id: i64,
     ) -> Result<Vec<TournamentStanding>, sqlx::Error> {
         // Aggregate results by bot_version_id, joining with bots table for name
-        let rows: Vec<(i64, String, i64, i32)> = sqlx::query_as(
+        let mut rows: Vec<(i64, String, i64, i32)> = sqlx::query_as(
             r#"
             SELECT
                 tr.bot_version_id,
@@ -1674,52 +3266,64 @@ impl Database {
         .fetch_all(&self.pool)
         .await?;
 
-        // Compute wins/losses from the results
-        // A "win" means the bot had the highest score in a given result batch
-        // For simplicity, we compute wins as the count of times this bot had the
-        // max score among results with the same finished_at timestamp
-        let all_results = self.get_tournament_results(tournament_id).await?;
-
-        // Group results by finished_at (each game produces results with same timestamp)
-        let mut games: std::collections::HashMap<String, Vec<&TournamentResult>> =
-            std::collections::HashMap::new();
-        for r in &all_results {
-            games.entry(r.finished_at.clone()).or_default().push(r);
-        }
-
-        // Count wins/losses per bot_version_id
-        let mut wins_map: std::collections::HashMap<i64, i32> = std::collections::HashMap::new();
-        let mut losses_map: std::collections::HashMap<i64, i32> = std::collections::HashMap::new();
-        for game_results in games.values() {
-            if game_results.is_empty() {
-                continue;
-            }
-            let max_score = game_results
-                .iter()
-                .map(|r| r.final_score)
-                .max()
-                .unwrap_or(0);
-            for r in game_results {
-                if r.final_score == max_score {
-                    *wins_map.entry(r.bot_version_id).or_default() += 1;
-                } else {
-                    *losses_map.entry(r.bot_version_id).or_default() += 1;
-                }
+        // Entries yet to finish a game (a first-round bye, or a tournament
+        // that hasn't started) still get a row
+        for e in self.list_tournament_entries(tournament_id).await? {
+            if !rows.iter().any(|r| r.0 == e.bot_version_id) {
+                let name = e.bot_name.unwrap_or_else(|| "Unknown".to_string());
+                rows.push((e.bot_version_id, name, 0, 0));
             }
         }
 
-        let standings = rows
+        // Wins, points and tiebreakers come from match outcomes. Only Swiss
+        // tournaments are ranked by them (ties on all of them keep the
+        // total_score order); other formats stay ranked by total score.
+        let details = self.get_tournament_matches_detail(tournament_id).await?;
+        let byes = self.list_tournament_byes(tournament_id).await?;
+        let participants: Vec<i64> = rows.iter().map(|r| r.0).collect();
+        let records = crate::tournament::swiss_standings(
+            &participants,
+            &crate::tournament::bracket_games(&details),
+            &byes,
+        );
+        let swiss = matches!(
+            self.get_tournament(tournament_id)
+                .await?
+                .and_then(|t| crate::tournament::TournamentFormat::from_str_name(&t.format)),
+            Some(crate::tournament::TournamentFormat::Swiss { .. })
+        );
+        let order: Vec<i64> = if swiss {
+            records.iter().map(|r| r.bot_version_id).collect()
+        } else {
+            participants
+        };
+
+        let mut records: std::collections::HashMap<i64, crate::tournament::SwissRecord> =
+            records.into_iter().map(|r| (r.bot_version_id, r)).collect();
+        let mut rows: std::collections::HashMap<i64, (String, i64, i32)> = rows
             .into_iter()
-            .map(
-                |(bot_version_id, bot_name, total_score, matches_played)| TournamentStanding {
-                    bot_version_id,
+            .map(|(id, name, score, played)| (id, (name, score, played)))
+            .collect();
+        let standings = order
+            .into_iter()
+            .filter_map(|id| {
+                let (bot_name, total_score, matches_played) = rows.remove(&id)?;
+                let r = records.remove(&id)?;
+                Some(TournamentStanding {
+                    bot_version_id: r.bot_version_id,
                     bot_name,
                     total_score,
                     matches_played,
-                    wins: *wins_map.get(&bot_version_id).unwrap_or(&0),
-                    losses: *losses_map.get(&bot_version_id).unwrap_or(&0),
-                },
-            )
+                    wins: r.wins,
+                    draws: r.draws,
+                    losses: r.losses,
+                    byes: r.byes,
+                    points: r.points,
+                    buchholz: r.buchholz,
+                    sonneborn_berger: r.sonneborn_berger,
+                    head_to_head: r.head_to_head,
+                })
+            })
             .collect();
 
         Ok(standings)
@@ -1817,11 +3421,40 @@ impl Database {
             "INSERT INTO tournament_matches (tournament_id, match_id, round) VALUES ($1, $2, $3) RETURNING id, tournament_id, match_id, round",
         )
         .bind(tournament_id)
-        .bind(match_id)
-        .bind(round)
-        .fetch_one(&self.pool)
+        .bind(match_id)
+        .bind(round)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// Record that `bot_version_id` sits out `round` of a Swiss tournament.
+    pub async fn add_tournament_bye(
+        &self,
+        tournament_id: i64,
+        bot_version_id: i64,
+        round: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO tournament_byes (tournament_id, bot_version_id, round) VALUES ($1, $2, $3)",
+        )
+        .bind(tournament_id)
+        .bind(bot_version_id)
+        .bind(round)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Bot versions given a bye, once per bye, in round order.
+    pub async fn list_tournament_byes(&self, tournament_id: i64) -> Result<Vec<i64>, sqlx::Error> {
+        let rows: Vec<(i64,)> = sqlx::query_as(
+            "SELECT bot_version_id FROM tournament_byes WHERE tournament_id = $1 ORDER BY round, id",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
         .await?;
-        Ok(row)
+        Ok(rows.into_iter().map(|(id,)| id).collect())
     }
 
     pub async fn list_tournament_matches(
@@ -1899,81 +3532,316 @@ impl Database {
 
     // ── Leaderboards ─────────────────────────────────────────────────
 
+    /// `sort` is "glicko" to rank by Glicko-2 rating, anything else ranks by
+    /// Elo. Windowed boards (`filter.period_days`) also report each version's
+    /// Elo change over the window.
     pub async fn leaderboard_1v1(
         &self,
         limit: i64,
         offset: i64,
+        sort: &str,
+        filter: &LeaderboardFilter,
     ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
-        // CAST AS REAL works on SQLite; PostgreSQL needs DOUBLE PRECISION
-        let cast_type = if self.is_postgres { "DOUBLE PRECISION" } else { "REAL" };
+        let (rating, sort_key) = if sort == "glicko" {
+            ("CAST(ROUND(bv.glicko_rating) AS INTEGER)", "bv.glicko_rating")
+        } else {
+            ("bv.elo_1v1", "bv.elo_1v1")
+        };
+        let rating_change = match filter.period_days {
+            Some(days) if sort != "glicko" => Some(format!(
+                r#"bv.elo_1v1 - COALESCE(
+                    (SELECT rh.rating_before FROM rating_history rh
+                     WHERE rh.bot_version_id = bv.id AND rh.created_at >= {}
+                     ORDER BY rh.created_at, rh.id LIMIT 1),
+                    bv.elo_1v1
+                ) AS rating_change,"#,
+                self.days_ago_expr(days)
+            )),
+            _ => None,
+        };
+        let board = VersionBoard {
+            format: "1v1",
+            rating,
+            sort_key,
+            confidence: "CAST(ROUND(1.96 * bv.glicko_rd) AS INTEGER)",
+            uncertainty: "bv.glicko_rd",
+            provisional_limit: crate::glicko::PROVISIONAL_DEVIATION,
+            rating_change: rating_change.as_deref(),
+        };
+        self.version_leaderboard(&board, limit, offset, filter).await
+    }
+
+    /// `sort` is "skill" to rank by Weng-Lin skill (mu - 3 sigma, shown as
+    /// mu ± 3 sigma), anything else ranks by placement points (earned in the
+    /// window or on the map, for scoped boards).
+    pub async fn leaderboard_ffa(
+        &self,
+        limit: i64,
+        offset: i64,
+        sort: &str,
+        filter: &LeaderboardFilter,
+    ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+        let (rating, sort_key) = match (sort, filter.is_scoped()) {
+            ("skill", _) => ("CAST(ROUND(bv.ffa_mu) AS INTEGER)", "bv.ffa_mu - 3 * bv.ffa_sigma"),
+            (_, true) => ("s.points", "s.points"),
+            (_, false) => ("bv.ffa_placement_points", "bv.ffa_placement_points"),
+        };
+        let board = VersionBoard {
+            format: "ffa",
+            rating,
+            sort_key,
+            confidence: "CAST(ROUND(3 * bv.ffa_sigma) AS INTEGER)",
+            uncertainty: "bv.ffa_sigma",
+            provisional_limit: crate::openskill::PROVISIONAL_SIGMA,
+            rating_change: None,
+        };
+        self.version_leaderboard(&board, limit, offset, filter).await
+    }
+
+    /// Results of the ranked `format` games a scoped board covers (see
+    /// `LeaderboardFilter::is_scoped`), summed from `leaderboard_stats` and
+    /// joined as `s` on `entry` (the version or team version id column).
+    /// `map_param` is the placeholder bound to `filter.map`.
+    fn scoped_stats_sql(
+        &self,
+        format: &str,
+        entry: &str,
+        filter: &LeaderboardFilter,
+        map_param: Option<&str>,
+    ) -> String {
+        let mut scope = String::new();
+        if let Some(days) = filter.period_days {
+            // Whole days: the window starts at midnight `days` days ago
+            scope.push_str(&format!(" AND day >= substr({}, 1, 10)", self.days_ago_expr(days)));
+        }
+        if let Some(param) = map_param {
+            scope.push_str(&format!(" AND map = {param}"));
+        }
+        format!(
+            r#"JOIN (
+                SELECT
+                    entry_id,
+                    CAST(SUM(games_played) AS INTEGER) AS games_played,
+                    CAST(SUM(wins) AS INTEGER) AS wins,
+                    CAST(SUM(losses) AS INTEGER) AS losses,
+                    CAST(SUM(points) AS INTEGER) AS points
+                FROM leaderboard_stats
+                WHERE format = '{format}'{scope}
+                GROUP BY entry_id
+            ) s ON s.entry_id = {entry}"#
+        )
+    }
+
+    /// Add a rated participant's result to today's row of `leaderboard_stats`
+    /// for the match's format and map. Called from game completion for 1v1
+    /// and FFA; `points` are FFA placement points (0 otherwise).
+    pub async fn record_leaderboard_result(
+        &self,
+        match_id: i64,
+        bot_version_id: i64,
+        won: bool,
+        lost: bool,
+        points: i32,
+    ) -> Result<(), sqlx::Error> {
         let sql = format!(
-            r#"
-            SELECT
-                ROW_NUMBER() OVER (ORDER BY bv.elo_1v1 DESC) AS rank,
-                bv.id AS bot_version_id,
-                b.name AS bot_name,
-                bv.version,
-                COALESCE(u.username, 'anonymous') AS owner_username,
-                bv.elo_1v1 AS rating,
-                bv.games_played,
-                bv.wins,
-                bv.losses,
-                CASE WHEN bv.games_played > 0
-                    THEN CAST(bv.wins AS {cast_type}) / bv.games_played
-                    ELSE 0.0
-                END AS win_rate
-            FROM bot_versions bv
-            JOIN bots b ON b.id = bv.bot_id
-            LEFT JOIN users u ON u.id = b.owner_id
-            WHERE bv.is_archived = 0 AND bv.games_played > 0
-            ORDER BY bv.elo_1v1 DESC
-            LIMIT $1 OFFSET $2
-            "#
+            "INSERT INTO leaderboard_stats (format, entry_id, day, map, games_played, wins, losses, points)
+             SELECT m.format, $1, substr(m.finished_at, 1, 10), m.map, 1, $2, $3, $4
+             FROM matches m WHERE m.id = $5 AND m.finished_at IS NOT NULL
+             {LEADERBOARD_UPSERT}"
         );
-        let rows = sqlx::query_as::<_, LeaderboardEntry>(&sql)
-            .bind(limit)
-            .bind(offset)
-            .fetch_all(&self.pool)
+        sqlx::query(&sql)
+            .bind(bot_version_id)
+            .bind(won as i32)
+            .bind(lost as i32)
+            .bind(points)
+            .bind(match_id)
+            .execute(&self.pool)
             .await?;
-        Ok(rows)
+        Ok(())
     }
 
-    pub async fn leaderboard_ffa(
+    /// 2v2 counterpart of `record_leaderboard_result`, for every version of
+    /// the team made of `bot_version_a` and `bot_version_b` (either order).
+    pub async fn record_leaderboard_team_result(
+        &self,
+        match_id: i64,
+        bot_version_a: i64,
+        bot_version_b: i64,
+        won: bool,
+        lost: bool,
+    ) -> Result<(), sqlx::Error> {
+        let sql = format!(
+            "INSERT INTO leaderboard_stats (format, entry_id, day, map, games_played, wins, losses, points)
+             SELECT m.format, tv.id, substr(m.finished_at, 1, 10), m.map, 1, $1, $2, 0
+             FROM matches m
+             JOIN team_versions tv
+               ON (tv.bot_version_a = $3 AND tv.bot_version_b = $4)
+               OR (tv.bot_version_a = $4 AND tv.bot_version_b = $3)
+             WHERE m.id = $5 AND m.finished_at IS NOT NULL
+             {LEADERBOARD_UPSERT}"
+        );
+        sqlx::query(&sql)
+            .bind(won as i32)
+            .bind(lost as i32)
+            .bind(bot_version_a)
+            .bind(bot_version_b)
+            .bind(match_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Recompute `leaderboard_stats` from match history in one transaction,
+    /// repairing any drift in the incremental updates. Returns the number of
+    /// rows written.
+    pub async fn rebuild_leaderboard_stats(&self) -> Result<u64, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM leaderboard_stats")
+            .execute(&mut *tx)
+            .await?;
+        // 1v1 and FFA, per version; FFA points as in `elo::ffa_placement_points`
+        let versions = sqlx::query(
+            r#"INSERT INTO leaderboard_stats (format, entry_id, day, map, games_played, wins, losses, points)
+            SELECT
+                m.format,
+                mp.bot_version_id,
+                substr(m.finished_at, 1, 10),
+                m.map,
+                CAST(COUNT(*) AS INTEGER),
+                CAST(SUM(CASE WHEN m.winner_bot_version_id = mp.bot_version_id THEN 1 ELSE 0 END) AS INTEGER),
+                CAST(SUM(CASE WHEN m.winner_bot_version_id <> mp.bot_version_id THEN 1 ELSE 0 END) AS INTEGER),
+                CAST(SUM(CASE WHEN m.format = 'ffa'
+                    THEN n.players - COALESCE(mp.placement, n.players) + 1 ELSE 0 END) AS INTEGER)
+            FROM match_participants mp
+            JOIN matches m ON m.id = mp.match_id
+            JOIN (SELECT match_id, COUNT(*) AS players FROM match_participants GROUP BY match_id) n
+                ON n.match_id = m.id
+            WHERE m.status = 'finished' AND m.ranked = 1 AND mp.rated = 1
+              AND m.format IN ('1v1', 'ffa') AND m.finished_at IS NOT NULL
+            GROUP BY m.format, mp.bot_version_id, substr(m.finished_at, 1, 10), m.map"#,
+        )
+        .execute(&mut *tx)
+        .await?;
+        // 2v2, per team version: both members in the same half of the slots
+        // (0-1 or 2-3); the team with the better placement won
+        let teams = sqlx::query(
+            r#"INSERT INTO leaderboard_stats (format, entry_id, day, map, games_played, wins, losses, points)
+            SELECT
+                '2v2',
+                tv.id,
+                substr(m.finished_at, 1, 10),
+                m.map,
+                CAST(COUNT(*) AS INTEGER),
+                CAST(SUM(CASE WHEN pa.placement < n.worst THEN 1 ELSE 0 END) AS INTEGER),
+                CAST(SUM(CASE WHEN pa.placement > n.best THEN 1 ELSE 0 END) AS INTEGER),
+                0
+            FROM team_versions tv
+            JOIN match_participants pa ON pa.bot_version_id IN (tv.bot_version_a, tv.bot_version_b)
+            JOIN match_participants pb ON pb.match_id = pa.match_id
+                AND pb.player_slot = pa.player_slot + 1
+                AND ((pa.bot_version_id = tv.bot_version_a AND pb.bot_version_id = tv.bot_version_b)
+                  OR (pa.bot_version_id = tv.bot_version_b AND pb.bot_version_id = tv.bot_version_a))
+            JOIN matches m ON m.id = pa.match_id
+            JOIN (
+                SELECT match_id, MIN(placement) AS best, MAX(placement) AS worst
+                FROM match_participants GROUP BY match_id
+            ) n ON n.match_id = m.id
+            WHERE pa.player_slot % 2 = 0 AND m.status = 'finished' AND m.ranked = 1
+              AND pa.rated = 1 AND m.format = '2v2' AND m.finished_at IS NOT NULL
+            GROUP BY tv.id, substr(m.finished_at, 1, 10), m.map"#,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(versions.rows_affected() + teams.rows_affected())
+    }
+
+    /// Shared query behind the 1v1 and FFA boards. Equal sort keys share a
+    /// rank (1, 2, 2, 4); within a rank rows are ordered by version id.
+    /// `$3` is bound to the provisional limit, filter values follow from `$4`.
+    async fn version_leaderboard(
         &self,
+        board: &VersionBoard<'_>,
         limit: i64,
         offset: i64,
+        filter: &LeaderboardFilter,
     ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+        // CAST AS REAL works on SQLite; PostgreSQL needs DOUBLE PRECISION
         let cast_type = if self.is_postgres { "DOUBLE PRECISION" } else { "REAL" };
+        let mut args: Vec<&str> = Vec::new();
+        let mut conds = self.league_filter(filter.league);
+        let league_sql = self.league_expr("u");
+        if filter.hide_provisional {
+            conds.push_str(&format!(" AND {} <= $3", board.uncertainty));
+        }
+        if filter.active_only {
+            conds.push_str(" AND b.active_version_id = bv.id");
+        }
+        if let Some(owner) = &filter.owner {
+            args.push(owner);
+            conds.push_str(&format!(" AND u.username = ${}", args.len() + 3));
+        }
+        let (stats, source) = if filter.is_scoped() {
+            let map_param = filter.map.as_deref().map(|map| {
+                args.push(map);
+                format!("${}", args.len() + 3)
+            });
+            (
+                "s.games_played, s.wins, s.losses",
+                self.scoped_stats_sql(board.format, "bv.id", filter, map_param.as_deref()),
+            )
+        } else {
+            conds.push_str(" AND bv.games_played > 0");
+            ("bv.games_played, bv.wins, bv.losses", String::new())
+        };
+        let per_bot = if filter.per_bot { "WHERE x.bot_pos = 1" } else { "" };
         let sql = format!(
             r#"
             SELECT
-                ROW_NUMBER() OVER (ORDER BY bv.ffa_placement_points DESC) AS rank,
-                bv.id AS bot_version_id,
-                b.name AS bot_name,
-                bv.version,
-                COALESCE(u.username, 'anonymous') AS owner_username,
-                bv.ffa_placement_points AS rating,
-                bv.games_played,
-                bv.wins,
-                bv.losses,
-                CASE WHEN bv.games_played > 0
-                    THEN CAST(bv.wins AS {cast_type}) / bv.games_played
+                RANK() OVER (ORDER BY x.sort_key DESC) AS rank,
+                x.*,
+                CASE WHEN x.games_played > 0
+                    THEN CAST(x.wins AS {cast_type}) / x.games_played
                     ELSE 0.0
                 END AS win_rate
-            FROM bot_versions bv
-            JOIN bots b ON b.id = bv.bot_id
-            LEFT JOIN users u ON u.id = b.owner_id
-            WHERE bv.is_archived = 0 AND bv.games_played > 0
-            ORDER BY bv.ffa_placement_points DESC
+            FROM (
+                SELECT
+                    bv.id AS bot_version_id,
+                    b.name AS bot_name,
+                    bv.version,
+                    COALESCE(u.username, 'anonymous') AS owner_username,
+                    {league_sql} AS league,
+                    {rating} AS rating,
+                    {stats},
+                    {confidence} AS confidence,
+                    CASE WHEN {uncertainty} > $3 THEN 1 ELSE 0 END AS provisional,
+                    {rating_change}
+                    {sort_key} AS sort_key,
+                    ROW_NUMBER() OVER (PARTITION BY bv.bot_id ORDER BY {sort_key} DESC, bv.id) AS bot_pos
+                FROM bot_versions bv
+                JOIN bots b ON b.id = bv.bot_id
+                LEFT JOIN users u ON u.id = b.owner_id
+                {source}
+                WHERE bv.is_archived = 0 {conds}
+            ) x
+            {per_bot}
+            ORDER BY x.sort_key DESC, x.bot_version_id
             LIMIT $1 OFFSET $2
-            "#
+            "#,
+            rating = board.rating,
+            confidence = board.confidence,
+            uncertainty = board.uncertainty,
+            rating_change = board.rating_change.unwrap_or(""),
+            sort_key = board.sort_key,
         );
-        let rows = sqlx::query_as::<_, LeaderboardEntry>(&sql)
+        let mut query = sqlx::query_as::<_, LeaderboardEntry>(&sql)
             .bind(limit)
             .bind(offset)
-            .fetch_all(&self.pool)
-            .await?;
-        Ok(rows)
+            .bind(board.provisional_limit);
+        for arg in args {
+            query = query.bind(arg);
+        }
+        query.fetch_all(&self.pool).await
     }
 
     // ── Team CRUD ─────────────────────────────────────────────────────
@@ -2082,42 +3950,218 @@ impl Database {
         Ok(row)
     }
 
+    /// Count a 2v2 result for every team version made of this pair of bot
+    /// versions (in either order).
+    pub async fn record_team_version_result(
+        &self,
+        bot_version_a: i64,
+        bot_version_b: i64,
+        won: bool,
+        lost: bool,
+        draw: bool,
+    ) -> Result<u64, sqlx::Error> {
+        let result: AnyQueryResult = sqlx::query(
+            r#"UPDATE team_versions SET games_played = games_played + 1,
+                   wins = wins + $1, losses = losses + $2, draws = draws + $3
+               WHERE (bot_version_a = $4 AND bot_version_b = $5)
+                  OR (bot_version_a = $5 AND bot_version_b = $4)"#,
+        )
+        .bind(won as i32)
+        .bind(lost as i32)
+        .bind(draw as i32)
+        .bind(bot_version_a)
+        .bind(bot_version_b)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Team Elo and games played of the newest team version pairing these
+    /// two bot versions (in either order), if any team fields them.
+    pub async fn team_pair_rating(
+        &self,
+        bot_version_a: i64,
+        bot_version_b: i64,
+    ) -> Result<Option<(i32, i32)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"SELECT elo_rating, games_played FROM team_versions
+               WHERE (bot_version_a = $1 AND bot_version_b = $2)
+                  OR (bot_version_a = $2 AND bot_version_b = $1)
+               ORDER BY created_at DESC, id DESC LIMIT 1"#,
+        )
+        .bind(bot_version_a)
+        .bind(bot_version_b)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Set the team Elo of every team version pairing these two bot versions.
+    pub async fn update_team_pair_elo(
+        &self,
+        bot_version_a: i64,
+        bot_version_b: i64,
+        elo: i32,
+    ) -> Result<u64, sqlx::Error> {
+        let result: AnyQueryResult = sqlx::query(
+            r#"UPDATE team_versions SET elo_rating = $1
+               WHERE (bot_version_a = $2 AND bot_version_b = $3)
+                  OR (bot_version_a = $3 AND bot_version_b = $2)"#,
+        )
+        .bind(elo)
+        .bind(bot_version_a)
+        .bind(bot_version_b)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// `sort` is "skill" to rank by the Weng-Lin skill of the two member
+    /// versions combined, anything else ranks by team Elo. Skill needs a
+    /// square root, which SQLite lacks, so ranking happens here rather than in
+    /// SQL, with the same rank/ties semantics as the other boards.
     pub async fn leaderboard_2v2(
         &self,
         limit: i64,
         offset: i64,
+        sort: &str,
+        filter: &LeaderboardFilter,
     ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
-        let cast_type = if self.is_postgres { "DOUBLE PRECISION" } else { "REAL" };
+        #[derive(sqlx::FromRow)]
+        struct Row {
+            id: i64,
+            team_id: i64,
+            name: String,
+            version: i32,
+            owner_username: String,
+            league: String,
+            elo_rating: i32,
+            games_played: i32,
+            wins: i32,
+            losses: i32,
+            a_mu: f64,
+            a_sigma: f64,
+            b_mu: f64,
+            b_sigma: f64,
+        }
+
+        let mut args: Vec<&str> = Vec::new();
+        let mut conds = self.league_filter(filter.league);
+        let league_sql = self.league_expr("u");
+        if filter.active_only {
+            conds.push_str(
+                " AND tv.version = (SELECT MAX(l.version) FROM team_versions l WHERE l.team_id = tv.team_id)",
+            );
+        }
+        if let Some(owner) = &filter.owner {
+            args.push(owner);
+            conds.push_str(&format!(" AND u.username = ${}", args.len()));
+        }
+        let (stats, source) = if filter.is_scoped() {
+            let map_param = filter.map.as_deref().map(|map| {
+                args.push(map);
+                format!("${}", args.len())
+            });
+            let source = self.scoped_stats_sql("2v2", "tv.id", filter, map_param.as_deref());
+            ("s.games_played, s.wins, s.losses", source)
+        } else {
+            conds.push_str(" AND tv.games_played > 0");
+            ("tv.games_played, tv.wins, tv.losses", String::new())
+        };
         let sql = format!(
             r#"
             SELECT
-                ROW_NUMBER() OVER (ORDER BY tv.elo_rating DESC) AS rank,
-                tv.id AS bot_version_id,
-                t.name AS bot_name,
+                tv.id,
+                tv.team_id,
+                t.name,
                 tv.version,
                 COALESCE(u.username, 'anonymous') AS owner_username,
-                tv.elo_rating AS rating,
-                tv.games_played,
-                tv.wins,
-                tv.losses,
-                CASE WHEN tv.games_played > 0
-                    THEN CAST(tv.wins AS {cast_type}) / tv.games_played
-                    ELSE 0.0
-                END AS win_rate
+                {league_sql} AS league,
+                tv.elo_rating,
+                {stats},
+                a.team_mu AS a_mu,
+                a.team_sigma AS a_sigma,
+                b.team_mu AS b_mu,
+                b.team_sigma AS b_sigma
             FROM team_versions tv
             JOIN teams t ON t.id = tv.team_id
+            JOIN bot_versions a ON a.id = tv.bot_version_a
+            JOIN bot_versions b ON b.id = tv.bot_version_b
             LEFT JOIN users u ON u.id = t.owner_id
-            WHERE tv.games_played > 0
-            ORDER BY tv.elo_rating DESC
-            LIMIT $1 OFFSET $2
+            {source}
+            WHERE 1 = 1 {conds}
+            ORDER BY tv.id
             "#
         );
-        let rows = sqlx::query_as::<_, LeaderboardEntry>(&sql)
-            .bind(limit)
-            .bind(offset)
-            .fetch_all(&self.pool)
-            .await?;
-        Ok(rows)
+        let mut query = sqlx::query_as::<_, Row>(&sql);
+        for arg in args {
+            query = query.bind(arg);
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let mut rated: Vec<(Row, crate::openskill::SkillRating)> = rows
+            .into_iter()
+            .map(|r| {
+                let skill = crate::openskill::team_rating(&[
+                    crate::openskill::SkillRating { mu: r.a_mu, sigma: r.a_sigma },
+                    crate::openskill::SkillRating { mu: r.b_mu, sigma: r.b_sigma },
+                ]);
+                (r, skill)
+            })
+            .filter(|(_, skill)| !filter.hide_provisional || !skill.is_provisional())
+            .collect();
+        let sort_key = |(r, skill): &(Row, crate::openskill::SkillRating)| {
+            if sort == "skill" {
+                skill.ordinal()
+            } else {
+                r.elo_rating as f64
+            }
+        };
+        // Stable sort keeps version id order within equal keys
+        rated.sort_by(|x, y| sort_key(y).total_cmp(&sort_key(x)));
+        if filter.per_bot {
+            let mut seen = std::collections::HashSet::new();
+            rated.retain(|(r, _)| seen.insert(r.team_id));
+        }
+
+        // Competition ranking: equal keys share a rank (1, 2, 2, 4)
+        let keys: Vec<f64> = rated.iter().map(sort_key).collect();
+        let mut rank = 0;
+        let ranks: Vec<i64> = (0..keys.len())
+            .map(|i| {
+                if i == 0 || keys[i] != keys[i - 1] {
+                    rank = i as i64 + 1;
+                }
+                rank
+            })
+            .collect();
+
+        let entries = ranks
+            .into_iter()
+            .zip(rated)
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .map(|(rank, (r, skill))| LeaderboardEntry {
+                rank,
+                bot_version_id: r.id,
+                bot_name: r.name,
+                version: r.version,
+                owner_username: r.owner_username,
+                league: r.league,
+                rating: if sort == "skill" { skill.mu.round() as i32 } else { r.elo_rating },
+                games_played: r.games_played,
+                wins: r.wins,
+                losses: r.losses,
+                win_rate: if r.games_played > 0 {
+                    r.wins as f64 / r.games_played as f64
+                } else {
+                    0.0
+                },
+                confidence: Some(skill.confidence().round() as i32),
+                provisional: skill.is_provisional() as i32,
+                rating_change: None,
+            })
+            .collect();
+        Ok(entries)
     }
 
     // ── API Token CRUD ──────────────────────────────────────────────────
@@ -2286,52 +4330,207 @@ impl Database {
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
-        Ok(row.0)
+        Ok(row.0)
+    }
+
+    /// Get all unique owner IDs for bot versions involved in a match.
+    pub async fn get_match_participant_owner_ids(
+        &self,
+        match_id: i64,
+    ) -> Result<Vec<i64>, sqlx::Error> {
+        let rows: Vec<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT b.owner_id
+            FROM match_participants mp
+            JOIN bot_versions bv ON bv.id = mp.bot_version_id
+            JOIN bots b ON b.id = bv.bot_id
+            WHERE mp.match_id = $1 AND b.owner_id IS NOT NULL
+            "#,
+        )
+        .bind(match_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|r| r.0).collect())
+    }
+
+    // ── Feedback CRUD ─────────────────────────────────────────────────
+
+    pub async fn create_feedback(
+        &self,
+        user_id: Option<i64>,
+        category: &str,
+        description: &str,
+    ) -> Result<Feedback, sqlx::Error> {
+        let row = sqlx::query_as::<_, Feedback>(
+            "INSERT INTO feedback (user_id, category, description) VALUES ($1, $2, $3) RETURNING id, user_id, category, description, created_at",
+        )
+        .bind(user_id)
+        .bind(category)
+        .bind(description)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn list_feedback(&self) -> Result<Vec<Feedback>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, Feedback>(
+            "SELECT id, user_id, category, description, created_at FROM feedback ORDER BY created_at DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    // ── Custom Maps ──────────────────────────────────────────────────
+
+    /// Create a custom map together with its first version.
+    pub async fn create_custom_map(
+        &self,
+        owner_id: i64,
+        name: &str,
+        description: &str,
+        visibility: &str,
+        (width, height): (i32, i32),
+        data: &str,
+    ) -> Result<CustomMap, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query_as::<_, CustomMap>(
+            "INSERT INTO maps (owner_id, name, description, visibility, width, height) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, owner_id, name, description, visibility, width, height, version, created_at, updated_at",
+        )
+        .bind(owner_id)
+        .bind(name)
+        .bind(description)
+        .bind(visibility)
+        .bind(width)
+        .bind(height)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("INSERT INTO map_versions (map_id, version, data) VALUES ($1, $2, $3)")
+            .bind(row.id)
+            .bind(row.version)
+            .bind(data)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(row)
+    }
+
+    pub async fn get_custom_map(&self, id: i64) -> Result<Option<CustomMap>, sqlx::Error> {
+        let row = sqlx::query_as::<_, CustomMap>(
+            "SELECT id, owner_id, name, description, visibility, width, height, version, created_at, updated_at FROM maps WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn get_custom_map_by_name(&self, name: &str) -> Result<Option<CustomMap>, sqlx::Error> {
+        let row = sqlx::query_as::<_, CustomMap>(
+            "SELECT id, owner_id, name, description, visibility, width, height, version, created_at, updated_at FROM maps WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
     }
 
-    /// Get all unique owner IDs for bot versions involved in a match.
-    pub async fn get_match_participant_owner_ids(
-        &self,
-        match_id: i64,
-    ) -> Result<Vec<i64>, sqlx::Error> {
-        let rows: Vec<(i64,)> = sqlx::query_as(
-            r#"
-            SELECT DISTINCT b.owner_id
-            FROM match_participants mp
-            JOIN bot_versions bv ON bv.id = mp.bot_version_id
-            JOIN bots b ON b.id = bv.bot_id
-            WHERE mp.match_id = $1 AND b.owner_id IS NOT NULL
-            "#,
+    /// List public custom maps, plus the viewer's own private maps.
+    pub async fn list_custom_maps(&self, viewer_id: Option<i64>) -> Result<Vec<CustomMap>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CustomMap>(
+            "SELECT id, owner_id, name, description, visibility, width, height, version, created_at, updated_at FROM maps WHERE visibility = 'public' OR owner_id = $1 ORDER BY name",
         )
-        .bind(match_id)
+        .bind(viewer_id.unwrap_or(-1))
         .fetch_all(&self.pool)
         .await?;
-        Ok(rows.into_iter().map(|r| r.0).collect())
+        Ok(rows)
     }
 
-    // ── Feedback CRUD ─────────────────────────────────────────────────
-
-    pub async fn create_feedback(
+    /// Update a custom map's description and visibility and, when `contents`
+    /// holds a new size and tile data, store them as the next version. It's
+    /// one transaction, so a failure leaves neither a bumped version without
+    /// data nor data without its version.
+    pub async fn update_custom_map(
         &self,
-        user_id: Option<i64>,
-        category: &str,
+        id: i64,
         description: &str,
-    ) -> Result<Feedback, sqlx::Error> {
-        let row = sqlx::query_as::<_, Feedback>(
-            "INSERT INTO feedback (user_id, category, description) VALUES ($1, $2, $3) RETURNING id, user_id, category, description, created_at",
-        )
-        .bind(user_id)
-        .bind(category)
-        .bind(description)
-        .fetch_one(&self.pool)
-        .await?;
+        visibility: &str,
+        contents: Option<((i32, i32), &str)>,
+    ) -> Result<Option<CustomMap>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "UPDATE maps SET description = $1, visibility = $2, updated_at = {} WHERE id = $3",
+            self.now_expr()
+        );
+        let result: AnyQueryResult = sqlx::query(&sql)
+            .bind(description)
+            .bind(visibility)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        if let Some(((width, height), data)) = contents {
+            // The bump reads the version under the row lock taken above, so
+            // concurrent saves get consecutive versions
+            let (version,): (i32,) = sqlx::query_as(
+                "UPDATE maps SET version = version + 1, width = $1, height = $2 WHERE id = $3 RETURNING version",
+            )
+            .bind(width)
+            .bind(height)
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+            sqlx::query("INSERT INTO map_versions (map_id, version, data) VALUES ($1, $2, $3)")
+                .bind(id)
+                .bind(version)
+                .bind(data)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        self.get_custom_map(id).await
+    }
+
+    /// Get one version of a custom map's data, or the latest when `version` is None.
+    pub async fn get_custom_map_version(
+        &self,
+        map_id: i64,
+        version: Option<i32>,
+    ) -> Result<Option<CustomMapVersion>, sqlx::Error> {
+        let row = match version {
+            Some(v) => {
+                sqlx::query_as::<_, CustomMapVersion>(
+                    "SELECT id, map_id, version, data, created_at FROM map_versions WHERE map_id = $1 AND version = $2",
+                )
+                .bind(map_id)
+                .bind(v)
+                .fetch_optional(&self.pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as::<_, CustomMapVersion>(
+                    "SELECT id, map_id, version, data, created_at FROM map_versions WHERE map_id = $1 ORDER BY version DESC LIMIT 1",
+                )
+                .bind(map_id)
+                .fetch_optional(&self.pool)
+                .await?
+            }
+        };
         Ok(row)
     }
 
-    pub async fn list_feedback(&self) -> Result<Vec<Feedback>, sqlx::Error> {
-        let rows = sqlx::query_as::<_, Feedback>(
-            "SELECT id, user_id, category, description, created_at FROM feedback ORDER BY created_at DESC",
+    pub async fn list_custom_map_versions(
+        &self,
+        map_id: i64,
+    ) -> Result<Vec<CustomMapVersion>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CustomMapVersion>(
+            "SELECT id, map_id, version, data, created_at FROM map_versions WHERE map_id = $1 ORDER BY version",
         )
+        .bind(map_id)
         .fetch_all(&self.pool)
         .await?;
         Ok(rows)
@@ -2611,13 +4810,20 @@ mod tests {
         let db = test_db().await;
 
         let bot = db.create_bot("Original", "desc", None).await.unwrap();
-        let updated = db.update_bot(bot.id, "Updated", "new desc").await.unwrap();
+        let updated = db.update_bot(bot.id, "Updated", "new desc", None).await.unwrap();
         assert!(updated.is_some());
         let updated = updated.unwrap();
         assert_eq!(updated.name, "Updated");
         assert_eq!(updated.description, "new desc");
+        assert_eq!(updated.ladder, 0);
+
+        // The ladder flag changes with the same update, or stays when not given
+        for (ladder, expected) in [(Some(true), 1), (None, 1), (Some(false), 0)] {
+            let updated = db.update_bot(bot.id, "Updated", "", ladder).await.unwrap().unwrap();
+            assert_eq!(updated.ladder, expected);
+        }
 
-        let not_found = db.update_bot(999, "X", "Y").await.unwrap();
+        let not_found = db.update_bot(999, "X", "Y", Some(true)).await.unwrap();
         assert!(not_found.is_none());
     }
 
@@ -2663,6 +4869,46 @@ mod tests {
         assert_eq!(fetched.unwrap().code, "print('v1')");
     }
 
+    #[tokio::test]
+    async fn test_bot_version_records_api_level() {
+        use crate::engine::config::LUA_API_LEVEL;
+
+        let db = test_db().await;
+        let bot = db.create_bot("LevelBot", "", None).await.unwrap();
+
+        let current = db.create_bot_version(bot.id, "function bot() end").await.unwrap();
+        assert_eq!(current.api_type, "state");
+        assert_eq!(current.api_level, LUA_API_LEVEL as i32);
+
+        let pinned = db
+            .create_bot_version(bot.id, "needs_api(\"oo\", 1)\nfunction Creature:main() end")
+            .await
+            .unwrap();
+        let fetched = db.get_bot_version_by_id(pinned.id).await.unwrap().unwrap();
+        assert_eq!((fetched.api_type.as_str(), fetched.api_level), ("oo", 1));
+    }
+
+    #[tokio::test]
+    async fn test_legacy_bot_version_keeps_its_api_style() {
+        use crate::engine::player::{ApiStyle, ApiVersion};
+
+        let db = test_db().await;
+        let bot = db.create_bot("LegacyBot", "", None).await.unwrap();
+
+        // A row saved before API levels: the columns hold their defaults
+        sqlx::query("INSERT INTO bot_versions (bot_id, version, code) VALUES ($1, 1, $2)")
+            .bind(bot.id)
+            .bind("function bot()\n  return idle\nend")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let legacy = &db.list_bot_versions(bot.id).await.unwrap()[0];
+        assert_eq!((legacy.api_type.as_str(), legacy.api_level), ("oo", 1));
+
+        let api = ApiVersion::from_stored(&legacy.code, legacy.api_level);
+        assert_eq!(api, Some(ApiVersion { style: ApiStyle::State, level: 1 }));
+    }
+
     #[tokio::test]
     async fn test_tournament_crud() {
         let db = test_db().await;
@@ -2720,7 +4966,11 @@ mod tests {
         assert_eq!(v.elo_rating, 1500);
         assert_eq!(v.games_played, 0);
 
+        let m = db.create_match("1v1", "random", true).await.unwrap();
         db.update_version_elo(v.id, 1520).await.unwrap();
+        db.record_rating_history(v.id, m.id, 1500, 1520, None, 1500)
+            .await
+            .unwrap();
         db.update_version_stats(v.id, true, false, false, 100, 5, 3, 2)
             .await
             .unwrap();
@@ -2737,6 +4987,237 @@ mod tests {
         assert_eq!(updated.creatures_lost, 2);
     }
 
+    #[tokio::test]
+    async fn test_count_ranked_pair_matches() {
+        let db = test_db().await;
+        let mut ids = Vec::new();
+        for name in ["PairA", "PairB", "PairC"] {
+            let bot = db.create_bot(name, "", None).await.unwrap();
+            let v = db.create_bot_version(bot.id, "").await.unwrap();
+            ids.push((bot.id, v.id));
+        }
+        let (a, b, c) = (ids[0], ids[1], ids[2]);
+        for (ranked, x, y) in [(true, a, b), (true, b, a), (false, a, b), (true, a, c)] {
+            let sides = [(x.1, true), (y.1, true)];
+            db.create_match_with_participants("1v1", "random", ranked, &sides, None)
+                .await
+                .unwrap();
+        }
+        // Another version of the same bot still counts for the pair
+        let a2 = db.create_bot_version(a.0, "v2").await.unwrap();
+        let sides = [(a2.id, true), (b.1, true)];
+        let m = db
+            .create_match_with_participants("1v1", "random", true, &sides, None)
+            .await
+            .unwrap();
+        assert_eq!(db.count_ranked_pair_matches(a.0, b.0, 1).await.unwrap(), 3);
+        assert_eq!(db.count_ranked_pair_matches(b.0, a.0, 1).await.unwrap(), 3);
+        assert_eq!(db.count_ranked_pair_matches(b.0, c.0, 1).await.unwrap(), 0);
+
+        // Abandoned and older matches don't
+        db.exec(&format!("UPDATE matches SET status = 'abandoned' WHERE id = {}", m.id))
+            .await
+            .unwrap();
+        db.exec("UPDATE matches SET created_at = datetime('now', '-2 days') WHERE id = 1")
+            .await
+            .unwrap();
+        assert_eq!(db.count_ranked_pair_matches(a.0, b.0, 1).await.unwrap(), 1);
+
+        // The capped insert counts inside its own transaction
+        let sides = [(a.1, true), (b.1, true)];
+        let capped = db.create_capped_ranked_match("1v1", "random", &sides, (a.0, b.0), 2);
+        let m = capped.await.unwrap().unwrap();
+        assert_eq!(m.ranked, 1);
+        assert_eq!(db.get_match_participants(m.id).await.unwrap().len(), 2);
+        let capped = db.create_capped_ranked_match("1v1", "random", &sides, (b.0, a.0), 2);
+        assert!(capped.await.unwrap().is_none());
+        assert_eq!(db.count_ranked_pair_matches(a.0, b.0, 1).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rating_history_peak_and_rating_at() {
+        let db = test_db().await;
+        let bot = db.create_bot("HistBot", "", None).await.unwrap();
+        let v = db.create_bot_version(bot.id, "code").await.unwrap();
+        let opp = db.create_bot_version(bot.id, "opp").await.unwrap();
+
+        assert_eq!(db.version_peak_rating(v.id).await.unwrap(), None);
+        assert_eq!(db.version_rating_at(v.id, "2000-01-01").await.unwrap(), None);
+
+        // Rises to 1540, then falls below its start
+        for (before, after) in [(1500, 1520), (1520, 1540), (1540, 1480)] {
+            let m = db.create_match("1v1", "random", true).await.unwrap();
+            db.record_rating_history(v.id, m.id, before, after, Some(opp.id), 1500)
+                .await
+                .unwrap();
+            db.update_version_elo(v.id, after).await.unwrap();
+        }
+
+        let history = db.list_rating_history(v.id, 100, 0).await.unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            history.iter().map(|h| h.rating_after).collect::<Vec<_>>(),
+            vec![1520, 1540, 1480]
+        );
+        assert_eq!(history[0].opponent_version_id, Some(opp.id));
+        assert_eq!(db.list_rating_history(v.id, 1, 2).await.unwrap()[0].rating_after, 1480);
+
+        assert_eq!(db.version_peak_rating(v.id).await.unwrap(), Some(1540));
+        let current = db.get_bot_version_by_id(v.id).await.unwrap().unwrap();
+        assert_eq!((current.elo_1v1, current.elo_peak), (1480, 1540));
+
+        // Before the first match: the starting rating; after all: the latest
+        assert_eq!(db.version_rating_at(v.id, "2000-01-01").await.unwrap(), Some(1500));
+        assert_eq!(db.version_rating_at(v.id, "9999-12-31").await.unwrap(), Some(1480));
+
+        // ISO timestamps compare as instants, not as text against "YYYY-MM-DD HH:MM:SS"
+        let times = ["2025-06-01 10:00:00", "2025-06-01 12:00:00", "2025-06-02 09:00:00"];
+        for (id, at) in (1..).zip(times) {
+            db.exec(&format!("UPDATE rating_history SET created_at = '{at}' WHERE id = {id}"))
+                .await
+                .unwrap();
+        }
+        for (at, rating) in [
+            ("2025-06-01T11:00:00", 1520),
+            ("2025-06-01 11:00:00", 1520),
+            ("2025-06-01T12:00:00Z", 1540),
+            ("2025-06-01T13:00:00+02:00", 1520),
+            ("2025-06-01T12:00:00.5", 1540),
+            ("2025-06-02", 1540),
+        ] {
+            assert_eq!(db.version_rating_at(v.id, at).await.unwrap(), Some(rating), "{at}");
+        }
+        assert!(db.version_rating_at(v.id, "yesterday").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rating_history_migration_allows_season_resets() {
+        let db = test_db().await;
+        let bot = db.create_bot("OldHistory", "", None).await.unwrap();
+        let v = db.create_bot_version(bot.id, "code").await.unwrap();
+        let m = db.create_match("1v1", "random", true).await.unwrap();
+        // The table as first created, before season resets were recorded
+        db.exec("DROP TABLE rating_history").await.unwrap();
+        db.exec(
+            r#"CREATE TABLE rating_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                bot_version_id INTEGER NOT NULL REFERENCES bot_versions(id) ON DELETE CASCADE,
+                match_id INTEGER NOT NULL REFERENCES matches(id) ON DELETE CASCADE,
+                rating_before INTEGER NOT NULL,
+                rating_after INTEGER NOT NULL,
+                opponent_version_id INTEGER,
+                opponent_rating INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )"#,
+        )
+        .await
+        .unwrap();
+        db.record_rating_history(v.id, m.id, 1500, 1700, None, 1500)
+            .await
+            .unwrap();
+
+        db.run_migrations().await.unwrap();
+        db.update_version_elo(v.id, 1700).await.unwrap();
+        db.rollover_season(30).await.unwrap();
+        db.rollover_season(30).await.unwrap();
+        let history = db.list_rating_history(v.id, 10, 0).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].match_id, history[0].rating_after), (Some(m.id), 1700));
+        assert_eq!((history[1].match_id, history[1].rating_after), (None, 1600));
+    }
+
+    #[tokio::test]
+    async fn test_seed_rating_history_from_participants() {
+        let db = test_db().await;
+        let bot = db.create_bot("SeedBot", "", None).await.unwrap();
+        let a = db.create_bot_version(bot.id, "a").await.unwrap();
+        let b = db.create_bot_version(bot.id, "b").await.unwrap();
+
+        let m = db.create_match("1v1", "random", true).await.unwrap();
+        let pa = db.add_match_participant(m.id, a.id, 0).await.unwrap();
+        let pb = db.add_match_participant(m.id, b.id, 1).await.unwrap();
+        db.update_match_participant(pa.id, 100, Some(1), Some(1500), Some(1520), 0, 0, 0)
+            .await
+            .unwrap();
+        db.update_match_participant(pb.id, 50, Some(2), Some(1500), Some(1480), 0, 0, 0)
+            .await
+            .unwrap();
+        db.finish_match(m.id, Some(a.id)).await.unwrap();
+
+        assert_eq!(db.seed_rating_history().await.unwrap(), 2);
+        // Only seeds an empty table
+        assert_eq!(db.seed_rating_history().await.unwrap(), 0);
+
+        let history = db.list_rating_history(a.id, 100, 0).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!((history[0].rating_before, history[0].rating_after), (1500, 1520));
+        assert_eq!(history[0].opponent_version_id, Some(b.id));
+        let a = db.get_bot_version_by_id(a.id).await.unwrap().unwrap();
+        assert_eq!(a.elo_peak, 1520);
+    }
+
+    #[tokio::test]
+    async fn test_recompute_ratings() {
+        use crate::elo::{calculate_new_rating, Outcome};
+
+        let db = test_db().await;
+        let a_bot = db.create_bot("RecomputeA", "", None).await.unwrap();
+        let b_bot = db.create_bot("RecomputeB", "", None).await.unwrap();
+        let a = db.create_bot_version(a_bot.id, "a").await.unwrap();
+        let b = db.create_bot_version(b_bot.id, "b").await.unwrap();
+
+        let m = db.create_match("1v1", "random", true).await.unwrap();
+        let pa = db.add_match_participant(m.id, a.id, 0).await.unwrap();
+        let pb = db.add_match_participant(m.id, b.id, 1).await.unwrap();
+        db.update_match_participant(pa.id, 100, Some(1), Some(1500), Some(1600), 0, 0, 0)
+            .await
+            .unwrap();
+        db.update_match_participant(pb.id, 50, Some(2), Some(1500), Some(1400), 0, 0, 0)
+            .await
+            .unwrap();
+        db.finish_match(m.id, Some(a.id)).await.unwrap();
+        // Ratings from an older formula
+        db.update_version_elo(a.id, 1600).await.unwrap();
+        db.update_version_elo(b.id, 1400).await.unwrap();
+        for v in [a.id, b.id] {
+            db.update_version_stats(v, v == a.id, v == b.id, false, 0, 0, 0, 0)
+                .await
+                .unwrap();
+        }
+
+        let expected_a = calculate_new_rating(1500, 1500, Outcome::Win, 1);
+        let report = db.recompute_ratings(true).await.unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.matches_replayed, 1);
+        let change = report
+            .changes
+            .iter()
+            .find(|c| c.bot_version_id == a.id)
+            .unwrap();
+        assert_eq!((change.elo_before, change.elo_after), (1600, expected_a));
+        assert_eq!((change.rank_before, change.rank_after), (Some(1), Some(1)));
+        // Dry run leaves everything in place
+        assert_eq!(db.get_bot_version_by_id(a.id).await.unwrap().unwrap().elo_1v1, 1600);
+        assert!(db.list_rating_history(a.id, 10, 0).await.unwrap().is_empty());
+
+        let report = db.recompute_ratings(false).await.unwrap();
+        assert!(!report.dry_run);
+        let a_now = db.get_bot_version_by_id(a.id).await.unwrap().unwrap();
+        assert_eq!(
+            (a_now.elo_1v1, a_now.elo_rating, a_now.elo_peak),
+            (expected_a, expected_a, expected_a)
+        );
+        assert!(a_now.glicko().rating > crate::glicko::STARTING_RATING);
+        let history = db.list_rating_history(b.id, 10, 0).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].opponent_version_id, Some(a.id));
+        let participants = db.get_match_participants(m.id).await.unwrap();
+        assert_eq!(participants[0].elo_after, Some(expected_a));
+
+        // Replaying again is a no-op
+        assert!(db.recompute_ratings(false).await.unwrap().changes.is_empty());
+    }
+
     #[tokio::test]
     async fn test_active_version() {
         let db = test_db().await;
@@ -2780,7 +5261,7 @@ mod tests {
         let v1 = db.create_bot_version(bot.id, "code1").await.unwrap();
         let v2 = db.create_bot_version(bot.id, "code2").await.unwrap();
 
-        let m = db.create_match("1v1", "random").await.unwrap();
+        let m = db.create_match("1v1", "random", true).await.unwrap();
         assert_eq!(m.format, "1v1");
         assert_eq!(m.status, "running");
 
@@ -2811,9 +5292,9 @@ mod tests {
         let db = test_db().await;
 
         // Create several matches
-        let m1 = db.create_match("1v1", "random").await.unwrap();
-        let m2 = db.create_match("ffa", "desert").await.unwrap();
-        let m3 = db.create_match("1v1", "forest").await.unwrap();
+        let m1 = db.create_match("1v1", "random", true).await.unwrap();
+        let m2 = db.create_match("ffa", "desert", true).await.unwrap();
+        let m3 = db.create_match("1v1", "forest", true).await.unwrap();
 
         // List all
         let matches = db.list_recent_matches(10, 0).await.unwrap();
@@ -2940,6 +5421,46 @@ mod tests {
         assert_eq!(versions.len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_team_pair_elo_is_rebuilt_by_recompute() {
+        use crate::elo::{calculate_new_rating, Outcome};
+
+        let db = test_db().await;
+        let user = db
+            .create_user("pairuser", "pair@test.com", "hash", "Pair User")
+            .await
+            .unwrap();
+        let mut v = Vec::new();
+        for i in 0..4 {
+            let bot = db.create_bot(&format!("PairBot{i}"), "", Some(user.id)).await.unwrap();
+            v.push(db.create_bot_version(bot.id, "code").await.unwrap().id);
+        }
+        let red = db.create_team(user.id, "Red").await.unwrap();
+        let blue = db.create_team(user.id, "Blue").await.unwrap();
+        let red_tv = db.create_team_version(red.id, v[0], v[1]).await.unwrap();
+        let blue_tv = db.create_team_version(blue.id, v[2], v[3]).await.unwrap();
+
+        assert_eq!(db.team_pair_rating(v[1], v[0]).await.unwrap(), Some((1500, 0)));
+        assert_eq!(db.team_pair_rating(v[0], v[2]).await.unwrap(), None);
+
+        let m = db.create_match("2v2", "random", true).await.unwrap();
+        for (slot, (version, score)) in v.iter().zip([40, 40, 50, 10]).enumerate() {
+            let p = db.add_match_participant(m.id, *version, slot as i32).await.unwrap();
+            db.update_match_participant(p.id, score, None, None, None, 0, 0, 0)
+                .await
+                .unwrap();
+        }
+        db.finish_match(m.id, Some(v[2])).await.unwrap();
+        // Ratings from before team Elo was kept
+        assert_eq!(db.update_team_pair_elo(v[0], v[1], 1234).await.unwrap(), 1);
+
+        db.recompute_ratings(false).await.unwrap();
+        let red_now = db.get_team_version(red.id, red_tv.id).await.unwrap().unwrap();
+        let blue_now = db.get_team_version(blue.id, blue_tv.id).await.unwrap().unwrap();
+        assert_eq!(red_now.elo_rating, calculate_new_rating(1500, 1500, Outcome::Win, 0));
+        assert_eq!(blue_now.elo_rating, calculate_new_rating(1500, 1500, Outcome::Loss, 0));
+    }
+
     #[tokio::test]
     async fn test_update_and_delete_team() {
         let db = test_db().await;
@@ -2997,7 +5518,10 @@ mod tests {
             .await
             .unwrap();
 
-        let leaderboard = db.leaderboard_1v1(50, 0).await.unwrap();
+        let leaderboard = db
+            .leaderboard_1v1(50, 0, "elo", &LeaderboardFilter::default())
+            .await
+            .unwrap();
         assert_eq!(leaderboard.len(), 2);
         assert_eq!(leaderboard[0].bot_name, "BotHigh");
         assert_eq!(leaderboard[0].rating, 1600);
@@ -3008,6 +5532,362 @@ mod tests {
         assert_eq!(leaderboard[1].rank, 2);
     }
 
+    #[tokio::test]
+    async fn test_leagues_by_account_age() {
+        use crate::league::League;
+        let db = test_db().await;
+
+        let fresh = db.create_user("fresh", "fresh@test.com", "hash", "Fresh").await.unwrap();
+        let veteran = db.create_user("veteran", "vet@test.com", "hash", "Vet").await.unwrap();
+        assert_eq!(fresh.league, "newcomer");
+        assert_eq!(veteran.league, "newcomer");
+        // The league follows the account age as soon as it passes NEWCOMER_DAYS
+        let ages = [(fresh.id, "-13 days', '-23 hours"), (veteran.id, "-14 days', '-1 minutes")];
+        for (id, age) in ages {
+            let sql =
+                format!("UPDATE users SET created_at = datetime('now', '{age}') WHERE id = {id}");
+            db.exec(&sql).await.unwrap();
+        }
+        assert_eq!(db.get_user(veteran.id).await.unwrap().unwrap().league, "open");
+        assert_eq!(db.get_user(fresh.id).await.unwrap().unwrap().league, "newcomer");
+        let by_name = db.get_user_by_username("veteran").await.unwrap().unwrap();
+        assert_eq!(by_name.league, "open");
+
+        let mut bots = Vec::new();
+        for (name, owner) in [
+            ("Rookie", Some(fresh.id)),
+            ("Elder", Some(veteran.id)),
+            ("House", None),
+        ] {
+            let bot = db.create_bot(name, "", owner).await.unwrap();
+            let v = db.create_bot_version(bot.id, "code").await.unwrap();
+            db.update_version_stats(v.id, true, false, false, 100, 0, 0, 0)
+                .await
+                .unwrap();
+            bots.push(bot.id);
+        }
+        assert_eq!(db.bot_league(bots[0]).await.unwrap(), League::Newcomer);
+        assert_eq!(db.bot_league(bots[1]).await.unwrap(), League::Open);
+        assert_eq!(db.bot_league(bots[2]).await.unwrap(), League::Open);
+
+        let newcomers = db
+            .leaderboard_1v1(
+                50,
+                0,
+                "elo",
+                &LeaderboardFilter { league: Some(League::Newcomer), ..Default::default() },
+            )
+            .await
+            .unwrap();
+        assert_eq!(newcomers.len(), 1);
+        assert_eq!((newcomers[0].bot_name.as_str(), newcomers[0].rank), ("Rookie", 1));
+        let open = db
+            .leaderboard_ffa(
+                50,
+                0,
+                "points",
+                &LeaderboardFilter { league: Some(League::Open), ..Default::default() },
+            )
+            .await
+            .unwrap();
+        assert_eq!(open.len(), 2);
+        assert!(open.iter().all(|e| e.league == "open"));
+        let all = db.leaderboard_1v1(50, 0, "elo", &LeaderboardFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_leaderboards() {
+        let db = test_db().await;
+        let alice = db.create_user("alice", "alice@test.com", "hash", "Alice").await.unwrap();
+        let bob = db.create_user("bob", "bob@test.com", "hash", "Bob").await.unwrap();
+        let bot_a = db.create_bot("A", "", Some(alice.id)).await.unwrap();
+        let a1 = db.create_bot_version(bot_a.id, "code").await.unwrap().id;
+        let a2 = db.create_bot_version(bot_a.id, "code").await.unwrap().id;
+        let bot_b = db.create_bot("B", "", Some(bob.id)).await.unwrap();
+        let b1 = db.create_bot_version(bot_b.id, "code").await.unwrap().id;
+        db.set_active_version(bot_a.id, a2).await.unwrap();
+        db.set_active_version(bot_b.id, b1).await.unwrap();
+        for (id, elo) in [(a1, 1600), (a2, 1600), (b1, 1500)] {
+            db.update_version_elo(id, elo).await.unwrap();
+            db.update_version_stats(id, true, false, false, 0, 0, 0, 0).await.unwrap();
+        }
+
+        // Equal ratings share a rank
+        let board = |filter: LeaderboardFilter| {
+            let db = &db;
+            async move { db.leaderboard_1v1(50, 0, "elo", &filter).await.unwrap() }
+        };
+        let ranks = |entries: &[LeaderboardEntry]| -> Vec<(i64, i64)> {
+            entries.iter().map(|e| (e.rank, e.bot_version_id)).collect()
+        };
+        let all = board(LeaderboardFilter::default()).await;
+        assert_eq!(ranks(&all), vec![(1, a1), (1, a2), (3, b1)]);
+        assert!(all.iter().all(|e| e.rating_change.is_none()));
+        let per_bot = board(LeaderboardFilter { per_bot: true, ..Default::default() }).await;
+        assert_eq!(ranks(&per_bot), vec![(1, a1), (2, b1)]);
+        let active = board(LeaderboardFilter { active_only: true, ..Default::default() }).await;
+        assert_eq!(ranks(&active), vec![(1, a2), (2, b1)]);
+        let bob_only = LeaderboardFilter { owner: Some("bob".into()), ..Default::default() };
+        let owned = board(bob_only).await;
+        assert_eq!(ranks(&owned), vec![(1, b1)]);
+
+        // This week A v2 beat B on "arena"; 40 days ago B beat A v1 on "random"
+        let recent = db.create_match("1v1", "arena", true).await.unwrap();
+        db.add_match_participant(recent.id, a2, 0).await.unwrap();
+        db.add_match_participant(recent.id, b1, 1).await.unwrap();
+        db.finish_match(recent.id, Some(a2)).await.unwrap();
+        db.record_rating_history(a2, recent.id, 1580, 1600, Some(b1), 1500)
+            .await
+            .unwrap();
+        let old = db.create_match("1v1", "random", true).await.unwrap();
+        db.add_match_participant(old.id, b1, 0).await.unwrap();
+        db.add_match_participant(old.id, a1, 1).await.unwrap();
+        db.finish_match(old.id, Some(b1)).await.unwrap();
+        sqlx::query("UPDATE matches SET finished_at = datetime('now', '-40 days') WHERE id = $1")
+            .bind(old.id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        // Scoped boards read the materialized stats
+        assert!(board(LeaderboardFilter { period_days: Some(30), ..Default::default() })
+            .await
+            .is_empty());
+        assert_eq!(db.rebuild_leaderboard_stats().await.unwrap(), 4);
+
+        let month = board(LeaderboardFilter { period_days: Some(30), ..Default::default() }).await;
+        assert_eq!(ranks(&month), vec![(1, a2), (2, b1)]);
+        assert_eq!((month[0].games_played, month[0].wins, month[0].win_rate), (1, 1, 1.0));
+        assert_eq!((month[1].games_played, month[1].losses), (1, 1));
+        assert_eq!(month[0].rating_change, Some(20));
+        assert_eq!(month[1].rating_change, Some(0));
+
+        let random_map = LeaderboardFilter { map: Some("random".into()), ..Default::default() };
+        let on_random = board(random_map.clone()).await;
+        assert_eq!(ranks(&on_random), vec![(1, a1), (2, b1)]);
+        assert_eq!((on_random[0].losses, on_random[1].wins), (1, 1));
+        let filter = LeaderboardFilter { per_bot: true, ..random_map };
+        assert_eq!(db.leaderboard_ffa(50, 0, "points", &filter).await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_leaderboard_2v2() {
+        let db = test_db().await;
+        let owner = db.create_user("teamer", "teamer@test.com", "hash", "Teamer").await.unwrap();
+        let mut ids = Vec::new();
+        for name in ["P", "Q", "R", "S"] {
+            let bot = db.create_bot(name, "", Some(owner.id)).await.unwrap();
+            ids.push(db.create_bot_version(bot.id, "code").await.unwrap().id);
+        }
+        let red = db.create_team(owner.id, "Red").await.unwrap();
+        let red_v1 = db.create_team_version(red.id, ids[0], ids[1]).await.unwrap();
+        let red_v2 = db.create_team_version(red.id, ids[1], ids[0]).await.unwrap();
+        let blue = db.create_team(owner.id, "Blue").await.unwrap();
+        let blue_v1 = db.create_team_version(blue.id, ids[2], ids[3]).await.unwrap();
+
+        // Blue beats Red on "arena", members seated in either order
+        let m = db.create_match("2v2", "arena", true).await.unwrap();
+        for (slot, (id, placement)) in [(ids[3], 1), (ids[2], 1), (ids[1], 2), (ids[0], 2)]
+            .into_iter()
+            .enumerate()
+        {
+            let p = db.add_match_participant(m.id, id, slot as i32).await.unwrap();
+            db.update_match_participant(p.id, 0, Some(placement), None, None, 0, 0, 0)
+                .await
+                .unwrap();
+        }
+        db.finish_match(m.id, Some(ids[3])).await.unwrap();
+        db.record_team_version_result(ids[2], ids[3], true, false, false).await.unwrap();
+        db.record_team_version_result(ids[0], ids[1], false, true, false).await.unwrap();
+        db.record_leaderboard_team_result(m.id, ids[3], ids[2], true, false).await.unwrap();
+        db.record_leaderboard_team_result(m.id, ids[1], ids[0], false, true).await.unwrap();
+
+        let week = LeaderboardFilter { period_days: Some(7), ..Default::default() };
+        let board = db.leaderboard_2v2(50, 0, "elo", &week).await.unwrap();
+        let rows: Vec<(i64, i64, i32, i32)> =
+            board.iter().map(|e| (e.rank, e.bot_version_id, e.wins, e.losses)).collect();
+        // Both versions pair the same bots, so both count the game; equal Elo ties
+        assert_eq!(
+            rows,
+            vec![(1, red_v1.id, 0, 1), (1, red_v2.id, 0, 1), (1, blue_v1.id, 1, 0)]
+        );
+
+        let latest = LeaderboardFilter { active_only: true, ..week.clone() };
+        let board = db.leaderboard_2v2(50, 0, "elo", &latest).await.unwrap();
+        let ids_on_board: Vec<i64> = board.iter().map(|e| e.bot_version_id).collect();
+        assert_eq!(ids_on_board, vec![red_v2.id, blue_v1.id]);
+        let per_team = LeaderboardFilter { per_bot: true, ..Default::default() };
+        assert_eq!(db.leaderboard_2v2(50, 0, "elo", &per_team).await.unwrap().len(), 2);
+        let elsewhere = LeaderboardFilter { map: Some("random".into()), ..Default::default() };
+        assert!(db.leaderboard_2v2(50, 0, "elo", &elsewhere).await.unwrap().is_empty());
+
+        // A rebuild from match history agrees with the incremental updates
+        let before = db.leaderboard_2v2(50, 0, "elo", &week).await.unwrap();
+        assert_eq!(db.rebuild_leaderboard_stats().await.unwrap(), 3);
+        let after = db.leaderboard_2v2(50, 0, "elo", &week).await.unwrap();
+        assert_eq!(serde_json::to_value(before).unwrap(), serde_json::to_value(after).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_season_rollover_waits_for_rating_updates() {
+        let db = std::sync::Arc::new(test_db().await);
+        let completing = db.rating_update_guard().await;
+        let rollover = tokio::spawn({
+            let db = db.clone();
+            async move { db.rollover_season(30).await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!rollover.is_finished());
+        drop(completing);
+        assert_eq!(rollover.await.unwrap().unwrap().name, "Season 1");
+    }
+
+    #[tokio::test]
+    async fn test_season_rollover() {
+        let db = test_db().await;
+        let season = db.rollover_season(30).await.unwrap();
+        assert!(db.season_standings(&season, 50, 0).await.unwrap().is_empty());
+
+        let mut ids = Vec::new();
+        for name in ["Champ", "Runner"] {
+            let bot = db.create_bot(name, "", None).await.unwrap();
+            ids.push(db.create_bot_version(bot.id, "code").await.unwrap().id);
+        }
+        let m = db.create_match("1v1", "random", true).await.unwrap();
+        db.add_match_participant(m.id, ids[0], 0).await.unwrap();
+        db.add_match_participant(m.id, ids[1], 1).await.unwrap();
+        db.finish_match(m.id, Some(ids[0])).await.unwrap();
+        db.update_version_elo(ids[0], 1700).await.unwrap();
+        db.update_version_elo(ids[1], 1400).await.unwrap();
+        db.record_rating_history(ids[0], m.id, 1500, 1700, Some(ids[1]), 1500)
+            .await
+            .unwrap();
+        // Unranked games don't count
+        let friendly = db.create_match("1v1", "random", false).await.unwrap();
+        db.add_match_participant(friendly.id, ids[0], 0).await.unwrap();
+        db.add_match_participant(friendly.id, ids[1], 1).await.unwrap();
+        db.finish_match(friendly.id, None).await.unwrap();
+
+        let live = db.season_standings(&season, 50, 0).await.unwrap();
+        assert_eq!(live.len(), 2);
+        assert_eq!((live[0].bot_name.as_str(), live[0].rank), ("Champ", 1));
+        assert_eq!((live[0].games_played, live[0].wins, live[0].draws), (1, 1, 0));
+        assert_eq!((live[1].losses, live[1].peak_rating), (1, 1400));
+        assert_eq!(live[0].peak_rating, 1700);
+
+        let next = db.rollover_season(30).await.unwrap();
+        assert_eq!(next.name, "Season 2");
+        let finished = db.get_season(season.id).await.unwrap().unwrap();
+        assert!(finished.finished_at.is_some());
+        let archived = db.season_standings(&finished, 50, 0).await.unwrap();
+        assert_eq!(archived.len(), 2);
+        assert_eq!((archived[0].rating, archived[1].rating), (1700, 1400));
+
+        // Ratings are pulled halfway back to the start
+        let champ = db.get_bot_version_by_id(ids[0]).await.unwrap().unwrap();
+        assert_eq!((champ.elo_1v1, champ.elo_rating), (1600, 1600));
+        assert_eq!(champ.elo_peak, 1700);
+        let runner = db.get_bot_version_by_id(ids[1]).await.unwrap().unwrap();
+        assert_eq!(runner.elo_1v1, 1450);
+        // ...and the reset is part of their rating history
+        let history = db.list_rating_history(ids[0], 10, 0).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[1].match_id, history[1].opponent_rating), (None, None));
+        assert_eq!((history[1].rating_before, history[1].rating_after), (1700, 1600));
+        assert_eq!(history[1].created_at, finished.finished_at.clone().unwrap());
+        let at = finished.finished_at.as_deref().unwrap();
+        assert_eq!(db.version_rating_at(ids[1], at).await.unwrap(), Some(1450));
+
+        let seasons = db.list_seasons().await.unwrap();
+        assert_eq!(seasons[1].champion_bot_name.as_deref(), Some("Champ"));
+        assert_eq!(seasons[1].champion_rating, Some(1700));
+        assert_eq!(seasons[0].champion_bot_name, None);
+
+        // Recomputing replays the reset instead of undoing it
+        db.recompute_ratings(false).await.unwrap();
+        let won = crate::elo::calculate_new_rating(1500, 1500, crate::elo::Outcome::Win, 1);
+        let champ = db.get_bot_version_by_id(ids[0]).await.unwrap().unwrap();
+        assert_eq!(champ.elo_1v1, crate::elo::soft_reset_elo(won));
+        let history = db.list_rating_history(ids[0], 10, 0).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].match_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_glicko_backfill_and_leaderboard() {
+        let db = test_db().await;
+        let user = db
+            .create_user("glicko", "glicko@test.com", "hash", "Glicko")
+            .await
+            .unwrap();
+
+        let mut versions = Vec::new();
+        for name in ["Strong", "Weak"] {
+            let bot = db.create_bot(name, "", Some(user.id)).await.unwrap();
+            let v = db.create_bot_version(bot.id, "code").await.unwrap();
+            assert_eq!((v.glicko_rating, v.glicko_rd), (1500.0, 350.0));
+            versions.push(v.id);
+        }
+        let (strong, weak) = (versions[0], versions[1]);
+
+        // Matches recorded before Glicko existed: Strong wins them all
+        for _ in 0..30 {
+            let m = db.create_match("1v1", "random", true).await.unwrap();
+            db.add_match_participant(m.id, strong, 0).await.unwrap();
+            db.add_match_participant(m.id, weak, 1).await.unwrap();
+            db.finish_match(m.id, Some(strong)).await.unwrap();
+            db.update_version_stats(strong, true, false, false, 100, 1, 1, 0)
+                .await
+                .unwrap();
+            db.update_version_stats(weak, false, true, false, 10, 1, 0, 1)
+                .await
+                .unwrap();
+        }
+        assert!(!db.data_migration_applied(GLICKO_BACKFILL).await.unwrap());
+
+        assert_eq!(db.backfill_glicko().await.unwrap(), 2);
+        assert!(db.data_migration_applied(GLICKO_BACKFILL).await.unwrap());
+        let expected = crate::glicko::replay(&db.list_1v1_results().await.unwrap());
+        let s = db.get_bot_version_by_id(strong).await.unwrap().unwrap();
+        assert!((s.glicko_rating - expected[&strong].rating).abs() < 1e-6);
+        assert!(s.glicko_rating > 1500.0);
+        assert!(s.glicko_rd < 350.0);
+        // Only the Glicko-2 columns are rewritten
+        assert_eq!(s.elo_1v1, 1500);
+
+        let board = db
+            .leaderboard_1v1(50, 0, "glicko", &LeaderboardFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(board.len(), 2);
+        assert_eq!(board[0].bot_version_id, strong);
+        assert_eq!(board[0].rating, s.glicko_rating.round() as i32);
+        assert_eq!(board[0].confidence, Some((1.96 * s.glicko_rd).round() as i32));
+
+        // A fresh version with one game is provisional and can be hidden
+        let bot = db.create_bot("Fresh", "", Some(user.id)).await.unwrap();
+        let fresh = db.create_bot_version(bot.id, "code").await.unwrap();
+        db.update_version_stats(fresh.id, true, false, false, 10, 1, 0, 0)
+            .await
+            .unwrap();
+        let board = db
+            .leaderboard_1v1(50, 0, "glicko", &LeaderboardFilter::default())
+            .await
+            .unwrap();
+        let entry = board.iter().find(|e| e.bot_version_id == fresh.id).unwrap();
+        assert_eq!(entry.provisional, 1);
+        let board = db
+            .leaderboard_1v1(
+                50,
+                0,
+                "glicko",
+                &LeaderboardFilter { hide_provisional: true, ..Default::default() },
+            )
+            .await
+            .unwrap();
+        assert!(board.iter().all(|e| e.bot_version_id != fresh.id));
+    }
+
     #[tokio::test]
     async fn test_leaderboard_filters_archived_and_zero_games() {
         let db = test_db().await;
@@ -3050,18 +5930,24 @@ mod tests {
             .await
             .unwrap();
 
-        let leaderboard = db.leaderboard_1v1(50, 0).await.unwrap();
+        let leaderboard = db
+            .leaderboard_1v1(50, 0, "elo", &LeaderboardFilter::default())
+            .await
+            .unwrap();
         assert_eq!(leaderboard.len(), 1);
         assert_eq!(leaderboard[0].bot_name, "ActiveBot");
 
         // FFA leaderboard should behave the same way
         db.update_version_ffa_stats(v_active.id, 10).await.unwrap();
-        let ffa_lb = db.leaderboard_ffa(50, 0).await.unwrap();
+        let ffa_lb = db
+            .leaderboard_ffa(50, 0, "points", &LeaderboardFilter::default())
+            .await
+            .unwrap();
         assert_eq!(ffa_lb.len(), 1);
         assert_eq!(ffa_lb[0].bot_name, "ActiveBot");
 
         // 2v2 placeholder should return empty
-        let lb_2v2 = db.leaderboard_2v2(50, 0).await.unwrap();
+        let lb_2v2 = db.leaderboard_2v2(50, 0, "elo", &LeaderboardFilter::default()).await.unwrap();
         assert!(lb_2v2.is_empty());
     }
 
@@ -3119,7 +6005,7 @@ mod tests {
     async fn test_save_and_get_replay() {
         let db = test_db().await;
 
-        let m = db.create_match("1v1", "random").await.unwrap();
+        let m = db.create_match("1v1", "random", true).await.unwrap();
 
         let data = vec![1, 2, 3, 4, 5];
         let replay = db.save_replay(m.id, &data, 42).await.unwrap();
@@ -3206,6 +6092,26 @@ mod tests {
         assert_eq!(standings[1].bot_name, "StandingsB");
         assert_eq!(standings[1].total_score, 80);
         assert_eq!(standings[1].matches_played, 1);
+
+        // B won the game despite the lower score: outside Swiss the order
+        // stays by total score, Swiss ranks by points
+        let m = db.create_match("1v1", "default", true).await.unwrap();
+        db.add_match_participant(m.id, va.id, 0).await.unwrap();
+        db.add_match_participant(m.id, vb.id, 1).await.unwrap();
+        db.add_tournament_match(t.id, m.id, 1).await.unwrap();
+        db.finish_match(m.id, Some(vb.id)).await.unwrap();
+        let standings = db.get_tournament_standings(t.id).await.unwrap();
+        assert_eq!((standings[0].bot_version_id, standings[0].losses), (va.id, 1));
+        assert_eq!((standings[1].bot_version_id, standings[1].wins), (vb.id, 1));
+        db.update_tournament(t.id, None, None, Some("swiss_3"), None)
+            .await
+            .unwrap();
+        let standings = db.get_tournament_standings(t.id).await.unwrap();
+        assert_eq!(standings[0].bot_version_id, vb.id);
+
+        // One bye per round
+        db.add_tournament_bye(t.id, va.id, 2).await.unwrap();
+        assert!(db.add_tournament_bye(t.id, vb.id, 2).await.is_err());
     }
 
     #[tokio::test]
@@ -3297,9 +6203,9 @@ mod tests {
             .unwrap();
 
         // Create some matches
-        let m1 = db.create_match("1v1", "default").await.unwrap();
-        let m2 = db.create_match("1v1", "default").await.unwrap();
-        let m3 = db.create_match("1v1", "default").await.unwrap();
+        let m1 = db.create_match("1v1", "default", true).await.unwrap();
+        let m2 = db.create_match("1v1", "default", true).await.unwrap();
+        let m3 = db.create_match("1v1", "default", true).await.unwrap();
 
         // Link matches to tournament rounds
         let tm1 = db.add_tournament_match(t.id, m1.id, 1).await.unwrap();
@@ -3339,8 +6245,58 @@ mod tests {
         assert_eq!(result3, Some((t.id, 2)));
 
         // Non-tournament match returns None
-        let m4 = db.create_match("1v1", "default").await.unwrap();
+        let m4 = db.create_match("1v1", "default", true).await.unwrap();
         let no_tournament = db.get_tournament_for_match(m4.id).await.unwrap();
         assert!(no_tournament.is_none());
     }
+
+    #[tokio::test]
+    async fn test_custom_map_versions() {
+        let db = test_db().await;
+        let owner = db.create_user("mapper", "m@example.com", "pw", "Mapper").await.unwrap();
+        let other = db.create_user("other", "o@example.com", "pw", "Other").await.unwrap();
+
+        let map = db
+            .create_custom_map(owner.id, "arena", "an arena", "private", (10, 10), "{\"v\":1}")
+            .await
+            .unwrap();
+        assert_eq!(map.version, 1);
+        // A duplicate name surfaces as a unique violation, which the API maps to 409
+        let dup = db.create_custom_map(other.id, "arena", "", "public", (10, 10), "{}").await;
+        assert!(matches!(dup, Err(sqlx::Error::Database(e)) if e.is_unique_violation()));
+
+        // Private maps are listed only for their owner
+        assert_eq!(db.list_custom_maps(Some(owner.id)).await.unwrap().len(), 1);
+        assert!(db.list_custom_maps(Some(other.id)).await.unwrap().is_empty());
+        assert!(db.list_custom_maps(None).await.unwrap().is_empty());
+
+        let updated = db
+            .update_custom_map(map.id, "renamed", "public", None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.description, "renamed");
+        assert_eq!(updated.version, 1);
+        assert_eq!(db.list_custom_maps(None).await.unwrap().len(), 1);
+
+        let v2 = db
+            .update_custom_map(map.id, "renamed", "public", Some(((12, 14), "{\"v\":2}")))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((v2.version, v2.width, v2.height), (2, 12, 14));
+
+        let latest = db.get_custom_map_version(map.id, None).await.unwrap().unwrap();
+        assert_eq!(latest.data, "{\"v\":2}");
+        let first = db.get_custom_map_version(map.id, Some(1)).await.unwrap().unwrap();
+        assert_eq!(first.data, "{\"v\":1}");
+        assert!(db.get_custom_map_version(map.id, Some(3)).await.unwrap().is_none());
+        assert_eq!(db.list_custom_map_versions(map.id).await.unwrap().len(), 2);
+
+        let by_name = db.get_custom_map_by_name("arena").await.unwrap().unwrap();
+        assert_eq!(by_name.id, map.id);
+        let missing = db.update_custom_map(9999, "", "public", Some(((1, 1), "{}"))).await;
+        assert!(missing.unwrap().is_none());
+        assert_eq!(db.list_custom_map_versions(map.id).await.unwrap().len(), 2);
+    }
 }
@@ -1,7 +1,8 @@
 // Elo rating calculation system.
 //
 // Per-version Elo for 1v1 and 2v2 matches.
-// FFA uses placement-based scoring (no Elo).
+// FFA uses placement-based scoring (no Elo); the Weng-Lin skill model for FFA
+// and 2v2 lives in `openskill`.
 
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +33,16 @@ impl Outcome {
             Outcome::Loss => 0.0,
         }
     }
+
+    /// Outcome for the side ranked `rank` against one ranked `opponent_rank`
+    /// (1 = best, ties share a rank).
+    pub fn from_ranks(rank: u32, opponent_rank: u32) -> Self {
+        match rank.cmp(&opponent_rank) {
+            std::cmp::Ordering::Less => Outcome::Win,
+            std::cmp::Ordering::Equal => Outcome::Draw,
+            std::cmp::Ordering::Greater => Outcome::Loss,
+        }
+    }
 }
 
 /// Get the K-factor for a player based on their games played and current rating.
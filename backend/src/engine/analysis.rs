@@ -0,0 +1,371 @@
+// Map fairness analysis.
+//
+// For every spawn point we compute walking distances (BFS flow field over the
+// same 4-neighbour grid `World::find_path` uses) to the KOTH and to each food
+// spawner, and the food income reachable within a fixed travel time. The
+// per-slot scores are compared to flag maps that favour one slot. Choke points
+// are the walkable tiles whose loss would cut part of the map off from the KOTH.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use super::config::*;
+use super::world::{RandomMapParams, World};
+
+/// Travel time (seconds) used for "reachable food income".
+pub const INCOME_TIME_RADIUS_SECS: i32 = 60;
+
+/// A choke point must cut off at least this many tiles from the KOTH.
+const CHOKE_MIN_CUT_OFF: usize = 8;
+
+/// At most this many choke points (largest cut-off first) are reported.
+const MAX_CHOKE_POINTS: usize = 32;
+
+/// Random maps tried by `generate_fair_random` before settling for the fairest.
+const FAIR_MAP_ATTEMPTS: usize = 10;
+
+/// Distance marker for unreachable tiles.
+const UNREACHABLE: u32 = u32::MAX;
+
+/// Fairness data for a single spawn point / player slot.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpawnAnalysis {
+    pub slot: usize,
+    pub x: usize,
+    pub y: usize,
+    /// Walking distance to the KOTH in tiles, `None` if unreachable.
+    pub koth_distance: Option<u32>,
+    /// Walking distance to each food spawner (in map order), `None` if unreachable.
+    pub spawner_distances: Vec<Option<u32>>,
+    /// Food per second from spawners within `INCOME_TIME_RADIUS_SECS` of travel.
+    pub reachable_income: f64,
+    /// Food already lying on tiles within the same radius (straight line),
+    /// e.g. placed by a level script. Not part of the score.
+    pub nearby_food: i64,
+    /// Slot score in 0..=1 (KOTH closeness and reachable income, equally weighted).
+    pub score: f64,
+    /// Score minus the mean score of all slots; positive favours this slot.
+    pub advantage: f64,
+}
+
+/// A walkable tile whose removal disconnects part of the map from the KOTH.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChokePoint {
+    pub x: usize,
+    pub y: usize,
+    /// Number of walkable tiles cut off from the KOTH without this tile.
+    pub cut_off: usize,
+}
+
+/// Result of `analyze`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MapAnalysis {
+    pub width: usize,
+    pub height: usize,
+    pub koth: (usize, usize),
+    pub walkable_tiles: usize,
+    /// Food lying on the map's tiles.
+    pub total_food: i64,
+    pub spawns: Vec<SpawnAnalysis>,
+    /// Largest cut-off first, at most `MAX_CHOKE_POINTS` entries.
+    pub choke_points: Vec<ChokePoint>,
+    pub choke_point_count: usize,
+    /// Spread between the best and worst slot score (0 = perfectly fair).
+    pub unfairness: f64,
+}
+
+/// Analyse a map's fairness across its spawn points.
+pub fn analyze(world: &World) -> MapAnalysis {
+    let tiles_per_sec = BASE_SPEED[CREATURE_SMALL as usize] as f64 / TILE_SIZE as f64;
+    let income_radius = (tiles_per_sec * INCOME_TIME_RADIUS_SECS as f64) as u32;
+
+    let mut spawns: Vec<SpawnAnalysis> = world
+        .spawn_points
+        .iter()
+        .enumerate()
+        .map(|(slot, &(x, y))| {
+            let field = distance_field(world, x, y);
+            let dist = |tx: usize, ty: usize| {
+                let d = field[ty * world.width + tx];
+                (d != UNREACHABLE).then_some(d)
+            };
+            let spawner_distances: Vec<Option<u32>> = world
+                .food_spawners
+                .iter()
+                .map(|s| {
+                    if world.is_on_map(s.x, s.y) {
+                        dist(s.x, s.y)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            let reachable_income = world
+                .food_spawners
+                .iter()
+                .zip(&spawner_distances)
+                .filter(|(s, d)| s.interval > 0 && d.is_some_and(|d| d <= income_radius))
+                .map(|(s, _)| s.amount as f64 * 1000.0 / s.interval as f64)
+                .sum();
+            SpawnAnalysis {
+                slot,
+                x,
+                y,
+                koth_distance: dist(world.koth_x, world.koth_y),
+                spawner_distances,
+                reachable_income,
+                nearby_food: world.food_in_radius(
+                    World::tile_center(x),
+                    World::tile_center(y),
+                    income_radius as i32 * TILE_SIZE,
+                )
+                .total,
+                score: 0.0,
+                advantage: 0.0,
+            }
+        })
+        .collect();
+
+    // Score each slot relative to the best slot
+    let best_koth = spawns.iter().filter_map(|s| s.koth_distance).min();
+    let best_income = spawns
+        .iter()
+        .map(|s| s.reachable_income)
+        .fold(0.0, f64::max);
+    for s in &mut spawns {
+        let koth_score = match (s.koth_distance, best_koth) {
+            (Some(d), Some(best)) => (best.max(1) as f64) / (d.max(1) as f64),
+            _ => 0.0,
+        };
+        let income_score = if best_income > 0.0 {
+            s.reachable_income / best_income
+        } else {
+            1.0
+        };
+        s.score = 0.5 * koth_score + 0.5 * income_score;
+    }
+    let unfairness = if spawns.is_empty() {
+        0.0
+    } else {
+        let mean = spawns.iter().map(|s| s.score).sum::<f64>() / spawns.len() as f64;
+        for s in &mut spawns {
+            s.advantage = s.score - mean;
+        }
+        let max = spawns.iter().map(|s| s.score).fold(f64::MIN, f64::max);
+        let min = spawns.iter().map(|s| s.score).fold(f64::MAX, f64::min);
+        max - min
+    };
+
+    let (mut choke_points, walkable_tiles) = find_choke_points(world);
+    let choke_point_count = choke_points.len();
+    choke_points.sort_by_key(|c| std::cmp::Reverse(c.cut_off));
+    choke_points.truncate(MAX_CHOKE_POINTS);
+
+    MapAnalysis {
+        width: world.width,
+        height: world.height,
+        koth: world.koth_pos(),
+        walkable_tiles,
+        total_food: world.total_food(),
+        spawns,
+        choke_points,
+        choke_point_count,
+        unfairness,
+    }
+}
+
+/// Generate random maps until one is no more unfair than `max_unfairness`.
+/// Returns the fairest map found if none qualifies within `FAIR_MAP_ATTEMPTS`.
+pub fn generate_fair_random(params: &RandomMapParams, max_unfairness: f64) -> World {
+    let mut best: Option<(f64, World)> = None;
+    for _ in 0..FAIR_MAP_ATTEMPTS {
+        let world = World::generate_random(params.clone());
+        let unfairness = analyze(&world).unfairness;
+        if unfairness <= max_unfairness {
+            return world;
+        }
+        if best.as_ref().is_none_or(|(u, _)| unfairness < *u) {
+            best = Some((unfairness, world));
+        }
+    }
+    best.map(|(_, w)| w)
+        .unwrap_or_else(|| World::generate_random(params.clone()))
+}
+
+/// Walking distance in tiles from (sx, sy) to every tile.
+fn distance_field(world: &World, sx: usize, sy: usize) -> Vec<u32> {
+    let w = world.width;
+    let mut dist = vec![UNREACHABLE; w * world.height];
+    if !world.is_walkable(sx, sy) {
+        return dist;
+    }
+    let mut queue = VecDeque::new();
+    dist[sy * w + sx] = 0;
+    queue.push_back((sx, sy));
+    while let Some((x, y)) = queue.pop_front() {
+        let d = dist[y * w + x];
+        for (nx, ny) in neighbors(world, x, y) {
+            if dist[ny * w + nx] == UNREACHABLE {
+                dist[ny * w + nx] = d + 1;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    dist
+}
+
+/// Walkable 4-neighbours of a tile.
+fn neighbors(world: &World, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    [(1i32, 0i32), (-1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .filter_map(move |(dx, dy)| {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            (nx >= 0 && ny >= 0).then_some((nx as usize, ny as usize))
+        })
+        .filter(|&(nx, ny)| world.is_walkable(nx, ny))
+}
+
+/// Articulation points of the walkable region around the KOTH (iterative
+/// Tarjan, DFS rooted at the KOTH). Returns choke points with their cut-off
+/// size, and the number of walkable tiles reachable from the KOTH.
+fn find_choke_points(world: &World) -> (Vec<ChokePoint>, usize) {
+    let w = world.width;
+    let (kx, ky) = world.koth_pos();
+    if !world.is_walkable(kx, ky) {
+        return (Vec::new(), 0);
+    }
+    let n = w * world.height;
+    let root = ky * w + kx;
+    let mut disc = vec![UNREACHABLE; n];
+    let mut low = vec![0u32; n];
+    let mut parent = vec![usize::MAX; n];
+    let mut size = vec![1usize; n];
+    let mut cut_off = vec![0usize; n];
+    let mut time = 0u32;
+
+    disc[root] = time;
+    low[root] = time;
+    let mut stack: Vec<(usize, Vec<usize>)> = vec![(root, neighbor_indices(world, root))];
+    while let Some((u, pending)) = stack.last_mut() {
+        let u = *u;
+        if let Some(v) = pending.pop() {
+            if disc[v] == UNREACHABLE {
+                time += 1;
+                disc[v] = time;
+                low[v] = time;
+                parent[v] = u;
+                stack.push((v, neighbor_indices(world, v)));
+            } else if v != parent[u] {
+                low[u] = low[u].min(disc[v]);
+            }
+            continue;
+        }
+        stack.pop();
+        let p = parent[u];
+        if p != usize::MAX {
+            low[p] = low[p].min(low[u]);
+            size[p] += size[u];
+            if low[u] >= disc[p] {
+                cut_off[p] += size[u];
+            }
+        }
+    }
+
+    let chokes = (0..n)
+        .filter(|&i| i != root && cut_off[i] >= CHOKE_MIN_CUT_OFF)
+        .map(|i| ChokePoint {
+            x: i % w,
+            y: i / w,
+            cut_off: cut_off[i],
+        })
+        .collect();
+    (chokes, size[root])
+}
+
+fn neighbor_indices(world: &World, idx: usize) -> Vec<usize> {
+    let w = world.width;
+    neighbors(world, idx % w, idx / w)
+        .map(|(x, y)| y * w + x)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::symmetry::MapSymmetry;
+
+    #[test]
+    fn test_symmetric_map_is_fair() {
+        let text = "\
+[tiles]
+SSSSSSSSSSS
+SPPPPPPPPPS
+SPPPPPPPPPS
+SPPPPKPPPPS
+SPPPPPPPPPS
+SPPPPPPPPPS
+SSSSSSSSSSS
+[spawners]
+2 3 1 100 1000
+8 3 1 100 1000
+[spawn_points]
+1 3
+9 3
+";
+        let mut world = World::from_ascii(text).unwrap();
+        world.add_food(5, 3, 1000);
+        let a = analyze(&world);
+        assert_eq!(a.spawns.len(), 2);
+        assert_eq!(a.spawns[0].koth_distance, Some(4));
+        assert_eq!(a.spawns[0].spawner_distances, vec![Some(1), Some(7)]);
+        assert_eq!(a.spawns[0].reachable_income, 200.0);
+        assert_eq!(a.unfairness, 0.0);
+        assert!(a.choke_points.is_empty());
+        assert_eq!(a.walkable_tiles, 45);
+        assert_eq!(a.total_food, 1000);
+        assert!(a.spawns.iter().all(|s| s.nearby_food == 1000));
+    }
+
+    #[test]
+    fn test_unfair_map_and_choke_point() {
+        // Slot 1 reaches the KOTH only through a long corridor
+        let text = "\
+[tiles]
+SSSSSSSSSSSSSSSSSSSSS
+SPPPKPPPPPPPPPPPPPPPS
+SSSSSSSSSSSSSSSSSSSPS
+SPPPPPPPPPPPPPPPPPPPS
+SSSSSSSSSSSSSSSSSSSSS
+[spawn_points]
+3 1
+1 3
+";
+        let a = analyze(&World::from_ascii(text).unwrap());
+        assert_eq!(a.spawns[0].koth_distance, Some(1));
+        assert_eq!(a.spawns[1].koth_distance, Some(35));
+        assert!(a.unfairness > 0.4);
+        assert!(a.spawns[0].advantage > 0.0 && a.spawns[1].advantage < 0.0);
+        // Every corridor tile past the KOTH cuts off the rest of the corridor
+        let corner = a
+            .choke_points
+            .iter()
+            .find(|c| (c.x, c.y) == (19, 2))
+            .unwrap();
+        assert_eq!(corner.cut_off, 19);
+        // Cutting the corridor right after the KOTH separates everything east of it
+        assert_eq!(a.choke_points[0].cut_off, 34);
+        assert_eq!((a.choke_points[0].x, a.choke_points[0].y), (5, 1));
+    }
+
+    #[test]
+    fn test_generate_fair_random() {
+        let params = RandomMapParams {
+            symmetry: MapSymmetry::Rotational,
+            ..RandomMapParams::default()
+        };
+        let world = generate_fair_random(&params, 0.0);
+        assert!(analyze(&world).unfairness < 1e-9);
+    }
+}
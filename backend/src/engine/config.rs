@@ -37,6 +37,21 @@ pub const TILE_GFX_NONE: u8 = 8;
 pub const TILE_GFX_KOTH: u8 = 9;
 pub const TILE_GFX_DESERT: u8 = 10;
 
+pub const TILE_GFX_TYPES: usize = 11;
+
+// Terrain effects per tile gfx live in the ruleset the game reads at runtime
+// (`world::TerrainRules`); a map can override them through its metadata.
+
+// Tile gfx names used in terrain metadata keys, indexed by gfx
+pub const TILE_GFX_NAMES: [&str; TILE_GFX_TYPES] = [
+    "solid", "plain", "border", "snow_solid", "snow_plain", "snow_border", "water", "lava", "none",
+    "koth", "desert",
+];
+
+// A* cost of entering a plain tile. Terrain never costs less, which keeps the
+// Manhattan heuristic admissible.
+pub const MIN_PATH_COST: i32 = 10;
+
 // Max health per type [small, big, flyer, unused]
 pub const MAX_HEALTH: [i32; CREATURE_TYPES] = [10000, 20000, 5000, 0];
 
@@ -103,5 +118,49 @@ pub const FEED_DISTANCE: [i32; CREATURE_TYPES] = [256, 0, 256, 0];
 // Feed speed (food transferred per second)
 pub const FEED_SPEED: [i32; CREATURE_TYPES] = [400, 0, 400, 0];
 
-// Lua VM instruction limit per tick (prevents infinite loops)
+// Pathfinding: cached (start, goal) results per world, and the cluster size
+// (tiles per side) for hierarchical search.
+pub const PATH_CACHE_SIZE: usize = 256;
+pub const HPA_CLUSTER_SIZE: usize = 10;
+
+// Food index bucket size (tiles per side)
+pub const FOOD_INDEX_BUCKET_SIZE: usize = 8;
+
+// Lua instruction budget per tick (prevents infinite loops). VM instructions
+// and query API costs are both charged against it.
 pub const LUA_MAX_INSTRUCTIONS: u32 = 500_000;
+
+// The VM hook runs every LUA_HOOK_INTERVAL instructions and charges them to
+// the budget.
+pub const LUA_HOOK_INTERVAL: u32 = 1000;
+
+// Lua API levels. Bots declare the level they were written against with
+// needs_api(style, level); the engine installs that level's functions and
+// constants. Levels below LUA_API_DEPRECATED_BELOW still load but validation
+// warns about them.
+pub const LUA_API_LEVEL: u32 = 2;
+pub const LUA_API_MIN_LEVEL: u32 = 1;
+pub const LUA_API_DEPRECATED_BELOW: u32 = 2;
+
+// Lua query API costs, charged against LUA_MAX_INSTRUCTIONS: a flat cost per
+// call plus a cost per creature, tile or waypoint examined. Path queries also
+// pay for the search itself.
+pub const LUA_QUERY_CALL_COST: u32 = 100;
+pub const LUA_QUERY_ITEM_COST: u32 = 10;
+pub const LUA_PATH_QUERY_COST: u32 = 2000;
+
+// Limits for the Lua area queries
+pub const LUA_MAX_QUERY_RADIUS: i32 = 16 * TILE_SIZE;
+pub const LUA_MAX_QUERY_TILES: usize = 1024;
+
+// Instruction limit for trusted level scripts (level_init / level_tick).
+// Higher than the bot limit since level_init may dig thousands of tiles.
+pub const LEVEL_MAX_INSTRUCTIONS: u32 = 5_000_000;
+
+// Size limits for user-uploaded maps (tiles per side).
+pub const MAP_MIN_SIZE: usize = 8;
+pub const MAP_MAX_SIZE: usize = 256;
+
+// Uploaded maps whose slot scores differ by more than this are rejected
+// (see analysis::MapAnalysis::unfairness).
+pub const MAX_MAP_UNFAIRNESS: f64 = 0.2;
@@ -0,0 +1,363 @@
+// Spatial index over tile food.
+//
+// The map is split into square buckets of `FOOD_INDEX_BUCKET_SIZE` tiles. Each
+// bucket keeps its food total, an upper bound on its richest tile and the list
+// of its non-empty tiles. `World::add_food` / `eat_food` update it on every
+// change, so "nearest tile with at least N food" and "food within a radius"
+// only look at buckets that can matter instead of scanning the whole map.
+// Distances are measured in pixels to tile centers.
+
+use super::config::*;
+
+/// A tile holding food, as returned by `FoodIndex::nearest`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoodTile {
+    pub x: usize,
+    pub y: usize,
+    pub food: i32,
+}
+
+/// Result of a nearest-food search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NearestFood {
+    pub tile: Option<FoodTile>,
+    /// Buckets and tiles looked at, for callers that charge per item.
+    pub visited: usize,
+}
+
+/// Result of a food-within-radius sum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoodInRadius {
+    pub total: i64,
+    /// Buckets and tiles looked at, for callers that charge per item.
+    pub visited: usize,
+}
+
+#[derive(Clone, Default)]
+struct Bucket {
+    total: i64,
+    /// Never below the richest tile; reset once the bucket is empty.
+    max: i32,
+    /// Tile indices (y * width + x) with food > 0, unordered.
+    tiles: Vec<usize>,
+}
+
+#[derive(Clone)]
+pub struct FoodIndex {
+    width: usize,
+    height: usize,
+    cols: usize,
+    buckets: Vec<Bucket>,
+}
+
+impl FoodIndex {
+    /// An empty index for a `width` x `height` tile map.
+    pub fn new(width: usize, height: usize) -> Self {
+        let cols = width.div_ceil(FOOD_INDEX_BUCKET_SIZE);
+        let rows = height.div_ceil(FOOD_INDEX_BUCKET_SIZE);
+        FoodIndex {
+            width,
+            height,
+            cols,
+            buckets: vec![Bucket::default(); cols * rows],
+        }
+    }
+
+    fn bucket_of(&self, x: usize, y: usize) -> usize {
+        (y / FOOD_INDEX_BUCKET_SIZE) * self.cols + x / FOOD_INDEX_BUCKET_SIZE
+    }
+
+    /// Record that tile (x, y) went from `old` to `new` food.
+    pub fn update(&mut self, x: usize, y: usize, old: i32, new: i32) {
+        if old == new || x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = y * self.width + x;
+        let b = self.bucket_of(x, y);
+        let bucket = &mut self.buckets[b];
+        bucket.total += (new - old) as i64;
+        if old <= 0 && new > 0 {
+            bucket.tiles.push(idx);
+        } else if old > 0 && new <= 0 {
+            if let Some(pos) = bucket.tiles.iter().position(|&i| i == idx) {
+                bucket.tiles.swap_remove(pos);
+            }
+        }
+        if bucket.tiles.is_empty() {
+            bucket.max = 0;
+        } else {
+            bucket.max = bucket.max.max(new);
+        }
+    }
+
+    /// Total food on the map.
+    pub fn total(&self) -> i64 {
+        self.buckets.iter().map(|b| b.total).sum()
+    }
+
+    /// Number of tiles holding food.
+    pub fn tile_count(&self) -> usize {
+        self.buckets.iter().map(|b| b.tiles.len()).sum()
+    }
+
+    /// Pixel range covered by the tile centers of bucket column/row `i`
+    /// along an axis of `size` tiles.
+    fn center_span(i: usize, size: usize) -> (i64, i64) {
+        let first = i * FOOD_INDEX_BUCKET_SIZE;
+        let last = (first + FOOD_INDEX_BUCKET_SIZE).min(size) - 1;
+        let center = |t: usize| (t as i64) * TILE_SIZE as i64 + TILE_SIZE as i64 / 2;
+        (center(first), center(last))
+    }
+
+    /// Squared distance from (px, py) to the nearest and farthest tile center
+    /// of bucket `b`.
+    fn bucket_dist_sq(&self, b: usize, px: i64, py: i64) -> (i64, i64) {
+        let (x_lo, x_hi) = Self::center_span(b % self.cols, self.width);
+        let (y_lo, y_hi) = Self::center_span(b / self.cols, self.height);
+        let near = |p: i64, lo: i64, hi: i64| (lo - p).max(p - hi).max(0);
+        let far = |p: i64, lo: i64, hi: i64| (p - lo).abs().max((hi - p).abs());
+        let (nx, ny) = (near(px, x_lo, x_hi), near(py, y_lo, y_hi));
+        let (fx, fy) = (far(px, x_lo, x_hi), far(py, y_lo, y_hi));
+        (nx * nx + ny * ny, fx * fx + fy * fy)
+    }
+
+    fn tile_dist_sq(&self, idx: usize, px: i64, py: i64) -> i64 {
+        let center = |t: usize| (t as i64) * TILE_SIZE as i64 + TILE_SIZE as i64 / 2;
+        let dx = center(idx % self.width) - px;
+        let dy = center(idx / self.width) - py;
+        dx * dx + dy * dy
+    }
+
+    /// Nearest tile (by center, from pixel position (px, py)) holding at
+    /// least `min_amount` food and accepted by `accept(x, y)`. `food(x, y)`
+    /// reads the current tile food. Ties go to the lowest tile index.
+    pub fn nearest(
+        &self,
+        px: i32,
+        py: i32,
+        min_amount: i32,
+        food: impl Fn(usize, usize) -> i32,
+        accept: impl Fn(usize, usize) -> bool,
+    ) -> NearestFood {
+        let (px, py) = (px as i64, py as i64);
+        let min_amount = min_amount.max(1);
+        let mut candidates: Vec<(i64, usize)> = self
+            .buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.max >= min_amount)
+            .map(|(i, _)| (self.bucket_dist_sq(i, px, py).0, i))
+            .collect();
+        candidates.sort_unstable();
+
+        let mut visited = 0;
+        let mut best: Option<(i64, usize, i32)> = None;
+        for (near, b) in candidates {
+            if best.is_some_and(|(d, ..)| near > d) {
+                break;
+            }
+            visited += 1;
+            for &idx in &self.buckets[b].tiles {
+                visited += 1;
+                let (x, y) = (idx % self.width, idx / self.width);
+                let amount = food(x, y);
+                if amount < min_amount || !accept(x, y) {
+                    continue;
+                }
+                let d = self.tile_dist_sq(idx, px, py);
+                if best.is_none_or(|(bd, bi, _)| (d, idx) < (bd, bi)) {
+                    best = Some((d, idx, amount));
+                }
+            }
+        }
+
+        NearestFood {
+            tile: best.map(|(_, idx, food)| FoodTile {
+                x: idx % self.width,
+                y: idx / self.width,
+                food,
+            }),
+            visited,
+        }
+    }
+
+    /// Total food on tiles whose center lies within `radius` pixels of
+    /// (px, py). Buckets entirely inside the circle use their running total.
+    pub fn within_radius(
+        &self,
+        px: i32,
+        py: i32,
+        radius: i32,
+        food: impl Fn(usize, usize) -> i32,
+    ) -> FoodInRadius {
+        let (px, py) = (px as i64, py as i64);
+        let r_sq = (radius.max(0) as i64).pow(2);
+        let mut total = 0;
+        let mut visited = 0;
+        for (b, bucket) in self.buckets.iter().enumerate() {
+            if bucket.tiles.is_empty() {
+                continue;
+            }
+            let (near, far) = self.bucket_dist_sq(b, px, py);
+            if near > r_sq {
+                continue;
+            }
+            visited += 1;
+            if far <= r_sq {
+                total += bucket.total;
+                continue;
+            }
+            visited += bucket.tiles.len();
+            for &idx in &bucket.tiles {
+                if self.tile_dist_sq(idx, px, py) <= r_sq {
+                    total += food(idx % self.width, idx / self.width) as i64;
+                }
+            }
+        }
+        FoodInRadius { total, visited }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn center(t: usize) -> i32 {
+        t as i32 * TILE_SIZE + TILE_SIZE / 2
+    }
+
+    /// Index plus a flat food array standing in for the world's tiles.
+    struct Fixture {
+        index: FoodIndex,
+        food: Vec<i32>,
+        width: usize,
+    }
+
+    impl Fixture {
+        fn new(width: usize, height: usize) -> Self {
+            Fixture {
+                index: FoodIndex::new(width, height),
+                food: vec![0; width * height],
+                width,
+            }
+        }
+
+        fn set(&mut self, x: usize, y: usize, amount: i32) {
+            let idx = y * self.width + x;
+            self.index.update(x, y, self.food[idx], amount);
+            self.food[idx] = amount;
+        }
+
+        fn nearest(&self, x: usize, y: usize, min: i32) -> Option<(usize, usize, i32)> {
+            let w = self.width;
+            self.index
+                .nearest(
+                    center(x),
+                    center(y),
+                    min,
+                    |x, y| self.food[y * w + x],
+                    |_, _| true,
+                )
+                .tile
+                .map(|t| (t.x, t.y, t.food))
+        }
+
+        fn within(&self, x: usize, y: usize, radius: i32) -> i64 {
+            let w = self.width;
+            self.index
+                .within_radius(center(x), center(y), radius, |x, y| self.food[y * w + x])
+                .total
+        }
+
+        /// Brute-force reference for `within`.
+        fn within_slow(&self, x: usize, y: usize, radius: i32) -> i64 {
+            let r_sq = (radius as i64).pow(2);
+            let mut total = 0;
+            for (i, &f) in self.food.iter().enumerate() {
+                let dx = (center(i % self.width) - center(x)) as i64;
+                let dy = (center(i / self.width) - center(y)) as i64;
+                if dx * dx + dy * dy <= r_sq {
+                    total += f as i64;
+                }
+            }
+            total
+        }
+    }
+
+    #[test]
+    fn test_totals_follow_updates() {
+        let mut f = Fixture::new(20, 20);
+        f.set(1, 1, 500);
+        f.set(15, 12, 300);
+        assert_eq!(f.index.total(), 800);
+        assert_eq!(f.index.tile_count(), 2);
+
+        f.set(1, 1, 200);
+        f.set(15, 12, 0);
+        assert_eq!(f.index.total(), 200);
+        assert_eq!(f.index.tile_count(), 1);
+
+        f.set(1, 1, 0);
+        assert_eq!(f.index.total(), 0);
+        assert_eq!(f.index.tile_count(), 0);
+        assert!(f.nearest(1, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_nearest_respects_min_amount_and_distance() {
+        let mut f = Fixture::new(30, 30);
+        f.set(3, 3, 100);
+        f.set(12, 3, 5000);
+        f.set(28, 28, 9000);
+
+        assert_eq!(f.nearest(10, 3, 1), Some((12, 3, 5000)));
+        assert_eq!(f.nearest(5, 3, 1), Some((3, 3, 100)));
+        assert_eq!(f.nearest(5, 3, 1000), Some((12, 3, 5000)));
+        assert_eq!(f.nearest(5, 3, 6000), Some((28, 28, 9000)));
+        assert!(f.nearest(5, 3, 10_000).is_none());
+
+        // A bucket's max is only an upper bound after food is eaten
+        f.set(12, 3, 50);
+        assert_eq!(f.nearest(10, 3, 1000), Some((28, 28, 9000)));
+    }
+
+    #[test]
+    fn test_nearest_skips_rejected_tiles() {
+        let mut f = Fixture::new(20, 20);
+        f.set(2, 2, 100);
+        f.set(9, 9, 100);
+        let food = f.food.clone();
+        let found = f
+            .index
+            .nearest(
+                center(2),
+                center(2),
+                1,
+                |x, y| food[y * 20 + x],
+                |x, _| x != 2,
+            )
+            .tile
+            .unwrap();
+        assert_eq!((found.x, found.y), (9, 9));
+    }
+
+    #[test]
+    fn test_within_radius_matches_brute_force() {
+        let mut f = Fixture::new(37, 23);
+        for (i, (x, y)) in [(0, 0), (5, 5), (8, 8), (9, 7), (20, 11), (36, 22), (17, 3)]
+            .into_iter()
+            .enumerate()
+        {
+            f.set(x, y, 100 * (i as i32 + 1));
+        }
+        for &(x, y) in &[(0, 0), (8, 8), (18, 11), (36, 22)] {
+            for radius in [0, TILE_SIZE, 3 * TILE_SIZE, 10 * TILE_SIZE, 100 * TILE_SIZE] {
+                assert_eq!(
+                    f.within(x, y, radius),
+                    f.within_slow(x, y, radius),
+                    "({x}, {y}) r={radius}"
+                );
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::time::Instant;
@@ -7,10 +7,12 @@ use serde::Serialize;
 
 use super::config::*;
 use super::creature::Creature;
+use super::level::LevelScript;
 use super::lua_api::{self, LuaGameState};
-use super::player::Player;
+use super::pathfinding::PathConfig;
+use super::player::{ApiVersion, Player};
 use super::spatial::SpatialGrid;
-use super::world::World;
+use super::world::{TerrainRules, World};
 
 /// Map a creature type constant to a string label for metrics.
 fn creature_type_label(creature_type: u8) -> &'static str {
@@ -170,15 +172,25 @@ pub struct Game {
     broadcast_events: Vec<BroadcastEvent>,
     /// Per-player statistics (spawns, kills, losses)
     player_stats: HashMap<u32, PlayerStats>,
-    /// Spatial index for fast creature proximity queries. Rebuilt each tick.
+    /// Spatial index for fast creature proximity queries. Updated as creatures
+    /// spawn, walk and die.
     spatial_grid: Rc<RefCell<SpatialGrid>>,
     /// Timing data from the last tick.
     pub last_tick_timings: TickTimings,
+    /// Scripted level behavior (`level_tick`) for worlds loaded from a level script.
+    level: Option<LevelScript>,
 }
 
 impl Game {
-    pub fn new(world: World) -> Self {
+    pub fn new(mut world: World) -> Self {
+        world.set_path_config(PathConfig::from_metadata(&world.metadata));
+        world.set_terrain_rules(TerrainRules::from_metadata(&world.metadata));
         let grid = SpatialGrid::new(world.width, world.height);
+        let level = world.level_source.as_deref().and_then(|source| {
+            LevelScript::new(source, &world)
+                .map_err(|e| tracing::warn!("Failed to load level script: {e}"))
+                .ok()
+        });
         Game {
             world: Rc::new(RefCell::new(world)),
             creatures: Rc::new(RefCell::new(HashMap::new())),
@@ -197,16 +209,31 @@ impl Game {
             player_stats: HashMap::new(),
             spatial_grid: Rc::new(RefCell::new(grid)),
             last_tick_timings: TickTimings::default(),
+            level,
         }
     }
 
-    /// Add a player with the given bot code.
+    /// Add a player with the given bot code, using the API version the code
+    /// declares (or the current one).
     /// Returns the player ID on success.
     pub fn add_player(&mut self, name: &str, code: &str) -> Result<u32, String> {
+        self.add_player_with_api(name, code, None)
+    }
+
+    /// Add a player with the given bot code and API version, e.g. the one
+    /// stored with its bot version. `None` detects it from the code.
+    /// Returns the player ID on success.
+    pub fn add_player_with_api(
+        &mut self,
+        name: &str,
+        code: &str,
+        api: Option<ApiVersion>,
+    ) -> Result<u32, String> {
         let player_id = self.next_player_id;
         self.next_player_id += 1;
 
-        let player = Player::new(player_id, name)?;
+        let api = api.unwrap_or_else(|| ApiVersion::detect(code));
+        let player = Player::new(player_id, name, api)?;
 
         // Set game state so top-level bot code can call API functions
         // (e.g. world_size(), get_koth_pos() during script initialization)
@@ -221,6 +248,7 @@ impl Game {
             king_player_id: self.king_player_id,
             print_output: print_output.clone(),
             spatial_grid: Some(self.spatial_grid.clone()),
+            instructions: Cell::new(0),
         }));
         lua_api::set_game_state(&player.lua, gs);
 
@@ -286,7 +314,9 @@ impl Game {
             .map(|(id, _)| *id)
             .collect();
         for id in to_kill {
-            self.creatures.borrow_mut().remove(&id);
+            if let Some(c) = self.creatures.borrow_mut().remove(&id) {
+                self.spatial_grid.borrow_mut().remove(id, c.x, c.y);
+            }
         }
     }
 
@@ -316,6 +346,7 @@ impl Game {
 
         let creature = Creature::new(id, x, y, creature_type, player_id);
         self.creatures.borrow_mut().insert(id, creature);
+        self.spatial_grid.borrow_mut().insert(id, x, y, player_id);
 
         // Update player creature count
         if let Some(player) = self.players.get_mut(&player_id) {
@@ -365,6 +396,7 @@ impl Game {
 
         let creature = Creature::new(id, x, y, creature_type, player_id);
         self.creatures.borrow_mut().insert(id, creature);
+        self.spatial_grid.borrow_mut().insert(id, x, y, player_id);
 
         if let Some(player) = self.players.get_mut(&player_id) {
             player.num_creatures += 1;
@@ -532,7 +564,9 @@ impl Game {
                 drop(creatures);
             }
 
-            self.creatures.borrow_mut().remove(&creature_id);
+            if let Some(c) = self.creatures.borrow_mut().remove(&creature_id) {
+                self.spatial_grid.borrow_mut().remove(creature_id, c.x, c.y);
+            }
 
             if let Some(player) = self.players.get_mut(&player_id) {
                 player.num_creatures -= 1;
@@ -545,9 +579,6 @@ impl Game {
         let tick_start = Instant::now();
         let delta = self.tick_delta;
 
-        // 0. Rebuild spatial index from current creature positions
-        self.rebuild_spatial_index();
-
         // 1. Run each player's think (Lua execution)
         let think_start = Instant::now();
         self.process_player_think();
@@ -561,8 +592,9 @@ impl Game {
         // 3. King of the Hill scoring
         self.process_koth();
 
-        // 4. Food spawning
+        // 4. Food spawning (map spawners and scripted level_tick)
         self.process_food_spawners();
+        self.process_level_tick();
 
         // 5. Advance game time
         self.game_time += delta as i64;
@@ -589,16 +621,6 @@ impl Game {
         }
     }
 
-    /// Rebuild the spatial index from current creature positions.
-    fn rebuild_spatial_index(&self) {
-        let mut grid = self.spatial_grid.borrow_mut();
-        grid.clear();
-        let creatures = self.creatures.borrow();
-        for (_, c) in creatures.iter() {
-            grid.insert(c.id, c.x, c.y, c.player_id);
-        }
-    }
-
     /// Spawn food from map food spawners. Each spawner places food at a random tile
     /// within its radius every `interval` ticks.
     fn process_food_spawners(&mut self) {
@@ -625,19 +647,33 @@ impl Game {
             if tx >= 0 && ty >= 0 {
                 let tx = tx as usize;
                 let ty = ty as usize;
-                if world.is_walkable(tx, ty) {
+                if world.is_walkable(tx, ty) && world.terrain(tx, ty).food_growth {
                     world.add_food(tx, ty, spawner.amount);
                 }
             }
         }
     }
 
+    /// Run the level script's `level_tick`, if the world came from a level script.
+    fn process_level_tick(&mut self) {
+        if let Some(level) = &self.level {
+            if let Err(e) = level.tick(&self.world, self.game_time) {
+                tracing::warn!(game_time = self.game_time, "Level script error: {e}");
+            }
+        }
+    }
+
     /// Ensure the map has enough food spawners. If fewer than 10, add random ones on
     /// walkable tiles (like the original game's world_find_digged() spawners).
+    /// Scripted levels place their own food, so this is a no-op for them.
     pub fn ensure_food_spawners(&mut self) {
         use super::world::FoodSpawner;
         use rand::Rng;
 
+        if self.level.is_some() {
+            return;
+        }
+
         let mut world = self.world.borrow_mut();
         let existing = world.food_spawners.len();
         let target = 15;
@@ -648,7 +684,7 @@ impl Game {
         let mut rng = rand::thread_rng();
         let walkable: Vec<(usize, usize)> = (0..world.width)
             .flat_map(|x| (0..world.height).map(move |y| (x, y)))
-            .filter(|&(x, y)| world.is_walkable(x, y))
+            .filter(|&(x, y)| world.is_walkable(x, y) && world.terrain(x, y).food_growth)
             .collect();
 
         if walkable.is_empty() {
@@ -672,13 +708,31 @@ impl Game {
     /// Place initial food from spawners so maps start with food already on them.
     /// Mirrors the original game behavior: each spawner gets a big initial food dump.
     pub fn seed_initial_food(&mut self) {
+        if self.level.is_some() {
+            return;
+        }
         let mut world = self.world.borrow_mut();
 
         for spawner in &world.food_spawners.clone() {
             // Place a large initial food pile at the spawner's center tile
             // (original game does world_add_food(spawner.x, spawner.y, 10000))
-            if world.is_walkable(spawner.x, spawner.y) {
-                world.add_food(spawner.x, spawner.y, 9000);
+            let (x, y) = (spawner.x, spawner.y);
+            if world.is_walkable(x, y) && world.terrain(x, y).food_growth {
+                world.add_food(x, y, 9000);
+            }
+        }
+    }
+
+    /// Place each player's starting creatures. `player_ids` are in slot order;
+    /// slot `i` starts on the map's `i`-th spawn point (see `World::spawn_tile_for_slot`).
+    pub fn spawn_initial_creatures(&mut self, player_ids: &[u32]) {
+        let initial_creatures = 2;
+        for (slot, &pid) in player_ids.iter().enumerate() {
+            for _ in 0..initial_creatures {
+                let tile = self.world.borrow().spawn_tile_for_slot(slot);
+                if let Some((tx, ty)) = tile {
+                    self.spawn_creature(pid, World::tile_center(tx), World::tile_center(ty), CREATURE_SMALL);
+                }
             }
         }
     }
@@ -713,15 +767,13 @@ impl Game {
                 king_player_id: self.king_player_id,
                 print_output: print_output.clone(),
                 spatial_grid: Some(self.spatial_grid.clone()),
+                instructions: Cell::new(0),
             }));
 
             lua_api::set_game_state(&player.lua, gs);
 
             // Set instruction count limit to prevent infinite loops
-            let _ = player.lua.set_hook(
-                mlua::HookTriggers::new().every_nth_instruction(LUA_MAX_INSTRUCTIONS),
-                |_lua, _debug| Err(mlua::Error::RuntimeError("lua vm cycles exceeded".into())),
-            );
+            lua_api::set_instruction_limit(&player.lua);
 
             // Build the events table in Lua
             let result = (|| -> mlua::Result<()> {
@@ -810,7 +862,19 @@ impl Game {
             let died = {
                 let mut creatures = self.creatures.borrow_mut();
                 if let Some(creature) = creatures.get_mut(&id) {
-                    creature.do_age(delta)
+                    let mut died = creature.do_age(delta);
+                    // Hazardous terrain (lava) hurts ground creatures
+                    if creature.is_ground_based() && !died {
+                        let terrain = self
+                            .world
+                            .borrow()
+                            .terrain(creature.tile_x(), creature.tile_y());
+                        if terrain.damage > 0 {
+                            creature.health -= terrain.damage * delta / 1000;
+                            died = creature.health <= 0;
+                        }
+                    }
+                    died
                 } else {
                     false
                 }
@@ -840,7 +904,22 @@ impl Game {
 
             match creature.state {
                 CREATURE_WALK => {
-                    creature.do_walk(delta);
+                    // Terrain under a ground creature scales its speed
+                    let speed = if creature.is_ground_based() {
+                        self.world
+                            .borrow()
+                            .terrain(creature.tile_x(), creature.tile_y())
+                            .speed
+                    } else {
+                        100
+                    };
+                    let (old_x, old_y) = (creature.x, creature.y);
+                    creature.do_walk(delta * speed / 100);
+                    if (creature.x, creature.y) != (old_x, old_y) {
+                        self.spatial_grid.borrow_mut().update(
+                            id, old_x, old_y, creature.x, creature.y,
+                        );
+                    }
                 }
                 CREATURE_HEAL => {
                     let finished = creature.do_heal(delta);
@@ -851,6 +930,11 @@ impl Game {
                 CREATURE_EAT => {
                     let tx = creature.tile_x();
                     let ty = creature.tile_y();
+                    // Flyers can't land to eat over solid tiles
+                    if !self.world.borrow().is_walkable(tx, ty) {
+                        creature.set_state(CREATURE_IDLE);
+                        continue;
+                    }
                     let tile_food = self.world.borrow().get_food(tx, ty);
                     let (eaten, finished) = creature.do_eat(delta, tile_food);
                     if eaten > 0 {
@@ -917,6 +1001,16 @@ impl Game {
                     }
                 }
                 CREATURE_CONVERT => {
+                    // Converting lands the creature, so a flyer over a solid
+                    // tile can't turn into a ground creature there
+                    if !self
+                        .world
+                        .borrow()
+                        .is_walkable(creature.tile_x(), creature.tile_y())
+                    {
+                        creature.set_state(CREATURE_IDLE);
+                        continue;
+                    }
                     let result = creature.do_convert(delta);
                     if result.is_some() {
                         creature.set_state(CREATURE_IDLE);
@@ -968,12 +1062,17 @@ impl Game {
                     let target_y = target.y;
                     let target_food = target.food;
                     let target_max_food = target.max_food();
+                    let target_landed = self
+                        .world
+                        .borrow()
+                        .is_walkable(target.tile_x(), target.tile_y());
 
                     let dx = (feeder_x - target_x) as i64;
                     let dy = (feeder_y - target_y) as i64;
                     let dist = ((dx * dx + dy * dy) as f64).sqrt() as i32;
 
-                    if dist > feed_dist {
+                    // Creatures over solid tiles can't be fed
+                    if dist > feed_dist || !target_landed {
                         let c = creatures.get_mut(&id).unwrap();
                         c.set_state(CREATURE_IDLE);
                         continue;
@@ -1373,6 +1472,106 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_flyer_walks_over_walls() {
+        // Open world split by a solid column at x = 5
+        let mut world = World::new(10, 10);
+        for y in 1..9 {
+            for x in (1..9).filter(|&x| x != 5) {
+                world.set_type(x, y, TILE_PLAIN);
+            }
+        }
+        let mut game = Game::new(world);
+
+        let target_x = World::tile_center(7);
+        let target_y = World::tile_center(3);
+        let code = format!(
+            r#"
+            function Creature:main()
+                self.path_ok = self:set_path({target_x}, {target_y})
+                self:begin_walk_path()
+                while self:is_walking() do
+                    self:wait_for_next_round()
+                end
+            end
+            "#
+        );
+        let pid = game.add_player("FlyBot", &code).unwrap();
+        let (cx, cy) = (World::tile_center(3), World::tile_center(3));
+        let flyer = game.spawn_creature(pid, cx, cy, CREATURE_FLYER).unwrap();
+        let small = game.spawn_creature(pid, cx, cy, CREATURE_SMALL).unwrap();
+
+        for _ in 0..20 {
+            game.tick();
+        }
+
+        let creatures = game.creatures.borrow();
+        let flyer = creatures.get(&flyer).unwrap();
+        assert_eq!((flyer.x, flyer.y), (target_x, target_y));
+        let small = creatures.get(&small).unwrap();
+        assert_eq!((small.x, small.y), (cx, cy), "ground creatures can't cross");
+    }
+
+    #[test]
+    fn test_flyer_cannot_eat_over_solid_tile() {
+        let mut world = make_test_world();
+        world.add_food(4, 4, 5000);
+        let mut game = Game::new(world);
+        let code = r#"
+            function Creature:main()
+                self:begin_eating()
+                self:wait_for_next_round()
+            end
+        "#;
+        let pid = game.add_player("FlyBot", code).unwrap();
+
+        // Hovering over the solid border the flyer can't land to eat
+        let over_wall = game
+            .spawn_creature(pid, World::tile_center(0), World::tile_center(4), CREATURE_FLYER)
+            .unwrap();
+        let landed = game
+            .spawn_creature(pid, World::tile_center(4), World::tile_center(4), CREATURE_FLYER)
+            .unwrap();
+        for _ in 0..5 {
+            game.tick();
+        }
+
+        let creatures = game.creatures.borrow();
+        assert_eq!(creatures.get(&over_wall).unwrap().food, 0);
+        assert!(creatures.get(&landed).unwrap().food > 0);
+    }
+
+    #[test]
+    fn test_lava_hurts_ground_creatures() {
+        let standard = TerrainRules::default().get(TILE_GFX_LAVA).damage;
+        // The map's terrain rules decide how much
+        for (damage_meta, damage) in [(None, standard), (Some("300"), 300)] {
+            let mut world = make_test_world();
+            world.set_gfx(5, 5, TILE_GFX_LAVA);
+            if let Some(d) = damage_meta {
+                world.metadata.insert("terrain_lava_damage".to_string(), d.to_string());
+            }
+            let mut game = Game::new(world);
+            let pid = game.add_player("LavaBot", "").unwrap();
+            let (x, y) = (World::tile_center(5), World::tile_center(5));
+            let small = game.spawn_creature(pid, x, y, CREATURE_SMALL).unwrap();
+            let flyer = game.spawn_creature(pid, x, y, CREATURE_FLYER).unwrap();
+
+            game.tick();
+
+            let creatures = game.creatures.borrow();
+            let aging = AGING[CREATURE_SMALL as usize];
+            assert_eq!(
+                creatures.get(&small).unwrap().health,
+                MAX_HEALTH[CREATURE_SMALL as usize] - aging - damage / 10
+            );
+            assert_eq!(
+                creatures.get(&flyer).unwrap().health,
+                MAX_HEALTH[CREATURE_FLYER as usize] - AGING[CREATURE_FLYER as usize]
+            );
+        }
+    }
+
     #[test]
     fn test_combat() {
         let world = make_test_world();
@@ -1579,6 +1778,34 @@ mod tests {
         assert_eq!(game.game_time, 100);
     }
 
+    #[test]
+    fn test_instruction_limit_is_shared_by_coroutines() {
+        let world = make_test_world();
+        let mut game = Game::new(world);
+
+        // Each creature stays under the limit alone, but not together
+        let code = r#"
+            function Creature:main()
+                for i = 1, 200000 do local x = i end
+                self:wait_for_next_round()
+            end
+        "#;
+        let pid = game.add_player("BusyBot", code).unwrap();
+        for x in [3, 4, 5] {
+            let (cx, cy) = (World::tile_center(x), World::tile_center(3));
+            game.spawn_creature(pid, cx, cy, CREATURE_SMALL);
+        }
+
+        game.tick();
+        let snap = game.snapshot();
+        let player = snap.players.iter().find(|p| p.id == pid).unwrap();
+        assert!(
+            player.output.iter().any(|line| line.contains("cycles exceeded")),
+            "Expected 'cycles exceeded' error in output, got: {:?}",
+            player.output
+        );
+    }
+
     #[test]
     fn test_delta_compression_no_change() {
         let snap = GameSnapshot {
@@ -1750,7 +1977,7 @@ mod tests {
 
     #[test]
     fn test_spatial_index_used_in_tick() {
-        // Verify that the spatial index is rebuilt each tick without errors
+        // Verify that the spatial index stays in sync over several ticks
         let world = make_test_world();
         let mut game = Game::new(world);
         let pid1 = game.add_player("Bot1", "").unwrap();
@@ -1761,10 +1988,107 @@ mod tests {
         game.spawn_creature(pid1, cx, cy, CREATURE_SMALL);
         game.spawn_creature(pid2, cx + 256, cy, CREATURE_SMALL);
 
-        // Run several ticks -- the spatial index is rebuilt each time
         for _ in 0..10 {
             game.tick();
         }
         assert_eq!(game.game_time, 1000);
+        assert_eq!(
+            game.spatial_grid.borrow().len(),
+            game.creatures.borrow().len()
+        );
+    }
+
+    #[test]
+    fn test_spatial_index_updates_mid_tick() {
+        let mut game = Game::new(make_test_world());
+        let pid1 = game.add_player("Bot1", "").unwrap();
+        let pid2 = game.add_player("Bot2", "").unwrap();
+        let (cx, cy) = (World::tile_center(2), World::tile_center(2));
+        let me = game.spawn_creature(pid1, cx, cy, CREATURE_SMALL).unwrap();
+        let enemy = game.spawn_creature(pid2, cx + 256, cy, CREATURE_SMALL).unwrap();
+
+        // Spawns show up without waiting for a tick
+        let nearest = game.spatial_grid.borrow().find_nearest_enemy(cx, cy, pid1);
+        assert_eq!(nearest.map(|n| n.0), Some(enemy));
+
+        // Walking across cell boundaries keeps the entry's position exact
+        {
+            let mut creatures = game.creatures.borrow_mut();
+            let c = creatures.get_mut(&me).unwrap();
+            c.path = vec![(World::tile_center(7), cy)];
+            c.set_state(CREATURE_WALK);
+        }
+        for _ in 0..30 {
+            game.process_creatures(100);
+        }
+        let (x, y) = {
+            let creatures = game.creatures.borrow();
+            let c = creatures.get(&me).unwrap();
+            (c.x, c.y)
+        };
+        assert!(x > 2 * 512, "should have left the spawn cell, x = {x}");
+        let grid = game.spatial_grid.borrow();
+        let found: Vec<(u32, i32, i32)> = grid
+            .query_radius(x, y, 0)
+            .iter()
+            .map(|e| (e.id, e.x, e.y))
+            .collect();
+        assert_eq!(found, vec![(me, x, y)]);
+        drop(grid);
+
+        // Killed creatures are gone immediately
+        game.kill_creature(enemy, None);
+        assert!(game
+            .spatial_grid
+            .borrow()
+            .find_nearest_enemy(x, y, pid1)
+            .is_none());
+        game.remove_player(pid1);
+        assert!(game.spatial_grid.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_initial_creatures_use_spawn_points() {
+        let mut world = make_test_world();
+        world.spawn_points = vec![(2, 2), (7, 7)];
+        world.rotate_spawn_points(1, 2);
+        let mut game = Game::new(world);
+        let p1 = game.add_player("A", "").unwrap();
+        let p2 = game.add_player("B", "").unwrap();
+        game.spawn_initial_creatures(&[p1, p2]);
+
+        let creatures = game.creatures.borrow();
+        assert_eq!(creatures.len(), 4);
+        for c in creatures.values() {
+            let expected = if c.player_id == p1 { 7 } else { 2 };
+            assert_eq!((c.x, c.y), (World::tile_center(expected), World::tile_center(expected)));
+        }
+    }
+
+    #[test]
+    fn test_level_tick_runs_each_tick() {
+        let source = r#"
+function level_size() return 8, 8 end
+function level_init()
+    for x = 1, 6 do
+        for y = 1, 6 do
+            world_dig(x, y, TILE_PLAIN, TILE_GFX_PLAIN)
+        end
+    end
+end
+function level_tick()
+    world_add_food(2, 2, 10)
+end
+"#;
+        let world = crate::engine::level::load_level(source).unwrap();
+        let mut game = Game::new(world);
+        // Scripted levels bring their own food, no generic spawners are added
+        game.ensure_food_spawners();
+        assert!(game.world.borrow().food_spawners.is_empty());
+
+        for _ in 0..5 {
+            game.tick();
+        }
+        assert_eq!(game.world.borrow().get_food(2, 2), 50);
     }
 }
@@ -0,0 +1,558 @@
+// Loader for the original Infon level scripts (orig_game/level/*.lua).
+//
+// A level script defines `level_size()`, `level_koth_pos()`, `level_init()` and
+// optionally `level_tick()`. The scripts run in a trusted Lua VM with the same
+// restricted environment the original server used (see `world_load` in
+// orig_game/infond.lua) plus the `world_*` API operating on a `World`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use mlua::{FromLuaMulti, Function, Lua, MultiValue, Table, Value};
+
+use super::config::*;
+use super::world::World;
+
+/// Largest width/height a level script may request from `level_size()`.
+const MAX_LEVEL_SIZE: usize = 256;
+
+/// Tile type old levels pass to `world_dig` for water. Water tiles stay solid
+/// and only get the water gfx (compatibility behavior from infond.lua).
+const TILE_WATER_COMPAT: i64 = 2;
+
+/// World and clock a level script operates on.
+/// Stored as Lua app_data while level code runs, then removed afterward.
+struct LevelContext {
+    world: Rc<RefCell<World>>,
+    game_time: i64,
+}
+
+/// A level script loaded into its own Lua VM, used to run `level_tick` during a game.
+pub struct LevelScript {
+    lua: Lua,
+    env: Table,
+}
+
+impl LevelScript {
+    /// Load a level script for a game running on `world`.
+    ///
+    /// `level_init` is replayed against a scratch copy of the world so the script's
+    /// own state (spawner tables, food timers) exists for `level_tick`. The game
+    /// world keeps the tiles and food it got from `load_level`.
+    pub fn new(source: &str, world: &World) -> Result<Self, String> {
+        let (lua, env) = create_vm(source)?;
+        let script = LevelScript { lua, env };
+        let scratch = Rc::new(RefCell::new(world.clone()));
+        script.run(&scratch, 0, "level_init")?;
+        Ok(script)
+    }
+
+    /// Run the script's `level_tick()` against the live game world.
+    /// Does nothing if the level defines no `level_tick`.
+    pub fn tick(&self, world: &Rc<RefCell<World>>, game_time: i64) -> Result<(), String> {
+        self.run(world, game_time, "level_tick")
+    }
+
+    fn run(&self, world: &Rc<RefCell<World>>, game_time: i64, name: &str) -> Result<(), String> {
+        let func: Option<Function> = self
+            .env
+            .get(name)
+            .map_err(|e| format!("{name} is not a function: {e}"))?;
+        let Some(func) = func else {
+            return Ok(());
+        };
+
+        self.lua.set_app_data(LevelContext {
+            world: world.clone(),
+            game_time,
+        });
+        let result: Result<(), String> = call_limited(&self.lua, &func, name);
+        self.lua.remove_app_data::<LevelContext>();
+        result
+    }
+}
+
+/// Build a `World` from a level script.
+///
+/// Runs `level_size()` and `level_koth_pos()` to size the world, then `level_init()`
+/// to dig tiles and place initial food. The source is kept on the world so a game
+/// can load it again for `level_tick`.
+pub fn load_level(source: &str) -> Result<World, String> {
+    let (lua, env) = create_vm(source)?;
+
+    let level_size: Function = env
+        .get::<Option<Function>>("level_size")
+        .ok()
+        .flatten()
+        .ok_or("Level script does not define level_size()")?;
+    let (w, h): (i64, i64) = call_limited(&lua, &level_size, "level_size")?;
+    if w < 3 || h < 3 || w as usize > MAX_LEVEL_SIZE || h as usize > MAX_LEVEL_SIZE {
+        return Err(format!(
+            "Level size {w}x{h} out of range (3..={MAX_LEVEL_SIZE})"
+        ));
+    }
+    let mut world = World::new(w as usize, h as usize);
+
+    if let Some(koth_pos) = env.get::<Option<Function>>("level_koth_pos").ok().flatten() {
+        let (kx, ky): (i64, i64) = call_limited(&lua, &koth_pos, "level_koth_pos")?;
+        match tile_coords(kx, ky) {
+            Some((kx, ky)) if world.is_on_map(kx, ky) => {
+                world.koth_x = kx;
+                world.koth_y = ky;
+            }
+            _ => return Err(format!("KOTH position ({kx}, {ky}) is outside the level")),
+        }
+    }
+
+    let world = Rc::new(RefCell::new(world));
+    let script = LevelScript { lua, env };
+    script.run(&world, 0, "level_init")?;
+    drop(script);
+
+    let mut world = Rc::try_unwrap(world)
+        .map_err(|_| "Level world is still referenced by the script".to_string())?
+        .into_inner();
+    world.level_source = Some(source.to_string());
+    Ok(world)
+}
+
+/// Convert Lua tile coordinates to indices, rejecting negative values.
+fn tile_coords(x: i64, y: i64) -> Option<(usize, usize)> {
+    if x < 0 || y < 0 {
+        return None;
+    }
+    Some((x as usize, y as usize))
+}
+
+/// Install the level instruction limit to stop runaway level scripts.
+fn set_instruction_limit(lua: &Lua) {
+    lua.set_hook(
+        mlua::HookTriggers::new().every_nth_instruction(LEVEL_MAX_INSTRUCTIONS),
+        |_lua, _debug| Err(mlua::Error::RuntimeError("level vm cycles exceeded".into())),
+    );
+}
+
+/// Call a level function with the level instruction limit installed.
+fn call_limited<R: FromLuaMulti>(lua: &Lua, func: &Function, name: &str) -> Result<R, String> {
+    set_instruction_limit(lua);
+    let result = func.call::<R>(());
+    lua.remove_hook();
+    result.map_err(|e| format!("calling {name} failed: {e}"))
+}
+
+/// Helper: get the world and game time from Lua app_data or return Lua error.
+fn get_context(lua: &Lua) -> mlua::Result<(Rc<RefCell<World>>, i64)> {
+    lua.app_data_ref::<LevelContext>()
+        .map(|ctx| (ctx.world.clone(), ctx.game_time))
+        .ok_or_else(|| mlua::Error::runtime("world not yet initialized"))
+}
+
+/// Create a Lua VM, build the level environment and execute the script in it.
+fn create_vm(source: &str) -> Result<(Lua, Table), String> {
+    let lua = Lua::new();
+    let env = build_env(&lua).map_err(|e| format!("Failed to set up level environment: {e}"))?;
+
+    let chunk = lua
+        .load(source)
+        .set_name("level")
+        .set_environment(env.clone());
+    set_instruction_limit(&lua);
+    let result = chunk.exec();
+    lua.remove_hook();
+    result.map_err(|e| format!("Failed to load level script: {e}"))?;
+
+    Ok((lua, env))
+}
+
+/// Copy selected fields of a standard library table into a fresh table.
+fn copy_lib(lua: &Lua, lib: &str, fields: &[&str]) -> mlua::Result<Table> {
+    let src: Table = lua.globals().get(lib)?;
+    let dst = lua.create_table()?;
+    for field in fields {
+        dst.set(*field, src.get::<Value>(*field)?)?;
+    }
+    Ok(dst)
+}
+
+/// Build the restricted environment level scripts run in: a whitelist of Lua
+/// builtins, the world API and the tile constants.
+fn build_env(lua: &Lua) -> mlua::Result<Table> {
+    let g = lua.globals();
+    let env = lua.create_table()?;
+
+    // Seed math.random so random spawner placement differs between games
+    let math: Table = g.get("math")?;
+    math.get::<Function>("randomseed")?
+        .call::<()>(rand::random::<u32>())?;
+
+    // Lua builtins (same whitelist as the original server)
+    for name in ["pairs", "ipairs", "unpack"] {
+        env.set(name, g.get::<Value>(name)?)?;
+    }
+    env.set("math", copy_lib(lua, "math", &["random", "sqrt", "floor"])?)?;
+    env.set("string", copy_lib(lua, "string", &["upper", "sub", "len"])?)?;
+    env.set("table", copy_lib(lua, "table", &["getn"])?)?;
+
+    // print(...) -> logged, level output has no client to go to
+    env.set(
+        "print",
+        lua.create_function(|_lua, args: MultiValue| {
+            let parts: Vec<String> = args
+                .iter()
+                .map(|v| v.to_string().unwrap_or_else(|_| "?".into()))
+                .collect();
+            tracing::debug!("level: {}", parts.join("\t"));
+            Ok(())
+        })?,
+    )?;
+
+    // Constants
+    env.set("TILE_WIDTH", TILE_SIZE)?;
+    env.set("TILE_HEIGHT", TILE_SIZE)?;
+    env.set("TILE_SOLID", TILE_SOLID as i32)?;
+    env.set("TILE_PLAIN", TILE_PLAIN as i32)?;
+    env.set("TILE_WATER", TILE_WATER_COMPAT)?;
+    env.set("TILE_GFX_SOLID", TILE_GFX_SOLID as i32)?;
+    env.set("TILE_GFX_PLAIN", TILE_GFX_PLAIN as i32)?;
+    env.set("TILE_GFX_BORDER", TILE_GFX_BORDER as i32)?;
+    env.set("TILE_GFX_SNOW_SOLID", TILE_GFX_SNOW_SOLID as i32)?;
+    env.set("TILE_GFX_SNOW_PLAIN", TILE_GFX_SNOW_PLAIN as i32)?;
+    env.set("TILE_GFX_SNOW_BORDER", TILE_GFX_SNOW_BORDER as i32)?;
+    env.set("TILE_GFX_WATER", TILE_GFX_WATER as i32)?;
+    env.set("TILE_GFX_LAVA", TILE_GFX_LAVA as i32)?;
+    env.set("TILE_GFX_NONE", TILE_GFX_NONE as i32)?;
+    env.set("TILE_GFX_KOTH", TILE_GFX_KOTH as i32)?;
+    env.set("TILE_GFX_DESERT", TILE_GFX_DESERT as i32)?;
+
+    // world_set_type(x, y, type) -> bool
+    env.set(
+        "world_set_type",
+        lua.create_function(|lua, (x, y, tile_type): (i64, i64, i64)| {
+            let (world, _) = get_context(lua)?;
+            let mut world = world.borrow_mut();
+            Ok(match tile_coords(x, y) {
+                Some((x, y)) => world.set_type(x, y, tile_type as u8),
+                None => false,
+            })
+        })?,
+    )?;
+
+    // world_set_gfx(x, y, gfx) -> bool
+    env.set(
+        "world_set_gfx",
+        lua.create_function(|lua, (x, y, gfx): (i64, i64, i64)| {
+            let (world, _) = get_context(lua)?;
+            let mut world = world.borrow_mut();
+            if !(0..=TILE_GFX_DESERT as i64).contains(&gfx) {
+                return Ok(false);
+            }
+            Ok(match tile_coords(x, y) {
+                Some((x, y)) => world.set_gfx(x, y, gfx as u8),
+                None => false,
+            })
+        })?,
+    )?;
+
+    // world_get_type(x, y) -> number
+    env.set(
+        "world_get_type",
+        lua.create_function(|lua, (x, y): (i64, i64)| {
+            let (world, _) = get_context(lua)?;
+            let world = world.borrow();
+            match tile_coords(x, y) {
+                Some((tx, ty)) if world.is_on_map(tx, ty) => Ok(world.get_type(tx, ty) as i32),
+                _ => Err(mlua::Error::runtime(format!("{x},{y} is not on map"))),
+            }
+        })?,
+    )?;
+
+    // world_get_gfx(x, y) -> number
+    env.set(
+        "world_get_gfx",
+        lua.create_function(|lua, (x, y): (i64, i64)| {
+            let (world, _) = get_context(lua)?;
+            let world = world.borrow();
+            match tile_coords(x, y) {
+                Some((tx, ty)) if world.is_on_map(tx, ty) => Ok(world.get_gfx(tx, ty) as i32),
+                _ => Err(mlua::Error::runtime(format!("{x},{y} is not on map"))),
+            }
+        })?,
+    )?;
+
+    // world_is_walkable(x, y) -> bool
+    env.set(
+        "world_is_walkable",
+        lua.create_function(|lua, (x, y): (i64, i64)| {
+            let (world, _) = get_context(lua)?;
+            let world = world.borrow();
+            Ok(tile_coords(x, y).is_some_and(|(x, y)| world.is_walkable(x, y)))
+        })?,
+    )?;
+
+    // world_add_food(x, y, amount) -> number (actual change, 0 on non-plain tiles)
+    env.set(
+        "world_add_food",
+        lua.create_function(|lua, (x, y, amount): (i64, i64, i32)| {
+            let (world, _) = get_context(lua)?;
+            let mut world = world.borrow_mut();
+            Ok(match tile_coords(x, y) {
+                Some((x, y)) if world.is_walkable(x, y) => world.add_food(x, y, amount),
+                _ => 0,
+            })
+        })?,
+    )?;
+
+    // world_find_digged() -> x, y | nothing
+    env.set(
+        "world_find_digged",
+        lua.create_function(|lua, ()| {
+            let (world, _) = get_context(lua)?;
+            let world = world.borrow();
+            match world.find_plain_tile() {
+                Some((x, y)) => Ok(MultiValue::from_vec(vec![
+                    Value::Integer(x as i64),
+                    Value::Integer(y as i64),
+                ])),
+                None => Ok(MultiValue::new()),
+            }
+        })?,
+    )?;
+
+    // world_dig(x, y, type, gfx?) -> bool
+    // Compatibility helper from infond.lua: gfx defaults to type, the KOTH tile
+    // always gets TILE_GFX_KOTH, and TILE_WATER only changes the gfx.
+    env.set(
+        "world_dig",
+        lua.create_function(
+            |lua, (x, y, tile_type, gfx): (i64, i64, i64, Option<i64>)| {
+                let (world, _) = get_context(lua)?;
+                let mut world = world.borrow_mut();
+                let Some((tx, ty)) = tile_coords(x, y) else {
+                    return Ok(false);
+                };
+                if tile_type == TILE_WATER_COMPAT {
+                    world.set_gfx(tx, ty, TILE_GFX_WATER);
+                    return Ok(false);
+                }
+                let mut gfx = gfx.unwrap_or(tile_type);
+                if (tx, ty) == world.koth_pos() {
+                    gfx = TILE_GFX_KOTH as i64;
+                }
+                Ok(world.set_type(tx, ty, tile_type as u8) && world.set_gfx(tx, ty, gfx as u8))
+            },
+        )?,
+    )?;
+
+    // world_fill_all(gfx)
+    env.set(
+        "world_fill_all",
+        lua.create_function(|lua, gfx: u8| {
+            let (world, _) = get_context(lua)?;
+            let mut world = world.borrow_mut();
+            for y in 0..world.height {
+                for x in 0..world.width {
+                    world.set_gfx(x, y, gfx);
+                }
+            }
+            Ok(())
+        })?,
+    )?;
+
+    // world_make_border(gfx)
+    env.set(
+        "world_make_border",
+        lua.create_function(|lua, gfx: u8| {
+            let (world, _) = get_context(lua)?;
+            let mut world = world.borrow_mut();
+            let (w, h) = (world.width, world.height);
+            for x in 0..w {
+                world.set_gfx(x, 0, gfx);
+                world.set_gfx(x, h - 1, gfx);
+            }
+            for y in 0..h {
+                world.set_gfx(0, y, gfx);
+                world.set_gfx(w - 1, y, gfx);
+            }
+            Ok(())
+        })?,
+    )?;
+
+    // world_tile_center(x, y) -> px, py
+    env.set(
+        "world_tile_center",
+        lua.create_function(|_lua, (x, y): (usize, usize)| {
+            Ok((World::tile_center(x), World::tile_center(y)))
+        })?,
+    )?;
+
+    // game_time() -> number (milliseconds)
+    env.set(
+        "game_time",
+        lua.create_function(|lua, ()| {
+            let (_, game_time) = get_context(lua)?;
+            Ok(game_time)
+        })?,
+    )?;
+
+    Ok(env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_LEVEL: &str = r#"
+function level_size()
+    return 6, 5
+end
+
+function level_koth_pos()
+    return 2, 2
+end
+
+function level_init()
+    for x = 1, 4 do
+        for y = 1, 3 do
+            world_dig(x, y, TILE_PLAIN, TILE_GFX_PLAIN)
+        end
+    end
+    world_dig(4, 3, TILE_WATER)
+    world_make_border(TILE_GFX_BORDER)
+    world_add_food(1, 1, 500)
+    last_food = game_time()
+end
+
+function level_tick()
+    if game_time() >= last_food + 1000 then
+        world_add_food(3, 1, 100)
+        last_food = game_time()
+    end
+end
+"#;
+
+    #[test]
+    fn test_load_small_level() {
+        let world = load_level(SMALL_LEVEL).unwrap();
+        assert_eq!((world.width, world.height), (6, 5));
+        assert_eq!(world.koth_pos(), (2, 2));
+        assert!(world.is_walkable(1, 1));
+        assert!(world.is_walkable(3, 2));
+        assert_eq!(world.get_gfx(2, 2), TILE_GFX_KOTH);
+        assert_eq!(world.get_gfx(0, 0), TILE_GFX_BORDER);
+        assert_eq!(world.get_food(1, 1), 500);
+        assert!(world.level_source.is_some());
+    }
+
+    #[test]
+    fn test_world_dig_water_compat_only_sets_gfx() {
+        let world = load_level(SMALL_LEVEL).unwrap();
+        // (4,3) was dug plain first, then marked as water: gfx changes, type stays
+        assert_eq!(world.get_gfx(4, 3), TILE_GFX_WATER);
+        assert!(world.is_walkable(4, 3));
+        // Border stays solid
+        assert!(!world.is_walkable(0, 0));
+    }
+
+    #[test]
+    fn test_level_tick_adds_food() {
+        let world = load_level(SMALL_LEVEL).unwrap();
+        let script = LevelScript::new(world.level_source.as_deref().unwrap(), &world).unwrap();
+        let world = Rc::new(RefCell::new(world));
+
+        script.tick(&world, 500).unwrap();
+        assert_eq!(world.borrow().get_food(3, 1), 0);
+        script.tick(&world, 1000).unwrap();
+        assert_eq!(world.borrow().get_food(3, 1), 100);
+        // Replaying level_init for the script must not touch the game world's food
+        assert_eq!(world.borrow().get_food(1, 1), 500);
+    }
+
+    #[test]
+    fn test_level_requires_size() {
+        let err = load_level("function level_init() end").err().unwrap();
+        assert!(err.contains("level_size"));
+    }
+
+    #[test]
+    fn test_level_size_out_of_range() {
+        assert!(load_level("function level_size() return 1000, 10 end").is_err());
+        assert!(load_level("function level_size() return 2, 10 end").is_err());
+    }
+
+    #[test]
+    fn test_level_environment_is_restricted() {
+        let src = r#"
+function level_size() return 5, 5 end
+function level_init()
+    if os ~= nil or io ~= nil or require ~= nil then world_add_food(nil) end
+end
+"#;
+        assert!(load_level(src).is_ok());
+    }
+
+    #[test]
+    fn test_level_tick_infinite_loop_is_stopped() {
+        let src = r#"
+function level_size() return 5, 5 end
+function level_tick() while true do end end
+"#;
+        let world = load_level(src).unwrap();
+        let script = LevelScript::new(src, &world).unwrap();
+        let world = Rc::new(RefCell::new(world));
+        let err = script.tick(&world, 100).unwrap_err();
+        assert!(err.contains("cycles exceeded"));
+    }
+
+    #[test]
+    fn test_original_levels_load() {
+        let levels: [(&str, &[u8]); 9] = [
+            (
+                "castle",
+                include_bytes!("../../../orig_game/level/castle.lua"),
+            ),
+            ("cn", include_bytes!("../../../orig_game/level/cn.lua")),
+            ("foo", include_bytes!("../../../orig_game/level/foo.lua")),
+            ("gpn", include_bytes!("../../../orig_game/level/gpn.lua")),
+            (
+                "infon",
+                include_bytes!("../../../orig_game/level/infon.lua"),
+            ),
+            ("owl", include_bytes!("../../../orig_game/level/owl.lua")),
+            (
+                "pacman",
+                include_bytes!("../../../orig_game/level/pacman.lua"),
+            ),
+            (
+                "stripeslice",
+                include_bytes!("../../../orig_game/level/stripeslice.lua"),
+            ),
+            (
+                "water",
+                include_bytes!("../../../orig_game/level/water.lua"),
+            ),
+        ];
+        for (name, bytes) in levels {
+            let source = String::from_utf8_lossy(bytes);
+            let world = load_level(&source).unwrap_or_else(|e| panic!("{name}: {e}"));
+            assert!(
+                world.find_plain_tile().is_some(),
+                "{name} has no walkable tiles"
+            );
+            LevelScript::new(&source, &world).unwrap_or_else(|e| panic!("{name}: {e}"));
+        }
+    }
+
+    #[test]
+    fn test_pacman_level_tick_drops_food() {
+        let source = include_str!("../../../orig_game/level/pacman.lua");
+        let world = load_level(source).unwrap();
+        assert_eq!(world.koth_pos(), (20, 21));
+        let script = LevelScript::new(source, &world).unwrap();
+        let world = Rc::new(RefCell::new(world));
+
+        // "." tiles get 100 food once game_time passes last_food + 10000
+        assert_eq!(world.borrow().get_food(3, 2), 0);
+        script.tick(&world, 10_100).unwrap();
+        assert_eq!(world.borrow().get_food(3, 2), 100);
+    }
+}
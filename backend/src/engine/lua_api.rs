@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -22,6 +22,9 @@ pub struct LuaGameState {
     pub print_output: Rc<RefCell<Vec<String>>>,
     /// Optional spatial index for fast nearest-enemy queries.
     pub spatial_grid: Option<Rc<RefCell<SpatialGrid>>>,
+    /// Instruction budget used during this think, by VM instructions and
+    /// query API calls alike.
+    pub instructions: Cell<u32>,
 }
 
 /// Register all Lua constants into the VM.
@@ -76,6 +79,31 @@ pub fn register_constants(lua: &Lua, player_id: u32) -> LuaResult<()> {
     Ok(())
 }
 
+/// Globals added after API level 1, with the level that introduced them.
+/// Bots written against an older level don't see them.
+const API_ADDITIONS: &[(&str, u32)] = &[
+    ("get_tile_gfx", 2),
+    ("get_terrain", 2),
+    ("get_creatures_in_range", 2),
+    ("get_my_creatures", 2),
+    ("get_nearest_food", 2),
+    ("get_food_in_range", 2),
+    ("get_tiles_in_rect", 2),
+    ("get_path_length", 2),
+];
+
+/// Remove the functions and constants introduced after API `level`.
+/// Call after `register_constants` and `register_functions`.
+pub fn restrict_to_level(lua: &Lua, level: u32) -> LuaResult<()> {
+    let g = lua.globals();
+    for &(name, added) in API_ADDITIONS {
+        if added > level {
+            g.set(name, Value::Nil)?;
+        }
+    }
+    Ok(())
+}
+
 /// Helper: get LuaGameState from Lua app_data or return Lua error.
 fn get_game_state(lua: &Lua) -> LuaResult<Rc<RefCell<LuaGameState>>> {
     lua.app_data_ref::<Rc<RefCell<LuaGameState>>>()
@@ -100,6 +128,44 @@ fn check_ownership(
     }
 }
 
+/// Charge `cost` against the tick's instruction budget. The VM hook counts a
+/// native call as a single instruction, so query functions account for the
+/// work they do here.
+fn charge(gs: &LuaGameState, cost: u32) -> LuaResult<()> {
+    let used = gs.instructions.get().saturating_add(cost);
+    gs.instructions.set(used);
+    if used > LUA_MAX_INSTRUCTIONS {
+        return Err(mlua::Error::runtime("lua vm cycles exceeded"));
+    }
+    Ok(())
+}
+
+/// Charge VM instructions counted by a hook. Outside the think phase there is
+/// no budget to charge.
+pub fn charge_instructions(lua: &Lua, count: u32) -> LuaResult<()> {
+    match lua.app_data_ref::<Rc<RefCell<LuaGameState>>>() {
+        Some(gs) => charge(&gs.borrow(), count),
+        None => Ok(()),
+    }
+}
+
+/// Install the main thread's instruction hook. Coroutines get theirs from the
+/// bootstrap's `coroutine.resume` wrapper; both charge the same budget.
+pub fn set_instruction_limit(lua: &Lua) {
+    lua.set_hook(
+        mlua::HookTriggers::new().every_nth_instruction(LUA_HOOK_INTERVAL),
+        |lua, _debug| {
+            charge_instructions(lua, LUA_HOOK_INTERVAL)?;
+            Ok(mlua::VmState::Continue)
+        },
+    );
+}
+
+/// Cost of a query call that examined `items` creatures, tiles or waypoints.
+fn query_cost(items: usize) -> u32 {
+    LUA_QUERY_CALL_COST.saturating_add(LUA_QUERY_ITEM_COST.saturating_mul(items as u32))
+}
+
 /// Register all bot-facing API functions into the Lua VM.
 pub fn register_functions(lua: &Lua, _player_id: u32) -> LuaResult<()> {
     let g = lua.globals();
@@ -116,7 +182,8 @@ pub fn register_functions(lua: &Lua, _player_id: u32) -> LuaResult<()> {
             check_ownership(&creatures, creature_id, player_id)?;
 
             let creature = creatures.get(&creature_id).unwrap();
-            let path = world.find_path(creature.x, creature.y, x, y);
+            let path =
+                world.find_path_for(creature.is_ground_based(), creature.x, creature.y, x, y);
             match path {
                 Some(waypoints) => {
                     let creature = creatures.get_mut(&creature_id).unwrap();
@@ -299,6 +366,44 @@ pub fn register_functions(lua: &Lua, _player_id: u32) -> LuaResult<()> {
         })?,
     )?;
 
+    // get_tile_gfx(creature_id) -> number (TILE_GFX_*, which decides terrain effects)
+    g.set(
+        "get_tile_gfx",
+        lua.create_function(|lua, creature_id: u32| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let creatures = gs.creatures.borrow();
+            let creature = creatures
+                .get(&creature_id)
+                .ok_or_else(|| mlua::Error::runtime(format!("Creature {creature_id} not found")))?;
+            let world = gs.world.borrow();
+            Ok(world.get_gfx(creature.tile_x(), creature.tile_y()) as i32)
+        })?,
+    )?;
+
+    // get_terrain(x, y) -> speed_percent, damage_per_sec, food_growth (pixel coords)
+    g.set(
+        "get_terrain",
+        lua.create_function(|lua, (x, y): (i32, i32)| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let world = gs.world.borrow();
+            if x < 0 || y < 0 {
+                return Ok(MultiValue::new());
+            }
+            let (tx, ty) = (World::pixel_to_tile(x), World::pixel_to_tile(y));
+            if !world.is_on_map(tx, ty) {
+                return Ok(MultiValue::new());
+            }
+            let terrain = world.terrain(tx, ty);
+            Ok(MultiValue::from_vec(vec![
+                Value::Integer(terrain.speed as i64),
+                Value::Integer(terrain.damage as i64),
+                Value::Boolean(terrain.food_growth),
+            ]))
+        })?,
+    )?;
+
     // get_max_food(creature_id) -> number
     g.set(
         "get_max_food",
@@ -381,6 +486,204 @@ pub fn register_functions(lua: &Lua, _player_id: u32) -> LuaResult<()> {
         })?,
     )?;
 
+    // get_creatures_in_range(creature_id, radius, filter) -> array of
+    // {id, x, y, player, type, distance}, nearest first. filter is "all"
+    // (default), "own" or "enemy".
+    g.set(
+        "get_creatures_in_range",
+        lua.create_function(
+            |lua, (creature_id, radius, filter): (u32, i32, Option<String>)| {
+                let gs_rc = get_game_state(lua)?;
+                let gs = gs_rc.borrow();
+                let player_id = gs.player_id;
+                let creatures = gs.creatures.borrow();
+                check_ownership(&creatures, creature_id, player_id)?;
+                let filter = filter.as_deref().unwrap_or("all");
+                if !matches!(filter, "all" | "own" | "enemy") {
+                    return Err(mlua::Error::runtime(format!(
+                        "Unknown filter '{filter}' (use 'all', 'own' or 'enemy')"
+                    )));
+                }
+                let me = creatures.get(&creature_id).unwrap();
+                let (x, y) = (me.x, me.y);
+                let radius = radius.clamp(0, LUA_MAX_QUERY_RADIUS);
+
+                let candidates: Vec<u32> = match gs.spatial_grid {
+                    Some(ref grid_rc) => grid_rc
+                        .borrow()
+                        .query_radius(x, y, radius)
+                        .iter()
+                        .map(|e| e.id)
+                        .collect(),
+                    None => creatures.keys().copied().collect(),
+                };
+                charge(&gs, query_cost(candidates.len()))?;
+
+                let mut found: Vec<(i32, &Creature)> = candidates
+                    .iter()
+                    .filter(|&&id| id != creature_id)
+                    .filter_map(|id| creatures.get(id))
+                    .filter(|c| match filter {
+                        "own" => c.player_id == player_id,
+                        "enemy" => c.player_id != player_id,
+                        _ => true,
+                    })
+                    .map(|c| (me.distance_to(c.x, c.y), c))
+                    .filter(|&(dist, _)| dist <= radius)
+                    .collect();
+                found.sort_by_key(|&(dist, c)| (dist, c.id));
+
+                let result = lua.create_table()?;
+                for (i, (dist, c)) in found.into_iter().enumerate() {
+                    let entry = lua.create_table()?;
+                    entry.set("id", c.id)?;
+                    entry.set("x", c.x)?;
+                    entry.set("y", c.y)?;
+                    entry.set("player", c.player_id)?;
+                    entry.set("type", c.creature_type as i32)?;
+                    entry.set("distance", dist)?;
+                    result.set(i + 1, entry)?;
+                }
+                Ok(result)
+            },
+        )?,
+    )?;
+
+    // get_my_creatures() -> array of own creature ids, ascending
+    g.set(
+        "get_my_creatures",
+        lua.create_function(|lua, ()| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let player_id = gs.player_id;
+            let creatures = gs.creatures.borrow();
+            let mut ids: Vec<u32> = creatures
+                .values()
+                .filter(|c| c.player_id == player_id)
+                .map(|c| c.id)
+                .collect();
+            ids.sort_unstable();
+            charge(&gs, query_cost(ids.len()))?;
+            lua.create_sequence_from(ids)
+        })?,
+    )?;
+
+    // get_nearest_food(creature_id, min_amount) -> x, y, amount (tile center, or nil)
+    g.set(
+        "get_nearest_food",
+        lua.create_function(|lua, (creature_id, min_amount): (u32, Option<i32>)| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let creatures = gs.creatures.borrow();
+            check_ownership(&creatures, creature_id, gs.player_id)?;
+            let me = creatures.get(&creature_id).unwrap();
+            let world = gs.world.borrow();
+            let found = world.nearest_food(me.x, me.y, min_amount.unwrap_or(1));
+            charge(&gs, query_cost(found.visited))?;
+
+            match found.tile {
+                Some(t) => Ok(MultiValue::from_vec(vec![
+                    Value::Integer(World::tile_center(t.x) as i64),
+                    Value::Integer(World::tile_center(t.y) as i64),
+                    Value::Integer(t.food as i64),
+                ])),
+                None => Ok(MultiValue::new()),
+            }
+        })?,
+    )?;
+
+    // get_food_in_range(creature_id, radius) -> total food on tiles whose
+    // center is within radius pixels
+    g.set(
+        "get_food_in_range",
+        lua.create_function(|lua, (creature_id, radius): (u32, i32)| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let creatures = gs.creatures.borrow();
+            check_ownership(&creatures, creature_id, gs.player_id)?;
+            let me = creatures.get(&creature_id).unwrap();
+            let radius = radius.clamp(0, LUA_MAX_QUERY_RADIUS);
+            let found = gs.world.borrow().food_in_radius(me.x, me.y, radius);
+            charge(&gs, query_cost(found.visited))?;
+            Ok(found.total)
+        })?,
+    )?;
+
+    // get_tiles_in_rect(x1, y1, x2, y2) -> array of {x, y, type, food}
+    // (pixel coords; tiles listed row by row by their centers)
+    g.set(
+        "get_tiles_in_rect",
+        lua.create_function(|lua, (x1, y1, x2, y2): (i32, i32, i32, i32)| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let world = gs.world.borrow();
+            let to_tile =
+                |p: i32, size: usize| (p.max(0) / TILE_SIZE).min(size as i32 - 1) as usize;
+            let (tx1, tx2) = (
+                to_tile(x1.min(x2), world.width),
+                to_tile(x1.max(x2), world.width),
+            );
+            let (ty1, ty2) = (
+                to_tile(y1.min(y2), world.height),
+                to_tile(y1.max(y2), world.height),
+            );
+            let count = (tx2 - tx1 + 1) * (ty2 - ty1 + 1);
+            if count > LUA_MAX_QUERY_TILES {
+                return Err(mlua::Error::runtime(format!(
+                    "Rect covers {count} tiles (max {LUA_MAX_QUERY_TILES})"
+                )));
+            }
+            charge(&gs, query_cost(count))?;
+
+            let result = lua.create_table()?;
+            let mut i = 0;
+            for ty in ty1..=ty2 {
+                for tx in tx1..=tx2 {
+                    let entry = lua.create_table()?;
+                    entry.set("x", World::tile_center(tx))?;
+                    entry.set("y", World::tile_center(ty))?;
+                    entry.set("type", world.get_type(tx, ty) as i32)?;
+                    entry.set("food", world.get_food(tx, ty))?;
+                    i += 1;
+                    result.set(i, entry)?;
+                }
+            }
+            Ok(result)
+        })?,
+    )?;
+
+    // get_path_length(creature_id, x, y) -> pixels (or nil), without changing the path
+    g.set(
+        "get_path_length",
+        lua.create_function(|lua, (creature_id, x, y): (u32, i32, i32)| {
+            let gs_rc = get_game_state(lua)?;
+            let gs = gs_rc.borrow();
+            let creatures = gs.creatures.borrow();
+            check_ownership(&creatures, creature_id, gs.player_id)?;
+            let me = creatures.get(&creature_id).unwrap();
+            let path = gs
+                .world
+                .borrow()
+                .find_path_for(me.is_ground_based(), me.x, me.y, x, y);
+            let waypoints = path.as_ref().map_or(0, Vec::len);
+            charge(
+                &gs,
+                LUA_PATH_QUERY_COST.saturating_add(query_cost(waypoints)),
+            )?;
+
+            Ok(path.map(|path| {
+                let mut pos = (me.x as i64, me.y as i64);
+                let mut length = 0.0;
+                for (wx, wy) in path {
+                    let (dx, dy) = (wx as i64 - pos.0, wy as i64 - pos.1);
+                    length += ((dx * dx + dy * dy) as f64).sqrt();
+                    pos = (wx as i64, wy as i64);
+                }
+                length as i32
+            }))
+        })?,
+    )?;
+
     // set_message(creature_id, msg)
     g.set(
         "set_message",
@@ -578,6 +881,7 @@ pub fn clear_game_state(lua: &Lua) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::world::TerrainRules;
 
     /// Helper: create a test Lua VM with API registered and game state set
     fn setup_test_lua() -> (Lua, Rc<RefCell<LuaGameState>>) {
@@ -614,6 +918,7 @@ mod tests {
             king_player_id: None,
             print_output: Rc::new(RefCell::new(Vec::new())),
             spatial_grid: None,
+            instructions: Cell::new(0),
         }));
 
         set_game_state(&lua, gs.clone());
@@ -684,6 +989,10 @@ mod tests {
         let tt: i32 = lua.load("return get_tile_type(100)").eval().unwrap();
         assert_eq!(tt, TILE_PLAIN as i32);
 
+        // get_tile_gfx
+        let gfx: i32 = lua.load("return get_tile_gfx(100)").eval().unwrap();
+        assert_eq!(gfx, TILE_GFX_PLAIN as i32);
+
         // creature_exists
         let exists: bool = lua.load("return creature_exists(100)").eval().unwrap();
         assert!(exists);
@@ -720,6 +1029,25 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn test_lua_get_terrain() {
+        let (lua, state) = setup_test_lua();
+        state
+            .borrow()
+            .world
+            .borrow_mut()
+            .set_gfx(3, 2, TILE_GFX_WATER);
+
+        let (speed, damage, growth): (i32, i32, bool) =
+            lua.load("return get_terrain(512, 512)").eval().unwrap();
+        assert_eq!((speed, damage, growth), (100, 0, true));
+        let speed: i32 = lua.load("return get_terrain(3 * 256, 512)").eval().unwrap();
+        assert_eq!(speed, TerrainRules::default().get(TILE_GFX_WATER).speed);
+
+        let off_map: Value = lua.load("return get_terrain(-1, 512)").eval().unwrap();
+        assert!(off_map.is_nil());
+    }
+
     #[test]
     fn test_lua_print() {
         let (lua, gs) = setup_test_lua();
@@ -770,4 +1098,137 @@ mod tests {
         assert_eq!(x, 768);
         assert_eq!(y, 768);
     }
+
+    #[test]
+    fn test_lua_area_queries() {
+        let (lua, gs) = setup_test_lua();
+        {
+            let gs_inner = gs.borrow();
+            let mut creatures = gs_inner.creatures.borrow_mut();
+            creatures.insert(101, Creature::new(101, 1024, 512, CREATURE_FLYER, 1)); // dist 512
+            creatures.insert(200, Creature::new(200, 768, 512, CREATURE_BIG, 2)); // dist 256
+            creatures.insert(201, Creature::new(201, 2048, 1536, CREATURE_BIG, 2));
+            // far
+        }
+
+        let ids: Vec<u32> = lua
+            .load("local t = {} for i, c in ipairs(get_creatures_in_range(100, 600)) do t[i] = c.id end return t")
+            .eval()
+            .unwrap();
+        assert_eq!(ids, vec![200, 101]);
+        let enemy: (u32, i32, i32) = lua
+            .load("local c = get_creatures_in_range(100, 600, 'enemy')[1] return c.id, c.type, c.distance")
+            .eval()
+            .unwrap();
+        assert_eq!(enemy, (200, CREATURE_BIG as i32, 256));
+        let own: usize = lua
+            .load("return #get_creatures_in_range(100, 600, 'own')")
+            .eval()
+            .unwrap();
+        assert_eq!(own, 1);
+        assert!(lua
+            .load("return get_creatures_in_range(100, 600, 'bogus')")
+            .exec()
+            .is_err());
+        // Only around own creatures
+        assert!(lua
+            .load("return get_creatures_in_range(200, 600)")
+            .exec()
+            .is_err());
+
+        let mine: Vec<u32> = lua.load("return get_my_creatures()").eval().unwrap();
+        assert_eq!(mine, vec![100, 101]);
+    }
+
+    #[test]
+    fn test_lua_food_and_tile_queries() {
+        let (lua, gs) = setup_test_lua();
+        gs.borrow().world.borrow_mut().add_food(6, 2, 3000);
+
+        // Nearest food is the creature's own tile; a higher minimum skips it
+        let (x, y, amount): (i32, i32, i32) =
+            lua.load("return get_nearest_food(100)").eval().unwrap();
+        assert_eq!(
+            (x, y, amount),
+            (World::tile_center(2), World::tile_center(2), 500)
+        );
+        let (x, amount): (i32, i32) = lua
+            .load("local x, y, a = get_nearest_food(100, 1000) return x, a")
+            .eval()
+            .unwrap();
+        assert_eq!((x, amount), (World::tile_center(6), 3000));
+        let none: Value = lua
+            .load("return get_nearest_food(100, 5000)")
+            .eval()
+            .unwrap();
+        assert!(none.is_nil());
+
+        // Food totals by radius from (512, 512)
+        let (near, far): (i64, i64) = lua
+            .load("return get_food_in_range(100, 256), get_food_in_range(100, 2000)")
+            .eval()
+            .unwrap();
+        assert_eq!((near, far), (500, 3500));
+
+        let (count, food, tile_type): (usize, i32, i32) = lua
+            .load("local t = get_tiles_in_rect(256, 256, 767, 767) return #t, t[4].food, t[1].type")
+            .eval()
+            .unwrap();
+        assert_eq!((count, food, tile_type), (4, 500, TILE_PLAIN as i32));
+        assert!(lua
+            .load("return get_tiles_in_rect(0, 0, 1000000, 1000000)")
+            .exec()
+            .is_ok());
+
+        // Path length without committing the path
+        let len: i32 = lua
+            .load("return get_path_length(100, 6 * 256 + 128, 512)")
+            .eval()
+            .unwrap();
+        // (512, 512) is a tile corner: 404 px to the first tile center, then 3 tiles
+        assert_eq!(len, 404 + 3 * TILE_SIZE);
+        let creatures = gs.borrow().creatures.clone();
+        assert!(creatures.borrow().get(&100).unwrap().path.is_empty());
+        let blocked: Value = lua
+            .load("return get_path_length(100, 0, 0)")
+            .eval()
+            .unwrap();
+        assert!(blocked.is_nil());
+    }
+
+    #[test]
+    fn test_lua_query_cost_is_charged() {
+        let (lua, gs) = setup_test_lua();
+        lua.load("get_my_creatures()").exec().unwrap();
+        assert_eq!(gs.borrow().instructions.get(), query_cost(1));
+
+        // Exhausting the budget raises the same error as the VM hook
+        gs.borrow().instructions.set(LUA_MAX_INSTRUCTIONS);
+        let err = lua.load("get_my_creatures()").exec().unwrap_err();
+        assert!(err.to_string().contains("cycles exceeded"));
+
+        // Food sums pay per bucket and tile looked at
+        gs.borrow().instructions.set(0);
+        lua.load("get_food_in_range(100, 256)").exec().unwrap();
+        let visited = gs.borrow().world.borrow().food_in_radius(512, 512, 256).visited;
+        assert!(visited > 0);
+        assert_eq!(gs.borrow().instructions.get(), query_cost(visited));
+    }
+
+    #[test]
+    fn test_vm_instructions_share_the_query_budget() {
+        let (lua, gs) = setup_test_lua();
+        set_instruction_limit(&lua);
+        lua.load("for i = 1, 10000 do local x = i end").exec().unwrap();
+        let used = gs.borrow().instructions.get();
+        assert!(used >= 10000, "charged {used}");
+
+        // Queries eat into what the VM may still run
+        gs.borrow().instructions.set(LUA_MAX_INSTRUCTIONS - 2 * LUA_HOOK_INTERVAL);
+        let err = lua
+            .load("get_my_creatures() for i = 1, 10000 do local x = i end")
+            .exec()
+            .unwrap_err();
+        assert!(err.to_string().contains("cycles exceeded"));
+    }
 }
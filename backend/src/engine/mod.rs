@@ -1,8 +1,13 @@
+pub mod analysis;
 pub mod config;
 pub mod creature;
+pub mod food_index;
 pub mod game;
+pub mod level;
 pub mod lua_api;
+pub mod pathfinding;
 pub mod player;
 pub mod server;
 pub mod spatial;
+pub mod symmetry;
 pub mod world;
@@ -0,0 +1,802 @@
+// Ground pathfinding behind `World::find_path`.
+//
+// The base search is A* over walkable tiles weighted by terrain cost. Maps can
+// turn on extra features through metadata (see `PathConfig::from_metadata`):
+// 8-directional movement without corner cutting, string-pulling smoothing, and
+// hierarchical search (HPA*) over fixed-size clusters for large maps. Results
+// are kept in a small LRU cache keyed on (start, goal) tiles; the world clears
+// it, together with the cluster graph, whenever a tile changes.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+use super::config::*;
+use super::world::World;
+
+/// Optional pathfinding features for a map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PathConfig {
+    /// Allow diagonal steps (never between two solid corners).
+    pub diagonal: bool,
+    /// Drop waypoints that a straight line can skip (string pulling).
+    pub smooth: bool,
+    /// Search a graph of cluster entrances first, then refine (HPA*).
+    pub hierarchical: bool,
+}
+
+impl PathConfig {
+    /// Read the `path_diagonal`, `path_smooth` and `path_hierarchical` map
+    /// metadata flags ("true" turns a feature on).
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Self {
+        let flag = |key: &str| metadata.get(key).is_some_and(|v| v == "true");
+        PathConfig {
+            diagonal: flag("path_diagonal"),
+            smooth: flag("path_smooth"),
+            hierarchical: flag("path_hierarchical"),
+        }
+    }
+}
+
+/// Cost multiplier for diagonal steps, in tenths.
+const DIAGONAL_COST: i32 = 14;
+
+/// Border runs at least this long get an entrance at each end instead of one
+/// in the middle.
+const HPA_LONG_ENTRANCE: usize = 6;
+
+// right, left, down, up, then the diagonals
+const DIRS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct AStarNode {
+    cost: i32,
+    idx: usize,
+}
+
+impl Ord for AStarNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost) // min-heap via reversed ordering
+    }
+}
+
+impl PartialOrd for AStarNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Tile rectangle `[x0, x1) x [y0, y1)` a search may not leave.
+#[derive(Clone, Copy)]
+struct Rect {
+    x0: usize,
+    y0: usize,
+    x1: usize,
+    y1: usize,
+}
+
+impl Rect {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x0 as i32 && y >= self.y0 as i32 && x < self.x1 as i32 && y < self.y1 as i32
+    }
+}
+
+/// A* bookkeeping reused between searches. Entries are only valid when their
+/// stamp matches the current search, so nothing is cleared per call.
+#[derive(Clone, Default)]
+struct Scratch {
+    g: Vec<i32>,
+    came_from: Vec<usize>,
+    seen: Vec<u32>,
+    closed: Vec<u32>,
+    stamp: u32,
+}
+
+impl Scratch {
+    fn begin(&mut self, size: usize) {
+        if self.g.len() != size || self.stamp == u32::MAX {
+            self.g = vec![0; size];
+            self.came_from = vec![usize::MAX; size];
+            self.seen = vec![0; size];
+            self.closed = vec![0; size];
+            self.stamp = 0;
+        }
+        self.stamp += 1;
+    }
+}
+
+/// A cached search result and when it was last used.
+#[derive(Clone)]
+struct CacheEntry {
+    path: Option<Vec<usize>>,
+    used: u64,
+}
+
+/// LRU cache of search results keyed on (start, goal) tile indices.
+#[derive(Clone, Default)]
+struct PathCache {
+    entries: HashMap<(usize, usize), CacheEntry>,
+    clock: u64,
+}
+
+impl PathCache {
+    fn get(&mut self, key: (usize, usize)) -> Option<Option<Vec<usize>>> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(&key).map(|entry| {
+            entry.used = clock;
+            entry.path.clone()
+        })
+    }
+
+    fn insert(&mut self, key: (usize, usize), path: Option<Vec<usize>>) {
+        if self.entries.len() >= PATH_CACHE_SIZE && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(k, _)| *k);
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.clock += 1;
+        let used = self.clock;
+        self.entries.insert(key, CacheEntry { path, used });
+    }
+}
+
+/// Abstract graph for HPA*: entrance tiles on cluster borders, linked by
+/// single steps across borders and by precomputed costs inside clusters.
+#[derive(Clone)]
+struct HpaGraph {
+    clusters_x: usize,
+    /// Tile index of each node.
+    nodes: Vec<usize>,
+    node_at: HashMap<usize, usize>,
+    /// Node ids in each cluster.
+    cluster_nodes: Vec<Vec<usize>>,
+    /// Outgoing (node, cost) edges per node.
+    edges: Vec<Vec<(usize, i32)>>,
+}
+
+impl HpaGraph {
+    fn cluster_of(&self, idx: usize, width: usize) -> usize {
+        let (x, y) = (idx % width, idx / width);
+        (y / HPA_CLUSTER_SIZE) * self.clusters_x + x / HPA_CLUSTER_SIZE
+    }
+
+    fn add_node(&mut self, idx: usize, width: usize) -> usize {
+        if let Some(&id) = self.node_at.get(&idx) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(idx);
+        self.node_at.insert(idx, id);
+        self.edges.push(Vec::new());
+        let cluster = self.cluster_of(idx, width);
+        self.cluster_nodes[cluster].push(id);
+        id
+    }
+}
+
+/// Ground pathfinder state owned by a `World`.
+#[derive(Clone, Default)]
+pub struct Pathfinder {
+    config: PathConfig,
+    cache: PathCache,
+    scratch: Scratch,
+    hpa: Option<HpaGraph>,
+}
+
+impl Pathfinder {
+    pub fn config(&self) -> PathConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: PathConfig) {
+        self.config = config;
+        self.invalidate();
+    }
+
+    /// Forget cached paths and the cluster graph after the map changed.
+    pub fn invalidate(&mut self) {
+        self.cache = PathCache::default();
+        self.hpa = None;
+    }
+
+    /// Tiles (as indices) from `start` to `goal`, excluding `start`. Both
+    /// tiles must be walkable.
+    pub fn find(&mut self, world: &World, start: usize, goal: usize) -> Option<Vec<usize>> {
+        if let Some(path) = self.cache.get((start, goal)) {
+            return path;
+        }
+        let path = if self.config.hierarchical {
+            self.find_hierarchical(world, start, goal)
+        } else {
+            let all = Rect {
+                x0: 0,
+                y0: 0,
+                x1: world.width,
+                y1: world.height,
+            };
+            self.astar(world, start, goal, all).map(|(path, _)| path)
+        };
+        let path = match path {
+            Some(path) if self.config.smooth => Some(smooth(world, start, &path)),
+            other => other,
+        };
+        self.cache.insert((start, goal), path.clone());
+        path
+    }
+
+    /// A* from `start` to `goal` inside `bounds`. Returns the tiles after
+    /// `start` and the total cost.
+    fn astar(
+        &mut self,
+        world: &World,
+        start: usize,
+        goal: usize,
+        bounds: Rect,
+    ) -> Option<(Vec<usize>, i32)> {
+        let w = world.width;
+        let diagonal = self.config.diagonal;
+        let s = &mut self.scratch;
+        s.begin(w * world.height);
+        let stamp = s.stamp;
+
+        let (gx, gy) = ((goal % w) as i32, (goal / w) as i32);
+        let heuristic = |idx: usize| -> i32 {
+            let dx = ((idx % w) as i32 - gx).abs();
+            let dy = ((idx / w) as i32 - gy).abs();
+            let plain = MIN_PATH_COST;
+            if diagonal {
+                // Octile distance
+                plain * (dx + dy) + (plain * DIAGONAL_COST / 10 - 2 * plain) * dx.min(dy)
+            } else {
+                plain * (dx + dy) // Manhattan distance
+            }
+        };
+
+        s.g[start] = 0;
+        s.seen[start] = stamp;
+        let mut open = BinaryHeap::new();
+        open.push(AStarNode {
+            cost: heuristic(start),
+            idx: start,
+        });
+        let dirs = if diagonal { &DIRS[..] } else { &DIRS[..4] };
+
+        while let Some(AStarNode { idx, .. }) = open.pop() {
+            if idx == goal {
+                let mut path = Vec::new();
+                let mut i = goal;
+                while i != start {
+                    path.push(i);
+                    i = s.came_from[i];
+                }
+                path.reverse();
+                return Some((path, s.g[goal]));
+            }
+            if s.closed[idx] == stamp {
+                continue;
+            }
+            s.closed[idx] = stamp;
+
+            let (x, y) = ((idx % w) as i32, (idx / w) as i32);
+            for &(dx, dy) in dirs {
+                let (nx, ny) = (x + dx, y + dy);
+                if !bounds.contains(nx, ny) || !world.is_walkable(nx as usize, ny as usize) {
+                    continue;
+                }
+                let is_diagonal = dx != 0 && dy != 0;
+                // No corner cutting: both tiles beside a diagonal step must be open
+                if is_diagonal
+                    && (!world.is_walkable(nx as usize, y as usize)
+                        || !world.is_walkable(x as usize, ny as usize))
+                {
+                    continue;
+                }
+                let nidx = ny as usize * w + nx as usize;
+                if s.closed[nidx] == stamp {
+                    continue;
+                }
+                let mut step = world.terrain(nx as usize, ny as usize).path_cost;
+                if is_diagonal {
+                    step = step * DIAGONAL_COST / 10;
+                }
+                let tentative = s.g[idx] + step;
+                if s.seen[nidx] != stamp || tentative < s.g[nidx] {
+                    s.seen[nidx] = stamp;
+                    s.g[nidx] = tentative;
+                    s.came_from[nidx] = idx;
+                    open.push(AStarNode {
+                        cost: tentative + heuristic(nidx),
+                        idx: nidx,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    fn cluster_rect(world: &World, graph: &HpaGraph, cluster: usize) -> Rect {
+        let (cx, cy) = (cluster % graph.clusters_x, cluster / graph.clusters_x);
+        let (x0, y0) = (cx * HPA_CLUSTER_SIZE, cy * HPA_CLUSTER_SIZE);
+        Rect {
+            x0,
+            y0,
+            x1: (x0 + HPA_CLUSTER_SIZE).min(world.width),
+            y1: (y0 + HPA_CLUSTER_SIZE).min(world.height),
+        }
+    }
+
+    /// Build the cluster graph: entrances where walkable tiles face each other
+    /// across a cluster border, and intra-cluster costs between entrances.
+    fn build_hpa(&mut self, world: &World) -> HpaGraph {
+        let (w, h) = (world.width, world.height);
+        let clusters_x = w.div_ceil(HPA_CLUSTER_SIZE);
+        let clusters_y = h.div_ceil(HPA_CLUSTER_SIZE);
+        let mut graph = HpaGraph {
+            clusters_x,
+            nodes: Vec::new(),
+            node_at: HashMap::new(),
+            cluster_nodes: vec![Vec::new(); clusters_x * clusters_y],
+            edges: Vec::new(),
+        };
+        let cost = |idx: usize| world.terrain(idx % w, idx / w).path_cost;
+
+        for cluster in 0..clusters_x * clusters_y {
+            let rect = Self::cluster_rect(world, &graph, cluster);
+            // Pairs of (inside, outside) tiles along the right and bottom borders
+            let mut borders: Vec<Vec<(usize, usize)>> = Vec::new();
+            if rect.x1 < w {
+                borders.push(
+                    (rect.y0..rect.y1)
+                        .map(|y| (y * w + rect.x1 - 1, y * w + rect.x1))
+                        .collect(),
+                );
+            }
+            if rect.y1 < h {
+                borders.push(
+                    (rect.x0..rect.x1)
+                        .map(|x| ((rect.y1 - 1) * w + x, rect.y1 * w + x))
+                        .collect(),
+                );
+            }
+            for border in borders {
+                let open = |&(a, b): &(usize, usize)| {
+                    world.is_walkable(a % w, a / w) && world.is_walkable(b % w, b / w)
+                };
+                let mut i = 0;
+                while i < border.len() {
+                    if !open(&border[i]) {
+                        i += 1;
+                        continue;
+                    }
+                    let run_start = i;
+                    while i < border.len() && open(&border[i]) {
+                        i += 1;
+                    }
+                    let run = &border[run_start..i];
+                    let entrances = if run.len() >= HPA_LONG_ENTRANCE {
+                        vec![run[0], run[run.len() - 1]]
+                    } else {
+                        vec![run[run.len() / 2]]
+                    };
+                    for (a, b) in entrances {
+                        let na = graph.add_node(a, w);
+                        let nb = graph.add_node(b, w);
+                        graph.edges[na].push((nb, cost(b)));
+                        graph.edges[nb].push((na, cost(a)));
+                    }
+                }
+            }
+        }
+
+        for cluster in 0..graph.cluster_nodes.len() {
+            let rect = Self::cluster_rect(world, &graph, cluster);
+            let members = graph.cluster_nodes[cluster].clone();
+            for (i, &a) in members.iter().enumerate() {
+                for &b in &members[i + 1..] {
+                    let (ta, tb) = (graph.nodes[a], graph.nodes[b]);
+                    if let Some((_, c)) = self.astar(world, ta, tb, rect) {
+                        // The reverse path enters `ta` instead of `tb`
+                        graph.edges[a].push((b, c));
+                        graph.edges[b].push((a, c - cost(tb) + cost(ta)));
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    fn find_hierarchical(
+        &mut self,
+        world: &World,
+        start: usize,
+        goal: usize,
+    ) -> Option<Vec<usize>> {
+        let graph = match self.hpa.take() {
+            Some(graph) => graph,
+            None => self.build_hpa(world),
+        };
+        let path = self.search_hpa(world, &graph, start, goal);
+        self.hpa = Some(graph);
+        path
+    }
+
+    fn search_hpa(
+        &mut self,
+        world: &World,
+        graph: &HpaGraph,
+        start: usize,
+        goal: usize,
+    ) -> Option<Vec<usize>> {
+        let w = world.width;
+        let start_cluster = graph.cluster_of(start, w);
+        let goal_cluster = graph.cluster_of(goal, w);
+        let start_rect = Self::cluster_rect(world, graph, start_cluster);
+        let goal_rect = Self::cluster_rect(world, graph, goal_cluster);
+        if start_cluster == goal_cluster {
+            if let Some((path, _)) = self.astar(world, start, goal, start_rect) {
+                return Some(path);
+            }
+        }
+
+        // Temporary links from the start tile and to the goal tile
+        let mut from_start = Vec::new();
+        for &n in &graph.cluster_nodes[start_cluster] {
+            if let Some((_, c)) = self.astar(world, start, graph.nodes[n], start_rect) {
+                from_start.push((n, c));
+            }
+        }
+        let mut to_goal = HashMap::new();
+        for &n in &graph.cluster_nodes[goal_cluster] {
+            if let Some((_, c)) = self.astar(world, graph.nodes[n], goal, goal_rect) {
+                to_goal.insert(n, c);
+            }
+        }
+
+        // A* over the abstract graph; `start_id` and `goal_id` are virtual nodes
+        let n = graph.nodes.len();
+        let (start_id, goal_id) = (n, n + 1);
+        let tile_of = |id: usize| match id {
+            id if id == start_id => start,
+            id if id == goal_id => goal,
+            id => graph.nodes[id],
+        };
+        let (gx, gy) = ((goal % w) as i32, (goal / w) as i32);
+        let heuristic = |id: usize| {
+            let t = tile_of(id);
+            let dx = ((t % w) as i32 - gx).abs();
+            let dy = ((t / w) as i32 - gy).abs();
+            let plain = MIN_PATH_COST;
+            if self.config.diagonal {
+                plain * (dx + dy) + (plain * DIAGONAL_COST / 10 - 2 * plain) * dx.min(dy)
+            } else {
+                plain * (dx + dy)
+            }
+        };
+        let mut g = vec![i32::MAX; n + 2];
+        let mut came_from = vec![usize::MAX; n + 2];
+        let mut closed = vec![false; n + 2];
+        let mut open = BinaryHeap::new();
+        g[start_id] = 0;
+        open.push(AStarNode {
+            cost: heuristic(start_id),
+            idx: start_id,
+        });
+        while let Some(AStarNode { idx, .. }) = open.pop() {
+            if idx == goal_id {
+                break;
+            }
+            if closed[idx] {
+                continue;
+            }
+            closed[idx] = true;
+            let edges: Vec<(usize, i32)> = if idx == start_id {
+                from_start.clone()
+            } else {
+                let mut edges = graph.edges[idx].clone();
+                if let Some(&c) = to_goal.get(&idx) {
+                    edges.push((goal_id, c));
+                }
+                edges
+            };
+            for (next, c) in edges {
+                let tentative = g[idx] + c;
+                if !closed[next] && tentative < g[next] {
+                    g[next] = tentative;
+                    came_from[next] = idx;
+                    open.push(AStarNode {
+                        cost: tentative + heuristic(next),
+                        idx: next,
+                    });
+                }
+            }
+        }
+        if g[goal_id] == i32::MAX {
+            return None;
+        }
+
+        let mut waypoints = vec![goal];
+        let mut id = came_from[goal_id];
+        while id != start_id {
+            waypoints.push(graph.nodes[id]);
+            id = came_from[id];
+        }
+        waypoints.push(start);
+        waypoints.reverse();
+
+        // Refine: steps across a border are single moves, everything else is a
+        // search inside one cluster
+        let mut path = Vec::new();
+        for pair in waypoints.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if a == b {
+                continue;
+            }
+            let cluster = graph.cluster_of(a, w);
+            if cluster == graph.cluster_of(b, w) {
+                let rect = Self::cluster_rect(world, graph, cluster);
+                path.extend(self.astar(world, a, b, rect)?.0);
+            } else {
+                path.push(b);
+            }
+        }
+        Some(path)
+    }
+}
+
+/// String pulling: from each kept waypoint, skip ahead to the farthest later
+/// tile reachable in a straight line over walkable tiles no costlier than the
+/// ones skipped.
+fn smooth(world: &World, start: usize, path: &[usize]) -> Vec<usize> {
+    let w = world.width;
+    let cost = |idx: usize| world.terrain(idx % w, idx / w).path_cost;
+    let mut full = Vec::with_capacity(path.len() + 1);
+    full.push(start);
+    full.extend_from_slice(path);
+
+    let mut out = Vec::new();
+    let mut anchor = 0;
+    while anchor + 1 < full.len() {
+        let mut next = anchor + 1;
+        let mut max_cost = cost(full[next]);
+        for (j, &tile) in full.iter().enumerate().skip(anchor + 2) {
+            max_cost = max_cost.max(cost(tile));
+            if line_clear(world, full[anchor], tile, max_cost) {
+                next = j;
+            } else {
+                break;
+            }
+        }
+        out.push(full[next]);
+        anchor = next;
+    }
+    out
+}
+
+/// Whether every tile touched by the segment between two tile centres is
+/// walkable and costs at most `max_cost`. Passing exactly through a corner
+/// touches both tiles beside it.
+fn line_clear(world: &World, from: usize, to: usize, max_cost: i32) -> bool {
+    let w = world.width as i64;
+    let (mut x, mut y) = (from as i64 % w, from as i64 / w);
+    let (tx, ty) = (to as i64 % w, to as i64 / w);
+    let (dx, dy) = ((tx - x).abs(), (ty - y).abs());
+    let (sx, sy) = ((tx - x).signum(), (ty - y).signum());
+    let ok = |x: i64, y: i64| {
+        world.is_walkable(x as usize, y as usize)
+            && world.terrain(x as usize, y as usize).path_cost <= max_cost
+    };
+
+    let mut error = dx - dy;
+    let mut n = 1 + dx + dy;
+    while n > 0 {
+        if !ok(x, y) {
+            return false;
+        }
+        match error.cmp(&0) {
+            Ordering::Greater => {
+                x += sx;
+                error -= 2 * dy;
+            }
+            Ordering::Less => {
+                y += sy;
+                error += 2 * dx;
+            }
+            Ordering::Equal => {
+                if !ok(x + sx, y) || !ok(x, y + sy) {
+                    return false;
+                }
+                x += sx;
+                y += sy;
+                error += 2 * (dx - dy);
+                n -= 1;
+            }
+        }
+        n -= 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_world(width: usize, height: usize) -> World {
+        let mut w = World::new(width, height);
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                w.set_type(x, y, TILE_PLAIN);
+            }
+        }
+        w
+    }
+
+    fn path_len(w: &World, from: (usize, usize), to: (usize, usize)) -> Option<usize> {
+        w.find_path(
+            World::tile_center(from.0),
+            World::tile_center(from.1),
+            World::tile_center(to.0),
+            World::tile_center(to.1),
+        )
+        .map(|p| p.len())
+    }
+
+    #[test]
+    fn test_from_metadata() {
+        let mut meta = BTreeMap::new();
+        meta.insert("path_diagonal".to_string(), "true".to_string());
+        meta.insert("path_smooth".to_string(), "false".to_string());
+        let cfg = PathConfig::from_metadata(&meta);
+        assert!(cfg.diagonal && !cfg.smooth && !cfg.hierarchical);
+    }
+
+    #[test]
+    fn test_diagonal_paths_do_not_cut_corners() {
+        let mut w = open_world(10, 10);
+        assert_eq!(path_len(&w, (1, 1), (5, 5)), Some(8));
+        w.set_path_config(PathConfig {
+            diagonal: true,
+            ..Default::default()
+        });
+        assert_eq!(path_len(&w, (1, 1), (5, 5)), Some(4));
+
+        // A diagonal between two walls is blocked even though both ends are open
+        let mut w = World::new(5, 5);
+        w.set_type(1, 1, TILE_PLAIN);
+        w.set_type(2, 2, TILE_PLAIN);
+        w.set_path_config(PathConfig {
+            diagonal: true,
+            ..Default::default()
+        });
+        assert_eq!(path_len(&w, (1, 1), (2, 2)), None);
+    }
+
+    #[test]
+    fn test_smoothing_removes_waypoints() {
+        let mut w = open_world(12, 12);
+        w.set_path_config(PathConfig {
+            smooth: true,
+            ..Default::default()
+        });
+        // Open ground: a single straight segment
+        assert_eq!(path_len(&w, (1, 1), (9, 4)), Some(1));
+
+        // A wall at x = 5 (open only at y >= 9) keeps waypoints around its end
+        let mut walled = World::new(12, 12);
+        for y in 1..11 {
+            for x in (1..11).filter(|&x| x != 5 || y >= 9) {
+                walled.set_type(x, y, TILE_PLAIN);
+            }
+        }
+        let rough = path_len(&walled, (2, 2), (8, 2)).unwrap();
+        walled.set_path_config(PathConfig {
+            smooth: true,
+            ..Default::default()
+        });
+        let path = walled
+            .find_path(
+                World::tile_center(2),
+                World::tile_center(2),
+                World::tile_center(8),
+                World::tile_center(2),
+            )
+            .unwrap();
+        assert!(path.len() >= 2 && path.len() < rough, "{path:?}");
+        assert_eq!(
+            *path.last().unwrap(),
+            (World::tile_center(8), World::tile_center(2))
+        );
+    }
+
+    #[test]
+    fn test_cache_is_invalidated_when_map_changes() {
+        let mut w = open_world(10, 10);
+        let straight = w
+            .find_path(
+                World::tile_center(1),
+                World::tile_center(4),
+                World::tile_center(8),
+                World::tile_center(4),
+            )
+            .unwrap();
+        assert!(straight.contains(&(World::tile_center(4), World::tile_center(4))));
+
+        // Lava on the straight route: a fresh search detours around it
+        for x in 2..8 {
+            w.set_gfx(x, 4, TILE_GFX_LAVA);
+        }
+        let detour = w
+            .find_path(
+                World::tile_center(1),
+                World::tile_center(4),
+                World::tile_center(8),
+                World::tile_center(4),
+            )
+            .unwrap();
+        assert!(!detour.contains(&(World::tile_center(4), World::tile_center(4))));
+
+        let mut cache = PathCache::default();
+        for i in 0..PATH_CACHE_SIZE + 1 {
+            cache.insert((i, i), None);
+        }
+        assert_eq!(cache.entries.len(), PATH_CACHE_SIZE);
+        assert!(cache.get((0, 0)).is_none(), "oldest entry is evicted");
+    }
+
+    /// A 60x60 map spanning many clusters, with walls every 8 columns that
+    /// have a few gaps.
+    fn maze() -> World {
+        let mut w = World::new(60, 60);
+        for y in 1..59 {
+            for x in 1..59 {
+                let wall = x % 8 == 6 && x < 54 && (x + y) % 23 != 0;
+                if !wall {
+                    w.set_type(x, y, TILE_PLAIN);
+                }
+            }
+        }
+        w
+    }
+
+    #[test]
+    fn test_hierarchical_matches_flat_search() {
+        let flat = maze();
+        let mut hpa = maze();
+        hpa.set_path_config(PathConfig {
+            hierarchical: true,
+            ..Default::default()
+        });
+
+        for &(from, to) in &[((1, 1), (58, 58)), ((2, 30), (57, 3)), ((3, 3), (4, 4))] {
+            let a = path_len(&flat, from, to).unwrap();
+            let b = path_len(&hpa, from, to).unwrap();
+            // HPA* is near-optimal, never shorter than the true optimum
+            assert!(b >= a && b <= a + a / 4 + 4, "flat {a}, hpa {b}");
+        }
+
+        // A goal cut off from the rest is unreachable
+        let mut sealed = World::new(60, 60);
+        for y in 1..59 {
+            for x in (1..59).filter(|&x| x != 50) {
+                sealed.set_type(x, y, TILE_PLAIN);
+            }
+        }
+        sealed.set_path_config(hpa.path_config());
+        assert_eq!(path_len(&sealed, (1, 1), (58, 58)), None);
+        assert_eq!(path_len(&sealed, (51, 1), (58, 58)), Some(64));
+    }
+}
@@ -1,17 +1,116 @@
 use mlua::Lua;
 
-use super::config::LUA_MAX_INSTRUCTIONS;
+use super::config::{
+    LUA_API_DEPRECATED_BELOW, LUA_API_LEVEL, LUA_API_MIN_LEVEL, LUA_HOOK_INTERVAL,
+};
 use super::lua_api;
 
 /// Which high-level API style the bot uses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ApiStyle {
+pub enum ApiStyle {
     /// Coroutine-based: `Creature:main()`, blocking methods like `self:moveto()`.
     Oo,
     /// State-machine-based: `bot()` with state functions and event handlers.
     State,
 }
 
+impl ApiStyle {
+    /// Name used by `needs_api` and stored in `bot_versions.api_type`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApiStyle::Oo => "oo",
+            ApiStyle::State => "state",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "oo" => Some(ApiStyle::Oo),
+            "state" => Some(ApiStyle::State),
+            _ => None,
+        }
+    }
+}
+
+/// The API style and level a bot was written against. Decides which
+/// functions, constants and wrappers `Player::new` installs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiVersion {
+    pub style: ApiStyle,
+    pub level: u32,
+}
+
+impl ApiVersion {
+    /// Read the `needs_api(style, level)` declaration from bot source.
+    /// Without one, the style is sniffed (see `Player::detect_api_style`) and
+    /// the bot gets the current level.
+    pub fn detect(code: &str) -> Self {
+        ApiVersion {
+            style: Player::detect_api_style(code),
+            level: parse_needs_api(code)
+                .and_then(|(_, level)| level)
+                .unwrap_or(LUA_API_LEVEL),
+        }
+    }
+
+    /// Rebuild for a stored bot version from its code and `api_level`
+    /// column. The style is always detected from the code: versions saved
+    /// before API levels existed hold the `api_type` column default ('oo')
+    /// whatever style they were written in.
+    pub fn from_stored(code: &str, api_level: i32) -> Option<Self> {
+        Some(ApiVersion {
+            style: Player::detect_api_style(code),
+            level: u32::try_from(api_level).ok()?,
+        })
+    }
+
+    /// Err if this level can't be installed; Ok(Some(warning)) if it is
+    /// deprecated.
+    pub fn check(&self) -> Result<Option<String>, String> {
+        if self.level < LUA_API_MIN_LEVEL || self.level > LUA_API_LEVEL {
+            return Err(format!(
+                "Unknown API level {} (supported: {LUA_API_MIN_LEVEL} to {LUA_API_LEVEL})",
+                self.level
+            ));
+        }
+        if self.level < LUA_API_DEPRECATED_BELOW {
+            return Ok(Some(format!(
+                "API level {} is deprecated; use needs_api(\"{}\", {LUA_API_LEVEL})",
+                self.level,
+                self.style.as_str()
+            )));
+        }
+        Ok(None)
+    }
+}
+
+/// Find the first `needs_api("style")` / `needs_api("style", level)` call
+/// outside a line comment. Also accepts the `needs_api "style"` call form.
+fn parse_needs_api(code: &str) -> Option<(ApiStyle, Option<u32>)> {
+    for line in code.lines() {
+        let line = line.split("--").next().unwrap_or("");
+        let Some(pos) = line.find("needs_api") else {
+            continue;
+        };
+        let rest = line[pos + "needs_api".len()..].trim_start();
+        let rest = rest.strip_prefix('(').unwrap_or(rest).trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let rest = &rest[1..];
+        let end = rest.find(quote)?;
+        let style = ApiStyle::parse(&rest[..end])?;
+        let level = rest[end + 1..]
+            .trim_start()
+            .strip_prefix(',')
+            .and_then(|r| {
+                let r = r.trim_start();
+                let digits = r.len() - r.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                r[..digits].parse().ok()
+            });
+        return Some((style, level));
+    }
+    None
+}
+
 /// Represents a player controlling a swarm of creatures.
 pub struct Player {
     pub id: u32,
@@ -25,10 +124,12 @@ pub struct Player {
 
 impl Player {
     /// Create a new player with a fresh Lua VM.
-    /// Registers all API functions, constants, and bootstrap code.
-    /// The high-level API (oo.lua or state.lua) is NOT loaded here — it is
-    /// auto-detected and loaded in `load_code()` based on the bot source.
-    pub fn new(id: u32, name: &str) -> Result<Self, String> {
+    /// Registers the API functions and constants of `api.level`, the bootstrap
+    /// code, and the high-level API wrappers (oo.lua or state.lua) for
+    /// `api.style`. Use `ApiVersion::detect` for bots without a stored version.
+    pub fn new(id: u32, name: &str, api: ApiVersion) -> Result<Self, String> {
+        api.check()?;
+
         // SAFETY: We need the debug library for debug.sethook to set instruction
         // limits on coroutine threads. The debug global is removed in the bootstrap
         // after saving a reference to debug.sethook, so user code cannot access it.
@@ -39,6 +140,16 @@ impl Player {
             .map_err(|e| format!("Failed to register constants: {e}"))?;
         lua_api::register_functions(&lua, id)
             .map_err(|e| format!("Failed to register API functions: {e}"))?;
+        lua_api::restrict_to_level(&lua, api.level)
+            .map_err(|e| format!("Failed to select API level: {e}"))?;
+
+        // Budget charging for the coroutine hooks, false once the budget is
+        // spent; the bootstrap keeps a private reference and removes the global.
+        lua.create_function(|lua, count: u32| {
+            Ok(lua_api::charge_instructions(lua, count).is_ok())
+        })
+        .and_then(|f| lua.globals().set("_charge_instructions", f))
+        .map_err(|e| format!("Failed to register instruction hook: {e}"))?;
 
         // Provide _TRACEBACK as a simple passthrough (debug.traceback removed in sandbox)
         lua.load(
@@ -55,6 +166,7 @@ impl Player {
         .map_err(|e| format!("Failed to set up _TRACEBACK/epcall: {e}"))?;
 
         // Compatibility aliases and bootstrap (from original player.lua)
+        let api_level = api.level;
         let bootstrap = format!(
             r#"
 -- Compatibility aliases (from player.lua)
@@ -72,11 +184,17 @@ creature_config = setmetatable({{}}, {{
 -- The full Creature class methods are loaded later by oo.lua.
 Creature = {{}}
 
--- needs_api: accepted for backward compatibility but API loading
--- is handled automatically by the engine. Both "oo" and "state" are valid.
-function needs_api(needed)
+-- API level installed for this bot
+API_LEVEL = {api_level}
+
+-- needs_api(style, level): the engine reads the declaration from the source
+-- before running it and installs the matching API; this only checks the
+-- arguments. Both "oo" and "state" are valid.
+function needs_api(needed, level)
     assert(needed == "oo" or needed == "state",
            "Unknown API style '" .. tostring(needed) .. "'. Use 'oo' or 'state'.")
+    assert(level == nil or (type(level) == "number" and level >= {LUA_API_MIN_LEVEL} and level <= {LUA_API_LEVEL}),
+           "Unknown API level " .. tostring(level) .. ". Supported: {LUA_API_MIN_LEVEL} to {LUA_API_LEVEL}.")
 end
 
 -- Switch print to client_print
@@ -115,12 +233,18 @@ end
 
 -- Instruction limit for coroutines: Lua 5.1 hooks are per-thread,
 -- so we wrap coroutine.resume to install the hook on each coroutine.
+-- It charges the same per-tick budget as the main thread's hook.
 do
     local _sethook = debug.sethook
     local _resume = coroutine.resume
-    local _instruction_limit = {LUA_MAX_INSTRUCTIONS}
+    local _charge = _charge_instructions
+    local _hook_interval = {LUA_HOOK_INTERVAL}
+    local _hook = function()
+        if not _charge(_hook_interval) then error("lua vm cycles exceeded") end
+    end
+    _charge_instructions = nil
     coroutine.resume = function(co, ...)
-        _sethook(co, function() error("lua vm cycles exceeded") end, "", _instruction_limit)
+        _sethook(co, _hook, "", _hook_interval)
         local results = {{_resume(co, ...)}}
         _sethook(co)
         -- If resume failed with cycles exceeded, print so it appears in output
@@ -148,7 +272,7 @@ collectgarbage = nil
             .exec()
             .map_err(|e| format!("Failed to load bootstrap: {e}"))?;
 
-        Ok(Player {
+        let player = Player {
             id,
             name: name.to_string(),
             score: 0,
@@ -156,23 +280,20 @@ collectgarbage = nil
             num_creatures: 0,
             lua,
             output: Vec::new(),
-        })
+        };
+        player.load_api(api)?;
+        Ok(player)
     }
 
     /// Detect which high-level API style the bot source uses.
     ///
     /// Detection rules (checked against the source text):
-    /// 1. Explicit `needs_api("state")` or `needs_api('state')` → State
+    /// 1. Explicit `needs_api("state")` or `needs_api("oo")` → that style
     /// 2. `function bot()` defined → State
-    /// 3. Everything else (including `needs_api("oo")`) → OO (default)
+    /// 3. Everything else → OO (default)
     fn detect_api_style(code: &str) -> ApiStyle {
-        // Check for explicit needs_api("state") / needs_api('state')
-        if code.contains("needs_api(\"state\")")
-            || code.contains("needs_api('state')")
-            || code.contains("needs_api \"state\"")
-            || code.contains("needs_api 'state'")
-        {
-            return ApiStyle::State;
+        if let Some((style, _)) = parse_needs_api(code) {
+            return style;
         }
 
         // Check for function bot() pattern (state-machine style entry point)
@@ -185,9 +306,10 @@ collectgarbage = nil
         ApiStyle::Oo
     }
 
-    /// Load the appropriate high-level API files into the Lua VM.
-    fn load_api(&self, style: ApiStyle) -> Result<(), String> {
-        match style {
+    /// Load the high-level API files for the bot's style into the Lua VM.
+    /// The wrappers haven't changed between API levels so far.
+    fn load_api(&self, api: ApiVersion) -> Result<(), String> {
+        match api.style {
             ApiStyle::Oo => {
                 let api_code = include_str!("../../../orig_game/api/oo.lua");
                 self.lua
@@ -223,17 +345,11 @@ collectgarbage = nil
     }
 
     /// Load user bot code into the Lua VM.
-    /// Auto-detects whether the bot uses the OO or State high-level API style,
-    /// loads the appropriate API wrapper, then executes the user's bot code.
+    /// The high-level API + defaults were loaded by `new`, so user code can
+    /// override default callbacks (Creature:main, bot(), etc.).
     /// Game state must be set in app_data before calling this, so top-level
     /// bot code (e.g. `world_size()` calls) can access the game world.
     pub fn load_code(&self, code: &str) -> Result<(), String> {
-        let style = Self::detect_api_style(code);
-
-        // Load the high-level API + defaults BEFORE user code, so that
-        // user code can override default callbacks (Creature:main, bot(), etc.)
-        self.load_api(style)?;
-
         if !code.is_empty() {
             self.lua
                 .load(code)
@@ -251,7 +367,7 @@ mod tests {
 
     #[test]
     fn test_create_player() {
-        let player = Player::new(1, "TestBot");
+        let player = Player::new(1, "TestBot", ApiVersion::detect(""));
         assert!(player.is_ok());
         let player = player.unwrap();
         assert_eq!(player.id, 1);
@@ -261,7 +377,7 @@ mod tests {
 
     #[test]
     fn test_player_lua_constants() {
-        let player = Player::new(1, "TestBot").unwrap();
+        let player = Player::new(1, "TestBot", ApiVersion::detect("")).unwrap();
         let lua = &player.lua;
 
         // Check creature type constants
@@ -303,16 +419,18 @@ mod tests {
 
     #[test]
     fn test_player_think_exists_after_oo_load() {
-        let player = Player::new(1, "TestBot").unwrap();
-        // player_think is defined by the high-level API, loaded in load_code()
-        player.load_code("function Creature:main() end").unwrap();
+        let code = "function Creature:main() end";
+        let player = Player::new(1, "TestBot", ApiVersion::detect(code)).unwrap();
+        // player_think is defined by the high-level API, loaded in new()
+        player.load_code(code).unwrap();
         let _func: mlua::Function = player.lua.globals().get("player_think").unwrap();
     }
 
     #[test]
     fn test_player_think_exists_after_state_load() {
-        let player = Player::new(1, "TestBot").unwrap();
-        player.load_code("function bot() function onIdle() end end").unwrap();
+        let code = "function bot() function onIdle() end end";
+        let player = Player::new(1, "TestBot", ApiVersion::detect(code)).unwrap();
+        player.load_code(code).unwrap();
         let _func: mlua::Function = player.lua.globals().get("player_think").unwrap();
     }
 
@@ -333,12 +451,65 @@ mod tests {
 
     #[test]
     fn test_needs_api_accepts_both_styles() {
-        let player = Player::new(1, "TestBot").unwrap();
+        let code = "needs_api(\"oo\")\nfunction Creature:main() end";
+        let player = Player::new(1, "TestBot", ApiVersion::detect(code)).unwrap();
         // needs_api("oo") should not error
-        player.load_code("needs_api(\"oo\")\nfunction Creature:main() end").unwrap();
+        player.load_code(code).unwrap();
 
-        let player2 = Player::new(2, "TestBot2").unwrap();
+        let code = "needs_api(\"state\")\nfunction bot() function onIdle() end end";
+        let player2 = Player::new(2, "TestBot2", ApiVersion::detect(code)).unwrap();
         // needs_api("state") should not error
-        player2.load_code("needs_api(\"state\")\nfunction bot() function onIdle() end end").unwrap();
+        player2.load_code(code).unwrap();
+    }
+
+    #[test]
+    fn test_detect_api_version() {
+        let current = |style| ApiVersion { style, level: LUA_API_LEVEL };
+        assert_eq!(ApiVersion::detect(""), current(ApiStyle::Oo));
+        assert_eq!(ApiVersion::detect("function bot() end"), current(ApiStyle::State));
+        assert_eq!(
+            ApiVersion::detect("needs_api(\"state\", 1)\nfunction bot() end"),
+            ApiVersion { style: ApiStyle::State, level: 1 }
+        );
+        assert_eq!(
+            ApiVersion::detect("needs_api 'oo'\n"),
+            current(ApiStyle::Oo)
+        );
+        // Commented-out declarations are ignored
+        assert_eq!(
+            ApiVersion::detect("-- needs_api(\"state\", 1)\nfunction Creature:main() end"),
+            current(ApiStyle::Oo)
+        );
+        assert_eq!(
+            ApiVersion::from_stored("function bot() end", 1),
+            Some(ApiVersion { style: ApiStyle::State, level: 1 })
+        );
+        assert_eq!(ApiVersion::from_stored("function bot() end", -1), None);
+    }
+
+    #[test]
+    fn test_api_level_check() {
+        let version = |level| ApiVersion { style: ApiStyle::Oo, level };
+        assert_eq!(version(LUA_API_LEVEL).check(), Ok(None));
+        assert!(version(1).check().unwrap().unwrap().contains("deprecated"));
+        assert!(version(LUA_API_LEVEL + 1).check().is_err());
+        assert!(version(0).check().is_err());
+        assert!(Player::new(1, "Future", version(LUA_API_LEVEL + 1)).is_err());
+    }
+
+    #[test]
+    fn test_old_api_level_hides_newer_functions() {
+        let old = Player::new(1, "Old", ApiVersion { style: ApiStyle::Oo, level: 1 }).unwrap();
+        let missing: mlua::Value = old.lua.globals().get("get_nearest_food").unwrap();
+        assert!(missing.is_nil());
+        let level: u32 = old.lua.globals().get("API_LEVEL").unwrap();
+        assert_eq!(level, 1);
+        // Functions from level 1 are still there
+        let _f: mlua::Function = old.lua.globals().get("get_nearest_enemy").unwrap();
+
+        let new = Player::new(2, "New", ApiVersion::detect("")).unwrap();
+        let _f: mlua::Function = new.lua.globals().get("get_nearest_food").unwrap();
+        assert!(new.lua.load("needs_api(\"oo\", 99)").exec().is_err());
+        assert!(new.lua.load("needs_api(\"oo\", 1)").exec().is_ok());
     }
 }
@@ -1,18 +1,22 @@
 // Game server: manages a running game instance and broadcasts state to WebSocket clients.
 
+use std::collections::HashMap;
 use std::panic::AssertUnwindSafe;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
+use lazy_static::lazy_static;
 use serde::Serialize;
 use tokio::sync::broadcast;
 
 use crate::metrics;
 use crate::replay::ReplayRecorder;
 
-use super::config::*;
 use super::game::{Game, GameSnapshot, GameSnapshotDelta, PlayerSnapshot, WorldSnapshot};
+use super::level::load_level;
+use super::player::ApiVersion;
 use super::world::{RandomMapParams, World};
 
 /// Result of a completed game, passed to the on_complete callback.
@@ -45,54 +49,134 @@ pub struct MapInfo {
     pub description: String,
 }
 
-/// Scan a directory for `*.json` map files and return their metadata, sorted by name.
-pub fn list_maps(maps_dir: &Path) -> Vec<MapInfo> {
-    let mut maps = Vec::new();
+/// Modification time and size of a map file, to tell when a cached entry is stale.
+type FileStamp = (Option<SystemTime>, u64);
+
+lazy_static! {
+    /// Parsed map metadata by file path. Listing maps would otherwise run
+    /// every level script on each request.
+    static ref MAP_INFO_CACHE: Mutex<HashMap<PathBuf, (FileStamp, Option<MapInfo>)>> =
+        Mutex::new(HashMap::new());
+}
 
+/// Scan a directory for map files and return their metadata, sorted by name.
+///
+/// `*.json` tile maps, `*.map` ASCII maps and `*.lua` level scripts are listed.
+/// When a map exists in several formats, the one `load_map` would pick wins.
+/// Each file is parsed once and then served from a cache until it changes.
+pub fn list_maps(maps_dir: &Path) -> Vec<MapInfo> {
     let entries = match std::fs::read_dir(maps_dir) {
         Ok(e) => e,
-        Err(_) => return maps,
+        Err(_) => return Vec::new(),
     };
 
+    // The highest-priority file for each map name
+    let mut files: HashMap<String, (usize, PathBuf)> = HashMap::new();
     for entry in entries.flatten() {
         let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let Some(rank) = MAP_EXTENSIONS.iter().position(|&e| e == ext) else {
             continue;
-        }
-        let stem = match path.file_stem().and_then(|s| s.to_str()) {
-            Some(s) => s.to_string(),
-            None => continue,
         };
-        let contents = match std::fs::read_to_string(&path) {
-            Ok(c) => c,
-            Err(_) => continue,
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
         };
-        // Parse just enough to get width/height
-        #[derive(serde::Deserialize)]
-        struct MapMeta {
-            width: usize,
-            height: usize,
-        }
-        if let Ok(meta) = serde_json::from_str::<MapMeta>(&contents) {
-            maps.push(MapInfo {
-                name: stem,
-                width: meta.width,
-                height: meta.height,
-                description: format!("{}x{} map", meta.width, meta.height),
-            });
+        match files.get(stem) {
+            Some((r, _)) if *r < rank => {}
+            _ => {
+                files.insert(stem.to_string(), (rank, path));
+            }
         }
     }
 
+    let mut maps: Vec<MapInfo> = files
+        .into_iter()
+        .filter_map(|(name, (rank, path))| cached_map_info(&path, name, MAP_EXTENSIONS[rank]))
+        .collect();
     maps.sort_by(|a, b| a.name.cmp(&b.name));
     maps
 }
 
+/// Metadata for one map file, parsing it only if it changed since last time.
+/// `None` (also cached) if the file doesn't parse.
+fn cached_map_info(path: &Path, name: String, ext: &str) -> Option<MapInfo> {
+    let stamp: FileStamp = match std::fs::metadata(path) {
+        Ok(meta) => (meta.modified().ok(), meta.len()),
+        Err(_) => return None,
+    };
+    if let Some((cached_stamp, info)) = MAP_INFO_CACHE.lock().unwrap().get(path) {
+        if *cached_stamp == stamp {
+            return info.clone();
+        }
+    }
+
+    let info = match read_map_file(path, ext) {
+        Ok(world) => {
+            let kind = if ext == "lua" { "level" } else { "map" };
+            let description = world
+                .metadata
+                .get("description")
+                .cloned()
+                .unwrap_or_else(|| format!("{}x{} {}", world.width, world.height, kind));
+            Some(MapInfo {
+                name,
+                width: world.width,
+                height: world.height,
+                description,
+            })
+        }
+        Err(e) => {
+            tracing::warn!("Skipping map {}: {}", path.display(), e);
+            None
+        }
+    };
+    MAP_INFO_CACHE
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), (stamp, info.clone()));
+    info
+}
+
+/// Map file extensions in lookup order: JSON tile maps, ASCII maps, level
+/// scripts. A bundled JSON map can't be shadowed by a script of the same name,
+/// so the original level scripts ship as `level_<name>.lua`.
+const MAP_EXTENSIONS: [&str; 3] = ["json", "map", "lua"];
+
 /// Load a map by name from the given directory. Returns a World or an error message.
+///
+/// Looks for a `{name}.json` tile map first, then a `{name}.map` ASCII map,
+/// then a `{name}.lua` level script.
 pub fn load_map(maps_dir: &Path, name: &str) -> Result<World, String> {
-    let path = maps_dir.join(format!("{}.json", name));
-    let contents = std::fs::read_to_string(&path)
-        .map_err(|e| format!("Failed to read map '{}': {}", name, e))?;
-    World::from_json(&contents)
+    for ext in MAP_EXTENSIONS {
+        let path = maps_dir.join(format!("{}.{}", name, ext));
+        if path.is_file() {
+            return read_map_file(&path, ext)
+                .map_err(|e| format!("Failed to load map '{}': {}", name, e));
+        }
+    }
+    Err(format!("Failed to read map '{}': not found", name))
+}
+
+/// Parse a single map file according to its extension.
+fn read_map_file(path: &Path, ext: &str) -> Result<World, String> {
+    if ext == "lua" {
+        return load_level(&read_level_source(path)?);
+    }
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    if ext == "map" {
+        World::from_ascii(&contents)
+    } else {
+        World::from_json(&contents)
+    }
+}
+
+/// Read a level script. Some original levels contain Latin-1 comments, so the
+/// bytes are decoded lossily instead of requiring UTF-8.
+fn read_level_source(path: &Path) -> Result<String, String> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| format!("Failed to read level '{}': {}", path.display(), e))?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
 }
 
 /// Messages sent from the game loop to WebSocket clients.
@@ -138,6 +222,8 @@ pub struct PlayerEndStats {
 pub struct PlayerEntry {
     pub name: String,
     pub code: String,
+    /// API version stored with the bot version; `None` detects it from `code`.
+    pub api: Option<ApiVersion>,
 }
 
 /// Metadata about a currently running game.
@@ -184,7 +270,7 @@ pub fn run_game_headless(
         let mut player_ids = Vec::new();
         let mut failed_version_ids: Vec<i64> = Vec::new();
         for (i, entry) in players.iter().enumerate() {
-            match game.add_player(&entry.name, &entry.code) {
+            match game.add_player_with_api(&entry.name, &entry.code, entry.api) {
                 Ok(pid) => {
                     player_ids.push(pid);
                 }
@@ -204,17 +290,8 @@ pub fn run_game_headless(
         game.ensure_food_spawners();
         game.seed_initial_food();
 
-        // Spawn initial creatures
-        for &pid in &player_ids {
-            for _ in 0..2 {
-                let tile = game.world.borrow().find_plain_tile();
-                if let Some((tx_pos, ty_pos)) = tile {
-                    let cx = World::tile_center(tx_pos);
-                    let cy = World::tile_center(ty_pos);
-                    game.spawn_creature(pid, cx, cy, CREATURE_SMALL);
-                }
-            }
-        }
+        // Spawn initial creatures at the map's spawn points
+        game.spawn_initial_creatures(&player_ids);
 
         // Record initial world snapshot
         let world_snap = game.world_snapshot();
@@ -535,7 +612,7 @@ impl GameServer {
                 let mut player_ids = Vec::new();
                 let mut failed_version_ids: Vec<i64> = Vec::new();
                 for (i, entry) in players.iter().enumerate() {
-                    match game.add_player(&entry.name, &entry.code) {
+                    match game.add_player_with_api(&entry.name, &entry.code, entry.api) {
                         Ok(pid) => {
                             player_ids.push(pid);
                         }
@@ -564,18 +641,8 @@ impl GameServer {
                 // Place initial food from spawners
                 game.seed_initial_food();
 
-                // Spawn initial creatures for each player on random walkable tiles
-                for &pid in &player_ids {
-                    let initial_creatures = 2;
-                    for _ in 0..initial_creatures {
-                        let tile = game.world.borrow().find_plain_tile();
-                        if let Some((tx_pos, ty_pos)) = tile {
-                            let cx = World::tile_center(tx_pos);
-                            let cy = World::tile_center(ty_pos);
-                            game.spawn_creature(pid, cx, cy, CREATURE_SMALL);
-                        }
-                    }
-                }
+                // Spawn initial creatures for each player at the map's spawn points
+                game.spawn_initial_creatures(&player_ids);
 
                 // Send initial world snapshot and cache it for late joiners
                 let world_snap = game.world_snapshot();
@@ -814,6 +881,71 @@ mod tests {
         assert!(!world.food_spawners.is_empty());
     }
 
+    #[test]
+    fn test_load_map_level_script() {
+        let dir = std::env::temp_dir().join(format!("infon-levels-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let levels = Path::new(env!("CARGO_MANIFEST_DIR")).join("../orig_game/level");
+        std::fs::copy(levels.join("pacman.lua"), dir.join("pacman.lua")).unwrap();
+        std::fs::copy(levels.join("owl.lua"), dir.join("owl.lua")).unwrap();
+        let bundled = Path::new(env!("CARGO_MANIFEST_DIR")).join("../data/maps/owl.json");
+        std::fs::copy(bundled, dir.join("owl.json")).unwrap();
+
+        let world = load_map(&dir, "pacman").unwrap();
+        assert!(world.level_source.is_some());
+        assert_eq!(world.koth_pos(), (20, 21));
+        // A JSON map isn't shadowed by a level script of the same name
+        assert!(load_map(&dir, "owl").unwrap().level_source.is_none());
+
+        let maps = list_maps(&dir);
+        assert_eq!(maps.iter().filter(|m| m.name == "owl").count(), 1);
+        let pacman = maps.iter().find(|m| m.name == "pacman").unwrap();
+        assert_eq!((pacman.width, pacman.height), (world.width, world.height));
+        assert!(pacman.description.ends_with("level"));
+        // Listed again from the cache
+        assert_eq!(list_maps(&dir).len(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_bundled_level_scripts_load() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../data/maps");
+        let levels: Vec<MapInfo> = list_maps(&dir)
+            .into_iter()
+            .filter(|m| m.name.starts_with("level_"))
+            .collect();
+        assert_eq!(levels.len(), 9);
+        for level in &levels {
+            let world = load_map(&dir, &level.name).unwrap();
+            assert!(world.level_source.is_some(), "{}", level.name);
+            assert!(level.description.ends_with("level"), "{}", level.name);
+        }
+    }
+
+    #[test]
+    fn test_load_map_ascii_format() {
+        let dir = std::env::temp_dir().join(format!("infon-maps-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("tiny.map"),
+            "[meta]\ndescription = tiny arena\n[tiles]\nSSSS\nSPKS\nSSSS\n",
+        )
+        .unwrap();
+
+        let world = load_map(&dir, "tiny").unwrap();
+        assert_eq!((world.width, world.height), (4, 3));
+        assert_eq!(world.koth_pos(), (2, 1));
+
+        let maps = list_maps(&dir);
+        assert_eq!(maps.len(), 1);
+        assert_eq!(maps[0].width, 4);
+        assert_eq!(maps[0].description, "tiny arena");
+        assert!(load_map(&dir, "missing").is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_game_message_serialization() {
         let snap = GameSnapshot {
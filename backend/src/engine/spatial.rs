@@ -57,6 +57,54 @@ impl SpatialGrid {
         self.cells[idx].push(SpatialEntry { id, x, y, player_id });
     }
 
+    /// Remove a creature last inserted or moved to (x, y).
+    /// Returns false if it isn't in that cell.
+    pub fn remove(&mut self, id: u32, x: i32, y: i32) -> bool {
+        let (col, row) = self.cell_coords(x, y);
+        let cell = &mut self.cells[row * self.cols + col];
+        match cell.iter().position(|e| e.id == id) {
+            Some(pos) => {
+                cell.swap_remove(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move a creature from (old_x, old_y) to (x, y). The entry only changes
+    /// cells when the move crosses a cell boundary.
+    /// Returns false if the creature isn't in the old cell.
+    pub fn update(&mut self, id: u32, old_x: i32, old_y: i32, x: i32, y: i32) -> bool {
+        let (old_col, old_row) = self.cell_coords(old_x, old_y);
+        let (col, row) = self.cell_coords(x, y);
+        let old_idx = old_row * self.cols + old_col;
+        let Some(pos) = self.cells[old_idx].iter().position(|e| e.id == id) else {
+            return false;
+        };
+        let idx = row * self.cols + col;
+        if idx == old_idx {
+            let entry = &mut self.cells[idx][pos];
+            entry.x = x;
+            entry.y = y;
+        } else {
+            let mut entry = self.cells[old_idx].swap_remove(pos);
+            entry.x = x;
+            entry.y = y;
+            self.cells[idx].push(entry);
+        }
+        true
+    }
+
+    /// Total number of entries in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.iter().map(Vec::len).sum()
+    }
+
+    /// Returns true if the grid holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(Vec::is_empty)
+    }
+
     /// Find the nearest enemy creature to the given position.
     /// Returns (id, x, y, player_id, distance) or None if no enemies exist.
     pub fn find_nearest_enemy(
@@ -149,6 +197,27 @@ impl SpatialGrid {
         results
     }
 
+    /// Get all creatures within `radius` pixels of (x, y), visiting only the
+    /// cells the circle overlaps.
+    pub fn query_radius(&self, x: i32, y: i32, radius: i32) -> Vec<&SpatialEntry> {
+        let (c0, r0) = self.cell_coords(x - radius, y - radius);
+        let (c1, r1) = self.cell_coords(x + radius, y + radius);
+        let radius_sq = radius as i64 * radius as i64;
+        let mut results = Vec::new();
+        for row in r0..=r1 {
+            for col in c0..=c1 {
+                for entry in &self.cells[row * self.cols + col] {
+                    let dx = (entry.x - x) as i64;
+                    let dy = (entry.y - y) as i64;
+                    if dx * dx + dy * dy <= radius_sq {
+                        results.push(entry);
+                    }
+                }
+            }
+        }
+        results
+    }
+
     /// Convert pixel coordinates to cell coordinates, clamped to grid bounds.
     fn cell_coords(&self, x: i32, y: i32) -> (usize, usize) {
         let col = (x / CELL_SIZE).clamp(0, self.cols as i32 - 1) as usize;
@@ -215,6 +284,25 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_query_radius() {
+        let mut grid = SpatialGrid::new(20, 20);
+        grid.insert(1, 1000, 1000, 1);
+        grid.insert(2, 1300, 1000, 2); // dist 300
+        grid.insert(3, 1000, 1600, 2); // dist 600
+        grid.insert(4, 4000, 4000, 1); // far away
+
+        let mut ids: Vec<u32> = grid
+            .query_radius(1000, 1000, 600)
+            .iter()
+            .map(|e| e.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(grid.query_radius(1000, 1000, 599).len(), 2);
+        assert_eq!(grid.query_radius(-500, -500, 100).len(), 0);
+    }
+
     #[test]
     fn test_clear() {
         let mut grid = SpatialGrid::new(10, 10);
@@ -226,6 +314,30 @@ mod tests {
         assert_eq!(neighbors.len(), 0);
     }
 
+    #[test]
+    fn test_remove_and_update() {
+        let mut grid = SpatialGrid::new(10, 10);
+        grid.insert(1, 128, 128, 1);
+        grid.insert(2, 200, 200, 2);
+
+        // Within the same cell: position changes, cell doesn't
+        assert!(grid.update(2, 200, 200, 300, 300));
+        assert_eq!(grid.find_nearest_enemy(128, 128, 1).unwrap().1, 300);
+
+        // Across a cell boundary
+        assert!(grid.update(2, 300, 300, 2000, 300));
+        assert_eq!(grid.query_radius(128, 128, 600).len(), 1);
+        assert_eq!(grid.query_radius(2000, 300, 10)[0].id, 2);
+        assert!(!grid.update(2, 300, 300, 400, 400)); // stale old position
+
+        assert!(grid.remove(2, 2000, 300));
+        assert!(!grid.remove(2, 2000, 300));
+        assert!(grid.find_nearest_enemy(128, 128, 1).is_none());
+        assert_eq!(grid.len(), 1);
+        assert!(grid.remove(1, 128, 128));
+        assert!(grid.is_empty());
+    }
+
     #[test]
     fn test_cell_coords_clamping() {
         let grid = SpatialGrid::new(10, 10);
@@ -0,0 +1,216 @@
+// Map symmetries for the random map generator.
+//
+// A symmetric map gives every player slot the same distances to walls, food
+// spawners and the KOTH. Mirror and 180° symmetries are exact on the tile grid
+// (the generator uses odd dimensions so the centre is a tile). N-fold radial
+// symmetry is exact for 2 and 4 folds and approximated by rounding otherwise.
+
+use std::f64::consts::PI;
+
+/// Symmetry applied by `World::generate_random`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MapSymmetry {
+    /// No symmetry (plain cellular automata).
+    #[default]
+    None,
+    /// Left half mirrored onto the right half.
+    MirrorHorizontal,
+    /// Top half mirrored onto the bottom half.
+    MirrorVertical,
+    /// 180° rotation about the centre.
+    Rotational,
+    /// N-fold rotation about the centre, for FFA maps. Forces a square map.
+    Radial(usize),
+}
+
+/// Fold counts accepted for radial symmetry.
+pub const MAX_RADIAL_FOLDS: usize = 8;
+
+impl MapSymmetry {
+    /// Parse a symmetry name as used in map parameters: `none`, `mirror_horizontal`,
+    /// `mirror_vertical`, `rotational` or `radial` (with `folds` in 2..=8).
+    pub fn from_str_name(name: &str, folds: Option<usize>) -> Option<Self> {
+        match name {
+            "none" => Some(Self::None),
+            "mirror_horizontal" => Some(Self::MirrorHorizontal),
+            "mirror_vertical" => Some(Self::MirrorVertical),
+            "rotational" => Some(Self::Rotational),
+            "radial" => {
+                let folds = folds.unwrap_or(4);
+                (2..=MAX_RADIAL_FOLDS)
+                    .contains(&folds)
+                    .then_some(Self::Radial(folds))
+            }
+            _ => None,
+        }
+    }
+
+    /// Adjust map dimensions so the symmetry centre falls on a tile:
+    /// odd width and height, and a square for radial symmetry.
+    pub fn adjust_size(self, width: usize, height: usize) -> (usize, usize) {
+        let odd = |n: usize| if n.is_multiple_of(2) { n - 1 } else { n };
+        match self {
+            Self::None => (width, height),
+            Self::Radial(_) => {
+                let size = odd(width.min(height));
+                (size, size)
+            }
+            _ => (odd(width), odd(height)),
+        }
+    }
+
+    /// Map a tile to the tile whose value it copies. Tiles in the same orbit
+    /// share a canonical tile.
+    pub fn canonical(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Self::None => (x, y),
+            Self::MirrorHorizontal => (x.min(width - 1 - x), y),
+            Self::MirrorVertical => (x, y.min(height - 1 - y)),
+            Self::Rotational => {
+                let (rx, ry) = (width - 1 - x, height - 1 - y);
+                if (ry, rx) < (y, x) {
+                    (rx, ry)
+                } else {
+                    (x, y)
+                }
+            }
+            Self::Radial(n) => {
+                let (cx, cy) = center(width, height);
+                let (dx, dy) = (x as f64 - cx, y as f64 - cy);
+                if dx == 0.0 && dy == 0.0 {
+                    return (x, y);
+                }
+                let wedge = 2.0 * PI / n as f64;
+                let angle = dy.atan2(dx).rem_euclid(2.0 * PI);
+                let k = ((angle / wedge).floor() as usize).min(n - 1);
+                rotate(x, y, -(k as f64) * wedge, width, height)
+            }
+        }
+    }
+
+    /// All images of a tile under the symmetry, starting with the tile itself.
+    /// For radial symmetry, image `k` is the tile rotated by `k` folds.
+    pub fn images(self, x: usize, y: usize, width: usize, height: usize) -> Vec<(usize, usize)> {
+        let mut out = vec![(x, y)];
+        match self {
+            Self::None => {}
+            Self::MirrorHorizontal => out.push((width - 1 - x, y)),
+            Self::MirrorVertical => out.push((x, height - 1 - y)),
+            Self::Rotational => out.push((width - 1 - x, height - 1 - y)),
+            Self::Radial(n) => {
+                let wedge = 2.0 * PI / n as f64;
+                for k in 1..n {
+                    out.push(rotate(x, y, k as f64 * wedge, width, height));
+                }
+            }
+        }
+        out
+    }
+
+    /// Directions (radians, y pointing down) from the centre to each player
+    /// slot's spawn area, in slot order. Empty for `None`.
+    pub fn spawn_angles(self) -> Vec<f64> {
+        match self {
+            Self::None => Vec::new(),
+            Self::MirrorHorizontal | Self::Rotational => vec![PI, 0.0],
+            Self::MirrorVertical => vec![1.5 * PI, 0.5 * PI],
+            Self::Radial(n) => (0..n)
+                .map(|k| PI + k as f64 * 2.0 * PI / n as f64)
+                .collect(),
+        }
+    }
+
+    /// Make a tile grid symmetric by copying every tile from its canonical tile.
+    pub fn symmetrize(self, grid: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let height = grid.len();
+        let width = grid.first().map_or(0, |row| row.len());
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let (sx, sy) = self.canonical(x, y, width, height);
+                        grid[sy][sx]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Centre of the map in tile coordinates.
+pub fn center(width: usize, height: usize) -> (f64, f64) {
+    ((width - 1) as f64 / 2.0, (height - 1) as f64 / 2.0)
+}
+
+/// Rotate a tile about the map centre, rounding to the nearest tile.
+fn rotate(x: usize, y: usize, angle: f64, width: usize, height: usize) -> (usize, usize) {
+    let (cx, cy) = center(width, height);
+    let (dx, dy) = (x as f64 - cx, y as f64 - cy);
+    let (sin, cos) = angle.sin_cos();
+    let rx = (cx + dx * cos - dy * sin).round();
+    let ry = (cy + dx * sin + dy * cos).round();
+    (
+        (rx.max(0.0) as usize).min(width - 1),
+        (ry.max(0.0) as usize).min(height - 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_name() {
+        assert_eq!(
+            MapSymmetry::from_str_name("none", None),
+            Some(MapSymmetry::None)
+        );
+        assert_eq!(
+            MapSymmetry::from_str_name("rotational", None),
+            Some(MapSymmetry::Rotational)
+        );
+        assert_eq!(
+            MapSymmetry::from_str_name("radial", Some(6)),
+            Some(MapSymmetry::Radial(6))
+        );
+        assert_eq!(MapSymmetry::from_str_name("radial", Some(1)), None);
+        assert_eq!(MapSymmetry::from_str_name("radial", Some(9)), None);
+        assert_eq!(MapSymmetry::from_str_name("spiral", None), None);
+    }
+
+    #[test]
+    fn test_adjust_size() {
+        assert_eq!(MapSymmetry::None.adjust_size(30, 40), (30, 40));
+        assert_eq!(MapSymmetry::MirrorHorizontal.adjust_size(30, 41), (29, 41));
+        assert_eq!(MapSymmetry::Radial(3).adjust_size(40, 31), (31, 31));
+    }
+
+    #[test]
+    fn test_exact_symmetries_are_consistent() {
+        let (w, h) = (9, 7);
+        for sym in [
+            MapSymmetry::MirrorHorizontal,
+            MapSymmetry::MirrorVertical,
+            MapSymmetry::Rotational,
+        ] {
+            for y in 0..h {
+                for x in 0..w {
+                    let c = sym.canonical(x, y, w, h);
+                    for (ix, iy) in sym.images(x, y, w, h) {
+                        assert_eq!(sym.canonical(ix, iy, w, h), c, "{sym:?} at ({x}, {y})");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radial_four_fold_is_exact() {
+        let sym = MapSymmetry::Radial(4);
+        assert_eq!(sym.images(1, 3, 9, 9), vec![(1, 3), (5, 1), (7, 5), (3, 7)]);
+        assert_eq!(sym.canonical(4, 4, 9, 9), (4, 4));
+        for &(x, y) in &sym.images(1, 3, 9, 9) {
+            assert_eq!(sym.canonical(x, y, 9, 9), sym.canonical(1, 3, 9, 9));
+        }
+    }
+}
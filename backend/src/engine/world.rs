@@ -1,18 +1,24 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, VecDeque};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
 
 use rand::Rng;
 use serde::Deserialize;
 
 use super::config::*;
+use super::food_index::{FoodInRadius, FoodIndex, NearestFood};
+use super::pathfinding::{PathConfig, Pathfinder};
+use super::symmetry::{self, MapSymmetry};
 
 /// Parameters for random map generation.
+#[derive(Clone)]
 pub struct RandomMapParams {
     pub width: usize,
     pub height: usize,
     pub wall_density: f64,
     pub food_amount: i32,
     pub num_food_spots: usize,
+    /// Symmetry for walls, food spawners and spawn points.
+    pub symmetry: MapSymmetry,
 }
 
 impl Default for RandomMapParams {
@@ -23,6 +29,7 @@ impl Default for RandomMapParams {
             wall_density: 0.35,
             food_amount: 50000,
             num_food_spots: 10,
+            symmetry: MapSymmetry::None,
         }
     }
 }
@@ -45,6 +52,84 @@ impl Default for Tile {
     }
 }
 
+/// Gameplay effects of a tile's terrain for ground creatures (see
+/// `TerrainRules`). Flyers ignore terrain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Terrain {
+    /// Ground speed in percent of normal.
+    pub speed: i32,
+    /// Health lost per second.
+    pub damage: i32,
+    /// Whether food spawners grow food here.
+    pub food_growth: bool,
+    /// A* cost of entering the tile (`MIN_PATH_COST` = plain).
+    pub path_cost: i32,
+}
+
+/// The terrain ruleset: effects per tile gfx, read by the game while it runs.
+/// Solid water or lava blocks all but flyers like any other solid tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerrainRules {
+    by_gfx: [Terrain; TILE_GFX_TYPES],
+}
+
+impl Default for TerrainRules {
+    /// Standard rules: water halves ground speed and snow slows it to 75%,
+    /// lava burns 1,000 HP/s, and food doesn't grow on desert.
+    fn default() -> Self {
+        let plain = Terrain {
+            speed: 100,
+            damage: 0,
+            food_growth: true,
+            path_cost: MIN_PATH_COST,
+        };
+        let mut by_gfx = [plain; TILE_GFX_TYPES];
+        by_gfx[TILE_GFX_SNOW_PLAIN as usize] = Terrain { speed: 75, path_cost: 13, ..plain };
+        by_gfx[TILE_GFX_WATER as usize] = Terrain { speed: 50, path_cost: 20, ..plain };
+        by_gfx[TILE_GFX_LAVA as usize] = Terrain { damage: 1000, path_cost: 60, ..plain };
+        by_gfx[TILE_GFX_DESERT as usize] = Terrain { food_growth: false, ..plain };
+        TerrainRules { by_gfx }
+    }
+}
+
+impl TerrainRules {
+    /// The standard rules with overrides from `terrain_<gfx>_<effect>` map
+    /// metadata, where `<gfx>` is a name from `TILE_GFX_NAMES` and `<effect>`
+    /// is `speed`, `damage`, `food_growth` or `path_cost` (e.g.
+    /// `terrain_water_speed = 30`). Values that don't parse are ignored, and
+    /// path costs stay at least `MIN_PATH_COST`.
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Self {
+        let mut rules = TerrainRules::default();
+        for (name, terrain) in TILE_GFX_NAMES.iter().zip(rules.by_gfx.iter_mut()) {
+            let value = |effect: &str| metadata.get(&format!("terrain_{name}_{effect}"));
+            let number = |effect: &str| value(effect).and_then(|v| v.trim().parse::<i32>().ok());
+            if let Some(speed) = number("speed") {
+                terrain.speed = speed.max(0);
+            }
+            if let Some(damage) = number("damage") {
+                terrain.damage = damage;
+            }
+            if let Some(growth) = value("food_growth").and_then(|v| v.trim().parse().ok()) {
+                terrain.food_growth = growth;
+            }
+            if let Some(cost) = number("path_cost") {
+                terrain.path_cost = cost.max(MIN_PATH_COST);
+            }
+        }
+        rules
+    }
+
+    /// Terrain for a tile gfx. Unknown gfx values behave like plain ground.
+    pub fn get(&self, gfx: u8) -> Terrain {
+        let i = if (gfx as usize) < TILE_GFX_TYPES {
+            gfx as usize
+        } else {
+            TILE_GFX_PLAIN as usize
+        };
+        self.by_gfx[i]
+    }
+}
+
 /// Food spawner definition loaded from map JSON.
 #[derive(Clone, Debug, Deserialize)]
 pub struct FoodSpawner {
@@ -56,6 +141,7 @@ pub struct FoodSpawner {
 }
 
 /// The game world: a 2D tile grid with food, pathfinding, and coordinate conversions.
+#[derive(Clone)]
 pub struct World {
     pub width: usize,
     pub height: usize,
@@ -63,13 +149,25 @@ pub struct World {
     pub koth_x: usize,
     pub koth_y: usize,
     pub food_spawners: Vec<FoodSpawner>,
+    /// Tile positions where players' initial creatures are placed.
+    pub spawn_points: Vec<(usize, usize)>,
+    /// Free-form map metadata (name, author, description, ...).
+    pub metadata: BTreeMap<String, String>,
+    /// Source of the original Lua level script this world was built from, if any.
+    /// The game re-loads it to run `level_tick` each tick (see `engine::level`).
+    pub level_source: Option<String>,
+    /// Ground pathfinding state (options, path cache, cluster graph).
+    pathfinder: RefCell<Pathfinder>,
+    /// Terrain effects in play (see `set_terrain_rules`).
+    terrain_rules: TerrainRules,
+    /// Bucketed index of non-empty food tiles, kept in sync by `add_food`/`eat_food`.
+    food_index: FoodIndex,
 }
 
 // --- JSON deserialization helpers ---
 
 #[derive(Deserialize)]
 struct MapJson {
-    #[allow(dead_code)]
     name: Option<String>,
     width: usize,
     height: usize,
@@ -77,6 +175,8 @@ struct MapJson {
     koth_y: Option<usize>,
     tiles: Vec<TileJson>,
     food_spawners: Option<Vec<FoodSpawner>>,
+    spawn_points: Option<Vec<(usize, usize)>>,
+    metadata: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Deserialize)]
@@ -88,25 +188,143 @@ struct TileJson {
     gfx: Option<u8>,
 }
 
-// --- A* internals ---
+// --- ASCII map format helpers ---
+
+/// Glyphs for each tile gfx, in `TILE_GFX_*` order, matching the original
+/// `maplayout()` legend (see `ascii_glyph` for the solid and plain glyphs
+/// export uses). As in the original level scripts, case doesn't matter
+/// and a glyph gives the gfx's usual tile type: plain for `P`, `U`, `K`, `D`,
+/// solid for the rest.
+const ASCII_GLYPHS: [char; 11] = ['S', 'P', 'B', 'T', 'U', 'V', 'W', 'L', 'N', 'K', 'D'];
+
+/// Whether a gfx's glyph makes the tile walkable.
+fn gfx_default_plain(gfx: u8) -> bool {
+    matches!(
+        gfx,
+        TILE_GFX_PLAIN | TILE_GFX_SNOW_PLAIN | TILE_GFX_KOTH | TILE_GFX_DESERT
+    )
+}
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct AStarNode {
-    cost: i32,
-    x: usize,
-    y: usize,
+/// Tile type implied by a gfx's glyph.
+fn gfx_default_type(gfx: u8) -> u8 {
+    if gfx_default_plain(gfx) {
+        TILE_PLAIN
+    } else {
+        TILE_SOLID
+    }
+}
+
+/// Map a tile gfx to the glyph `to_ascii` writes: the `maplayout()` legend
+/// with `@` for solid and `.` for plain, as in the pacman level.
+fn ascii_glyph(gfx: u8) -> char {
+    match gfx {
+        TILE_GFX_SOLID => '@',
+        TILE_GFX_PLAIN => '.',
+        _ => ASCII_GLYPHS.get(gfx as usize).copied().unwrap_or('@'),
+    }
+}
+
+/// Map an ASCII glyph to (tile_type, gfx). `@` is accepted for solid and
+/// ` ` / `.` for plain, as used by the pacman level.
+fn parse_ascii_glyph(c: char) -> Option<(u8, u8)> {
+    match c {
+        '@' => return Some((TILE_SOLID, TILE_GFX_SOLID)),
+        ' ' | '.' => return Some((TILE_PLAIN, TILE_GFX_PLAIN)),
+        _ => {}
+    }
+    let gfx = ASCII_GLYPHS.iter().position(|&g| g == c.to_ascii_uppercase())? as u8;
+    Some((gfx_default_type(gfx), gfx))
+}
+
+/// Escape a `[meta]` key or value so it stays on one line and its `=` can't
+/// be taken for the separator.
+fn escape_meta(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '=' => out.push_str("\\="),
+            _ => out.push(c),
+        }
+    }
+    out
 }
 
-impl Ord for AStarNode {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost) // min-heap via reversed ordering
+/// Reverse `escape_meta`. Unknown escapes keep the escaped character.
+fn unescape_meta(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
     }
+    out
 }
 
-impl PartialOrd for AStarNode {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+/// Split a `[meta]` line at its first unescaped `=`.
+fn split_meta(line: &str) -> Option<(&str, &str)> {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => return Some((&line[..i], &line[i + 1..])),
+            _ => {}
+        }
     }
+    None
+}
+
+/// Parse one tile row, expanding run-length counts (`12S`).
+fn parse_ascii_row(line: &str) -> Result<Vec<(u8, u8)>, String> {
+    let mut row = Vec::new();
+    let mut count: Option<usize> = None;
+    for c in line.chars() {
+        if let Some(d) = c.to_digit(10) {
+            count = Some(count.unwrap_or(0) * 10 + d as usize);
+            if count > Some(MAX_ASCII_RUN) {
+                return Err(format!("run length exceeds {MAX_ASCII_RUN}"));
+            }
+            continue;
+        }
+        let tile = parse_ascii_glyph(c).ok_or_else(|| format!("unknown tile glyph '{c}'"))?;
+        let run = count.take().unwrap_or(1);
+        if row.len() + run > MAP_MAX_SIZE {
+            return Err(format!("row is wider than {MAP_MAX_SIZE} tiles"));
+        }
+        row.extend(std::iter::repeat_n(tile, run));
+    }
+    if count.is_some() {
+        return Err("run length without a glyph".into());
+    }
+    Ok(row)
+}
+
+/// Upper bound for a single run-length count in a tile row.
+const MAX_ASCII_RUN: usize = 1024;
+
+/// Parse exactly `n` whitespace-separated integers.
+fn parse_numbers(line: &str, n: usize, line_no: usize) -> Result<Vec<i64>, String> {
+    let nums: Vec<i64> = line
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.parse::<i64>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("line {line_no}: {e}"))?;
+    if nums.len() != n || nums.iter().any(|&v| v < 0) {
+        return Err(format!("line {line_no}: expected {n} non-negative numbers"));
+    }
+    Ok(nums)
 }
 
 impl World {
@@ -119,6 +337,12 @@ impl World {
             koth_x: width / 2,
             koth_y: height / 2,
             food_spawners: Vec::new(),
+            spawn_points: Vec::new(),
+            metadata: BTreeMap::new(),
+            level_source: None,
+            pathfinder: RefCell::default(),
+            terrain_rules: TerrainRules::default(),
+            food_index: FoodIndex::new(width, height),
         }
     }
 
@@ -155,41 +379,27 @@ impl World {
             wall_density: 0.30, // slightly less walls for larger maps
             food_amount,
             num_food_spots,
+            symmetry: MapSymmetry::None,
         };
 
         let mut world = Self::generate_random(params);
 
         // Add spawn points spread around the map for each player
         // Place them in a ring around the center, evenly spaced
-        let center_x = size / 2;
-        let center_y = size / 2;
-        let ring_radius = (size as f64 * 0.35) as usize; // 35% of map size
-
-        let walkable: Vec<(usize, usize)> = (1..size - 1)
-            .flat_map(|x| (1..size - 1).map(move |y| (x, y)))
-            .filter(|&(x, y)| world.is_walkable(x, y))
+        let angles: Vec<f64> = (0..player_count)
+            .map(|i| 2.0 * std::f64::consts::PI * (i as f64) / (player_count as f64))
             .collect();
-
-        for i in 0..player_count {
-            let angle = 2.0 * std::f64::consts::PI * (i as f64) / (player_count as f64);
-            let target_x = center_x as f64 + ring_radius as f64 * angle.cos();
-            let target_y = center_y as f64 + ring_radius as f64 * angle.sin();
-
-            // Find nearest walkable tile to the target spawn position
-            if let Some(&(sx, sy)) = walkable.iter().min_by_key(|&&(wx, wy)| {
-                let dx = wx as f64 - target_x;
-                let dy = wy as f64 - target_y;
-                (dx * dx + dy * dy) as usize
-            }) {
-                // Add a food spawner near each spawn point so players start near food
-                world.food_spawners.push(FoodSpawner {
-                    x: sx,
-                    y: sy,
-                    radius: 3,
-                    amount: 800,
-                    interval: 3000,
-                });
-            }
+        world.spawn_points = world.ring_spawn_points(&angles);
+
+        for &(sx, sy) in &world.spawn_points.clone() {
+            // Add a food spawner near each spawn point so players start near food
+            world.food_spawners.push(FoodSpawner {
+                x: sx,
+                y: sy,
+                radius: 3,
+                amount: 800,
+                interval: 3000,
+            });
         }
 
         world
@@ -204,9 +414,14 @@ impl World {
     /// 4. Flood-fill to find largest connected walkable region; fill smaller regions with solid
     /// 5. Place KOTH at nearest walkable tile to map center
     /// 6. Scatter food spawners on random walkable tiles
+    ///
+    /// With a symmetry set, steps 2 and 3 keep the grid symmetric and the rest
+    /// is done by `finish_symmetric`.
     pub fn generate_random(params: RandomMapParams) -> Self {
-        let width = params.width.clamp(20, 150);
-        let height = params.height.clamp(20, 150);
+        let (width, height) = params
+            .symmetry
+            .adjust_size(params.width.clamp(20, 150), params.height.clamp(20, 150));
+        let symmetric = params.symmetry != MapSymmetry::None;
         let wall_density = params.wall_density.clamp(0.0, 0.6);
         let mut rng = rand::thread_rng();
 
@@ -221,6 +436,9 @@ impl World {
                 }
             }
         }
+        if symmetric {
+            grid = params.symmetry.symmetrize(&grid);
+        }
 
         // Step 3: Cellular automata smoothing (5 iterations)
         for _ in 0..5 {
@@ -248,7 +466,15 @@ impl World {
                     // 4 neighbors: keep current state
                 }
             }
-            grid = new_grid;
+            grid = if symmetric {
+                params.symmetry.symmetrize(&new_grid)
+            } else {
+                new_grid
+            };
+        }
+
+        if symmetric {
+            return Self::finish_symmetric(grid, &params, &mut rng);
         }
 
         // Step 4: Flood-fill to find the largest connected walkable region
@@ -366,9 +592,200 @@ impl World {
             }
         }
 
+        // Step 7: Spawn points on a ring around the KOTH. Opposite pairs come
+        // first so the first two slots (a 1v1) start across from each other.
+        let quarter = std::f64::consts::FRAC_PI_2;
+        world.spawn_points = world.ring_spawn_points(&[0.0, 2.0 * quarter, quarter, 3.0 * quarter]);
+
         world
     }
 
+    /// Finish a symmetric random map from a symmetric wall grid.
+    ///
+    /// Clears the centre and a corridor from the centre towards each player's
+    /// spawn area so all slots share one connected region, drops unreachable
+    /// pockets, and places the KOTH at the centre. Spawn points and food
+    /// spawners are placed once and copied to every symmetric image.
+    fn finish_symmetric(
+        mut grid: Vec<Vec<u8>>,
+        params: &RandomMapParams,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let sym = params.symmetry;
+        let height = grid.len();
+        let width = grid[0].len();
+        let (cx, cy) = symmetry::center(width, height);
+        let ring_radius = width.min(height) as f64 * 0.35;
+
+        // Carve a plus-shaped brush, staying inside the border
+        let mut carve = |x: f64, y: f64| {
+            let (x, y) = (x.round() as i32, y.round() as i32);
+            for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx >= 1 && ny >= 1 && (nx as usize) < width - 1 && (ny as usize) < height - 1 {
+                    grid[ny as usize][nx as usize] = TILE_PLAIN;
+                }
+            }
+        };
+        for (dx, dy) in [(0.0, 0.0), (1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+            carve(cx + dx, cy + dy);
+        }
+        let angles = sym.spawn_angles();
+        for &angle in &angles {
+            let steps = (ring_radius * 2.0).ceil() as usize;
+            for i in 0..=steps {
+                let t = ring_radius * i as f64 / steps as f64;
+                carve(cx + t * angle.cos(), cy + t * angle.sin());
+            }
+        }
+
+        // Keep only the region connected to the centre
+        let mut world = World::new(width, height);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, &tile) in row.iter().enumerate() {
+                if tile == TILE_PLAIN {
+                    world.set_type(x, y, TILE_PLAIN);
+                }
+            }
+        }
+        let (kx, ky) = (cx as usize, cy as usize);
+        let mut reached = vec![false; world.tiles.len()];
+        let mut queue = VecDeque::new();
+        reached[world.index(kx, ky)] = true;
+        queue.push_back((kx, ky));
+        while let Some((x, y)) = queue.pop_front() {
+            for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                let idx = world.index(nx, ny);
+                if world.is_walkable(nx, ny) && !reached[idx] {
+                    reached[idx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        for (tile, &r) in world.tiles.iter_mut().zip(reached.iter()) {
+            if !r {
+                tile.tile_type = TILE_SOLID;
+                tile.gfx = TILE_GFX_SOLID;
+            }
+        }
+        world.koth_x = kx;
+        world.koth_y = ky;
+
+        // Spawn points: one per slot, all images of the first one
+        let base = world.nearest_walkable(
+            cx + ring_radius * angles[0].cos(),
+            cy + ring_radius * angles[0].sin(),
+        );
+        if let Some((bx, by)) = base {
+            world.spawn_points = world.symmetric_images(bx, by, sym);
+        }
+
+        // Food spawners: random base spots copied to every image
+        let walkable: Vec<(usize, usize)> = (0..world.tiles.len())
+            .filter(|&i| reached[i])
+            .map(|i| (i % width, i / width))
+            .collect();
+        let orbit = sym.images(0, 0, width, height).len();
+        let food_per_spot = params.food_amount / params.num_food_spots.max(1) as i32;
+        let base_spots = params.num_food_spots.div_ceil(orbit).min(walkable.len());
+        for _ in 0..base_spots {
+            let (fx, fy) = walkable[rng.gen_range(0..walkable.len())];
+            let radius = rng.gen_range(2..=4);
+            for (x, y) in world.symmetric_images(fx, fy, sym) {
+                world.food_spawners.push(FoodSpawner {
+                    x,
+                    y,
+                    radius,
+                    amount: food_per_spot / 20,
+                    interval: 5000,
+                });
+            }
+        }
+
+        world
+    }
+
+    /// Symmetric images of a walkable tile, each snapped to the nearest
+    /// walkable tile not already taken by an earlier image. A tile that is its
+    /// own image (on a mirror axis, or the centre) is kept once; approximate
+    /// radial folds can also round two other images onto one tile, and those
+    /// are snapped apart so every fold gets its own tile.
+    fn symmetric_images(&self, x: usize, y: usize, sym: MapSymmetry) -> Vec<(usize, usize)> {
+        let mut out: Vec<(usize, usize)> = Vec::new();
+        for (ix, iy) in sym.images(x, y, self.width, self.height) {
+            if !out.is_empty() && (ix, iy) == (x, y) {
+                continue;
+            }
+            let p = if self.is_walkable(ix, iy) && !out.contains(&(ix, iy)) {
+                Some((ix, iy))
+            } else {
+                self.nearest_free_walkable(ix as f64, iy as f64, &out)
+            };
+            out.extend(p);
+        }
+        out
+    }
+
+    /// The walkable tile nearest to a position in tile coordinates.
+    fn nearest_walkable(&self, target_x: f64, target_y: f64) -> Option<(usize, usize)> {
+        self.nearest_free_walkable(target_x, target_y, &[])
+    }
+
+    /// The walkable tile nearest to a position, skipping the `taken` tiles.
+    fn nearest_free_walkable(
+        &self,
+        target_x: f64,
+        target_y: f64,
+        taken: &[(usize, usize)],
+    ) -> Option<(usize, usize)> {
+        (1..self.height.saturating_sub(1))
+            .flat_map(|y| (1..self.width.saturating_sub(1)).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.is_walkable(x, y) && !taken.contains(&(x, y)))
+            .min_by(|&(ax, ay), &(bx, by)| {
+                let da = (ax as f64 - target_x).powi(2) + (ay as f64 - target_y).powi(2);
+                let db = (bx as f64 - target_x).powi(2) + (by as f64 - target_y).powi(2);
+                da.total_cmp(&db)
+            })
+    }
+
+    /// Find the walkable tile nearest to each angle on a ring around the map
+    /// center (radius 35% of the map size). Angles are in radians.
+    fn ring_spawn_points(&self, angles: &[f64]) -> Vec<(usize, usize)> {
+        let center_x = self.width as f64 / 2.0;
+        let center_y = self.height as f64 / 2.0;
+        let ring_radius = self.width.min(self.height) as f64 * 0.35;
+
+        angles
+            .iter()
+            .filter_map(|angle| {
+                self.nearest_walkable(
+                    center_x + ring_radius * angle.cos(),
+                    center_y + ring_radius * angle.sin(),
+                )
+            })
+            .collect()
+    }
+
+    /// Rotate spawn point assignment by `offset` slots among the first
+    /// `player_count` spawn points, the ones the game's players start from, so
+    /// that across a series of games every player slot starts from each of
+    /// them. A 1v1 on a four-spawn map keeps its opposing pair.
+    pub fn rotate_spawn_points(&mut self, offset: usize, player_count: usize) {
+        let n = player_count.min(self.spawn_points.len());
+        if n > 0 {
+            self.spawn_points[..n].rotate_left(offset % n);
+        }
+    }
+
+    /// Starting tile for a player slot: the slot's spawn point if the map
+    /// defines enough of them, otherwise a random plain tile.
+    pub fn spawn_tile_for_slot(&self, slot: usize) -> Option<(usize, usize)> {
+        match self.spawn_points.get(slot) {
+            Some(&p) => Some(p),
+            None => self.find_plain_tile(),
+        }
+    }
+
     /// Load a world from the JSON map format.
     pub fn from_json(json: &str) -> Result<Self, String> {
         let map: MapJson =
@@ -382,6 +799,11 @@ impl World {
         world.koth_x = map.koth_x.unwrap_or(map.width / 2);
         world.koth_y = map.koth_y.unwrap_or(map.height / 2);
         world.food_spawners = map.food_spawners.unwrap_or_default();
+        world.spawn_points = map.spawn_points.unwrap_or_default();
+        world.metadata = map.metadata.unwrap_or_default();
+        if let Some(name) = map.name {
+            world.metadata.entry("name".into()).or_insert(name);
+        }
 
         for t in &map.tiles {
             if t.x >= map.width || t.y >= map.height {
@@ -395,10 +817,319 @@ impl World {
                 TILE_GFX_SOLID
             });
         }
+        for &(x, y) in &world.spawn_points {
+            if x >= map.width || y >= map.height {
+                return Err(format!("Spawn point ({x}, {y}) out of bounds"));
+            }
+        }
+
+        Ok(world)
+    }
+
+    /// Export the world in the JSON map format read by `from_json`.
+    /// Only non-solid tiles and tiles with non-default gfx are written.
+    pub fn to_json(&self) -> String {
+        let mut tiles = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let t = &self.tiles[self.index(x, y)];
+                if t.tile_type == TILE_SOLID && t.gfx == TILE_GFX_SOLID {
+                    continue;
+                }
+                tiles.push(serde_json::json!({
+                    "x": x, "y": y, "type": t.tile_type, "gfx": t.gfx,
+                }));
+            }
+        }
+        let mut map = serde_json::json!({
+            "width": self.width,
+            "height": self.height,
+            "koth_x": self.koth_x,
+            "koth_y": self.koth_y,
+            "tiles": tiles,
+            "food_spawners": self.food_spawners.iter().map(|s| serde_json::json!({
+                "x": s.x, "y": s.y, "radius": s.radius, "amount": s.amount, "interval": s.interval,
+            })).collect::<Vec<_>>(),
+            "spawn_points": self.spawn_points,
+        });
+        if let Some(name) = self.metadata.get("name") {
+            map["name"] = serde_json::json!(name);
+        }
+        if !self.metadata.is_empty() {
+            map["metadata"] = serde_json::json!(self.metadata);
+        }
+        serde_json::to_string_pretty(&map).unwrap_or_default()
+    }
+
+    /// Load a world from the ASCII map format.
+    ///
+    /// The format is line based with optional `[meta]`, `[tiles]`, `[tile_types]`,
+    /// `[spawners]` and `[spawn_points]` sections. Lines before the first header are
+    /// tiles, so a bare `maplayout()` grid is a valid map. `#` starts a comment line.
+    ///
+    /// ```text
+    /// [meta]
+    /// name = arena
+    /// koth = 3,2
+    ///
+    /// [tiles]
+    /// SSSSSSS
+    /// SPPPPPS
+    /// SPPKPPS
+    /// SSSSSSS
+    ///
+    /// [tile_types]
+    /// # x y type (0 solid, 1 plain)
+    /// 6 2 1
+    ///
+    /// [spawners]
+    /// # x y radius amount interval
+    /// 2 1 2 500 2000
+    ///
+    /// [spawn_points]
+    /// # x y
+    /// 1 1
+    /// 5 2
+    /// ```
+    ///
+    /// Tile rows use one glyph per tile (see `ASCII_GLYPHS` for the legend). A decimal
+    /// count before a glyph repeats it (`12S` is twelve solid tiles). Short rows are
+    /// padded with solid tiles. `[tile_types]` overrides the type a glyph implies, for
+    /// tiles such as walkable water. `[meta]` keys and values escape `\`, `=` and line
+    /// breaks with a backslash. The KOTH comes from `koth` in `[meta]`, else from the
+    /// last `K` glyph, else the map center.
+    pub fn from_ascii(text: &str) -> Result<Self, String> {
+        let mut section = "tiles";
+        let mut rows: Vec<Vec<(u8, u8)>> = Vec::new();
+        let mut metadata = BTreeMap::new();
+        let mut koth: Option<(usize, usize)> = None;
+        let mut tile_types = Vec::new();
+        let mut food_spawners = Vec::new();
+        let mut spawn_points = Vec::new();
+
+        for (line_no, raw) in text.lines().enumerate() {
+            let line_no = line_no + 1;
+            let line = raw.trim_end_matches('\r');
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.trim().strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = match name {
+                    "meta" => "meta",
+                    "tiles" => "tiles",
+                    "tile_types" => "tile_types",
+                    "spawners" => "spawners",
+                    "spawn_points" => "spawn_points",
+                    _ => return Err(format!("line {line_no}: unknown section [{name}]")),
+                };
+                continue;
+            }
+
+            match section {
+                "tiles" => {
+                    // Bound the grid before `World::new` allocates it
+                    if rows.len() == MAP_MAX_SIZE {
+                        return Err(format!("line {line_no}: more than {MAP_MAX_SIZE} tile rows"));
+                    }
+                    rows.push(parse_ascii_row(line).map_err(|e| format!("line {line_no}: {e}"))?)
+                }
+                "meta" => {
+                    let (key, value) = split_meta(line)
+                        .ok_or_else(|| format!("line {line_no}: expected key = value"))?;
+                    let (key, value) = (unescape_meta(key.trim()), unescape_meta(value.trim()));
+                    if key == "koth" {
+                        let nums = parse_numbers(&value, 2, line_no)?;
+                        koth = Some((nums[0] as usize, nums[1] as usize));
+                    } else {
+                        metadata.insert(key, value);
+                    }
+                }
+                "tile_types" => {
+                    let nums = parse_numbers(line, 3, line_no)?;
+                    let tile_type = match nums[2] {
+                        0 => TILE_SOLID,
+                        1 => TILE_PLAIN,
+                        _ => return Err(format!("line {line_no}: tile type must be 0 or 1")),
+                    };
+                    tile_types.push((nums[0] as usize, nums[1] as usize, tile_type));
+                }
+                "spawners" => {
+                    let nums = parse_numbers(line, 5, line_no)?;
+                    food_spawners.push(FoodSpawner {
+                        x: nums[0] as usize,
+                        y: nums[1] as usize,
+                        radius: nums[2] as usize,
+                        amount: nums[3] as i32,
+                        interval: nums[4] as u32,
+                    });
+                }
+                _ => {
+                    let nums = parse_numbers(line, 2, line_no)?;
+                    spawn_points.push((nums[0] as usize, nums[1] as usize));
+                }
+            }
+        }
+
+        let height = rows.len();
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        if width == 0 || height == 0 {
+            return Err("World dimensions must be > 0".into());
+        }
+
+        let mut world = World::new(width, height);
+        let mut koth_glyph = None;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &(tile_type, gfx)) in row.iter().enumerate() {
+                let idx = world.index(x, y);
+                world.tiles[idx].tile_type = tile_type;
+                world.tiles[idx].gfx = gfx;
+                if gfx == TILE_GFX_KOTH {
+                    koth_glyph = Some((x, y));
+                }
+            }
+        }
+
+        for &(x, y, tile_type) in &tile_types {
+            if !world.is_on_map(x, y) {
+                return Err(format!("Tile type override ({x}, {y}) out of bounds"));
+            }
+            let idx = world.index(x, y);
+            world.tiles[idx].tile_type = tile_type;
+        }
 
+        let (kx, ky) = koth.or(koth_glyph).unwrap_or((width / 2, height / 2));
+        if !world.is_on_map(kx, ky) {
+            return Err(format!("KOTH ({kx}, {ky}) out of bounds"));
+        }
+        world.koth_x = kx;
+        world.koth_y = ky;
+
+        for s in &food_spawners {
+            if !world.is_on_map(s.x, s.y) {
+                return Err(format!("Food spawner ({}, {}) out of bounds", s.x, s.y));
+            }
+        }
+        for &(x, y) in &spawn_points {
+            if !world.is_on_map(x, y) {
+                return Err(format!("Spawn point ({x}, {y}) out of bounds"));
+            }
+        }
+        world.food_spawners = food_spawners;
+        world.spawn_points = spawn_points;
+        world.metadata = metadata;
         Ok(world)
     }
 
+    /// Export the world in the ASCII map format read by `from_ascii`, writing
+    /// solid tiles as `@` and plain ones as `.`.
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("[meta]\n");
+        for (key, value) in &self.metadata {
+            out.push_str(&format!("{} = {}\n", escape_meta(key), escape_meta(value)));
+        }
+        out.push_str(&format!("koth = {},{}\n", self.koth_x, self.koth_y));
+
+        out.push_str("\n[tiles]\n");
+        let mut overrides = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let t = &self.tiles[self.index(x, y)];
+                out.push(ascii_glyph(t.gfx));
+                if t.tile_type != gfx_default_type(t.gfx) {
+                    overrides.push((x, y, t.tile_type));
+                }
+            }
+            out.push('\n');
+        }
+
+        if !overrides.is_empty() {
+            out.push_str("\n[tile_types]\n# x y type (0 solid, 1 plain)\n");
+            for (x, y, tile_type) in overrides {
+                out.push_str(&format!("{x} {y} {tile_type}\n"));
+            }
+        }
+
+        if !self.food_spawners.is_empty() {
+            out.push_str("\n[spawners]\n# x y radius amount interval\n");
+            for s in &self.food_spawners {
+                out.push_str(&format!(
+                    "{} {} {} {} {}\n",
+                    s.x, s.y, s.radius, s.amount, s.interval
+                ));
+            }
+        }
+
+        if !self.spawn_points.is_empty() {
+            out.push_str("\n[spawn_points]\n# x y\n");
+            for (x, y) in &self.spawn_points {
+                out.push_str(&format!("{x} {y}\n"));
+            }
+        }
+
+        out
+    }
+
+    /// Check that a map is fit for play: within the size limits, with a
+    /// walkable KOTH tile and spawn points, and a single connected walkable area.
+    /// Used to vet user-uploaded maps.
+    pub fn validate_playable(&self) -> Result<(), String> {
+        if self.width < MAP_MIN_SIZE || self.height < MAP_MIN_SIZE {
+            return Err(format!("Map must be at least {MAP_MIN_SIZE}x{MAP_MIN_SIZE}"));
+        }
+        if self.width > MAP_MAX_SIZE || self.height > MAP_MAX_SIZE {
+            return Err(format!("Map must be at most {MAP_MAX_SIZE}x{MAP_MAX_SIZE}"));
+        }
+        if !self.is_walkable(self.koth_x, self.koth_y) {
+            return Err(format!(
+                "KOTH tile ({}, {}) is not walkable",
+                self.koth_x, self.koth_y
+            ));
+        }
+        for &(x, y) in &self.spawn_points {
+            if !self.is_walkable(x, y) {
+                return Err(format!("Spawn point ({x}, {y}) is not walkable"));
+            }
+        }
+
+        // Flood fill from the KOTH; every walkable tile must be reached.
+        let mut visited = vec![false; self.tiles.len()];
+        let mut queue = VecDeque::new();
+        visited[self.index(self.koth_x, self.koth_y)] = true;
+        queue.push_back((self.koth_x, self.koth_y));
+        while let Some((cx, cy)) = queue.pop_front() {
+            for &(dx, dy) in &[(1i32, 0i32), (-1, 0), (0, 1), (0, -1)] {
+                let nx = cx as i32 + dx;
+                let ny = cy as i32 + dy;
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                if !self.is_walkable(nx, ny) {
+                    continue;
+                }
+                let idx = self.index(nx, ny);
+                if !visited[idx] {
+                    visited[idx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        let unreachable = self
+            .tiles
+            .iter()
+            .zip(visited.iter())
+            .filter(|(t, &v)| t.tile_type == TILE_PLAIN && !v)
+            .count();
+        if unreachable > 0 {
+            return Err(format!(
+                "Walkable area is disconnected: {unreachable} tiles cannot reach the KOTH"
+            ));
+        }
+        Ok(())
+    }
+
     // --- Index helper ---
 
     #[inline]
@@ -423,6 +1154,17 @@ impl World {
         self.is_on_map(tx, ty) && self.tiles[self.index(tx, ty)].tile_type == TILE_PLAIN
     }
 
+    /// Returns true if a creature can be over the tile at (tx, ty): walkable
+    /// tiles for ground creatures, any tile on the map for flyers. Eating,
+    /// feeding and converting still need a walkable tile.
+    pub fn is_passable(&self, tx: usize, ty: usize, ground_based: bool) -> bool {
+        if ground_based {
+            self.is_walkable(tx, ty)
+        } else {
+            self.is_on_map(tx, ty)
+        }
+    }
+
     /// Get the tile type at (x, y). Returns TILE_SOLID for out-of-bounds.
     pub fn get_type(&self, x: usize, y: usize) -> u8 {
         if !self.is_on_map(x, y) {
@@ -431,6 +1173,17 @@ impl World {
         self.tiles[self.index(x, y)].tile_type
     }
 
+    /// Terrain effects of the tile at (x, y) under the world's terrain rules.
+    pub fn terrain(&self, x: usize, y: usize) -> Terrain {
+        self.terrain_rules.get(self.get_gfx(x, y))
+    }
+
+    /// Change the terrain rules. Clears cached paths, whose costs may change.
+    pub fn set_terrain_rules(&mut self, rules: TerrainRules) {
+        self.terrain_rules = rules;
+        self.pathfinder.get_mut().invalidate();
+    }
+
     /// Get the tile gfx at (x, y). Returns TILE_GFX_SOLID for out-of-bounds.
     pub fn get_gfx(&self, x: usize, y: usize) -> u8 {
         if !self.is_on_map(x, y) {
@@ -453,6 +1206,7 @@ impl World {
         let idx = self.index(x, y);
         self.tiles[idx].tile_type = tile_type;
         self.tiles[idx].gfx = TILE_GFX_PLAIN;
+        self.pathfinder.get_mut().invalidate();
         true
     }
 
@@ -463,6 +1217,7 @@ impl World {
         }
         let idx = self.index(x, y);
         self.tiles[idx].gfx = gfx;
+        self.pathfinder.get_mut().invalidate();
         true
     }
 
@@ -486,6 +1241,7 @@ impl World {
         let old = self.tiles[idx].food;
         let new_val = (old + amount).clamp(0, MAX_TILE_FOOD);
         self.tiles[idx].food = new_val;
+        self.food_index.update(x, y, old, new_val);
         new_val - old
     }
 
@@ -498,9 +1254,33 @@ impl World {
         let available = self.tiles[idx].food;
         let eaten = amount.min(available);
         self.tiles[idx].food -= eaten;
+        self.food_index.update(x, y, available, available - eaten);
         eaten
     }
 
+    /// Nearest walkable tile (by center, from pixel position (px, py)) with at
+    /// least `min_amount` food.
+    pub fn nearest_food(&self, px: i32, py: i32, min_amount: i32) -> NearestFood {
+        self.food_index.nearest(
+            px,
+            py,
+            min_amount,
+            |x, y| self.get_food(x, y),
+            |x, y| self.is_walkable(x, y),
+        )
+    }
+
+    /// Total food on tiles whose center is within `radius` pixels of (px, py).
+    pub fn food_in_radius(&self, px: i32, py: i32, radius: i32) -> FoodInRadius {
+        self.food_index
+            .within_radius(px, py, radius, |x, y| self.get_food(x, y))
+    }
+
+    /// Total food on the map.
+    pub fn total_food(&self) -> i64 {
+        self.food_index.total()
+    }
+
     // --- Utility ---
 
     /// Find a random walkable (TILE_PLAIN) tile. Returns None if no walkable tiles exist.
@@ -559,7 +1339,9 @@ impl World {
 
     // --- Pathfinding (A* on tile grid) ---
 
-    /// Find a path from (sx, sy) to (ex, ey) in pixel coordinates.
+    /// Find a path from (sx, sy) to (ex, ey) in pixel coordinates, preferring
+    /// cheap terrain (`Terrain::path_cost`). See `pathfinding` for the optional
+    /// diagonal, smoothing and hierarchical modes.
     /// Returns a list of waypoints (pixel coordinates of tile centers), or None if no path exists.
     pub fn find_path(&self, sx: i32, sy: i32, ex: i32, ey: i32) -> Option<Vec<(i32, i32)>> {
         let start_tx = Self::pixel_to_tile(sx);
@@ -575,98 +1357,56 @@ impl World {
             return Some(vec![(Self::tile_center(end_tx), Self::tile_center(end_ty))]);
         }
 
-        let w = self.width;
-        let h = self.height;
-        let size = w * h;
-
-        let mut g_score = vec![i32::MAX; size];
-        let mut came_from = vec![usize::MAX; size];
-        let mut closed = vec![false; size];
-
-        let start_idx = start_ty * w + start_tx;
-        let end_idx = end_ty * w + end_tx;
-
-        g_score[start_idx] = 0;
-
-        let heuristic = |tx: usize, ty: usize| -> i32 {
-            let dx = (tx as i32 - end_tx as i32).abs();
-            let dy = (ty as i32 - end_ty as i32).abs();
-            dx + dy // Manhattan distance
-        };
-
-        let mut open = BinaryHeap::new();
-        open.push(AStarNode {
-            cost: heuristic(start_tx, start_ty),
-            x: start_tx,
-            y: start_ty,
-        });
-
-        // 4-directional neighbors: right, left, down, up
-        let dirs: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
-
-        while let Some(current) = open.pop() {
-            let cx = current.x;
-            let cy = current.y;
-            let cidx = cy * w + cx;
-
-            if cidx == end_idx {
-                // Reconstruct path
-                let mut path_tiles = Vec::new();
-                let mut idx = end_idx;
-                while idx != start_idx {
-                    let ty = idx / w;
-                    let tx = idx % w;
-                    path_tiles.push((Self::tile_center(tx), Self::tile_center(ty)));
-                    idx = came_from[idx];
-                }
-                path_tiles.reverse();
-                return Some(path_tiles);
-            }
-
-            if closed[cidx] {
-                continue;
-            }
-            closed[cidx] = true;
-
-            let current_g = g_score[cidx];
-
-            for (dx, dy) in &dirs {
-                let nx = cx as i32 + dx;
-                let ny = cy as i32 + dy;
-
-                if nx < 0 || ny < 0 {
-                    continue;
-                }
-                let nx = nx as usize;
-                let ny = ny as usize;
-
-                if nx >= w || ny >= h {
-                    continue;
-                }
+        let start = self.index(start_tx, start_ty);
+        let goal = self.index(end_tx, end_ty);
+        let path = self.pathfinder.borrow_mut().find(self, start, goal)?;
+        Some(
+            path.into_iter()
+                .map(|idx| {
+                    (
+                        Self::tile_center(idx % self.width),
+                        Self::tile_center(idx / self.width),
+                    )
+                })
+                .collect(),
+        )
+    }
 
-                if !self.is_walkable(nx, ny) {
-                    continue;
-                }
+    /// Optional pathfinding features in use (see `PathConfig`).
+    pub fn path_config(&self) -> PathConfig {
+        self.pathfinder.borrow().config()
+    }
 
-                let nidx = ny * w + nx;
-                if closed[nidx] {
-                    continue;
-                }
+    /// Change the pathfinding features. Clears cached paths.
+    pub fn set_path_config(&mut self, config: PathConfig) {
+        self.pathfinder.get_mut().set_config(config);
+    }
 
-                let tentative_g = current_g + 1;
-                if tentative_g < g_score[nidx] {
-                    g_score[nidx] = tentative_g;
-                    came_from[nidx] = cidx;
-                    open.push(AStarNode {
-                        cost: tentative_g + heuristic(nx, ny),
-                        x: nx,
-                        y: ny,
-                    });
-                }
-            }
+    /// Find a path for a creature. Ground creatures use A* over walkable
+    /// tiles; flyers fly straight to the target tile over solid terrain but
+    /// can't leave the map.
+    pub fn find_path_for(
+        &self,
+        ground_based: bool,
+        sx: i32,
+        sy: i32,
+        ex: i32,
+        ey: i32,
+    ) -> Option<Vec<(i32, i32)>> {
+        if ground_based {
+            return self.find_path(sx, sy, ex, ey);
         }
-
-        None // No path found
+        if sx < 0 || sy < 0 || ex < 0 || ey < 0 {
+            return None;
+        }
+        let end_tx = Self::pixel_to_tile(ex);
+        let end_ty = Self::pixel_to_tile(ey);
+        if !self.is_passable(Self::pixel_to_tile(sx), Self::pixel_to_tile(sy), false)
+            || !self.is_passable(end_tx, end_ty, false)
+        {
+            return None;
+        }
+        Some(vec![(Self::tile_center(end_tx), Self::tile_center(end_ty))])
     }
 }
 
@@ -797,6 +1537,32 @@ mod tests {
         assert_eq!(w.get_food(5, 4), 0);
     }
 
+    #[test]
+    fn test_food_index_tracks_add_and_eat() {
+        let mut w = World::new(20, 10);
+        for x in 1..19 {
+            w.set_type(x, 5, TILE_PLAIN);
+        }
+        w.add_food(3, 5, 400);
+        w.add_food(15, 5, 2000);
+        w.add_food(10, 0, 9000); // solid tile: counted, never "nearest"
+        assert_eq!(w.total_food(), 11400);
+
+        let (px, py) = (World::tile_center(5), World::tile_center(5));
+        let near = w.nearest_food(px, py, 1).tile.unwrap();
+        assert_eq!((near.x, near.y, near.food), (3, 5, 400));
+        let rich = w.nearest_food(px, py, 1000).tile.unwrap();
+        assert_eq!((rich.x, rich.food), (15, 2000));
+        assert!(w.nearest_food(px, py, 5000).tile.is_none());
+
+        w.eat_food(3, 5, 400);
+        w.add_food(15, 5, -1500);
+        assert_eq!(w.total_food(), 9500);
+        assert_eq!(w.nearest_food(px, py, 1).tile.unwrap().x, 15);
+        assert_eq!(w.food_in_radius(px, py, 3 * TILE_SIZE).total, 0);
+        assert_eq!(w.food_in_radius(px, py, 10 * TILE_SIZE).total, 9500);
+    }
+
     #[test]
     fn test_pixel_tile_conversion() {
         // pixel_to_tile
@@ -935,6 +1701,83 @@ mod tests {
         assert!(w.find_path(sx, sy, ex, ey).is_none());
     }
 
+    #[test]
+    fn test_pathfinding_avoids_costly_terrain() {
+        let mut w = make_open_world(10, 10);
+        // A lava strip across row 4 with a plain gap at x = 1: the path from
+        // (2, 2) to (2, 6) detours through the gap rather than crossing lava.
+        for x in 2..9 {
+            w.set_gfx(x, 4, TILE_GFX_LAVA);
+        }
+        let path = w
+            .find_path(
+                World::tile_center(2),
+                World::tile_center(2),
+                World::tile_center(2),
+                World::tile_center(6),
+            )
+            .unwrap();
+        assert!(path.contains(&(World::tile_center(1), World::tile_center(4))));
+        assert!(path
+            .iter()
+            .all(|&(x, y)| w.get_gfx(World::pixel_to_tile(x), World::pixel_to_tile(y)) != TILE_GFX_LAVA));
+
+        w.set_gfx(3, 3, TILE_GFX_DESERT);
+        assert!(!w.terrain(3, 3).food_growth);
+        // Unknown gfx values behave like plain ground
+        let rules = TerrainRules::default();
+        assert_eq!(rules.get(200), rules.get(TILE_GFX_PLAIN));
+    }
+
+    #[test]
+    fn test_terrain_rules_from_metadata() {
+        let meta: BTreeMap<String, String> = [
+            ("terrain_water_speed", "30"),
+            ("terrain_desert_food_growth", "true"),
+            ("terrain_lava_damage", "oops"),
+            ("terrain_plain_path_cost", "1"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let rules = TerrainRules::from_metadata(&meta);
+        let standard = TerrainRules::default();
+        assert_eq!(rules.get(TILE_GFX_WATER).speed, 30);
+        assert_eq!(rules.get(TILE_GFX_WATER).path_cost, standard.get(TILE_GFX_WATER).path_cost);
+        assert!(rules.get(TILE_GFX_DESERT).food_growth);
+        assert_eq!(rules.get(TILE_GFX_LAVA), standard.get(TILE_GFX_LAVA));
+        assert_eq!(rules.get(TILE_GFX_PLAIN).path_cost, MIN_PATH_COST);
+
+        // The world answers with the rules it was given
+        let mut w = make_open_world(5, 5);
+        w.set_gfx(2, 2, TILE_GFX_WATER);
+        assert_eq!(w.terrain(2, 2).speed, 50);
+        w.set_terrain_rules(rules);
+        assert_eq!(w.terrain(2, 2).speed, 30);
+    }
+
+    #[test]
+    fn test_flyer_path_crosses_walls() {
+        let mut w = make_open_world(10, 10);
+        for y in 1..9 {
+            let idx = y * w.width + 5;
+            w.tiles[idx].tile_type = TILE_SOLID;
+        }
+        let (sx, sy) = (World::tile_center(2), World::tile_center(4));
+        let (ex, ey) = (World::tile_center(8), World::tile_center(4));
+        assert!(w.find_path_for(true, sx, sy, ex, ey).is_none());
+        assert_eq!(w.find_path_for(false, sx, sy, ex, ey), Some(vec![(ex, ey)]));
+
+        // Flyers may target solid tiles, but not positions off the map
+        let wall = World::tile_center(5);
+        assert_eq!(
+            w.find_path_for(false, sx, sy, wall, ey),
+            Some(vec![(wall, ey)])
+        );
+        assert!(w.find_path_for(false, sx, sy, World::tile_center(10), ey).is_none());
+        assert!(w.find_path_for(false, sx, sy, -1, ey).is_none());
+    }
+
     #[test]
     fn test_from_json() {
         let json = r#"{
@@ -1013,6 +1856,120 @@ mod tests {
         assert!(w.food_spawners.len() >= 14);
     }
 
+    #[test]
+    fn test_generate_map_spawn_points() {
+        let w = World::generate_map(4);
+        assert_eq!(w.spawn_points.len(), 4);
+        for &(x, y) in &w.spawn_points {
+            assert!(w.is_walkable(x, y));
+        }
+
+        let r = World::generate_random(RandomMapParams::default());
+        assert_eq!(r.spawn_points.len(), 4);
+        // The first two spawn points sit on opposite sides of the map
+        let (a, b) = (r.spawn_points[0], r.spawn_points[1]);
+        assert!(a.0 > r.width / 2 && b.0 < r.width / 2);
+    }
+
+    fn assert_symmetric(w: &World, sym: MapSymmetry) {
+        for y in 0..w.height {
+            for x in 0..w.width {
+                for (ix, iy) in sym.images(x, y, w.width, w.height) {
+                    assert_eq!(
+                        w.is_walkable(x, y),
+                        w.is_walkable(ix, iy),
+                        "{sym:?}: ({x}, {y}) vs ({ix}, {iy})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_symmetric_maps() {
+        for sym in [
+            MapSymmetry::MirrorHorizontal,
+            MapSymmetry::MirrorVertical,
+            MapSymmetry::Rotational,
+            MapSymmetry::Radial(4),
+        ] {
+            let w = World::generate_random(RandomMapParams {
+                symmetry: sym,
+                ..RandomMapParams::default()
+            });
+            assert_eq!((w.width % 2, w.height % 2), (1, 1));
+            assert_symmetric(&w, sym);
+            assert_eq!(w.koth_pos(), (w.width / 2, w.height / 2));
+            assert!(w.validate_playable().is_ok(), "{sym:?}");
+
+            let expected_spawns = if sym == MapSymmetry::Radial(4) { 4 } else { 2 };
+            assert_eq!(w.spawn_points.len(), expected_spawns, "{sym:?}");
+            let (sx, sy) = w.spawn_points[0];
+            assert_eq!(sym.images(sx, sy, w.width, w.height), w.spawn_points);
+
+            // Every spawner has its mirror image
+            for s in &w.food_spawners {
+                for (ix, iy) in sym.images(s.x, s.y, w.width, w.height) {
+                    assert!(w.food_spawners.iter().any(|o| (o.x, o.y) == (ix, iy)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_radial_map_any_folds() {
+        // Without walls the layout, and so the spawn points, don't depend on the RNG
+        for folds in 2..=symmetry::MAX_RADIAL_FOLDS {
+            let w = World::generate_random(RandomMapParams {
+                width: 41,
+                height: 35,
+                wall_density: 0.0,
+                symmetry: MapSymmetry::Radial(folds),
+                ..RandomMapParams::default()
+            });
+            assert_eq!((w.width, w.height), (35, 35));
+            assert_eq!(w.spawn_points.len(), folds, "{folds} folds");
+            assert!(w.validate_playable().is_ok(), "{folds} folds");
+        }
+    }
+
+    #[test]
+    fn test_radial_images_stay_distinct() {
+        let mut w = World::new(9, 9);
+        for y in 1..8 {
+            for x in 1..8 {
+                w.set_type(x, y, TILE_PLAIN);
+            }
+        }
+        // Seven folds at radius 1 round two images onto (3, 4)
+        let sym = MapSymmetry::Radial(7);
+        let rounded = sym.images(5, 4, 9, 9);
+        assert_eq!(rounded[3], rounded[4]);
+        let images = w.symmetric_images(5, 4, sym);
+        assert_eq!(images.len(), 7);
+        for (i, p) in images.iter().enumerate() {
+            assert!(w.is_walkable(p.0, p.1));
+            assert!(!images[..i].contains(p), "{p:?} repeated");
+        }
+    }
+
+    #[test]
+    fn test_spawn_point_rotation() {
+        let mut w = World::from_ascii("SSSSS\nSPPPS\nSSSSS\n[spawn_points]\n1 1\n2 1\n3 1\n").unwrap();
+        assert_eq!(w.spawn_tile_for_slot(0), Some((1, 1)));
+        w.rotate_spawn_points(4, 3);
+        assert_eq!(w.spawn_points, vec![(2, 1), (3, 1), (1, 1)]);
+        assert_eq!(w.spawn_tile_for_slot(2), Some((1, 1)));
+        // Only the spawn points in play rotate
+        w.rotate_spawn_points(1, 2);
+        assert_eq!(w.spawn_points, vec![(3, 1), (2, 1), (1, 1)]);
+        w.rotate_spawn_points(1, 9);
+        assert_eq!(w.spawn_points, vec![(2, 1), (1, 1), (3, 1)]);
+        // Slots beyond the defined spawn points fall back to a plain tile
+        let (x, y) = w.spawn_tile_for_slot(3).unwrap();
+        assert!(w.is_walkable(x, y));
+    }
+
     #[test]
     fn test_generate_map_8_players() {
         let w = World::generate_map(8);
@@ -1038,4 +1995,171 @@ mod tests {
         assert_eq!(w.height, 108);
         assert!(w.find_plain_tile().is_some());
     }
+
+    fn assert_same_map(a: &World, b: &World) {
+        assert_eq!((a.width, a.height), (b.width, b.height));
+        assert_eq!(a.koth_pos(), b.koth_pos());
+        for (ta, tb) in a.tiles.iter().zip(b.tiles.iter()) {
+            assert_eq!((ta.tile_type, ta.gfx), (tb.tile_type, tb.gfx));
+        }
+        assert_eq!(a.food_spawners.len(), b.food_spawners.len());
+        for (sa, sb) in a.food_spawners.iter().zip(b.food_spawners.iter()) {
+            assert_eq!(
+                (sa.x, sa.y, sa.radius, sa.amount, sa.interval),
+                (sb.x, sb.y, sb.radius, sb.amount, sb.interval)
+            );
+        }
+        assert_eq!(a.spawn_points, b.spawn_points);
+        assert_eq!(a.metadata, b.metadata);
+    }
+
+    #[test]
+    fn test_ascii_round_trip_bundled_maps() {
+        let maps_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../data/maps");
+        for name in ["castle", "cn", "foo", "gpn", "infon", "owl", "pacman", "stripeslice", "water"] {
+            let json = std::fs::read_to_string(maps_dir.join(format!("{name}.json"))).unwrap();
+            let world = World::from_json(&json).unwrap();
+            let ascii = World::from_ascii(&world.to_ascii()).unwrap();
+            assert_same_map(&world, &ascii);
+            let back = World::from_json(&ascii.to_json()).unwrap();
+            assert_same_map(&world, &back);
+        }
+    }
+
+    #[test]
+    fn test_ascii_sections() {
+        let text = "\
+[meta]
+name = arena
+description = a tiny arena
+
+[tiles]
+SSSSSSS
+SPPPPPS
+SPPKPPS
+SSSSSSS
+
+[spawners]
+# x y radius amount interval
+2 1 2 500 2000
+
+[spawn_points]
+1 1
+5 2
+";
+        let w = World::from_ascii(text).unwrap();
+        assert_eq!((w.width, w.height), (7, 4));
+        assert_eq!(w.koth_pos(), (3, 2));
+        assert!(w.is_walkable(3, 2));
+        assert!(!w.is_walkable(0, 0));
+        assert_eq!(w.food_spawners.len(), 1);
+        assert_eq!(w.food_spawners[0].amount, 500);
+        assert_eq!(w.spawn_points, vec![(1, 1), (5, 2)]);
+        assert_eq!(w.metadata.get("name").map(String::as_str), Some("arena"));
+        assert_eq!(w.metadata.get("description").map(String::as_str), Some("a tiny arena"));
+    }
+
+    #[test]
+    fn test_ascii_legend_and_aliases() {
+        // Bare grid: no headers, maplayout-style aliases, short row padded solid.
+        let w = World::from_ascii("@@@@\n@ .w\n@L\n").unwrap();
+        assert_eq!((w.width, w.height), (4, 3));
+        assert!(w.is_walkable(1, 1));
+        assert!(w.is_walkable(2, 1));
+        let lava = &w.tiles[w.index(1, 2)];
+        assert_eq!((lava.tile_type, lava.gfx), (TILE_SOLID, TILE_GFX_LAVA));
+        assert!(!w.is_walkable(3, 2));
+
+        // Case doesn't matter, as in the original level scripts
+        for (c, &upper) in "spbtuvwlnkd".chars().zip(&ASCII_GLYPHS) {
+            assert_eq!(parse_ascii_glyph(c), parse_ascii_glyph(upper));
+        }
+        let water = &w.tiles[w.index(3, 1)];
+        assert_eq!((water.tile_type, water.gfx), (TILE_SOLID, TILE_GFX_WATER));
+
+        // [tile_types] overrides the glyph's type, and export writes it back
+        let w = World::from_ascii("SPW\n[tile_types]\n2 0 1\n1 0 0\n").unwrap();
+        assert!(w.is_walkable(2, 0) && !w.is_walkable(1, 0));
+        let back = World::from_ascii(&w.to_ascii()).unwrap();
+        assert_same_map(&w, &back);
+
+        // Export uses the original legend's solid and plain glyphs
+        let w = World::from_ascii("SSSS\nSPKS\nSSSS\n").unwrap();
+        assert!(w.to_ascii().contains("\n[tiles]\n@@@@\n@.K@\n@@@@\n"));
+    }
+
+    #[test]
+    fn test_ascii_metadata_is_escaped() {
+        let mut w = World::from_ascii("SPS\n").unwrap();
+        for (key, value) in [
+            ("description", "two\nlines"),
+            ("formula", "a = b"),
+            ("path", "C:\\maps\\new"),
+            ("odd=key", "x"),
+        ] {
+            w.metadata.insert(key.to_string(), value.to_string());
+        }
+        let ascii = w.to_ascii();
+        let back = World::from_ascii(&ascii).unwrap();
+        assert_eq!(back.metadata, w.metadata);
+        assert_eq!(back.koth_pos(), w.koth_pos());
+    }
+
+    #[test]
+    fn test_ascii_run_length() {
+        let w = World::from_ascii("5S\nS3PS\n5S\n").unwrap();
+        assert_eq!((w.width, w.height), (5, 3));
+        assert!(w.is_walkable(1, 1) && w.is_walkable(3, 1));
+        assert!(!w.is_walkable(4, 1));
+    }
+
+    #[test]
+    fn test_ascii_errors() {
+        assert!(World::from_ascii("").is_err());
+        assert!(World::from_ascii("SPX\n").is_err());
+        assert!(World::from_ascii("SPx\n").is_err());
+        assert!(World::from_ascii("SP\n[tile_types]\n0 0 2\n").is_err());
+        assert!(World::from_ascii("SP\n[tile_types]\n5 0 1\n").is_err());
+        assert!(World::from_ascii("SP3\n").is_err());
+        assert!(World::from_ascii("[bogus]\nSP\n").is_err());
+        assert!(World::from_ascii("[meta]\nkoth = 9,9\n[tiles]\nSP\n").is_err());
+        assert!(World::from_ascii("SP\n[spawners]\n1 1 1\n").is_err());
+        assert!(World::from_ascii("SP\n[spawn_points]\n5 0\n").is_err());
+
+        // Oversized grids are rejected while parsing
+        let max = MAP_MAX_SIZE;
+        assert!(World::from_ascii(&format!("{max}S\n")).is_ok());
+        let wide = format!("{}S\n", "1000S".repeat(max));
+        assert!(World::from_ascii(&wide).err().unwrap().contains("wider"));
+        let tall = "S\n".repeat(max + 1);
+        assert!(World::from_ascii(&tall).err().unwrap().contains("rows"));
+    }
+
+    #[test]
+    fn test_validate_playable() {
+        let grid = format!("10S\n{}10S\n", "S8PS\n".repeat(8));
+        let mut w = World::from_ascii(&grid).unwrap();
+        assert!(w.validate_playable().is_ok());
+
+        // Wall splitting the map into two halves
+        w.koth_x = 2;
+        for y in 0..10 {
+            let idx = w.index(5, y);
+            w.tiles[idx].tile_type = TILE_SOLID;
+        }
+        let err = w.validate_playable().unwrap_err();
+        assert!(err.contains("disconnected"), "{err}");
+
+        let mut w = World::from_ascii(&grid).unwrap();
+        let idx = w.index(w.koth_x, w.koth_y);
+        w.tiles[idx].tile_type = TILE_SOLID;
+        assert!(w.validate_playable().unwrap_err().contains("KOTH"));
+
+        let mut w = World::from_ascii(&grid).unwrap();
+        w.spawn_points.push((0, 0));
+        assert!(w.validate_playable().unwrap_err().contains("Spawn point"));
+
+        let tiny = World::from_ascii("SSS\nSKS\nSSS\n").unwrap();
+        assert!(tiny.validate_playable().is_err());
+    }
 }
@@ -0,0 +1,267 @@
+// Glicko-2 rating system (Glickman, "Example of the Glicko-2 system").
+//
+// Per-version 1v1 ratings kept alongside Elo. Each rating carries a deviation
+// (uncertainty) and a volatility, so a version with a handful of games shows a
+// wide confidence interval and is flagged provisional. Every game is its own
+// rating period.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::elo::Outcome;
+
+pub const STARTING_RATING: f64 = 1500.0;
+pub const STARTING_DEVIATION: f64 = 350.0;
+pub const STARTING_VOLATILITY: f64 = 0.06;
+
+/// Ratings with a deviation above this are provisional.
+pub const PROVISIONAL_DEVIATION: f64 = 110.0;
+
+/// System constant: how much the volatility may change per period.
+const TAU: f64 = 0.5;
+
+/// Conversion factor between the Glicko and Glicko-2 scales.
+const SCALE: f64 = 173.7178;
+
+/// Convergence tolerance for the volatility iteration.
+const EPSILON: f64 = 0.000001;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Glicko2Rating {
+    pub rating: f64,
+    pub deviation: f64,
+    pub volatility: f64,
+}
+
+impl Default for Glicko2Rating {
+    fn default() -> Self {
+        Glicko2Rating {
+            rating: STARTING_RATING,
+            deviation: STARTING_DEVIATION,
+            volatility: STARTING_VOLATILITY,
+        }
+    }
+}
+
+impl Glicko2Rating {
+    /// Too few games for the rating to be trusted.
+    pub fn is_provisional(&self) -> bool {
+        self.deviation > PROVISIONAL_DEVIATION
+    }
+
+    /// Half-width of the 95% confidence interval (rating ± confidence).
+    pub fn confidence(&self) -> f64 {
+        1.96 * self.deviation
+    }
+
+    fn mu(&self) -> f64 {
+        (self.rating - STARTING_RATING) / SCALE
+    }
+
+    fn phi(&self) -> f64 {
+        self.deviation / SCALE
+    }
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (std::f64::consts::PI * std::f64::consts::PI)).sqrt()
+}
+
+fn expected(mu: f64, mu_j: f64, phi_j: f64) -> f64 {
+    1.0 / (1.0 + (-g(phi_j) * (mu - mu_j)).exp())
+}
+
+/// New volatility via the Illinois variant of regula falsi (step 5 of the paper).
+fn new_volatility(sigma: f64, phi: f64, v: f64, delta: f64) -> f64 {
+    let a = (sigma * sigma).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        let d = phi * phi + v + ex;
+        ex * (delta * delta - phi * phi - v - ex) / (2.0 * d * d) - (x - a) / (TAU * TAU)
+    };
+
+    let mut lo = a;
+    let mut hi = if delta * delta > phi * phi + v {
+        (delta * delta - phi * phi - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+    let mut f_lo = f(lo);
+    let mut f_hi = f(hi);
+    while (hi - lo).abs() > EPSILON {
+        let c = lo + (lo - hi) * f_lo / (f_hi - f_lo);
+        let f_c = f(c);
+        if f_c * f_hi <= 0.0 {
+            lo = hi;
+            f_lo = f_hi;
+        } else {
+            f_lo /= 2.0;
+        }
+        hi = c;
+        f_hi = f_c;
+    }
+    (lo / 2.0).exp()
+}
+
+/// Rate a player after one rating period against the given opponents
+/// (their ratings before the period). With no games only the deviation grows.
+pub fn update(player: Glicko2Rating, games: &[(Glicko2Rating, Outcome)]) -> Glicko2Rating {
+    let (mu, phi, sigma) = (player.mu(), player.phi(), player.volatility);
+    if games.is_empty() {
+        let deviation = (phi * phi + sigma * sigma).sqrt() * SCALE;
+        return Glicko2Rating {
+            deviation: deviation.min(STARTING_DEVIATION),
+            ..player
+        };
+    }
+
+    let mut v_inv = 0.0;
+    let mut score_sum = 0.0;
+    for (opponent, outcome) in games {
+        let (mu_j, phi_j) = (opponent.mu(), opponent.phi());
+        let e = expected(mu, mu_j, phi_j);
+        v_inv += g(phi_j) * g(phi_j) * e * (1.0 - e);
+        score_sum += g(phi_j) * (outcome.score() - e);
+    }
+    let v = 1.0 / v_inv;
+    let delta = v * score_sum;
+
+    let volatility = new_volatility(sigma, phi, v, delta);
+    let phi_star = (phi * phi + volatility * volatility).sqrt();
+    let new_phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+    let new_mu = mu + new_phi * new_phi * score_sum;
+
+    Glicko2Rating {
+        rating: new_mu * SCALE + STARTING_RATING,
+        deviation: (new_phi * SCALE).min(STARTING_DEVIATION),
+        volatility,
+    }
+}
+
+/// Rate both sides of a 1v1 game. `outcome` is from `a`'s perspective.
+pub fn rate_1v1(
+    a: Glicko2Rating,
+    b: Glicko2Rating,
+    outcome: Outcome,
+) -> (Glicko2Rating, Glicko2Rating) {
+    let outcome_b = match outcome {
+        Outcome::Win => Outcome::Loss,
+        Outcome::Loss => Outcome::Win,
+        Outcome::Draw => Outcome::Draw,
+    };
+    (update(a, &[(b, outcome)]), update(b, &[(a, outcome_b)]))
+}
+
+/// Replay 1v1 results `(version_a, version_b, winner)` in order, every
+/// version starting from the default rating.
+pub fn replay(results: &[(i64, i64, Option<i64>)]) -> HashMap<i64, Glicko2Rating> {
+    let mut ratings: HashMap<i64, Glicko2Rating> = HashMap::new();
+    for &(a, b, winner) in results {
+        let outcome = match winner {
+            Some(w) if w == a => Outcome::Win,
+            Some(w) if w == b => Outcome::Loss,
+            _ => Outcome::Draw,
+        };
+        let ra = ratings.get(&a).copied().unwrap_or_default();
+        let rb = ratings.get(&b).copied().unwrap_or_default();
+        let (na, nb) = rate_1v1(ra, rb, outcome);
+        ratings.insert(a, na);
+        ratings.insert(b, nb);
+    }
+    ratings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(rating: f64, deviation: f64) -> Glicko2Rating {
+        Glicko2Rating {
+            rating,
+            deviation,
+            volatility: STARTING_VOLATILITY,
+        }
+    }
+
+    #[test]
+    fn test_glickman_example() {
+        // Worked example from the Glicko-2 paper
+        let player = rating(1500.0, 200.0);
+        let games = [
+            (rating(1400.0, 30.0), Outcome::Win),
+            (rating(1550.0, 100.0), Outcome::Loss),
+            (rating(1700.0, 300.0), Outcome::Loss),
+        ];
+        let new = update(player, &games);
+        assert!((new.rating - 1464.06).abs() < 0.05, "{}", new.rating);
+        assert!((new.deviation - 151.52).abs() < 0.05, "{}", new.deviation);
+        assert!(
+            (new.volatility - 0.05999).abs() < 0.0001,
+            "{}",
+            new.volatility
+        );
+    }
+
+    #[test]
+    fn test_win_between_new_players() {
+        let (a, b) = rate_1v1(
+            Glicko2Rating::default(),
+            Glicko2Rating::default(),
+            Outcome::Win,
+        );
+        assert!(a.rating > STARTING_RATING);
+        assert!(b.rating < STARTING_RATING);
+        assert!((a.rating - STARTING_RATING - (STARTING_RATING - b.rating)).abs() < 1e-6);
+        assert!(a.deviation < STARTING_DEVIATION);
+        assert!(a.is_provisional());
+    }
+
+    #[test]
+    fn test_draw_between_equals_keeps_rating() {
+        let (a, _) = rate_1v1(rating(1600.0, 80.0), rating(1600.0, 80.0), Outcome::Draw);
+        assert!((a.rating - 1600.0).abs() < 1e-6);
+        assert!(a.deviation < 80.0);
+    }
+
+    #[test]
+    fn test_inactivity_grows_deviation() {
+        let r = rating(1700.0, 60.0);
+        let idle = update(r, &[]);
+        assert_eq!(idle.rating, 1700.0);
+        assert!(idle.deviation > 60.0);
+        assert_eq!(
+            update(Glicko2Rating::default(), &[]).deviation,
+            STARTING_DEVIATION
+        );
+    }
+
+    #[test]
+    fn test_deviation_shrinks_with_games() {
+        let mut r = Glicko2Rating::default();
+        let opponent = rating(1500.0, 100.0);
+        for i in 0..40 {
+            let outcome = if i % 2 == 0 {
+                Outcome::Win
+            } else {
+                Outcome::Loss
+            };
+            r = update(r, &[(opponent, outcome)]);
+        }
+        assert!(!r.is_provisional(), "deviation {}", r.deviation);
+        assert!(r.confidence() < 2.0 * PROVISIONAL_DEVIATION);
+    }
+
+    #[test]
+    fn test_replay() {
+        let ratings = replay(&[(1, 2, Some(1)), (1, 3, Some(1)), (2, 3, None)]);
+        assert_eq!(ratings.len(), 3);
+        assert!(ratings[&1].rating > ratings[&2].rating);
+        assert!(ratings[&1].rating > ratings[&3].rating);
+        assert!(ratings.values().all(|r| r.deviation < STARTING_DEVIATION));
+    }
+}
@@ -0,0 +1,64 @@
+// Materialized leaderboards.
+//
+// Scoped boards (by period or map) read per-day, per-map results from
+// `leaderboard_stats` instead of aggregating match history on every request.
+// Game completion adds each rated result as it happens; a periodic rebuild
+// recomputes the table from match history so it can't drift. Leaderboard
+// responses carry an ETag so clients can revalidate without a new body.
+
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::db::Database;
+
+/// How often `leaderboard_stats` is rebuilt from match history.
+const REBUILD_INTERVAL_SECS: u64 = 6 * 60 * 60;
+
+/// Strong ETag of a response body.
+pub fn etag(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    format!("\"{}\"", hex::encode(&digest[..16]))
+}
+
+/// Whether an `If-None-Match` header value matches `etag`: `*` or any
+/// listed tag, compared weakly as RFC 9110 asks for this header.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let bare = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    if_none_match.trim() == "*" || if_none_match.split(',').any(|tag| bare(tag) == bare(etag))
+}
+
+/// Spawn the periodic rebuild of `leaderboard_stats`. The first run happens
+/// right away, which also fills the table on databases that predate it.
+pub fn spawn_leaderboard_rebuild(db: Arc<Database>) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(REBUILD_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            match db.rebuild_leaderboard_stats().await {
+                Ok(rows) => tracing::debug!(rows, "Rebuilt leaderboard stats"),
+                Err(e) => tracing::error!("Leaderboard rebuild failed: {e}"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_etag() {
+        let tag = etag(b"[]");
+        assert_eq!(tag, etag(b"[]"));
+        assert_ne!(tag, etag(b"[{}]"));
+        assert!(tag.starts_with('"') && tag.ends_with('"'));
+
+        assert!(etag_matches(&tag, &tag));
+        assert!(etag_matches(&format!("W/{tag}"), &tag));
+        assert!(etag_matches(&format!("\"stale\", {tag}"), &tag));
+        assert!(etag_matches("*", &tag));
+        assert!(!etag_matches("\"stale\"", &tag));
+    }
+}
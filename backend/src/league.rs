@@ -0,0 +1,81 @@
+// Leagues by account age.
+//
+// Accounts are in the Newcomer league until they are `NEWCOMER_DAYS` old and
+// in Open after that, derived from `users.created_at` whenever it is read; a
+// bot plays in its owner's league (ownerless built-in bots are Open). Ranked
+// pairing keeps the leagues apart: the ladder only pairs within a league, and
+// in a ranked challenge across leagues (issued by either side) the newcomer is
+// rated while the Open bot plays it unranked. Tournament games are unranked.
+// Ratings live on bot versions, so they carry over on promotion.
+
+use serde::{Deserialize, Serialize};
+
+/// Accounts younger than this are in the Newcomer league.
+pub const NEWCOMER_DAYS: i64 = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum League {
+    Newcomer,
+    Open,
+}
+
+impl League {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            League::Newcomer => "newcomer",
+            League::Open => "open",
+        }
+    }
+
+    /// Parse a league name as used in query parameters: `newcomer` or `open`.
+    pub fn from_str_name(name: &str) -> Option<Self> {
+        match name {
+            "newcomer" => Some(League::Newcomer),
+            "open" => Some(League::Open),
+            _ => None,
+        }
+    }
+
+    /// League of a bot from its owner's league name; bots without an owner
+    /// play in Open.
+    pub fn of_owner(owner_league: Option<&str>) -> Self {
+        owner_league
+            .and_then(League::from_str_name)
+            .unwrap_or(League::Open)
+    }
+}
+
+/// Which sides of a ranked game between leagues `a` and `b` are rated. Within
+/// a league both are; across leagues only the Newcomer side is.
+pub fn rated_sides(a: League, b: League) -> (bool, bool) {
+    if a == b {
+        (true, true)
+    } else {
+        (a == League::Newcomer, b == League::Newcomer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_league_names() {
+        for league in [League::Newcomer, League::Open] {
+            assert_eq!(League::from_str_name(league.as_str()), Some(league));
+        }
+        assert_eq!(League::from_str_name("all"), None);
+        assert_eq!(League::of_owner(Some("newcomer")), League::Newcomer);
+        assert_eq!(League::of_owner(None), League::Open);
+    }
+
+    #[test]
+    fn test_rated_sides() {
+        use League::*;
+        assert_eq!(rated_sides(Open, Open), (true, true));
+        assert_eq!(rated_sides(Newcomer, Newcomer), (true, true));
+        assert_eq!(rated_sides(Newcomer, Open), (true, false));
+        assert_eq!(rated_sides(Open, Newcomer), (false, true));
+    }
+}
@@ -4,10 +4,17 @@ pub mod config;
 pub mod db;
 pub mod elo;
 pub mod engine;
+pub mod glicko;
+pub mod leaderboard;
+pub mod league;
 pub mod llms_txt;
+pub mod matchmaker;
 pub mod metrics;
+pub mod openskill;
 pub mod queue;
 pub mod rate_limit;
+pub mod recompute;
 pub mod replay;
+pub mod season;
 pub mod tournament;
 pub mod worker_pool;
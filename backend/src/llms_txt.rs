@@ -16,6 +16,7 @@ Create keys in the web UI at /api-keys or via POST /api/api-keys.
 - GET/POST /api/bots/{id}/versions - List/create bot versions
 - PUT /api/bots/{id}/active-version - Set active version
 - GET /api/bots/{id}/stats - Get bot version stats
+- GET /api/bots/{id}/versions/{vid}/rating-history - 1v1 Elo over time for a version
 - GET /api/matches - List recent matches
 - GET /api/matches/mine - User's own match history (auth required)
 - GET /api/matches/{id} - Get match details
@@ -31,12 +32,17 @@ Create keys in the web UI at /api-keys or via POST /api/api-keys.
 - GET /api/leaderboards/1v1 - View 1v1 rankings
 - GET /api/leaderboards/ffa - View FFA rankings
 - GET /api/leaderboards/2v2 - View 2v2 rankings
+- GET /api/seasons - List competitive seasons and their champions
 - GET/POST /api/teams - List/create teams
 - GET/POST /api/api-keys - List/create API keys
 - DELETE /api/api-keys/{id} - Revoke API key
 - POST /api/validate-lua - Validate Lua syntax
 - GET /api/docs/lua-api - Lua API reference (Markdown)
 - GET /api/maps - List available maps
+- POST /api/maps - Upload a custom map
+- GET /api/maps/{name} - Get a custom map and its data (?version=N)
+- PUT /api/maps/{name} - Update a custom map (new data creates a new version)
+- GET /api/maps/{name}/analysis - Fairness analysis (per-slot distances, income, choke points)
 
 ## API Key Scopes
 Create an API key via POST /api/api-keys with scopes like "bots:read,matches:write".
@@ -49,6 +55,12 @@ Two high-level API styles wrap the low-level API (auto-detected from your code):
 - Coroutine style (oo.lua): Define `Creature:main()` with blocking methods
 - State machine style (state.lua): Define `bot()` with state functions and event handlers
 
+API levels: declare `needs_api("oo", 2)` (or "state") to pin the API level your code uses.
+Each bot version runs at the level it was saved with (current: 2; versions saved before
+levels existed run at 1). Level 2 adds get_tile_gfx, get_terrain and the area queries.
+Validation warns about deprecated levels (1) and rejects unknown ones. `API_LEVEL` holds the
+installed level.
+
 See /api/docs/lua-api for the full API reference.
 
 ## Documentation Links
@@ -153,9 +165,12 @@ Content-Type: application/json
 **Get/Update/Delete Bot:**
 ```
 GET /api/bots/{id}
-PUT /api/bots/{id}  {"name": "NewName", "description": "Updated"}
+PUT /api/bots/{id}  {"name": "NewName", "description": "Updated", "ladder": true}
 DELETE /api/bots/{id}
 ```
+With `"ladder": true` the bot's active version is entered into automatic
+ranked ladder matches against versions of similar rating, one at a time: a version
+isn't scheduled again while it still has an unfinished ranked match.
 
 ### Bot Versions
 
@@ -184,6 +199,16 @@ GET /api/bots/{id}/stats
 Response: [{version_id, elo_1v1, games_played, wins, losses, ...}]
 ```
 
+**Rating History:**
+```
+GET /api/bots/{id}/versions/{vid}/rating-history?limit=500&offset=0&at=2025-06-01
+Response: {version_id, rating, peak, rating_at, history: [{match_id, rating_before, rating_after, opponent_version_id, opponent_rating, created_at}]}
+```
+One history row per rated 1v1 match, oldest first, plus one per season reset (with
+`match_id`, `opponent_version_id` and `opponent_rating` null). `peak` includes the
+starting rating; `rating_at` (only with `at`) is the rating as of that timestamp or date
+(ISO 8601, UTC unless an offset is given; 400 if it can't be parsed).
+
 ### Matches
 
 **List Recent Matches:**
@@ -219,9 +244,22 @@ Content-Type: application/json
   "opponent_bot_version_id": 2,
   "format": "1v1",
   "headless": true,
-  "map": "default"
+  "map": "default",
+  "ranked": false
 }
 ```
+Only ranked challenges change ratings and leaderboard stats; unranked results
+are tracked separately in each version's `unranked_*` fields. Ranked
+challenges must be issued with your own bot and use each bot's active
+version, and a ranked challenge is refused once the same two bots have 5
+ranked games (of any kind) in the last 24 hours. Games started via
+/api/game/start and tournament games are always unranked.
+
+Accounts are in the Newcomer league for their first 14 days, then move to
+the Open league (ratings carry over). A newcomer may challenge an Open bot
+to a ranked game, and an Open player may challenge a newcomer bot: either
+way the game is rated for the newcomer and unranked for the Open bot.
+Ladder matches only pair bots of the same league.
 
 ### Game Control (Live Games)
 
@@ -276,9 +314,19 @@ GET /api/tournaments/{id}
 ```
 PUT /api/tournaments/{id}
 {"format": "round_robin", "config": "{}"}
-Formats: round_robin, single_elimination, swiss_N
+Formats: round_robin, single_elimination, double_elimination, double_elimination_no_reset, swiss_N
 ```
 
+Double elimination seeds bots in entry order. A bot drops to the losers bracket after its first
+loss and is out after its second; an odd bracket gives its last bot a bye, and a draw goes to the
+better seed. The winners- and losers-bracket champions meet in the grand final. If the
+losers-bracket side wins it, `double_elimination` replays it (bracket reset);
+`double_elimination_no_reset` doesn't.
+
+Swiss pairs bots with equal points where it can, floating one down a score group when that
+avoids a rematch; nobody meets the same opponent twice unless no other pairing exists. With an
+odd field the lowest-ranked bot that hasn't had a bye sits the round out and scores a point.
+
 **Tournament Entries:**
 ```
 GET /api/tournaments/{id}/entries
@@ -291,20 +339,71 @@ DELETE /api/tournaments/{id}/entries/{entry_id}
 POST /api/tournaments/{id}/run
 ```
 
+A tournament runs once: running it again after it started returns 409.
+
 **Standings & Results:**
 ```
 GET /api/tournaments/{id}/standings
 GET /api/tournaments/{id}/results
+GET /api/tournaments/{id}/bracket
 ```
 
+Swiss standings are ranked by `points` (1 per win or bye, 0.5 per draw), then `buchholz`
+(opponents' points), `sonneborn_berger` (points of beaten opponents plus half of drawn ones')
+and `head_to_head` (points scored against the bots still tied), then `total_score`. Other
+formats are ranked by `total_score` but report the same fields. Each row also has `wins`,
+`draws`, `losses` and `byes`.
+
+`/bracket` (double elimination only) lists every match with its `bracket` (`winners`, `losers`,
+`grand_final`, `grand_final_reset`) and the advancing `winner`. It also returns `standings`
+(seed, losses, `eliminated_round` and final `placement`, shared by bots knocked out in the same
+round) and the `champion` once decided.
+
 ### Leaderboards
 
 ```
-GET /api/leaderboards/1v1?limit=50&offset=0
-GET /api/leaderboards/ffa?limit=50&offset=0
-GET /api/leaderboards/2v2?limit=50&offset=0
+GET /api/leaderboards/1v1?limit=50&offset=0&sort=glicko&hide_provisional=true&league=newcomer
+GET /api/leaderboards/ffa?limit=50&offset=0&sort=skill&period=week&map=arena
+GET /api/leaderboards/2v2?limit=50&offset=0&sort=skill
+```
+
+The 1v1 board ranks by Elo by default; `sort=glicko` ranks by Glicko-2 rating instead.
+FFA ranks by placement points and 2v2 by team Elo by default; `sort=skill` ranks both by a
+Weng-Lin (OpenSkill) rating computed from the full final score ranking of every game.
+Entries carry `confidence` (the ± half-width around the skill rating: 95% Glicko-2 for 1v1,
+3 sigma for FFA and 2v2) and `provisional` (too few games for a reliable rating);
+`hide_provisional=true` drops those. `league=newcomer` or `league=open` limits any board to
+bots of that league (by the owner's account age); each entry carries its `league`.
+
+Filters, on every board:
+- `period=week|month`: games, wins, losses and FFA points only from ranked games finished
+  since midnight 7 or 30 days ago (default `all`). Ratings stay current; windowed 1v1 Elo boards add
+  `rating_change`, the Elo gained over the period.
+- `map=<name>`: only ranked games played on that map.
+- `owner=<username>`: only that user's bots.
+- `active_only=true`: only each bot's active version (each team's latest version on 2v2).
+- `group=bot`: one row per bot (per team on 2v2), its best-ranked version (default `version`).
+
+Rows with an equal rating share a rank (1, 2, 2, 4), and `rank` counts across pages.
+
+Leaderboard responses (including season boards) carry an `ETag`; send it back in
+`If-None-Match` to get `304 Not Modified` while the board is unchanged. Period and map
+results are kept per day and map as games finish and rebuilt from match history every
+6 hours; admins can force a rebuild with `POST /api/admin/leaderboards/rebuild`.
+
+### Seasons
+
+```
+GET /api/seasons
+GET /api/seasons/{id}/leaderboard?limit=50&offset=0
 ```
 
+Seasons last 90 days. When one ends, its final 1v1 standings are archived and every
+rating is pulled halfway back to its starting value (Elo 1700 becomes 1600). A season's
+leaderboard shows season-only games, wins, losses, draws and peak rating; the active
+season's board is live. `/api/seasons` lists seasons newest first, with the champion
+of each finished one.
+
 ### Teams (2v2)
 
 ```
@@ -322,8 +421,38 @@ POST /api/teams/{id}/versions  {"bot_version_a": 1, "bot_version_b": 2}
 ```
 GET /api/maps
 Response: [{"name": "random", "width": 30, "height": 30, "description": "..."}]
+
+POST /api/maps
+Authorization: Bearer <token>
+Content-Type: application/json
+{"name": "arena", "description": "...", "visibility": "public", "data": "<map>", "format": "ascii"}
+Response: {"id": 1, "name": "arena", "version": 1, ...}
+
+GET /api/maps/{name}?version=1
+Response: {"map": {...}, "version": 1, "data": "<map JSON>"}
+
+PUT /api/maps/{name}
+Authorization: Bearer <token>
+{"description": "...", "visibility": "private", "data": "<map>"}
 ```
 
+The original Infon levels are also listed as `level_<name>` (e.g. `level_pacman`). They run
+the level's own script, including its scripted food drops during the game; the plain `<name>`
+maps are static tile copies of the same layouts.
+
+Custom map data is either the JSON map format or the ASCII map format
+(`format` is "json" or "ascii", detected when omitted). Uploads are rejected
+unless the map is between 8x8 and 256x256, the KOTH tile is walkable, every
+walkable tile is reachable, and (with 2+ spawn points) no slot has a clear
+advantage in the fairness analysis. Use a custom map by name anywhere a map is accepted;
+`name@N` pins version N. Private maps can only be used by their owner.
+A POST or PUT that loses a race with a concurrent write to the same map returns 409.
+
+Map metadata can turn on optional ground pathfinding features: `path_diagonal`
+(8-directional moves, no cutting past wall corners), `path_smooth` (straight
+lines wherever the terrain allows) and `path_hierarchical` (cluster-based search
+for large maps). Set each to "true" in `[meta]` (ASCII) or `metadata` (JSON).
+
 ### Lua Validation
 
 ```
@@ -369,6 +498,14 @@ Messages:
 - 2D tile-based grid (each tile 256x256 units/pixels)
 - X increases rightward, Y increases downward
 - Tiles: TILE_SOLID (0, walls) or TILE_PLAIN (1, walkable)
+- Terrain comes from the tile gfx. On walkable tiles ground creatures move at 50% speed
+  through water (TILE_GFX_WATER) and 75% through snow (TILE_GFX_SNOW_PLAIN), lose
+  1,000 HP/s on lava (TILE_GFX_LAVA), and food never grows on desert (TILE_GFX_DESERT).
+  Flyers ignore terrain. Pathfinding prefers cheap terrain. A map can change these rules
+  with `terrain_<gfx>_<effect>` metadata, e.g. `terrain_water_speed = 30`, where `<gfx>` is
+  solid, plain, border, snow_solid, snow_plain, snow_border, water, lava, none, koth or desert
+  and `<effect>` is speed (percent), damage (HP/s), food_growth (true/false) or path_cost
+  (at least 10). get_terrain reports the rules in play.
 - Game runs in 100ms ticks (10 ticks per second)
 - world_size() returns playable boundaries as x1, y1, x2, y2
 
@@ -390,6 +527,9 @@ Messages:
 Small creatures have a unique speed bonus: effective speed = 200 + 625 × (current_health / max_health).
 At full health a Small moves at 825 px/s, nearly as fast as a Flyer.
 
+Flyers path in a straight line to the target tile and may hover over solid
+tiles, but they can only eat, convert or be fed over walkable (TILE_PLAIN) tiles.
+
 ### Combat System
 
 Combat is continuous DPS. While in ATTACK state with target in range, damage is applied every tick:
@@ -433,7 +573,8 @@ Food is consumed at 1,000 food/s during conversion:
 
 ### CPU Limits
 
-- Each player limited to 500,000 Lua VM instructions per tick
+- Each player limited to 500,000 instructions per tick, shared by Lua code (VM instructions,
+  coroutines included) and the area query costs below
 - If exceeded: current tick's player_think() aborts, error logged to console output
 - Creatures are NOT killed. Bot is NOT kicked. Game continues normally next tick
 - get_cpu_usage() currently returns 0 (stub — no real-time tracking)
@@ -474,7 +615,7 @@ CREATURE_SPAWNED=0  CREATURE_KILLED=1  CREATURE_ATTACKED=2  PLAYER_CREATED=3
 ### Low-Level API
 
 **Creature Actions:**
-- set_path(id, x, y) -> bool: Set movement destination
+- set_path(id, x, y) -> bool: Set movement destination (Flyers fly straight over walls)
 - set_state(id, state) -> bool: Set creature state
 - get_state(id) -> state: Get current state
 - set_target(id, target_id) -> bool: Set attack/feed target
@@ -490,16 +631,28 @@ CREATURE_SPAWNED=0  CREATURE_KILLED=1  CREATURE_ATTACKED=2  PLAYER_CREATED=3
 - get_speed(id) -> speed (own only)
 - get_tile_food(id) -> food (own only)
 - get_tile_type(id) -> type
+- get_tile_gfx(id) -> gfx (terrain at creature's position)
 - get_max_food(id) -> food (own only)
 - get_distance(id, target_id) -> dist
 - get_nearest_enemy(id) -> id, x, y, playernum, dist (or nil)
 - creature_exists(id) -> bool
 - creature_player(id) -> player_no
 
+**Area Queries** (cost 100 + 10 per creature/tile/waypoint examined, path queries +2000,
+charged against the per-tick instruction budget; `id` must be your own creature):
+- get_creatures_in_range(id, radius, filter) -> list of {id, x, y, player, type, distance},
+  nearest first (radius max 4096; filter "all" (default), "own" or "enemy")
+- get_my_creatures() -> list of your creature ids
+- get_nearest_food(id, min_amount) -> x, y, amount (nearest tile center with >= min_amount food, or nil)
+- get_food_in_range(id, radius) -> total food on tiles within radius (max 4096)
+- get_tiles_in_rect(x1, y1, x2, y2) -> list of {x, y, type, food} (max 1024 tiles)
+- get_path_length(id, x, y) -> length in pixels, or nil (does not change the path)
+
 **World Functions:**
 - world_size() -> x1, y1, x2, y2
 - game_time() -> ms
 - get_koth_pos() -> x, y
+- get_terrain(x, y) -> speed_percent, damage_per_sec, food_growth (nil off the map)
 - player_exists(id) -> bool
 - king_player() -> player_id
 - player_score(id) -> score
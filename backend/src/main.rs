@@ -6,11 +6,18 @@ mod config;
 mod db;
 mod elo;
 mod engine;
+mod glicko;
+mod leaderboard;
+mod league;
 mod llms_txt;
+mod matchmaker;
 mod metrics;
+mod openskill;
 mod queue;
 mod rate_limit;
+mod recompute;
 mod replay;
+mod season;
 mod tournament;
 mod worker_pool;
 
@@ -121,6 +128,37 @@ async fn main() {
         Err(e) => tracing::error!("Failed to clean up stale queue jobs: {e}"),
     }
 
+    // One-off admin job: rebuild every rating from match history and exit
+    if cfg.recompute_ratings {
+        match db.recompute_ratings(cfg.dry_run).await {
+            Ok(report) => {
+                print!("{}", report.render());
+                return;
+            }
+            Err(e) => {
+                tracing::error!("Failed to recompute ratings: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Seed rating history from matches played before it was recorded
+    match db.seed_rating_history().await {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Seeded {n} rating history entries from past matches"),
+        Err(e) => tracing::error!("Failed to seed rating history: {e}"),
+    }
+
+    // Seed Glicko-2 ratings for versions that played before they were tracked, once
+    match db.data_migration_applied(db::GLICKO_BACKFILL).await {
+        Ok(true) => {}
+        Ok(false) => match db.backfill_glicko().await {
+            Ok(n) => tracing::info!("Backfilled Glicko-2 ratings for {n} bot versions"),
+            Err(e) => tracing::error!("Failed to backfill Glicko-2 ratings: {e}"),
+        },
+        Err(e) => tracing::error!("Failed to check Glicko-2 backfill: {e}"),
+    }
+
     let game_server = Arc::new(GameServer::new());
     let rate_limiter = RateLimiter::new();
     let worker_pool = Arc::new(WorkerPool::new(cfg.worker_count));
@@ -143,6 +181,21 @@ async fn main() {
         worker_id,
     );
 
+    // Spawn the ladder matchmaker, which keeps ranked games flowing
+    crate::matchmaker::set_paused(cfg.ladder_paused);
+    crate::matchmaker::spawn_ladder_matchmaker(
+        db.clone(),
+        cfg.ladder_interval_secs,
+        cfg.ladder_matches_per_round,
+        cfg.ladder_max_pending,
+    );
+
+    // Archive standings and soft-reset ratings when a season ends
+    crate::season::spawn_season_scheduler(db.clone(), cfg.season_days);
+
+    // Rebuild the materialized leaderboard stats from match history
+    crate::leaderboard::spawn_leaderboard_rebuild(db.clone());
+
     // Inject Arc<Database> into request extensions so auth extractors can
     // look up API tokens without needing access to AppState directly.
     let db_for_ext = db.clone();
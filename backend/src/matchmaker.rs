@@ -0,0 +1,334 @@
+// Ladder matchmaker: schedules ranked games so ratings keep moving without
+// manual challenges.
+//
+// Every interval it takes the active, non-faulty versions of bots that opted
+// into the ladder and aren't already in an unfinished ranked match, pairs them
+// by rating closeness within their league (under-played versions are
+// considered first, recent pairings are penalised) and enqueues ranked
+// headless 1v1 matches on maps from the ranked pool. Runs next to the queue
+// worker; admins can pause it at runtime.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::db::Database;
+use crate::metrics;
+
+/// Queue priority of ladder games: below challenges (0) and tournaments (10).
+pub const LADDER_PRIORITY: i32 = -10;
+
+/// How many of the latest ranked 1v1 matches count as "recent" for rematches.
+pub const RECENT_MATCHES: i64 = 500;
+
+/// Cost of a pairing, in rating points, per recent match between the two.
+const REMATCH_PENALTY: f64 = 150.0;
+
+/// Set by the admin pause switch (or `INFON_LADDER_PAUSED` at startup).
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_paused(paused: bool) {
+    PAUSED.store(paused, Ordering::Relaxed);
+    metrics::LADDER_PAUSED.set(paused as i64);
+}
+
+pub fn is_paused() -> bool {
+    PAUSED.load(Ordering::Relaxed)
+}
+
+/// A version the matchmaker may schedule.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LadderCandidate {
+    pub bot_version_id: i64,
+    pub bot_id: i64,
+    pub rating: i32,
+    pub games_played: i32,
+    /// Owner's league; only versions of the same league are paired.
+    pub league: String,
+}
+
+fn pair_key(a: i64, b: i64) -> (i64, i64) {
+    (a.min(b), a.max(b))
+}
+
+/// Pair up to `max_pairs` candidates. Versions are taken in a random order
+/// weighted toward those with fewer games; each is matched against the free
+/// version of another bot in its league with the lowest cost (rating gap plus
+/// a penalty per recent meeting, from `recent`, keyed by version pair). Slot
+/// order within a pair is random.
+pub fn pair_candidates<R: Rng>(
+    candidates: &[LadderCandidate],
+    recent: &HashMap<(i64, i64), u32>,
+    max_pairs: usize,
+    rng: &mut R,
+) -> Vec<(i64, i64)> {
+    // Weighted random order (Efraimidis-Spirakis): key = u^(1/w)
+    let mut order: Vec<(f64, &LadderCandidate)> = candidates
+        .iter()
+        .map(|c| {
+            let weight = 1.0 / (1.0 + c.games_played.max(0) as f64);
+            (rng.gen::<f64>().powf(1.0 / weight), c)
+        })
+        .collect();
+    order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut used: HashSet<i64> = HashSet::new();
+    let mut pairs = Vec::new();
+    for (_, seed) in &order {
+        if pairs.len() >= max_pairs {
+            break;
+        }
+        if used.contains(&seed.bot_version_id) {
+            continue;
+        }
+        let cost = |c: &LadderCandidate| {
+            let rematches = recent
+                .get(&pair_key(seed.bot_version_id, c.bot_version_id))
+                .copied()
+                .unwrap_or(0);
+            (seed.rating - c.rating).abs() as f64 + REMATCH_PENALTY * rematches as f64
+        };
+        let opponent = candidates
+            .iter()
+            .filter(|c| {
+                c.bot_id != seed.bot_id
+                    && c.league == seed.league
+                    && !used.contains(&c.bot_version_id)
+            })
+            .min_by(|a, b| {
+                cost(a)
+                    .total_cmp(&cost(b))
+                    .then(a.bot_version_id.cmp(&b.bot_version_id))
+            });
+        let Some(opponent) = opponent else {
+            continue;
+        };
+        used.insert(seed.bot_version_id);
+        used.insert(opponent.bot_version_id);
+        if rng.gen_bool(0.5) {
+            pairs.push((seed.bot_version_id, opponent.bot_version_id));
+        } else {
+            pairs.push((opponent.bot_version_id, seed.bot_version_id));
+        }
+    }
+    pairs
+}
+
+/// Schedule one round of ladder matches: at most `matches_per_round`, and
+/// only as many as keep the pending queue at or below `max_pending`.
+/// Returns the created match ids.
+pub async fn run_round(
+    db: &Database,
+    matches_per_round: usize,
+    max_pending: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    metrics::LADDER_ROUNDS_TOTAL.inc();
+    let pending = db.queue_status().await?.pending;
+    let budget = (max_pending - pending).clamp(0, matches_per_round as i64) as usize;
+
+    let candidates = db.ladder_candidates().await?;
+    metrics::LADDER_ELIGIBLE_VERSIONS.set(candidates.len() as i64);
+    if budget == 0 || candidates.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    let mut recent: HashMap<(i64, i64), u32> = HashMap::new();
+    for (a, b) in db.recent_ranked_pairs(RECENT_MATCHES).await? {
+        *recent.entry(pair_key(a, b)).or_default() += 1;
+    }
+    let mut pool = db.list_ranked_maps().await?;
+    if pool.is_empty() {
+        pool.push("random".to_string());
+    }
+
+    let scheduled: Vec<((i64, i64), String)> = {
+        let mut rng = rand::thread_rng();
+        pair_candidates(&candidates, &recent, budget, &mut rng)
+            .into_iter()
+            .map(|pair| (pair, pool.choose(&mut rng).unwrap().clone()))
+            .collect()
+    };
+
+    let rating_of = |id: i64| {
+        candidates
+            .iter()
+            .find(|c| c.bot_version_id == id)
+            .map_or(0, |c| c.rating)
+    };
+    let mut match_ids = Vec::with_capacity(scheduled.len());
+    for ((a, b), map) in scheduled {
+        let m = db
+            .create_match_with_participants(
+                "1v1",
+                &map,
+                true,
+                &[(a, true), (b, true)],
+                Some((Some(&map), LADDER_PRIORITY)),
+            )
+            .await?;
+        metrics::LADDER_MATCHES_SCHEDULED_TOTAL.inc();
+        metrics::LADDER_PAIR_RATING_GAP.observe((rating_of(a) - rating_of(b)).abs() as f64);
+        match_ids.push(m.id);
+    }
+    Ok(match_ids)
+}
+
+/// Spawn the background matchmaker. `matches_per_round == 0` disables it.
+pub fn spawn_ladder_matchmaker(
+    db: Arc<Database>,
+    interval_secs: u64,
+    matches_per_round: usize,
+    max_pending: i64,
+) {
+    if matches_per_round == 0 {
+        return;
+    }
+    tokio::spawn(async move {
+        let interval = tokio::time::Duration::from_secs(interval_secs.max(1));
+        loop {
+            tokio::time::sleep(interval).await;
+            if is_paused() {
+                continue;
+            }
+            match run_round(&db, matches_per_round, max_pending).await {
+                Ok(ids) if !ids.is_empty() => {
+                    tracing::info!(count = ids.len(), "Ladder scheduled ranked matches")
+                }
+                Ok(_) => {}
+                Err(e) => tracing::error!("Ladder matchmaker round failed: {e}"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn candidate(id: i64, rating: i32, games_played: i32) -> LadderCandidate {
+        LadderCandidate {
+            bot_version_id: id,
+            bot_id: id,
+            rating,
+            games_played,
+            league: "open".to_string(),
+        }
+    }
+
+    fn sorted(pairs: &[(i64, i64)]) -> Vec<(i64, i64)> {
+        let mut out: Vec<_> = pairs.iter().map(|&(a, b)| pair_key(a, b)).collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn test_pairs_by_rating_closeness() {
+        let field = [
+            candidate(1, 1500, 10),
+            candidate(2, 1900, 10),
+            candidate(3, 1510, 10),
+            candidate(4, 1880, 10),
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+        let pairs = pair_candidates(&field, &HashMap::new(), 10, &mut rng);
+        assert_eq!(sorted(&pairs), vec![(1, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn test_recent_rematch_is_avoided() {
+        let field = [
+            candidate(1, 1500, 10),
+            candidate(2, 1510, 10),
+            candidate(3, 1600, 10),
+        ];
+        let recent = HashMap::from([((1, 2), 3)]);
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let pairs = pair_candidates(&field, &recent, 1, &mut rng);
+            assert_eq!(pairs.len(), 1);
+            assert_ne!(sorted(&pairs), vec![(1, 2)], "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_underplayed_versions_go_first() {
+        let mut field: Vec<LadderCandidate> = (1..=20).map(|id| candidate(id, 1500, 500)).collect();
+        field.push(candidate(21, 1500, 0));
+        let mut hits = 0;
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let pairs = pair_candidates(&field, &HashMap::new(), 1, &mut rng);
+            if pairs.iter().any(|&(a, b)| a == 21 || b == 21) {
+                hits += 1;
+            }
+        }
+        assert!(hits > 40, "new version paired {hits}/50 times");
+    }
+
+    #[test]
+    fn test_never_pairs_a_bot_with_itself() {
+        let mut field = vec![candidate(1, 1500, 0), candidate(2, 1500, 0)];
+        field[1].bot_id = 1;
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(pair_candidates(&field, &HashMap::new(), 5, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_leagues_are_not_mixed() {
+        let mut field = vec![
+            candidate(1, 1500, 0),
+            candidate(2, 1900, 0),
+            candidate(3, 1510, 0),
+            candidate(4, 1880, 0),
+        ];
+        field[0].league = "newcomer".to_string();
+        field[1].league = "newcomer".to_string();
+        let mut rng = StdRng::seed_from_u64(3);
+        let pairs = pair_candidates(&field, &HashMap::new(), 10, &mut rng);
+        assert_eq!(sorted(&pairs), vec![(1, 2), (3, 4)]);
+    }
+
+    #[tokio::test]
+    async fn test_run_round_enqueues_ranked_matches() {
+        sqlx::any::install_default_drivers();
+        let db = Database::new("sqlite::memory:").await.unwrap();
+        let mut versions = Vec::new();
+        for name in ["A", "B", "C"] {
+            let bot = db.create_bot(name, "", None).await.unwrap();
+            let v = db.create_bot_version(bot.id, "").await.unwrap();
+            db.set_active_version(bot.id, v.id).await.unwrap();
+            versions.push((bot.id, v.id));
+        }
+        // C hasn't opted in
+        db.set_bot_ladder(versions[0].0, true).await.unwrap();
+        db.set_bot_ladder(versions[1].0, true).await.unwrap();
+        db.set_ranked_maps(&["random".to_string()]).await.unwrap();
+
+        let ids = run_round(&db, 5, 10).await.unwrap();
+        assert_eq!(ids.len(), 1);
+        let m = db.get_match(ids[0]).await.unwrap().unwrap();
+        assert_eq!((m.ranked, m.map.as_str()), (1, "random"));
+        let participants = db.get_match_participants(m.id).await.unwrap();
+        let mut ids_in_match: Vec<i64> = participants.iter().map(|p| p.bot_version_id).collect();
+        ids_in_match.sort();
+        assert_eq!(ids_in_match, vec![versions[0].1, versions[1].1]);
+        assert_eq!(db.queue_status().await.unwrap().pending, 1);
+
+        // The queue budget is respected
+        assert!(run_round(&db, 5, 1).await.unwrap().is_empty());
+        // Versions already in an unfinished ranked match wait for it...
+        assert!(run_round(&db, 5, 10).await.unwrap().is_empty());
+        // ...unless its job failed for good
+        for _ in 0..3 {
+            let job = db.claim_queue_job("test").await.unwrap().unwrap();
+            db.fail_queue_job(job.id, "crashed").await.unwrap();
+        }
+        assert_eq!(run_round(&db, 5, 10).await.unwrap().len(), 1);
+        assert!(run_round(&db, 5, 10).await.unwrap().is_empty());
+    }
+}
@@ -31,6 +31,14 @@ lazy_static! {
     pub static ref HEADLESS_WORKERS_ACTIVE: IntGauge =
         IntGauge::new("infon_headless_workers_active", "Headless game workers currently active").unwrap();
 
+    /// Versions eligible for ladder matchmaking at the last round.
+    pub static ref LADDER_ELIGIBLE_VERSIONS: IntGauge =
+        IntGauge::new("infon_ladder_eligible_versions", "Versions eligible for ladder matches").unwrap();
+
+    /// 1 while the ladder matchmaker is paused by an admin.
+    pub static ref LADDER_PAUSED: IntGauge =
+        IntGauge::new("infon_ladder_paused", "Whether the ladder matchmaker is paused").unwrap();
+
     // ── Counters ─────────────────────────────────────────────────────
 
     /// Total games started, by format (1v1, ffa, 2v2).
@@ -82,6 +90,20 @@ lazy_static! {
     )
     .unwrap();
 
+    /// Total ladder matchmaking rounds run.
+    pub static ref LADDER_ROUNDS_TOTAL: IntCounter = IntCounter::new(
+        "infon_ladder_rounds_total",
+        "Ladder matchmaking rounds run",
+    )
+    .unwrap();
+
+    /// Total ranked matches scheduled by the ladder.
+    pub static ref LADDER_MATCHES_SCHEDULED_TOTAL: IntCounter = IntCounter::new(
+        "infon_ladder_matches_scheduled_total",
+        "Ranked matches scheduled by the ladder",
+    )
+    .unwrap();
+
     /// Total creatures spawned, by creature type.
     pub static ref CREATURES_SPAWNED_TOTAL: IntCounterVec = IntCounterVec::new(
         Opts::new("infon_creatures_spawned_total", "Total creatures spawned"),
@@ -113,6 +135,13 @@ lazy_static! {
     )
     .unwrap();
 
+    /// Rating gap between the two versions of each ladder pairing.
+    pub static ref LADDER_PAIR_RATING_GAP: Histogram = Histogram::with_opts(
+        HistogramOpts::new("infon_ladder_pair_rating_gap", "Rating gap of ladder pairings")
+            .buckets(vec![25.0, 50.0, 100.0, 200.0, 400.0, 800.0]),
+    )
+    .unwrap();
+
     /// API request duration in seconds, by endpoint.
     pub static ref API_REQUEST_DURATION_SECONDS: HistogramVec = HistogramVec::new(
         HistogramOpts::new(
@@ -133,6 +162,8 @@ pub fn register_metrics() {
         Box::new(CONNECTED_WEBSOCKETS.clone()),
         Box::new(LUA_VM_POOL_ACTIVE.clone()),
         Box::new(HEADLESS_WORKERS_ACTIVE.clone()),
+        Box::new(LADDER_ELIGIBLE_VERSIONS.clone()),
+        Box::new(LADDER_PAUSED.clone()),
         Box::new(GAMES_STARTED_TOTAL.clone()),
         Box::new(GAMES_COMPLETED_TOTAL.clone()),
         Box::new(GAMES_ERRORED_TOTAL.clone()),
@@ -140,10 +171,13 @@ pub fn register_metrics() {
         Box::new(WEBSOCKET_MESSAGES_SENT_TOTAL.clone()),
         Box::new(BOT_SUBMISSIONS_TOTAL.clone()),
         Box::new(BOT_VALIDATION_FAILURES_TOTAL.clone()),
+        Box::new(LADDER_ROUNDS_TOTAL.clone()),
+        Box::new(LADDER_MATCHES_SCHEDULED_TOTAL.clone()),
         Box::new(CREATURES_SPAWNED_TOTAL.clone()),
         Box::new(CREATURES_KILLED_TOTAL.clone()),
         Box::new(GAME_DURATION_SECONDS.clone()),
         Box::new(GAME_TICK_DURATION_MS.clone()),
+        Box::new(LADDER_PAIR_RATING_GAP.clone()),
         Box::new(API_REQUEST_DURATION_SECONDS.clone()),
     ];
 
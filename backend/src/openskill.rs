@@ -0,0 +1,228 @@
+// Weng-Lin Bayesian rating (the model behind OpenSkill), Bradley-Terry
+// full-pairing variant.
+//
+// Used for FFA and 2v2, where Elo's pairwise model doesn't fit: every game is
+// a ranking of teams (a single bot is a team of one), and each team is compared
+// against every other. Ratings are a mean `mu` and uncertainty `sigma`, scaled
+// so a new version starts at 1500 ± 500 like the Elo boards.
+
+use serde::{Deserialize, Serialize};
+
+pub const STARTING_MU: f64 = 1500.0;
+pub const STARTING_SIGMA: f64 = STARTING_MU / 3.0;
+
+/// Ratings with a sigma above this (half the starting sigma) are provisional.
+pub const PROVISIONAL_SIGMA: f64 = STARTING_SIGMA / 2.0;
+
+/// Performance variability of a single game.
+const BETA: f64 = STARTING_SIGMA / 2.0;
+
+/// Lower bound on the variance shrink factor, so sigma never collapses to zero.
+const KAPPA: f64 = 0.0001;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SkillRating {
+    pub mu: f64,
+    pub sigma: f64,
+}
+
+impl Default for SkillRating {
+    fn default() -> Self {
+        SkillRating {
+            mu: STARTING_MU,
+            sigma: STARTING_SIGMA,
+        }
+    }
+}
+
+impl SkillRating {
+    /// Conservative estimate (mu - 3 sigma); what leaderboards rank by.
+    pub fn ordinal(&self) -> f64 {
+        self.mu - 3.0 * self.sigma
+    }
+
+    /// Half-width of the interval shown next to the rating (3 sigma).
+    pub fn confidence(&self) -> f64 {
+        3.0 * self.sigma
+    }
+
+    /// Too few games for the rating to be trusted.
+    pub fn is_provisional(&self) -> bool {
+        self.sigma > PROVISIONAL_SIGMA
+    }
+}
+
+/// Combined rating of a team, on the same scale as its members: mean of the
+/// members' mu, with their variances pooled.
+pub fn team_rating(members: &[SkillRating]) -> SkillRating {
+    if members.is_empty() {
+        return SkillRating::default();
+    }
+    let n = members.len() as f64;
+    SkillRating {
+        mu: members.iter().map(|r| r.mu).sum::<f64>() / n,
+        sigma: members
+            .iter()
+            .map(|r| r.sigma * r.sigma)
+            .sum::<f64>()
+            .sqrt()
+            / n,
+    }
+}
+
+/// Competition ranking of final scores, highest first: `[50, 80, 50, 10]`
+/// gives `[2, 1, 2, 4]`.
+pub fn ranks_from_scores(scores: &[i64]) -> Vec<u32> {
+    scores
+        .iter()
+        .map(|s| 1 + scores.iter().filter(|other| *other > s).count() as u32)
+        .collect()
+}
+
+/// Rate one game. `teams[i]` finished at `ranks[i]` (1 = best, equal ranks
+/// are ties). Returns the updated ratings in the same shape.
+pub fn rate(teams: &[Vec<SkillRating>], ranks: &[u32]) -> Vec<Vec<SkillRating>> {
+    assert_eq!(teams.len(), ranks.len(), "one rank per team");
+    let totals: Vec<(f64, f64)> = teams
+        .iter()
+        .map(|team| {
+            let mu = team.iter().map(|r| r.mu).sum::<f64>();
+            let var = team.iter().map(|r| r.sigma * r.sigma).sum::<f64>();
+            (mu, var)
+        })
+        .collect();
+
+    teams
+        .iter()
+        .enumerate()
+        .map(|(i, team)| {
+            let (mu_i, var_i) = totals[i];
+            let mut omega = 0.0;
+            let mut delta = 0.0;
+            for (q, &(mu_q, var_q)) in totals.iter().enumerate() {
+                if q == i {
+                    continue;
+                }
+                let c = (var_i + var_q + 2.0 * BETA * BETA).sqrt();
+                let p = 1.0 / (1.0 + ((mu_q - mu_i) / c).exp());
+                let score = match ranks[q].cmp(&ranks[i]) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                };
+                omega += var_i / c * (score - p);
+                let gamma = var_i.sqrt() / c;
+                delta += gamma * var_i / (c * c) * p * (1.0 - p);
+            }
+            team.iter()
+                .map(|r| {
+                    let share = r.sigma * r.sigma / var_i;
+                    SkillRating {
+                        mu: r.mu + share * omega,
+                        sigma: r.sigma * (1.0 - share * delta).max(KAPPA).sqrt(),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solo(n: usize) -> Vec<Vec<SkillRating>> {
+        vec![vec![SkillRating::default()]; n]
+    }
+
+    #[test]
+    fn test_ranks_from_scores() {
+        assert_eq!(ranks_from_scores(&[50, 80, 50, 10]), vec![2, 1, 2, 4]);
+        assert_eq!(ranks_from_scores(&[3, 3]), vec![1, 1]);
+        assert!(ranks_from_scores(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_ffa_orders_by_placement() {
+        let new = rate(&solo(4), &[1, 2, 3, 4]);
+        let mus: Vec<f64> = new.iter().map(|t| t[0].mu).collect();
+        assert!(mus[0] > mus[1] && mus[1] > mus[2] && mus[2] > mus[3]);
+        assert!(mus[0] > STARTING_MU && mus[3] < STARTING_MU);
+        // Symmetric field: gains and losses cancel out
+        assert!(mus.iter().map(|m| m - STARTING_MU).sum::<f64>().abs() < 1e-6);
+        assert!(new.iter().all(|t| t[0].sigma < STARTING_SIGMA));
+    }
+
+    #[test]
+    fn test_tie_between_equals_keeps_mu() {
+        let new = rate(&solo(3), &[1, 1, 1]);
+        for team in &new {
+            assert!((team[0].mu - STARTING_MU).abs() < 1e-6);
+            assert!(team[0].sigma < STARTING_SIGMA);
+        }
+    }
+
+    #[test]
+    fn test_team_game_moves_both_members() {
+        let strong = SkillRating {
+            mu: 1700.0,
+            sigma: 100.0,
+        };
+        let teams = vec![
+            vec![SkillRating::default(), strong],
+            vec![SkillRating::default(), SkillRating::default()],
+        ];
+        let new = rate(&teams, &[1, 2]);
+        assert!(new[0].iter().all(|r| r.mu > STARTING_MU - 1e-9));
+        assert!(new[0][0].mu > STARTING_MU);
+        assert!(new[1].iter().all(|r| r.mu < STARTING_MU));
+        // The uncertain member absorbs more of the update
+        assert!(new[0][0].mu - STARTING_MU > new[0][1].mu - strong.mu);
+    }
+
+    #[test]
+    fn test_upset_moves_more_than_expected_result() {
+        let favourite = SkillRating {
+            mu: 1800.0,
+            sigma: 150.0,
+        };
+        let underdog = SkillRating {
+            mu: 1300.0,
+            sigma: 150.0,
+        };
+        let expected = rate(&[vec![favourite], vec![underdog]], &[1, 2]);
+        let upset = rate(&[vec![favourite], vec![underdog]], &[2, 1]);
+        let gain_expected = expected[0][0].mu - favourite.mu;
+        let gain_upset = upset[1][0].mu - underdog.mu;
+        assert!(gain_upset > gain_expected);
+    }
+
+    #[test]
+    fn test_sigma_settles_below_provisional() {
+        let mut field = rate(&solo(4), &[1, 2, 3, 4]);
+        assert!(field.iter().all(|t| t[0].is_provisional()));
+        for game in 1..15 {
+            let ranks: Vec<u32> = (0..4).map(|i| ((i + game) % 4) as u32 + 1).collect();
+            field = rate(&field, &ranks);
+        }
+        assert!(field.iter().all(|t| !t[0].is_provisional()), "{field:?}");
+    }
+
+    #[test]
+    fn test_team_rating() {
+        let members = [
+            SkillRating {
+                mu: 1400.0,
+                sigma: 300.0,
+            },
+            SkillRating {
+                mu: 1600.0,
+                sigma: 400.0,
+            },
+        ];
+        let team = team_rating(&members);
+        assert_eq!(team.mu, 1500.0);
+        assert_eq!(team.sigma, 250.0);
+        assert_eq!(team_rating(&[]), SkillRating::default());
+    }
+}
@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use crate::api::resolve_map;
 use crate::db::Database;
+use crate::engine::player::ApiVersion;
 use crate::engine::server::PlayerEntry;
 use crate::metrics;
 use crate::worker_pool::WorkerPool;
@@ -72,6 +73,7 @@ pub fn spawn_queue_worker(
                         let name = p.bot_name.clone().unwrap_or_else(|| format!("Bot v{}", v.version));
                         players.push(PlayerEntry {
                             name,
+                            api: ApiVersion::from_stored(&v.code, v.api_level),
                             code: v.code,
                         });
                         version_ids.push(p.bot_version_id);
@@ -98,7 +100,7 @@ pub fn spawn_queue_worker(
             let map_params: Option<crate::api::MapParamsRequest> = job.map_params
                 .as_deref()
                 .and_then(|s| serde_json::from_str(s).ok());
-            let world = match resolve_map(&maps_dir, &job.map, map_params.as_ref()) {
+            let mut world = match resolve_map(&db, &maps_dir, &job.map, map_params.as_ref()).await {
                 Ok(w) => w,
                 Err(e) => {
                     tracing::error!("Queue worker: invalid map for match {}: {e}", job.match_id);
@@ -108,6 +110,13 @@ pub fn spawn_queue_worker(
                 }
             };
 
+            // Tournament rounds form a series: rotate the players' spawn
+            // points each round so no slot keeps the same start position
+            // throughout.
+            if let Ok(Some((_, round))) = db.get_tournament_for_match(job.match_id).await {
+                world.rotate_spawn_points((round.max(1) - 1) as usize, players.len());
+            }
+
             let format = if players.len() == 2 {
                 "1v1".to_string()
             } else {
@@ -195,7 +204,9 @@ pub async fn run_game_completion(
         .winner_player_index
         .and_then(|idx| version_ids.get(idx).copied());
 
-    // 3. Finish match
+    // 3. Finish match. From here until the ratings are written, a season
+    // rollover or recompute waits so it can't be overwritten by stale values.
+    let _ratings = db.rating_update_guard().await;
     if let Err(e) = db.finish_match(match_id, winner_version_id).await {
         tracing::error!("Failed to finish match {match_id}: {e}");
     }
@@ -228,7 +239,13 @@ pub async fn run_game_completion(
         }
     }
 
-    // 4. Get participants and update stats + Elo
+    // 4. Get participants and update stats + Elo. Unranked matches, and the
+    // unrated side of a cross-league ranked match, only count towards the
+    // separate unranked record.
+    let ranked = match db.get_match(match_id).await {
+        Ok(Some(m)) => m.ranked != 0,
+        _ => true,
+    };
     let participants = match db.get_match_participants(match_id).await {
         Ok(p) => p,
         Err(e) => {
@@ -237,8 +254,42 @@ pub async fn run_game_completion(
         }
     };
 
+    let score_of = |version_id: i64| {
+        result
+            .player_scores
+            .iter()
+            .find(|s| s.bot_version_id == version_id)
+            .map(|s| s.score as i64)
+            .unwrap_or(0)
+    };
+
+    // 2v2: slots 0-1 and 2-3 are the two teams, scored by their summed score
+    let teams_2v2: Option<[[&crate::db::MatchParticipant; 2]; 2]> =
+        if format == "2v2" && participants.len() == 4 {
+            let mut by_slot: Vec<&crate::db::MatchParticipant> = participants.iter().collect();
+            by_slot.sort_by_key(|p| p.player_slot);
+            Some([[by_slot[0], by_slot[1]], [by_slot[2], by_slot[3]]])
+        } else {
+            None
+        };
+    let team_ranks = teams_2v2.map(|teams| {
+        let scores: Vec<i64> = teams
+            .iter()
+            .map(|t| score_of(t[0].bot_version_id) + score_of(t[1].bot_version_id))
+            .collect();
+        crate::openskill::ranks_from_scores(&scores)
+    });
+
+    // Placements: by final score in FFA (ties share a place), by team in 2v2
+    let score_ranks = crate::openskill::ranks_from_scores(
+        &participants
+            .iter()
+            .map(|p| score_of(p.bot_version_id))
+            .collect::<Vec<_>>(),
+    );
+
     // Update per-participant stats
-    for p in participants.iter() {
+    for (i, p) in participants.iter().enumerate() {
         let ps = result
             .player_scores
             .iter()
@@ -247,24 +298,54 @@ pub async fn run_game_completion(
         let spawned = ps.map(|s| s.creatures_spawned).unwrap_or(0);
         let killed = ps.map(|s| s.creatures_killed).unwrap_or(0);
         let lost_c = ps.map(|s| s.creatures_lost).unwrap_or(0);
-        let won = winner_version_id == Some(p.bot_version_id);
-        let lost = winner_version_id.is_some() && !won;
-        let draw = winner_version_id.is_none();
-        let placement = if won { 1 } else if lost { 2 } else { 0 };
+        // The game's winner is its top scorer; in 2v2 the team result counts
+        let team = teams_2v2
+            .and_then(|teams| teams.iter().position(|t| t.iter().any(|m| m.id == p.id)));
+        let (won, lost, draw) = match (team, &team_ranks) {
+            (Some(t), Some(ranks)) => (
+                ranks[t] < ranks[1 - t],
+                ranks[t] > ranks[1 - t],
+                ranks[t] == ranks[1 - t],
+            ),
+            _ => (
+                winner_version_id == Some(p.bot_version_id),
+                winner_version_id.is_some_and(|w| w != p.bot_version_id),
+                winner_version_id.is_none(),
+            ),
+        };
+        let placement = if format == "ffa" {
+            score_ranks[i] as i32
+        } else if let (Some(t), Some(ranks)) = (team, &team_ranks) {
+            ranks[t] as i32
+        } else if won {
+            1
+        } else if lost {
+            2
+        } else {
+            0
+        };
 
+        let rated = ranked && p.rated != 0;
+        let elo = if rated { Some(0) } else { None };
         let _ = db
             .update_match_participant(
                 p.id,
                 score,
                 Some(placement),
-                Some(0),
-                Some(0),
+                elo,
+                elo,
                 spawned,
                 killed,
                 lost_c,
             )
             .await;
 
+        if !rated {
+            let _ = db
+                .update_version_unranked_stats(p.bot_version_id, won, lost, draw)
+                .await;
+            continue;
+        }
         let _ = db
             .update_version_stats(
                 p.bot_version_id,
@@ -277,10 +358,21 @@ pub async fn run_game_completion(
                 lost_c,
             )
             .await;
+        // 2v2 results are kept per team, below
+        if format != "2v2" {
+            let points = if format == "ffa" {
+                crate::elo::ffa_placement_points(placement, participants.len() as i32)
+            } else {
+                0
+            };
+            let _ = db
+                .record_leaderboard_result(match_id, p.bot_version_id, won, lost, points)
+                .await;
+        }
     }
 
     // Elo calculation for 1v1
-    if format == "1v1" && participants.len() == 2 {
+    if ranked && format == "1v1" && participants.len() == 2 {
         let p0 = &participants[0];
         let p1 = &participants[1];
         let (v0, v1) = match (
@@ -317,77 +409,137 @@ pub async fn run_game_completion(
             v1.games_played,
         );
 
-        let ps0 = result
-            .player_scores
-            .iter()
-            .find(|s| s.bot_version_id == p0.bot_version_id);
-        let ps1 = result
-            .player_scores
-            .iter()
-            .find(|s| s.bot_version_id == p1.bot_version_id);
-
-        let _ = db
-            .update_match_participant(
-                p0.id,
-                ps0.map(|s| s.score).unwrap_or(0),
-                Some(if outcome_0 == crate::elo::Outcome::Win {
-                    1
-                } else {
-                    2
-                }),
-                Some(v0.elo_1v1),
-                Some(new_elo_0),
-                ps0.map(|s| s.creatures_spawned).unwrap_or(0),
-                ps0.map(|s| s.creatures_killed).unwrap_or(0),
-                ps0.map(|s| s.creatures_lost).unwrap_or(0),
-            )
-            .await;
-        let _ = db
-            .update_match_participant(
-                p1.id,
-                ps1.map(|s| s.score).unwrap_or(0),
-                Some(if outcome_1 == crate::elo::Outcome::Win {
-                    1
-                } else {
-                    2
-                }),
-                Some(v1.elo_1v1),
-                Some(new_elo_1),
-                ps1.map(|s| s.creatures_spawned).unwrap_or(0),
-                ps1.map(|s| s.creatures_killed).unwrap_or(0),
-                ps1.map(|s| s.creatures_lost).unwrap_or(0),
-            )
-            .await;
-
-        let _ = db.update_version_elo(p0.bot_version_id, new_elo_0).await;
-        let _ = db.update_version_elo(p1.bot_version_id, new_elo_1).await;
-    }
+        let (glicko_0, glicko_1) =
+            crate::glicko::rate_1v1(v0.glicko(), v1.glicko(), outcome_0);
 
-    // FFA placement scoring
-    if format == "ffa" && participants.len() > 2 {
-        let mut sorted: Vec<&crate::db::MatchParticipant> = participants.iter().collect();
-        sorted.sort_by(|a, b| {
-            let score_a = result
-                .player_scores
-                .iter()
-                .find(|s| s.bot_version_id == a.bot_version_id)
-                .map(|s| s.score)
-                .unwrap_or(0);
-            let score_b = result
+        // A newcomer's ranked challenge against an Open bot only rates the newcomer
+        for (p, v, opponent, outcome, new_elo, glicko) in [
+            (p0, &v0, &v1, outcome_0, new_elo_0, glicko_0),
+            (p1, &v1, &v0, outcome_1, new_elo_1, glicko_1),
+        ] {
+            if p.rated == 0 {
+                continue;
+            }
+            let ps = result
                 .player_scores
                 .iter()
-                .find(|s| s.bot_version_id == b.bot_version_id)
-                .map(|s| s.score)
-                .unwrap_or(0);
-            score_b.cmp(&score_a)
-        });
+                .find(|s| s.bot_version_id == p.bot_version_id);
+            let _ = db
+                .update_match_participant(
+                    p.id,
+                    ps.map(|s| s.score).unwrap_or(0),
+                    Some(if outcome == crate::elo::Outcome::Win {
+                        1
+                    } else {
+                        2
+                    }),
+                    Some(v.elo_1v1),
+                    Some(new_elo),
+                    ps.map(|s| s.creatures_spawned).unwrap_or(0),
+                    ps.map(|s| s.creatures_killed).unwrap_or(0),
+                    ps.map(|s| s.creatures_lost).unwrap_or(0),
+                )
+                .await;
+            let _ = db.update_version_elo(p.bot_version_id, new_elo).await;
+            let _ = db
+                .record_rating_history(
+                    p.bot_version_id,
+                    match_id,
+                    v.elo_1v1,
+                    new_elo,
+                    Some(opponent.id),
+                    opponent.elo_1v1,
+                )
+                .await;
+            let _ = db.update_version_glicko(p.bot_version_id, &glicko).await;
+        }
+    }
 
+    // FFA placement scoring, from the same tie-sharing places as the leaderboard;
+    // the unrated side of a cross-league game keeps its points and skill
+    if ranked && format == "ffa" && participants.len() > 2 {
         let n_players = participants.len() as i32;
-        for (placement_idx, p) in sorted.iter().enumerate() {
-            let placement = (placement_idx + 1) as i32;
-            let points = crate::elo::ffa_placement_points(placement, n_players);
+        for (p, &place) in participants.iter().zip(&score_ranks).filter(|(p, _)| p.rated != 0) {
+            let points = crate::elo::ffa_placement_points(place as i32, n_players);
             let _ = db.update_version_ffa_stats(p.bot_version_id, points).await;
         }
+
+        // Weng-Lin skill over the full score ranking
+        let mut skills = Vec::with_capacity(participants.len());
+        for p in participants.iter() {
+            match db.get_bot_version_by_id(p.bot_version_id).await {
+                Ok(Some(v)) => skills.push(vec![v.ffa_skill()]),
+                _ => return,
+            }
+        }
+        let rated = crate::openskill::rate(&skills, &score_ranks);
+        for (p, skill) in participants.iter().zip(rated).filter(|(p, _)| p.rated != 0) {
+            let _ = db.update_version_ffa_skill(p.bot_version_id, &skill[0]).await;
+        }
+    }
+
+    // 2v2 team skill and team version records
+    if let (true, Some(teams), Some(ranks)) = (ranked, teams_2v2, &team_ranks) {
+        let mut skills = Vec::with_capacity(2);
+        for team in &teams {
+            let mut members = Vec::with_capacity(2);
+            for p in team {
+                match db.get_bot_version_by_id(p.bot_version_id).await {
+                    Ok(Some(v)) => members.push(v.team_skill()),
+                    _ => return,
+                }
+            }
+            skills.push(members);
+        }
+        let rated = crate::openskill::rate(&skills, ranks);
+        // Team Elo of each pair before the game, from its newest team version
+        let mut team_elo = Vec::with_capacity(2);
+        for team in &teams {
+            team_elo.push(
+                db.team_pair_rating(team[0].bot_version_id, team[1].bot_version_id)
+                    .await
+                    .ok()
+                    .flatten(),
+            );
+        }
+        for (t, (team, members)) in teams.iter().zip(rated).enumerate() {
+            for (p, skill) in team.iter().zip(members).filter(|(p, _)| p.rated != 0) {
+                let _ = db.update_version_team_skill(p.bot_version_id, &skill).await;
+            }
+            let other = ranks[1 - t];
+            if let (Some((rating, games)), true) = (team_elo[t], team[0].rated != 0) {
+                let opponent = team_elo[1 - t].map_or(crate::elo::STARTING_ELO, |(r, _)| r);
+                let new_elo = crate::elo::calculate_new_rating(
+                    rating,
+                    opponent,
+                    crate::elo::Outcome::from_ranks(ranks[t], other),
+                    games,
+                );
+                let _ = db
+                    .update_team_pair_elo(team[0].bot_version_id, team[1].bot_version_id, new_elo)
+                    .await;
+            }
+            let _ = db
+                .record_team_version_result(
+                    team[0].bot_version_id,
+                    team[1].bot_version_id,
+                    ranks[t] < other,
+                    ranks[t] > other,
+                    ranks[t] == other,
+                )
+                .await;
+            if team[0].rated != 0 {
+                let _ = db
+                    .record_leaderboard_team_result(
+                        match_id,
+                        team[0].bot_version_id,
+                        team[1].bot_version_id,
+                        ranks[t] < other,
+                        ranks[t] > other,
+                    )
+                    .await;
+            }
+        }
     }
 
     // Tournament advancement
@@ -492,16 +644,33 @@ async fn advance_tournament_round(
             }
             generate_single_elimination_bracket(&winners)
         }
+        TournamentFormat::DoubleElimination { bracket_reset } => {
+            let details = db
+                .get_tournament_matches_detail(tournament_id)
+                .await
+                .unwrap_or_default();
+            double_elimination_state(&all_version_ids, &bracket_games(&details), *bracket_reset)
+                .next_round
+        }
         TournamentFormat::Swiss { .. } => {
-            let standings = db
-                .get_tournament_standings(tournament_id)
+            let details = db
+                .get_tournament_matches_detail(tournament_id)
                 .await
                 .unwrap_or_default();
-            let standings_tuples: Vec<(i64, f64)> = standings
-                .iter()
-                .map(|s| (s.bot_version_id, s.total_score as f64))
-                .collect();
-            generate_swiss_pairings(&all_version_ids, &standings_tuples, next_round as usize)
+            let byes = db
+                .list_tournament_byes(tournament_id)
+                .await
+                .unwrap_or_default();
+            let standings = swiss_standings(&all_version_ids, &bracket_games(&details), &byes);
+            let swiss = generate_swiss_pairings(&standings);
+            if let Some(bye) = swiss.bye {
+                if let Err(e) = db.add_tournament_bye(tournament_id, bye, next_round).await {
+                    tracing::error!(
+                        "Failed to record round {next_round} bye of tournament {tournament_id}: {e}"
+                    );
+                }
+            }
+            swiss.pairs
         }
         TournamentFormat::RoundRobin => return,
     };
@@ -512,7 +681,8 @@ async fn advance_tournament_round(
     }
 
     for (vid_a, vid_b) in &pairings {
-        let m = match db.create_match("1v1", map).await {
+        // Unranked, like the first round (see `run_tournament`)
+        let m = match db.create_match("1v1", map, false).await {
             Ok(m) => m,
             Err(_) => continue,
         };
@@ -0,0 +1,740 @@
+// Full rating recomputation from match history.
+//
+// Resets every version to its starting ratings and replays all finished ranked
+// matches in `finished_at` order through the current rating code: Elo (with
+// the live K-factors and soft reset), Glicko-2, Weng-Lin skill for FFA and 2v2,
+// team Elo for 2v2 and FFA placement points. Version creations are interleaved
+// with matches so each new version soft-resets from its parent's rating at the
+// time it was created, and each new team version starts its pair over.
+// `Database::recompute_ratings` loads the input, applies the result and builds
+// the report.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::elo::{self, Outcome};
+use crate::glicko::{self, Glicko2Rating};
+use crate::openskill::{self, SkillRating};
+
+/// A bot version as the replay sees it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReplayVersion {
+    pub id: i64,
+    pub bot_id: i64,
+    pub version: i32,
+    pub created_at: String,
+}
+
+/// A team version as the replay sees it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReplayTeamVersion {
+    pub id: i64,
+    pub bot_version_a: i64,
+    pub bot_version_b: i64,
+    pub created_at: String,
+}
+
+/// One participant of a finished match.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReplayParticipant {
+    pub id: i64,
+    pub match_id: i64,
+    pub bot_version_id: i64,
+    pub player_slot: i32,
+    pub final_score: i32,
+    /// 0 for the unrated side of a cross-league ranked match.
+    pub rated: i32,
+}
+
+/// A finished match, participants ordered by slot.
+#[derive(Debug, Clone)]
+pub struct ReplayMatch {
+    pub id: i64,
+    pub format: String,
+    pub winner_bot_version_id: Option<i64>,
+    pub finished_at: String,
+    pub participants: Vec<ReplayParticipant>,
+}
+
+/// Every rating a version ends up with after the replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecomputedVersion {
+    pub elo: i32,
+    pub elo_peak: i32,
+    pub glicko: Glicko2Rating,
+    pub ffa_skill: SkillRating,
+    pub team_skill: SkillRating,
+    pub ffa_placement_points: i32,
+    pub ffa_games: i32,
+}
+
+/// A rating history row produced by the replay. A season reset has no match
+/// or opponent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecomputedHistory {
+    pub bot_version_id: i64,
+    pub match_id: Option<i64>,
+    pub rating_before: i32,
+    pub rating_after: i32,
+    pub opponent_version_id: Option<i64>,
+    pub opponent_rating: Option<i32>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Recomputed {
+    pub versions: HashMap<i64, RecomputedVersion>,
+    /// Team Elo per team version.
+    pub team_elo: HashMap<i64, i32>,
+    pub history: Vec<RecomputedHistory>,
+    /// `(participant id, elo_before, elo_after)` for 1v1 participants.
+    pub participant_elo: Vec<(i64, i32, i32)>,
+    pub matches_replayed: usize,
+}
+
+struct State {
+    rating: RecomputedVersion,
+    games: i32,
+}
+
+/// Team versions by member pair, as `record_team_version_result` and
+/// `update_team_pair_elo` find them: the pair's rating is that of its newest
+/// team version, and a game updates every team version of the pair.
+#[derive(Default)]
+struct Teams {
+    by_pair: HashMap<(i64, i64), Vec<i64>>,
+    /// `(elo, games played)` per team version.
+    states: HashMap<i64, (i32, i32)>,
+}
+
+impl Teams {
+    fn pair(a: i64, b: i64) -> (i64, i64) {
+        (a.min(b), a.max(b))
+    }
+
+    fn rating(&self, a: i64, b: i64) -> Option<(i32, i32)> {
+        let newest = self.by_pair.get(&Teams::pair(a, b))?.last()?;
+        self.states.get(newest).copied()
+    }
+
+    fn record(&mut self, a: i64, b: i64, elo: Option<i32>) {
+        for id in self.by_pair.get(&Teams::pair(a, b)).into_iter().flatten() {
+            let state = self.states.get_mut(id).unwrap();
+            state.0 = elo.unwrap_or(state.0);
+            state.1 += 1;
+        }
+    }
+}
+
+impl State {
+    fn new(elo: i32) -> Self {
+        State {
+            rating: RecomputedVersion {
+                elo,
+                elo_peak: elo,
+                glicko: Glicko2Rating::default(),
+                ffa_skill: SkillRating::default(),
+                team_skill: SkillRating::default(),
+                ffa_placement_points: 0,
+                ffa_games: 0,
+            },
+            games: 0,
+        }
+    }
+}
+
+/// Replay `matches` over `versions` and `team_versions`, applying the
+/// season soft reset at each of `season_resets` (the `finished_at` of every
+/// finished season). Events are processed in timestamp order; a version
+/// created in the same second as a match finishes exists before that match,
+/// and a season ending in that second ends after it.
+pub fn replay(
+    versions: &[ReplayVersion],
+    team_versions: &[ReplayTeamVersion],
+    matches: &[ReplayMatch],
+    season_resets: &[String],
+) -> Recomputed {
+    enum Event<'a> {
+        Match(&'a ReplayMatch),
+        Version(&'a ReplayVersion),
+        TeamVersion(&'a ReplayTeamVersion),
+        SeasonReset(&'a str),
+    }
+    let mut events: Vec<(&str, u8, i64, Event)> = matches
+        .iter()
+        .map(|m| (m.finished_at.as_str(), 1, m.id, Event::Match(m)))
+        .chain(
+            versions
+                .iter()
+                .map(|v| (v.created_at.as_str(), 0, v.id, Event::Version(v))),
+        )
+        .chain(
+            team_versions
+                .iter()
+                .map(|t| (t.created_at.as_str(), 0, t.id, Event::TeamVersion(t))),
+        )
+        .chain(
+            season_resets
+                .iter()
+                .enumerate()
+                .map(|(i, at)| (at.as_str(), 2, i as i64, Event::SeasonReset(at))),
+        )
+        .collect();
+    events.sort_by(|a, b| (a.0, a.1, a.2).cmp(&(b.0, b.1, b.2)));
+
+    let by_bot_version: HashMap<(i64, i32), i64> = versions
+        .iter()
+        .map(|v| ((v.bot_id, v.version), v.id))
+        .collect();
+    let mut states: HashMap<i64, State> = HashMap::new();
+    let mut teams = Teams::default();
+    let mut out = Recomputed::default();
+
+    for (_, _, _, event) in events {
+        match event {
+            Event::Version(v) => {
+                let parent = by_bot_version
+                    .get(&(v.bot_id, v.version - 1))
+                    .and_then(|id| states.get(id));
+                let elo = match parent {
+                    Some(p) if v.version > 1 => elo::soft_reset_elo(p.rating.elo),
+                    _ => elo::STARTING_ELO,
+                };
+                states.insert(v.id, State::new(elo));
+            }
+            Event::TeamVersion(t) => {
+                let pair = Teams::pair(t.bot_version_a, t.bot_version_b);
+                teams.by_pair.entry(pair).or_default().push(t.id);
+                teams.states.insert(t.id, (elo::STARTING_ELO, 0));
+            }
+            Event::Match(m) => {
+                if m.participants
+                    .iter()
+                    .any(|p| !states.contains_key(&p.bot_version_id))
+                {
+                    continue;
+                }
+                replay_match(m, &mut states, &mut teams, &mut out);
+                out.matches_replayed += 1;
+            }
+            Event::SeasonReset(at) => season_reset(at, &mut states, &mut teams, &mut out),
+        }
+    }
+
+    out.versions = states.into_iter().map(|(id, s)| (id, s.rating)).collect();
+    out.team_elo = teams
+        .states
+        .into_iter()
+        .map(|(id, (elo, _))| (id, elo))
+        .collect();
+    out
+}
+
+/// Pull every rating halfway back to its start, as `rollover_season` does,
+/// and record the Elo change for versions away from the starting rating.
+fn season_reset(
+    at: &str,
+    states: &mut HashMap<i64, State>,
+    teams: &mut Teams,
+    out: &mut Recomputed,
+) {
+    let mut ids: Vec<i64> = states.keys().copied().collect();
+    ids.sort_unstable();
+    for id in ids {
+        let r = &mut states.get_mut(&id).unwrap().rating;
+        let before = r.elo;
+        r.elo = elo::soft_reset_elo(before);
+        r.elo_peak = r.elo_peak.max(r.elo);
+        r.glicko.rating = (r.glicko.rating + glicko::STARTING_RATING) / 2.0;
+        r.ffa_skill.mu = (r.ffa_skill.mu + openskill::STARTING_MU) / 2.0;
+        r.team_skill.mu = (r.team_skill.mu + openskill::STARTING_MU) / 2.0;
+        if before != elo::STARTING_ELO {
+            out.history.push(RecomputedHistory {
+                bot_version_id: id,
+                match_id: None,
+                rating_before: before,
+                rating_after: r.elo,
+                opponent_version_id: None,
+                opponent_rating: None,
+                created_at: at.to_string(),
+            });
+        }
+    }
+    for (elo, _) in teams.states.values_mut() {
+        *elo = elo::soft_reset_elo(*elo);
+    }
+}
+
+fn replay_match(
+    m: &ReplayMatch,
+    states: &mut HashMap<i64, State>,
+    teams: &mut Teams,
+    out: &mut Recomputed,
+) {
+    let ps = &m.participants;
+    for p in ps.iter().filter(|p| p.rated != 0) {
+        states.get_mut(&p.bot_version_id).unwrap().games += 1;
+    }
+    let scores: Vec<i64> = ps.iter().map(|p| p.final_score as i64).collect();
+
+    if m.format == "1v1" && ps.len() == 2 {
+        let (a, b) = (ps[0].bot_version_id, ps[1].bot_version_id);
+        let outcome_a = match m.winner_bot_version_id {
+            Some(w) if w == a => Outcome::Win,
+            Some(w) if w == b => Outcome::Loss,
+            _ => Outcome::Draw,
+        };
+        let outcome_b = match outcome_a {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Loss => Outcome::Win,
+            Outcome::Draw => Outcome::Draw,
+        };
+        let (ra, ga) = (&states[&a].rating, states[&a].games);
+        let (rb, gb) = (&states[&b].rating, states[&b].games);
+        let new_a = elo::calculate_new_rating(ra.elo, rb.elo, outcome_a, ga);
+        let new_b = elo::calculate_new_rating(rb.elo, ra.elo, outcome_b, gb);
+        let (glicko_a, glicko_b) = glicko::rate_1v1(ra.glicko, rb.glicko, outcome_a);
+        let (before_a, before_b) = (ra.elo, rb.elo);
+
+        for (p, before, after, opp, opp_before, g) in [
+            (&ps[0], before_a, new_a, b, before_b, glicko_a),
+            (&ps[1], before_b, new_b, a, before_a, glicko_b),
+        ] {
+            if p.rated == 0 {
+                continue;
+            }
+            let r = &mut states.get_mut(&p.bot_version_id).unwrap().rating;
+            r.elo = after;
+            r.elo_peak = r.elo_peak.max(after);
+            r.glicko = g;
+            out.participant_elo.push((p.id, before, after));
+            out.history.push(RecomputedHistory {
+                bot_version_id: p.bot_version_id,
+                match_id: Some(m.id),
+                rating_before: before,
+                rating_after: after,
+                opponent_version_id: Some(opp),
+                opponent_rating: Some(opp_before),
+                created_at: m.finished_at.clone(),
+            });
+        }
+    }
+
+    if m.format == "ffa" && ps.len() > 2 {
+        // Places share ties, as in the live placement points
+        let places = openskill::ranks_from_scores(&scores);
+        for (p, &place) in ps.iter().zip(&places).filter(|(p, _)| p.rated != 0) {
+            let r = &mut states.get_mut(&p.bot_version_id).unwrap().rating;
+            r.ffa_placement_points += elo::ffa_placement_points(place as i32, ps.len() as i32);
+            r.ffa_games += 1;
+        }
+
+        let teams: Vec<Vec<SkillRating>> = ps
+            .iter()
+            .map(|p| vec![states[&p.bot_version_id].rating.ffa_skill])
+            .collect();
+        let rated = openskill::rate(&teams, &places);
+        for (p, skill) in ps.iter().zip(rated).filter(|(p, _)| p.rated != 0) {
+            states.get_mut(&p.bot_version_id).unwrap().rating.ffa_skill = skill[0];
+        }
+    }
+
+    if m.format == "2v2" && ps.len() == 4 {
+        let skills: Vec<Vec<SkillRating>> = ps
+            .chunks(2)
+            .map(|t| {
+                t.iter()
+                    .map(|p| states[&p.bot_version_id].rating.team_skill)
+                    .collect()
+            })
+            .collect();
+        let team_scores: Vec<i64> = scores.chunks(2).map(|t| t.iter().sum()).collect();
+        let ranks = openskill::ranks_from_scores(&team_scores);
+        let rated = openskill::rate(&skills, &ranks);
+        for (p, skill) in ps.iter().zip(rated.into_iter().flatten()) {
+            if p.rated != 0 {
+                states.get_mut(&p.bot_version_id).unwrap().rating.team_skill = skill;
+            }
+        }
+
+        // Team Elo, from every side's rating before the game
+        let sides: Vec<&[ReplayParticipant]> = ps.chunks(2).collect();
+        let before: Vec<Option<(i32, i32)>> = sides
+            .iter()
+            .map(|t| teams.rating(t[0].bot_version_id, t[1].bot_version_id))
+            .collect();
+        for (t, side) in sides.iter().enumerate() {
+            let opponent = before[1 - t].map_or(elo::STARTING_ELO, |(r, _)| r);
+            let new_elo = match before[t] {
+                Some((rating, games)) if side[0].rated != 0 => Some(elo::calculate_new_rating(
+                    rating,
+                    opponent,
+                    Outcome::from_ranks(ranks[t], ranks[1 - t]),
+                    games,
+                )),
+                _ => None,
+            };
+            teams.record(side[0].bot_version_id, side[1].bot_version_id, new_elo);
+        }
+    }
+}
+
+/// Ratings of one version before and after a recomputation.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionChange {
+    pub bot_version_id: i64,
+    pub bot_name: String,
+    pub version: i32,
+    pub elo_before: i32,
+    pub elo_after: i32,
+    pub glicko_before: i32,
+    pub glicko_after: i32,
+    /// 1v1 Elo leaderboard rank, if the version is listed there.
+    pub rank_before: Option<usize>,
+    pub rank_after: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecomputeReport {
+    pub dry_run: bool,
+    pub matches_replayed: usize,
+    pub versions_rated: usize,
+    /// Versions whose Elo, Glicko-2 rating or leaderboard rank changed,
+    /// largest Elo change first.
+    pub changes: Vec<VersionChange>,
+}
+
+impl RecomputeReport {
+    /// Plain-text table for the command line.
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{} {} matches, {} versions rated, {} changed\n",
+            if self.dry_run {
+                "Dry run:"
+            } else {
+                "Recomputed:"
+            },
+            self.matches_replayed,
+            self.versions_rated,
+            self.changes.len()
+        );
+        if self.changes.is_empty() {
+            return out;
+        }
+        out.push_str(&format!(
+            "{:<24} {:>4} {:>11} {:>11} {:>9}\n",
+            "bot", "ver", "elo", "glicko", "rank"
+        ));
+        let rank = |r: Option<usize>| r.map_or("-".to_string(), |r| r.to_string());
+        for c in &self.changes {
+            out.push_str(&format!(
+                "{:<24} {:>4} {:>11} {:>11} {:>9}\n",
+                c.bot_name,
+                format!("v{}", c.version),
+                format!("{}->{}", c.elo_before, c.elo_after),
+                format!("{}->{}", c.glicko_before, c.glicko_after),
+                format!("{}->{}", rank(c.rank_before), rank(c.rank_after)),
+            ));
+        }
+        out
+    }
+}
+
+/// Rank versions by rating, highest first (ties by id), as the 1v1
+/// leaderboard lists them.
+pub fn rank(ratings: &[(i64, i32)]) -> HashMap<i64, usize> {
+    let mut sorted = ratings.to_vec();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, _))| (id, i + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(id: i64, bot_id: i64, version: i32, created_at: &str) -> ReplayVersion {
+        ReplayVersion {
+            id,
+            bot_id,
+            version,
+            created_at: created_at.into(),
+        }
+    }
+
+    fn game(
+        id: i64,
+        format: &str,
+        finished_at: &str,
+        players: &[(i64, i32)],
+        winner: Option<i64>,
+    ) -> ReplayMatch {
+        ReplayMatch {
+            id,
+            format: format.into(),
+            winner_bot_version_id: winner,
+            finished_at: finished_at.into(),
+            participants: players
+                .iter()
+                .enumerate()
+                .map(|(slot, &(bot_version_id, final_score))| ReplayParticipant {
+                    id: id * 10 + slot as i64,
+                    match_id: id,
+                    bot_version_id,
+                    player_slot: slot as i32,
+                    final_score,
+                    rated: 1,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_replay_matches_live_elo() {
+        let versions = [version(1, 1, 1, "01"), version(2, 2, 1, "01")];
+        let matches = [
+            game(1, "1v1", "02", &[(1, 10), (2, 5)], Some(1)),
+            game(2, "1v1", "03", &[(1, 10), (2, 5)], Some(1)),
+        ];
+        let r = replay(&versions, &[], &matches, &[]);
+
+        // First game counts towards games played, as in the queue
+        let a1 = elo::calculate_new_rating(1500, 1500, Outcome::Win, 1);
+        let b1 = elo::calculate_new_rating(1500, 1500, Outcome::Loss, 1);
+        let a2 = elo::calculate_new_rating(a1, b1, Outcome::Win, 2);
+        assert_eq!(r.versions[&1].elo, a2);
+        assert_eq!(r.versions[&1].elo_peak, a2);
+        assert_eq!(r.versions[&2].elo_peak, 1500);
+        assert_eq!(r.matches_replayed, 2);
+        assert_eq!(r.history.len(), 4);
+        assert_eq!(r.history[2].rating_before, a1);
+        assert_eq!(r.participant_elo[0], (10, 1500, a1));
+        assert!(r.versions[&1].glicko.rating > r.versions[&2].glicko.rating);
+    }
+
+    #[test]
+    fn test_soft_reset_uses_parent_rating_at_creation() {
+        let versions = [
+            version(1, 1, 1, "01"),
+            version(2, 2, 1, "01"),
+            version(3, 1, 2, "03"),
+        ];
+        let matches = [
+            game(1, "1v1", "02", &[(1, 10), (2, 5)], Some(1)),
+            // After v2 of bot 1 exists; doesn't affect its starting rating
+            game(2, "1v1", "04", &[(1, 10), (2, 5)], Some(1)),
+        ];
+        let r = replay(&versions, &[], &matches, &[]);
+        let parent_then = elo::calculate_new_rating(1500, 1500, Outcome::Win, 1);
+        assert_eq!(r.versions[&3].elo, elo::soft_reset_elo(parent_then));
+        assert_eq!(r.versions[&3].elo_peak, r.versions[&3].elo);
+    }
+
+    #[test]
+    fn test_ffa_and_2v2_replay() {
+        let versions: Vec<ReplayVersion> = (1..=4).map(|i| version(i, i, 1, "01")).collect();
+        let matches = [
+            game(
+                1,
+                "ffa",
+                "02",
+                &[(1, 50), (2, 90), (3, 10), (4, 30)],
+                Some(2),
+            ),
+            game(
+                2,
+                "2v2",
+                "03",
+                &[(1, 40), (2, 40), (3, 50), (4, 10)],
+                Some(3),
+            ),
+        ];
+        let r = replay(&versions, &[], &matches, &[]);
+        assert_eq!(r.versions[&2].ffa_placement_points, 4);
+        assert_eq!(r.versions[&3].ffa_placement_points, 1);
+        assert_eq!(r.versions[&1].ffa_games, 1);
+        assert!(r.versions[&2].ffa_skill.mu > r.versions[&1].ffa_skill.mu);
+        assert!(r.versions[&1].team_skill.mu > openskill::STARTING_MU);
+        assert!(r.versions[&3].team_skill.mu < openskill::STARTING_MU);
+        // Elo is 1v1 only
+        assert!(r.history.is_empty());
+        assert!(r.versions.values().all(|v| v.elo == 1500));
+    }
+
+    #[test]
+    fn test_tied_ffa_scores_share_placement_points() {
+        let versions: Vec<ReplayVersion> = (1..=4).map(|i| version(i, i, 1, "01")).collect();
+        let m = game(
+            1,
+            "ffa",
+            "02",
+            &[(1, 300), (2, 900), (3, 300), (4, 100)],
+            Some(2),
+        );
+        let r = replay(&versions, &[], &[m], &[]);
+        let points: Vec<i32> = (1..=4)
+            .map(|id| r.versions[&id].ffa_placement_points)
+            .collect();
+        assert_eq!(points, vec![3, 4, 3, 1]);
+    }
+
+    #[test]
+    fn test_unrated_side_keeps_its_rating() {
+        let versions = [version(1, 1, 1, "01"), version(2, 2, 1, "01")];
+        let mut m = game(1, "1v1", "02", &[(1, 10), (2, 5)], Some(1));
+        m.participants[1].rated = 0;
+        let r = replay(&versions, &[], &[m], &[]);
+        assert!(r.versions[&1].elo > 1500);
+        assert_eq!(r.versions[&2].elo, 1500);
+        assert_eq!(r.history.len(), 1);
+        assert_eq!(r.participant_elo.len(), 1);
+    }
+
+    #[test]
+    fn test_2v2_replay_rates_team_pairs() {
+        let versions: Vec<ReplayVersion> = (1..=4).map(|i| version(i, i, 1, "01")).collect();
+        let team = |id, a, b, created_at: &str| ReplayTeamVersion {
+            id,
+            bot_version_a: a,
+            bot_version_b: b,
+            created_at: created_at.to_string(),
+        };
+        // Team 10 re-registers its pair as team version 11 before game 2
+        let team_versions = [
+            team(10, 1, 2, "01"),
+            team(20, 4, 3, "01"),
+            team(11, 2, 1, "03"),
+        ];
+        let matches = [
+            game(
+                1,
+                "2v2",
+                "02",
+                &[(1, 40), (2, 40), (3, 50), (4, 10)],
+                Some(3),
+            ),
+            game(
+                2,
+                "2v2",
+                "04",
+                &[(1, 10), (2, 10), (3, 50), (4, 10)],
+                Some(3),
+            ),
+        ];
+        let r = replay(&versions, &team_versions, &matches, &[]);
+
+        let lost = elo::calculate_new_rating(1500, 1500, Outcome::Loss, 0);
+        // The new team version starts over, then the pair's versions share it
+        let lost_again = elo::calculate_new_rating(1500, lost, Outcome::Loss, 0);
+        assert_eq!(r.team_elo[&11], lost_again);
+        assert_eq!(r.team_elo[&10], lost_again);
+        assert_eq!(
+            r.team_elo[&20],
+            elo::calculate_new_rating(lost, 1500, Outcome::Win, 1)
+        );
+    }
+
+    #[test]
+    fn test_unrated_participants_keep_ffa_and_team_ratings() {
+        let versions: Vec<ReplayVersion> = (1..=4).map(|i| version(i, i, 1, "01")).collect();
+        let team_versions = [
+            ReplayTeamVersion {
+                id: 10,
+                bot_version_a: 1,
+                bot_version_b: 2,
+                created_at: "01".to_string(),
+            },
+            ReplayTeamVersion {
+                id: 20,
+                bot_version_a: 3,
+                bot_version_b: 4,
+                created_at: "01".to_string(),
+            },
+        ];
+        let mut ffa = game(
+            1,
+            "ffa",
+            "02",
+            &[(1, 50), (2, 90), (3, 10), (4, 30)],
+            Some(2),
+        );
+        ffa.participants[1].rated = 0;
+        let mut team = game(
+            2,
+            "2v2",
+            "03",
+            &[(1, 40), (2, 40), (3, 50), (4, 10)],
+            Some(3),
+        );
+        team.participants[0].rated = 0;
+        team.participants[1].rated = 0;
+        let r = replay(&versions, &team_versions, &[ffa, team], &[]);
+
+        assert_eq!(r.versions[&2].ffa_games, 0);
+        assert_eq!(r.versions[&2].ffa_placement_points, 0);
+        assert_eq!(r.versions[&2].ffa_skill, SkillRating::default());
+        assert_eq!(r.versions[&1].ffa_games, 1);
+        assert_eq!(r.versions[&1].team_skill, SkillRating::default());
+        assert!(r.versions[&3].team_skill.mu < openskill::STARTING_MU);
+        assert_eq!(r.team_elo[&10], elo::STARTING_ELO);
+        assert!(r.team_elo[&20] < elo::STARTING_ELO);
+    }
+
+    #[test]
+    fn test_season_reset_is_replayed() {
+        let versions = [version(1, 1, 1, "01"), version(2, 2, 1, "01")];
+        let matches = [
+            game(1, "1v1", "02", &[(1, 10), (2, 5)], Some(1)),
+            game(2, "1v1", "04", &[(1, 10), (2, 5)], Some(1)),
+        ];
+        let r = replay(&versions, &[], &matches, &["03".to_string()]);
+
+        let a1 = elo::calculate_new_rating(1500, 1500, Outcome::Win, 1);
+        let b1 = elo::calculate_new_rating(1500, 1500, Outcome::Loss, 1);
+        let (a_reset, b_reset) = (elo::soft_reset_elo(a1), elo::soft_reset_elo(b1));
+        assert_eq!(r.history.len(), 6);
+        assert_eq!(
+            (r.history[2].match_id, r.history[2].rating_before),
+            (None, a1)
+        );
+        assert_eq!(r.history[2].rating_after, a_reset);
+        assert_eq!(r.history[3].created_at, "03");
+        assert_eq!(r.history[4].rating_before, a_reset);
+        assert_eq!(r.history[4].opponent_rating, Some(b_reset));
+        assert_eq!(
+            r.versions[&1].elo,
+            elo::calculate_new_rating(a_reset, b_reset, Outcome::Win, 2)
+        );
+        assert_eq!(r.versions[&1].elo_peak, r.versions[&1].elo.max(a1));
+        let (mut ga, mut gb) = glicko::rate_1v1(
+            Glicko2Rating::default(),
+            Glicko2Rating::default(),
+            Outcome::Win,
+        );
+        ga.rating = (ga.rating + glicko::STARTING_RATING) / 2.0;
+        gb.rating = (gb.rating + glicko::STARTING_RATING) / 2.0;
+        assert_eq!(
+            r.versions[&1].glicko,
+            glicko::rate_1v1(ga, gb, Outcome::Win).0
+        );
+    }
+
+    #[test]
+    fn test_matches_with_unknown_versions_are_skipped() {
+        let versions = [version(1, 1, 1, "01")];
+        let matches = [game(1, "1v1", "02", &[(1, 10), (99, 5)], Some(1))];
+        let r = replay(&versions, &[], &matches, &[]);
+        assert_eq!(r.matches_replayed, 0);
+        assert_eq!(r.versions[&1].elo, 1500);
+    }
+
+    #[test]
+    fn test_rank() {
+        let ranks = rank(&[(1, 1500), (2, 1600), (3, 1500)]);
+        assert_eq!((ranks[&2], ranks[&1], ranks[&3]), (1, 2, 3));
+    }
+}
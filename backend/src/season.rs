@@ -0,0 +1,78 @@
+// Competitive seasons.
+//
+// One season is active at a time. When its end date passes, the scheduler
+// archives the final 1v1 standings and soft-resets all ratings (see
+// `Database::rollover_season`), then starts the next season of the same
+// length. Past standings stay readable through `/api/seasons/{id}/leaderboard`.
+
+use std::sync::Arc;
+
+use crate::db::{Database, Season};
+
+/// Season length unless configured otherwise (`INFON_SEASON_DAYS`).
+pub const DEFAULT_SEASON_DAYS: i64 = 90;
+
+/// How often the scheduler checks whether the active season is over.
+const CHECK_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Start the first season if there is none, or roll over the active one if
+/// it has ended. Returns the season that was started, if any.
+pub async fn check_rollover(
+    db: &Database,
+    length_days: i64,
+) -> Result<Option<Season>, sqlx::Error> {
+    if db.active_season().await?.is_some() && db.expired_season().await?.is_none() {
+        return Ok(None);
+    }
+    // Rechecked under the lock: an admin rollover may have started a new season
+    db.rollover_due_season(length_days).await
+}
+
+/// Spawn the season scheduler. `length_days == 0` disables seasons.
+pub fn spawn_season_scheduler(db: Arc<Database>, length_days: i64) {
+    if length_days <= 0 {
+        return;
+    }
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            match check_rollover(&db, length_days).await {
+                Ok(Some(season)) => {
+                    tracing::info!("{} started, ends {}", season.name, season.ends_at)
+                }
+                Ok(None) => {}
+                Err(e) => tracing::error!("Season rollover failed: {e}"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check_rollover() {
+        sqlx::any::install_default_drivers();
+        let db = Database::new("sqlite::memory:").await.unwrap();
+
+        let first = check_rollover(&db, 30).await.unwrap().unwrap();
+        assert_eq!(first.name, "Season 1");
+        assert!(first.ends_at > first.starts_at);
+        // Not over yet, also when rechecked under the lock
+        assert!(check_rollover(&db, 30).await.unwrap().is_none());
+        assert!(db.rollover_due_season(30).await.unwrap().is_none());
+
+        // A zero-length season is over right away
+        let short = db.rollover_season(0).await.unwrap();
+        assert_eq!(short.name, "Season 2");
+        let next = check_rollover(&db, 30).await.unwrap().unwrap();
+        assert_eq!(next.name, "Season 3");
+        let seasons = db.list_seasons().await.unwrap();
+        assert_eq!(seasons.len(), 3);
+        assert!(seasons[0].finished_at.is_none());
+        assert!(seasons[1..].iter().all(|s| s.finished_at.is_some()));
+    }
+}
@@ -1,5 +1,7 @@
 // Tournament format support: bracket generation for single elimination,
-// round robin, and Swiss-style pairings.
+// double elimination, round robin, and Swiss-style pairings.
+
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
@@ -7,8 +9,16 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "snake_case")]
 pub enum TournamentFormat {
     SingleElimination,
+    /// Winners and losers brackets; a bot is out after its second loss.
+    /// With `bracket_reset`, a grand final won by the losers-bracket side is
+    /// replayed, since the winners-bracket side then has only one loss.
+    DoubleElimination {
+        bracket_reset: bool,
+    },
     RoundRobin,
-    Swiss { rounds: usize },
+    Swiss {
+        rounds: usize,
+    },
 }
 
 impl TournamentFormat {
@@ -16,6 +26,12 @@ impl TournamentFormat {
     pub fn from_str_name(s: &str) -> Option<Self> {
         match s {
             "single_elimination" => Some(Self::SingleElimination),
+            "double_elimination" => Some(Self::DoubleElimination {
+                bracket_reset: true,
+            }),
+            "double_elimination_no_reset" => Some(Self::DoubleElimination {
+                bracket_reset: false,
+            }),
             "round_robin" => Some(Self::RoundRobin),
             s if s.starts_with("swiss") => {
                 // Parse "swiss_N" or just "swiss" (defaults to 3 rounds)
@@ -33,6 +49,12 @@ impl TournamentFormat {
     pub fn to_str_name(&self) -> String {
         match self {
             Self::SingleElimination => "single_elimination".to_string(),
+            Self::DoubleElimination {
+                bracket_reset: true,
+            } => "double_elimination".to_string(),
+            Self::DoubleElimination {
+                bracket_reset: false,
+            } => "double_elimination_no_reset".to_string(),
             Self::RoundRobin => "round_robin".to_string(),
             Self::Swiss { rounds } => format!("swiss_{rounds}"),
         }
@@ -70,47 +92,461 @@ pub fn generate_round_robin_pairings(participants: &[i64]) -> Vec<(i64, i64)> {
     pairs
 }
 
-/// Generate Swiss-style pairings for a given round.
-///
-/// Participants are sorted by their current standings (score descending),
-/// then paired top-down: 1st vs 2nd, 3rd vs 4th, etc.
-/// If the number of participants is odd, the lowest-ranked player gets a bye.
+/// Upper bound on backtracking steps when pairing a Swiss round without
+/// rematches; past it the round falls back to pairing neighbours.
+const SWISS_PAIRING_BUDGET: usize = 100_000;
+
+/// A participant's Swiss record, as returned by `swiss_standings`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwissRecord {
+    pub bot_version_id: i64,
+    /// 1 per win or bye, 0.5 per draw.
+    pub points: f64,
+    pub wins: i32,
+    pub draws: i32,
+    pub losses: i32,
+    pub byes: i32,
+    /// Sum of the points of every opponent faced.
+    pub buchholz: f64,
+    /// Sum of the points of beaten opponents plus half of drawn ones'.
+    pub sonneborn_berger: f64,
+    /// Points scored against the other participants tied on points,
+    /// Buchholz and Sonneborn-Berger.
+    pub head_to_head: f64,
+    /// Everyone this participant has been paired with, finished or not.
+    pub opponents: Vec<i64>,
+}
+
+/// Pairings for one Swiss round.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SwissPairings {
+    pub pairs: Vec<(i64, i64)>,
+    /// Participant sitting the round out; it scores a point for it.
+    pub bye: Option<i64>,
+}
+
+/// Score a Swiss tournament from its games and byes and rank it: points,
+/// then Buchholz, Sonneborn-Berger and head-to-head. Ties left after that
+/// keep the order of `participants`.
 ///
-/// `standings` is a slice of (version_id, score) tuples.
-/// Returns Vec of (version_id_a, version_id_b) match pairs.
-pub fn generate_swiss_pairings(
+/// Only finished games count towards the score; a finished game without a
+/// winner is a draw. A bye is worth a point but adds nothing to Buchholz.
+pub fn swiss_standings(
     participants: &[i64],
-    standings: &[(i64, f64)],
-    _round: usize,
-) -> Vec<(i64, i64)> {
-    // Sort participants by score descending
-    let mut sorted: Vec<i64> = participants.to_vec();
-    sorted.sort_by(|a, b| {
-        let score_a = standings
+    games: &[BracketGame],
+    byes: &[i64],
+) -> Vec<SwissRecord> {
+    let mut records: Vec<SwissRecord> = participants
+        .iter()
+        .map(|&id| SwissRecord {
+            bot_version_id: id,
+            points: 0.0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            byes: 0,
+            buchholz: 0.0,
+            sonneborn_berger: 0.0,
+            head_to_head: 0.0,
+            opponents: Vec::new(),
+        })
+        .collect();
+    let index: HashMap<i64, usize> = participants
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| (id, i))
+        .collect();
+
+    // Points each participant scored in each finished game, as (player, opponent, points).
+    let mut results: Vec<(usize, usize, f64)> = Vec::new();
+    for g in games {
+        let (Some(&a), Some(&b)) = (index.get(&g.a), index.get(&g.b)) else {
+            continue;
+        };
+        records[a].opponents.push(g.b);
+        records[b].opponents.push(g.a);
+        if !g.finished {
+            continue;
+        }
+        let score_a = match g.winner {
+            Some(w) if w == g.a => 1.0,
+            Some(w) if w == g.b => 0.0,
+            _ => 0.5,
+        };
+        results.push((a, b, score_a));
+        results.push((b, a, 1.0 - score_a));
+    }
+    for &(player, _, score) in &results {
+        let r = &mut records[player];
+        r.points += score;
+        if score == 1.0 {
+            r.wins += 1;
+        } else if score == 0.0 {
+            r.losses += 1;
+        } else {
+            r.draws += 1;
+        }
+    }
+    for id in byes {
+        if let Some(&i) = index.get(id) {
+            records[i].byes += 1;
+            records[i].points += 1.0;
+        }
+    }
+    for &(player, opponent, score) in &results {
+        let opponent_points = records[opponent].points;
+        records[player].buchholz += opponent_points;
+        records[player].sonneborn_berger += score * opponent_points;
+    }
+
+    let key = |r: &SwissRecord| (r.points, r.buchholz, r.sonneborn_berger);
+    let by_key = |a: &SwissRecord, b: &SwissRecord| {
+        key(b)
+            .partial_cmp(&key(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    };
+    records.sort_by(by_key);
+
+    // Head-to-head only separates participants still tied on everything else.
+    let mut start = 0;
+    while start < records.len() {
+        let mut end = start + 1;
+        while end < records.len() && key(&records[end]) == key(&records[start]) {
+            end += 1;
+        }
+        if end - start > 1 {
+            let tied: Vec<usize> = records[start..end]
+                .iter()
+                .map(|r| index[&r.bot_version_id])
+                .collect();
+            for r in &mut records[start..end] {
+                let me = index[&r.bot_version_id];
+                r.head_to_head = results
+                    .iter()
+                    .filter(|&&(player, opponent, _)| player == me && tied.contains(&opponent))
+                    .map(|&(_, _, score)| score)
+                    .sum();
+            }
+            records[start..end].sort_by(|a, b| {
+                b.head_to_head
+                    .partial_cmp(&a.head_to_head)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        start = end;
+    }
+    records
+}
+
+/// Generate Swiss pairings for the next round from `swiss_standings`.
+///
+/// Going down the standings, each participant is paired with the
+/// highest-ranked one it hasn't met yet, so it plays inside its score group
+/// when it can and floats down to the next group when it can't; dead ends
+/// are backtracked. With an odd field the lowest-ranked participant without
+/// a bye sits out. If no pairing avoids every rematch, neighbours are paired.
+pub fn generate_swiss_pairings(standings: &[SwissRecord]) -> SwissPairings {
+    let order: Vec<i64> = standings.iter().map(|r| r.bot_version_id).collect();
+    let played: HashSet<(i64, i64)> = standings
+        .iter()
+        .flat_map(|r| r.opponents.iter().map(move |&o| (r.bot_version_id, o)))
+        .collect();
+
+    if order.len().is_multiple_of(2) {
+        let mut budget = SWISS_PAIRING_BUDGET;
+        let pairs = pair_without_rematches(&order, &played, &mut budget)
+            .unwrap_or_else(|| pair_neighbours(&order));
+        return SwissPairings { pairs, bye: None };
+    }
+
+    // Bye candidates from the bottom up, those who've never had one first.
+    let mut candidates: Vec<&SwissRecord> = standings.iter().rev().collect();
+    candidates.sort_by_key(|r| r.byes);
+    let mut budget = SWISS_PAIRING_BUDGET;
+    for candidate in &candidates {
+        let rest: Vec<i64> = order
             .iter()
-            .find(|(id, _)| *id == *a)
-            .map(|(_, s)| *s)
-            .unwrap_or(0.0);
-        let score_b = standings
+            .copied()
+            .filter(|&id| id != candidate.bot_version_id)
+            .collect();
+        if let Some(pairs) = pair_without_rematches(&rest, &played, &mut budget) {
+            return SwissPairings {
+                pairs,
+                bye: Some(candidate.bot_version_id),
+            };
+        }
+    }
+    let bye = candidates[0].bot_version_id;
+    let rest: Vec<i64> = order.iter().copied().filter(|&id| id != bye).collect();
+    SwissPairings {
+        pairs: pair_neighbours(&rest),
+        bye: Some(bye),
+    }
+}
+
+fn pair_without_rematches(
+    order: &[i64],
+    played: &HashSet<(i64, i64)>,
+    budget: &mut usize,
+) -> Option<Vec<(i64, i64)>> {
+    let Some((&first, rest)) = order.split_first() else {
+        return Some(Vec::new());
+    };
+    for (j, &opponent) in rest.iter().enumerate() {
+        if played.contains(&(first, opponent)) {
+            continue;
+        }
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        let remaining: Vec<i64> = rest
             .iter()
-            .find(|(id, _)| *id == *b)
-            .map(|(_, s)| *s)
-            .unwrap_or(0.0);
-        score_b
-            .partial_cmp(&score_a)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+            .enumerate()
+            .filter(|&(k, _)| k != j)
+            .map(|(_, &id)| id)
+            .collect();
+        if let Some(mut pairs) = pair_without_rematches(&remaining, played, budget) {
+            pairs.insert(0, (first, opponent));
+            return Some(pairs);
+        }
+    }
+    None
+}
 
-    let mut pairs = Vec::new();
-    let mut i = 0;
-    while i + 1 < sorted.len() {
-        pairs.push((sorted[i], sorted[i + 1]));
-        i += 2;
+fn pair_neighbours(order: &[i64]) -> Vec<(i64, i64)> {
+    order.chunks_exact(2).map(|p| (p[0], p[1])).collect()
+}
+
+/// Part of a double-elimination bracket a match belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bracket {
+    Winners,
+    Losers,
+    GrandFinal,
+    /// Replay of a grand final won by the losers-bracket side.
+    GrandFinalReset,
+}
+
+/// A tournament match as input to `double_elimination_state` and
+/// `swiss_standings`.
+#[derive(Debug, Clone)]
+pub struct BracketGame {
+    pub match_id: i64,
+    pub round: i32,
+    pub a: i64,
+    pub b: i64,
+    pub finished: bool,
+    /// `None` on a finished match is a draw.
+    pub winner: Option<i64>,
+}
+
+/// A tournament match placed in the double-elimination bracket.
+#[derive(Debug, Clone, Serialize)]
+pub struct BracketMatch {
+    pub match_id: i64,
+    pub round: i32,
+    pub bracket: Bracket,
+    pub a: i64,
+    pub b: i64,
+    /// Who advances once the match is finished; draws go to the better seed.
+    pub winner: Option<i64>,
+}
+
+/// An entrant's progress through a double-elimination bracket.
+#[derive(Debug, Clone, Serialize)]
+pub struct BracketEntrant {
+    pub bot_version_id: i64,
+    /// 1-based, in entry order.
+    pub seed: usize,
+    pub losses: u32,
+    pub eliminated_round: Option<i32>,
+    /// Final place, shared by bots knocked out in the same round; `None`
+    /// while the bot is still in the tournament.
+    pub placement: Option<usize>,
+}
+
+/// Bracket state rebuilt from the matches played so far.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoubleEliminationState {
+    pub matches: Vec<BracketMatch>,
+    /// Ordered by placement, then seed.
+    pub entrants: Vec<BracketEntrant>,
+    pub champion: Option<i64>,
+    /// Pairings for the next round; empty once the tournament is decided or
+    /// while matches are still being played.
+    #[serde(skip)]
+    pub next_round: Vec<(i64, i64)>,
+}
+
+/// Bracket input from a tournament's matches (slot 0 is `a`, slot 1 `b`).
+pub fn bracket_games(details: &[crate::db::TournamentMatchDetail]) -> Vec<BracketGame> {
+    details
+        .iter()
+        .filter_map(|d| {
+            let slot = |n: i32| {
+                d.participants
+                    .iter()
+                    .find(|p| p.player_slot == n)
+                    .map(|p| p.bot_version_id)
+            };
+            Some(BracketGame {
+                match_id: d.match_id,
+                round: d.round,
+                a: slot(0)?,
+                b: slot(1)?,
+                finished: d.status == "finished",
+                winner: d.winner_bot_version_id,
+            })
+        })
+        .collect()
+}
+
+/// Replay a double-elimination tournament from its matches and pair the
+/// next round.
+///
+/// Bots with no losses form the winners bracket, bots with one loss the
+/// losers bracket. Each round pairs the winners bracket in seed order and
+/// the losers bracket by when each bot dropped into it, earliest against
+/// latest-but-one, so bots coming down from the winners bracket meet losers
+/// bracket survivors. With an odd count the last bot in either order gets a
+/// bye, which is just a round without a game. When one bot is left in each
+/// bracket they meet in the grand final. A draw counts as a win for the
+/// better seed.
+pub fn double_elimination_state(
+    seeds: &[i64],
+    games: &[BracketGame],
+    bracket_reset: bool,
+) -> DoubleEliminationState {
+    let seed_of: HashMap<i64, usize> = seeds.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let mut losses: HashMap<i64, u32> = seeds.iter().map(|&id| (id, 0)).collect();
+    let mut dropped_in: HashMap<i64, i32> = HashMap::new();
+    let mut eliminated: HashMap<i64, i32> = HashMap::new();
+    let alive = |losses: &HashMap<i64, u32>, n: u32| -> Vec<i64> {
+        seeds.iter().copied().filter(|id| losses[id] == n).collect()
+    };
+
+    let mut games: Vec<&BracketGame> = games
+        .iter()
+        .filter(|g| seed_of.contains_key(&g.a) && seed_of.contains_key(&g.b))
+        .collect();
+    games.sort_by_key(|g| (g.round, g.match_id));
+    let mut matches = Vec::with_capacity(games.len());
+    let mut all_finished = true;
+    for round_games in games.chunk_by(|x, y| x.round == y.round) {
+        let (winners, losers) = (alive(&losses, 0), alive(&losses, 1));
+        let mut results = Vec::new();
+        for g in round_games {
+            let bracket = match (losses[&g.a], losses[&g.b]) {
+                (0, 0) => Bracket::Winners,
+                (0, 1) | (1, 0) if winners.len() == 1 && losers.len() == 1 => Bracket::GrandFinal,
+                (1, 1) if winners.is_empty() && losers.len() == 2 => Bracket::GrandFinalReset,
+                _ => Bracket::Losers,
+            };
+            let winner = if g.finished {
+                let better_seed = if seed_of[&g.a] < seed_of[&g.b] {
+                    g.a
+                } else {
+                    g.b
+                };
+                Some(
+                    g.winner
+                        .filter(|w| *w == g.a || *w == g.b)
+                        .unwrap_or(better_seed),
+                )
+            } else {
+                all_finished = false;
+                None
+            };
+            if let Some(w) = winner {
+                let loser = if w == g.a { g.b } else { g.a };
+                results.push((loser, bracket));
+            }
+            matches.push(BracketMatch {
+                match_id: g.match_id,
+                round: g.round,
+                bracket,
+                a: g.a,
+                b: g.b,
+                winner,
+            });
+        }
+        // Results apply after the whole round is classified
+        for (loser, bracket) in results {
+            let n = losses.get_mut(&loser).unwrap();
+            *n += 1;
+            if bracket == Bracket::GrandFinal && !bracket_reset {
+                *n = 2;
+            }
+            if *n == 1 {
+                dropped_in.insert(loser, round_games[0].round);
+            } else {
+                eliminated.insert(loser, round_games[0].round);
+            }
+        }
+    }
+
+    let winners = alive(&losses, 0);
+    let mut losers = alive(&losses, 1);
+    let remaining = winners.len() + losers.len();
+    let champion = if remaining == 1 {
+        winners.first().or(losers.first()).copied()
+    } else {
+        None
+    };
+    let mut next_round = Vec::new();
+    if all_finished && remaining > 1 {
+        // Sequential pairing, last one out gets the bye
+        next_round.extend(generate_single_elimination_bracket(&winners));
+        losers.sort_by_key(|id| (dropped_in[id], seed_of[id]));
+        let paired = losers.len() / 2 * 2;
+        let half = paired / 2;
+        if winners.len() == 1 && losers.len() == 1 {
+            next_round.push((winners[0], losers[0]));
+        } else {
+            next_round.extend((0..half).map(|i| (losers[i], losers[i + half])));
+        }
+    }
+
+    // Champion first, then by how late each bot was knocked out; bots still
+    // in the running rank ahead of everyone knocked out
+    let mut entrants: Vec<BracketEntrant> = seeds
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| BracketEntrant {
+            bot_version_id: id,
+            seed: i + 1,
+            losses: losses[&id],
+            eliminated_round: eliminated.get(&id).copied(),
+            placement: None,
+        })
+        .collect();
+    let order_key = |e: &BracketEntrant| {
+        (
+            Some(e.bot_version_id) != champion,
+            std::cmp::Reverse(e.eliminated_round.unwrap_or(i32::MAX)),
+        )
+    };
+    entrants.sort_by_key(|e| (order_key(e), e.seed));
+    let keys: Vec<_> = entrants.iter().map(order_key).collect();
+    for (e, key) in entrants.iter_mut().zip(&keys) {
+        if e.eliminated_round.is_some() || Some(e.bot_version_id) == champion {
+            e.placement = Some(1 + keys.iter().filter(|k| *k < key).count());
+        }
+    }
+
+    DoubleEliminationState {
+        matches,
+        entrants,
+        champion,
+        next_round,
     }
-    pairs
 }
 
 /// Compute the total number of rounds needed for a tournament format.
+/// For double elimination this is an upper bound: byes and a skipped
+/// bracket reset end it sooner.
 pub fn total_rounds(format: &TournamentFormat, num_participants: usize) -> usize {
     match format {
         TournamentFormat::SingleElimination => {
@@ -121,6 +557,14 @@ pub fn total_rounds(format: &TournamentFormat, num_participants: usize) -> usize
                 (num_participants as f64).log2().ceil() as usize
             }
         }
+        TournamentFormat::DoubleElimination { .. } => {
+            if num_participants <= 1 {
+                0
+            } else {
+                // Winners bracket, losers bracket catching up, grand final and reset
+                2 * (num_participants as f64).log2().ceil() as usize + 2
+            }
+        }
         TournamentFormat::RoundRobin => {
             // Round robin is effectively 1 "round" of all pairings
             1
@@ -217,37 +661,149 @@ mod tests {
         assert!(pairs.is_empty());
     }
 
+    fn game(a: i64, b: i64, winner: Option<i64>) -> BracketGame {
+        BracketGame {
+            match_id: 0,
+            round: 1,
+            a,
+            b,
+            finished: true,
+            winner,
+        }
+    }
+
+    fn ids(standings: &[SwissRecord]) -> Vec<i64> {
+        standings.iter().map(|r| r.bot_version_id).collect()
+    }
+
     #[test]
     fn test_swiss_pairing_by_score() {
-        let participants = vec![1, 2, 3, 4];
-        // Player 3 has highest score, player 1 has lowest
-        let standings = vec![(1, 0.0), (2, 5.0), (3, 10.0), (4, 3.0)];
-        let pairs = generate_swiss_pairings(&participants, &standings, 1);
-        assert_eq!(pairs.len(), 2);
-        // Sorted by score desc: 3(10), 2(5), 4(3), 1(0)
-        assert_eq!(pairs[0], (3, 2));
-        assert_eq!(pairs[1], (4, 1));
+        let games = vec![game(1, 2, Some(2)), game(3, 4, Some(3))];
+        let standings = swiss_standings(&[1, 2, 3, 4], &games, &[]);
+        assert_eq!(ids(&standings), vec![2, 3, 1, 4]);
+        // Winners meet winners and losers meet losers
+        let pairings = generate_swiss_pairings(&standings);
+        assert_eq!(pairings.pairs, vec![(2, 3), (1, 4)]);
+        assert_eq!(pairings.bye, None);
     }
 
     #[test]
     fn test_swiss_pairing_no_standings() {
-        let participants = vec![1, 2, 3, 4];
-        let standings: Vec<(i64, f64)> = vec![];
-        let pairs = generate_swiss_pairings(&participants, &standings, 0);
-        assert_eq!(pairs.len(), 2);
-        // All scores default to 0.0, so order is preserved
-        assert_eq!(pairs[0], (1, 2));
-        assert_eq!(pairs[1], (3, 4));
+        let standings = swiss_standings(&[1, 2, 3, 4], &[], &[]);
+        let pairings = generate_swiss_pairings(&standings);
+        // All scores are equal, so entry order is preserved
+        assert_eq!(pairings.pairs, vec![(1, 2), (3, 4)]);
     }
 
     #[test]
     fn test_swiss_pairing_odd_players() {
-        let participants = vec![1, 2, 3];
-        let standings = vec![(1, 5.0), (2, 10.0), (3, 0.0)];
-        let pairs = generate_swiss_pairings(&participants, &standings, 1);
-        // Sorted: 2(10), 1(5), 3(0) -> only 1 pair, player 3 gets bye
-        assert_eq!(pairs.len(), 1);
-        assert_eq!(pairs[0], (2, 1));
+        let standings = swiss_standings(&[1, 2, 3], &[], &[]);
+        let pairings = generate_swiss_pairings(&standings);
+        assert_eq!(pairings.pairs, vec![(1, 2)]);
+        assert_eq!(pairings.bye, Some(3));
+
+        // The bye is a point and moves on to the lowest-ranked bot without one
+        let standings = swiss_standings(&[1, 2, 3], &[game(1, 2, Some(1))], &[3]);
+        assert_eq!(ids(&standings), vec![1, 3, 2]);
+        assert_eq!(standings[1].points, 1.0);
+        assert_eq!(standings[1].byes, 1);
+        let pairings = generate_swiss_pairings(&standings);
+        assert_eq!(pairings.pairs, vec![(1, 3)]);
+        assert_eq!(pairings.bye, Some(2));
+    }
+
+    #[test]
+    fn test_swiss_pairing_avoids_rematches() {
+        // 1 and 2 lead the field but have already met, so 1 floats down
+        let games = vec![game(1, 2, None), game(3, 4, Some(3)), game(5, 6, Some(5))];
+        let standings = swiss_standings(&[1, 2, 3, 4, 5, 6], &games, &[]);
+        assert_eq!(ids(&standings), vec![3, 5, 1, 2, 4, 6]);
+        let pairings = generate_swiss_pairings(&standings);
+        assert_eq!(pairings.pairs, vec![(3, 5), (1, 4), (2, 6)]);
+
+        // Two bots can only rematch; neighbours are paired again
+        let standings = swiss_standings(&[1, 2], &[game(1, 2, Some(1))], &[]);
+        assert_eq!(generate_swiss_pairings(&standings).pairs, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_swiss_full_tournament_has_no_rematches() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for n in 2..=17i64 {
+            let participants: Vec<i64> = (1..=n).collect();
+            // Few enough rounds that a rematch-free pairing always exists
+            let rounds = ((n - 1) / 2).min(5);
+            let mut games = Vec::new();
+            let mut byes = Vec::new();
+            for _ in 0..rounds {
+                let standings = swiss_standings(&participants, &games, &byes);
+                let pairings = generate_swiss_pairings(&standings);
+                assert_eq!(pairings.pairs.len(), n as usize / 2);
+                assert_eq!(pairings.bye.is_some(), n % 2 == 1);
+                for &(a, b) in &pairings.pairs {
+                    assert!(
+                        !games
+                            .iter()
+                            .any(|g: &BracketGame| (g.a, g.b) == (a, b) || (g.a, g.b) == (b, a)),
+                        "rematch {a} vs {b} with {n} bots"
+                    );
+                    let winner = match rng.gen_range(0..3) {
+                        0 => Some(a),
+                        1 => Some(b),
+                        _ => None,
+                    };
+                    games.push(game(a, b, winner));
+                }
+                if let Some(bye) = pairings.bye {
+                    assert!(!byes.contains(&bye), "second bye for {bye} with {n} bots");
+                    byes.push(bye);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_swiss_tiebreakers() {
+        // 1 beats 2 and 3, 2 beats 3 and 4, 3 beats 4, 4 beats 1
+        let games = vec![
+            game(1, 2, Some(1)),
+            game(1, 3, Some(1)),
+            game(2, 3, Some(2)),
+            game(2, 4, Some(2)),
+            game(3, 4, Some(3)),
+            game(4, 1, Some(4)),
+        ];
+        let standings = swiss_standings(&[4, 3, 2, 1], &games, &[]);
+        // 3 and 4 tie on points and Buchholz, but 4 beat the leader
+        assert_eq!(ids(&standings), vec![1, 2, 4, 3]);
+        assert_eq!(standings[2].sonneborn_berger, 2.0);
+        assert_eq!(standings[3].sonneborn_berger, 1.0);
+        let one = &standings[0];
+        assert_eq!((one.points, one.wins, one.losses), (2.0, 2, 1));
+        // Opponents 2, 3 and 4 scored 2 + 1 + 1
+        assert_eq!(one.buchholz, 4.0);
+        // Beat 2 (2 points) and 3 (1 point)
+        assert_eq!(one.sonneborn_berger, 3.0);
+        assert_eq!(standings[1].sonneborn_berger, 2.0);
+
+        // Equal on points, Buchholz and Sonneborn-Berger: head-to-head decides
+        let games = vec![
+            game(1, 2, Some(2)),
+            game(1, 3, Some(1)),
+            game(2, 3, Some(3)),
+        ];
+        let standings = swiss_standings(&[1, 2, 3], &games, &[]);
+        assert!(standings
+            .iter()
+            .all(|r| r.points == 1.0 && r.head_to_head == 1.0));
+        assert_eq!(ids(&standings), vec![1, 2, 3]);
+
+        let games = vec![game(1, 2, Some(2)), game(3, 4, None), game(2, 3, None)];
+        let standings = swiss_standings(&[1, 2, 3, 4], &games, &[]);
+        assert_eq!(standings[0].bot_version_id, 2);
+        assert_eq!(standings[0].draws, 1);
+        assert_eq!(standings[0].points, 1.5);
     }
 
     #[test]
@@ -291,5 +847,196 @@ mod tests {
         assert_eq!(total_rounds(&TournamentFormat::SingleElimination, 1), 0);
         assert_eq!(total_rounds(&TournamentFormat::RoundRobin, 4), 1);
         assert_eq!(total_rounds(&TournamentFormat::Swiss { rounds: 5 }, 10), 5);
+        let double = TournamentFormat::DoubleElimination {
+            bracket_reset: true,
+        };
+        assert_eq!(total_rounds(&double, 4), 6);
+        assert_eq!(total_rounds(&double, 1), 0);
+    }
+
+    #[test]
+    fn test_double_elimination_format_names() {
+        for reset in [true, false] {
+            let format = TournamentFormat::DoubleElimination {
+                bracket_reset: reset,
+            };
+            assert_eq!(
+                TournamentFormat::from_str_name(&format.to_str_name()),
+                Some(format)
+            );
+        }
+        assert_eq!(
+            TournamentFormat::DoubleElimination {
+                bracket_reset: true
+            }
+            .to_str_name(),
+            "double_elimination"
+        );
+    }
+
+    /// Play a double-elimination tournament to the end, `decide` picking
+    /// each game's winner from (a, b, round).
+    fn play_double_elimination(
+        seeds: &[i64],
+        bracket_reset: bool,
+        mut decide: impl FnMut(i64, i64, i32) -> Option<i64>,
+    ) -> (DoubleEliminationState, Vec<BracketGame>) {
+        let mut games: Vec<BracketGame> = Vec::new();
+        for round in 1.. {
+            let state = double_elimination_state(seeds, &games, bracket_reset);
+            if state.next_round.is_empty() {
+                return (state, games);
+            }
+            for (a, b) in state.next_round {
+                games.push(BracketGame {
+                    match_id: games.len() as i64 + 1,
+                    round,
+                    a,
+                    b,
+                    finished: true,
+                    winner: decide(a, b, round),
+                });
+            }
+        }
+        unreachable!()
+    }
+
+    fn brackets(state: &DoubleEliminationState) -> Vec<(i32, Bracket, i64, i64)> {
+        state
+            .matches
+            .iter()
+            .map(|m| (m.round, m.bracket, m.a, m.b))
+            .collect()
+    }
+
+    #[test]
+    fn test_double_elimination_4_players() {
+        use Bracket::*;
+        // The lower id always wins
+        let (state, _) = play_double_elimination(&[1, 2, 3, 4], true, |a, b, _| Some(a.min(b)));
+        assert_eq!(
+            brackets(&state),
+            vec![
+                (1, Winners, 1, 2),
+                (1, Winners, 3, 4),
+                (2, Winners, 1, 3),
+                (2, Losers, 2, 4),
+                (3, Losers, 2, 3),
+                (4, GrandFinal, 1, 2),
+            ]
+        );
+        assert_eq!(state.champion, Some(1));
+        let places: Vec<(i64, Option<usize>, u32)> = state
+            .entrants
+            .iter()
+            .map(|e| (e.bot_version_id, e.placement, e.losses))
+            .collect();
+        assert_eq!(
+            places,
+            vec![
+                (1, Some(1), 0),
+                (2, Some(2), 2),
+                (3, Some(3), 2),
+                (4, Some(4), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_double_elimination_bracket_reset() {
+        // The losers-bracket side takes the grand final in round 4
+        let upset = |a: i64, b: i64, round: i32| Some(if round == 4 { a.max(b) } else { a.min(b) });
+        let (state, _) = play_double_elimination(&[1, 2, 3, 4], true, upset);
+        let last = state.matches.last().unwrap();
+        assert_eq!((last.round, last.bracket), (5, Bracket::GrandFinalReset));
+        assert_eq!(state.champion, Some(1));
+        assert_eq!(state.entrants[1].bot_version_id, 2);
+        assert_eq!(state.entrants[1].eliminated_round, Some(5));
+
+        // Without a reset the grand final decides it
+        let (state, _) = play_double_elimination(&[1, 2, 3, 4], false, upset);
+        assert_eq!(state.matches.last().unwrap().bracket, Bracket::GrandFinal);
+        assert_eq!(state.champion, Some(2));
+        assert_eq!(state.entrants[1].bot_version_id, 1);
+        assert_eq!(state.entrants[1].placement, Some(2));
+    }
+
+    #[test]
+    fn test_double_elimination_draws_and_unfinished_rounds() {
+        let game = |round, a, b, finished| BracketGame {
+            match_id: round as i64,
+            round,
+            a,
+            b,
+            finished,
+            winner: None,
+        };
+        // A draw goes to the better seed
+        let state = double_elimination_state(&[7, 3], &[game(1, 3, 7, true)], true);
+        assert_eq!(state.matches[0].winner, Some(7));
+        assert_eq!(state.next_round, vec![(7, 3)]);
+        // Nothing is paired while a game is still running
+        let state = double_elimination_state(&[7, 3], &[game(1, 7, 3, false)], true);
+        assert!(state.next_round.is_empty());
+        assert!(state.champion.is_none());
+        assert!(state.entrants.iter().all(|e| e.placement.is_none()));
+    }
+
+    #[test]
+    fn test_double_elimination_byes() {
+        // With 3 bots, 3 sits out round 1 and the first loser waits in round 2
+        let (state, games) = play_double_elimination(&[1, 2, 3], true, |a, b, _| Some(a.min(b)));
+        let round_2: Vec<(i64, i64)> = games
+            .iter()
+            .filter(|g| g.round == 2)
+            .map(|g| (g.a, g.b))
+            .collect();
+        assert_eq!(round_2, vec![(1, 3)]);
+        assert_eq!(state.champion, Some(1));
+        assert_eq!(state.entrants.iter().filter(|e| e.losses == 2).count(), 2);
+    }
+
+    #[test]
+    fn test_double_elimination_random_fields() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(49);
+        for n in 2..=33 {
+            for reset in [true, false] {
+                let seeds: Vec<i64> = (1..=n).collect();
+                let (state, games) = play_double_elimination(&seeds, reset, |a, b, _| {
+                    Some(if rng.gen_bool(0.5) { a } else { b })
+                });
+                let format = TournamentFormat::DoubleElimination {
+                    bracket_reset: reset,
+                };
+                let rounds = games.iter().map(|g| g.round).max().unwrap() as usize;
+                assert!(
+                    rounds <= total_rounds(&format, n as usize),
+                    "n={n}: {rounds} rounds"
+                );
+                // Nobody plays twice in a round, or after their second loss
+                for round in 1..=rounds as i32 {
+                    let mut ids: Vec<i64> = games
+                        .iter()
+                        .filter(|g| g.round == round)
+                        .flat_map(|g| [g.a, g.b])
+                        .collect();
+                    let len = ids.len();
+                    ids.sort();
+                    ids.dedup();
+                    assert_eq!(ids.len(), len);
+                }
+                let champion = state.champion.expect("tournament decided");
+                for e in &state.entrants {
+                    if e.bot_version_id == champion {
+                        assert!(e.losses <= 1 && e.placement == Some(1));
+                    } else {
+                        assert_eq!(e.losses, 2);
+                        assert!(e.placement.unwrap() > 1);
+                    }
+                }
+            }
+        }
     }
 }
@@ -3,9 +3,9 @@
 
 use std::sync::Arc;
 
-use infon_backend::db::Database;
+use infon_backend::db::{Database, LeaderboardFilter};
 use infon_backend::engine::config::*;
-use infon_backend::engine::server::{run_game_headless, PlayerEntry};
+use infon_backend::engine::server::{run_game_headless, GameResult, PlayerEntry, PlayerScore};
 use infon_backend::engine::world::World;
 use infon_backend::worker_pool::WorkerPool;
 
@@ -46,10 +46,12 @@ fn test_headless_two_bots_produces_result() {
         PlayerEntry {
             name: "Bot A".into(),
             code: code.into(),
+            api: None,
         },
         PlayerEntry {
             name: "Bot B".into(),
             code: code.into(),
+            api: None,
         },
     ];
 
@@ -75,10 +77,12 @@ fn test_headless_determines_winner() {
         PlayerEntry {
             name: "Bot A".into(),
             code: code.into(),
+            api: None,
         },
         PlayerEntry {
             name: "Bot B".into(),
             code: code.into(),
+            api: None,
         },
     ];
 
@@ -102,10 +106,12 @@ fn test_headless_invalid_bot_code() {
         PlayerEntry {
             name: "Good Bot".into(),
             code: stupibot_code().into(),
+            api: None,
         },
         PlayerEntry {
             name: "Bad Bot".into(),
             code: "this is not valid lua %%%".into(),
+            api: None,
         },
     ];
 
@@ -135,6 +141,7 @@ fn test_headless_single_player_wins_by_default() {
     let players = vec![PlayerEntry {
         name: "Solo".into(),
         code: stupibot_code().into(),
+        api: None,
     }];
 
     let result = run_game_headless(world, players, 100, None, vec![1]);
@@ -149,7 +156,7 @@ async fn test_enqueue_and_claim_job() {
     let db = test_db().await;
 
     // Create a match first (queue references matches)
-    let m = db.create_match("1v1", "random").await.unwrap();
+    let m = db.create_match("1v1", "random", true).await.unwrap();
 
     // Enqueue
     let job = db.enqueue_game(m.id, Some("random"), 0, None).await.unwrap();
@@ -173,7 +180,7 @@ async fn test_enqueue_and_claim_job() {
 #[tokio::test]
 async fn test_complete_queue_job() {
     let db = test_db().await;
-    let m = db.create_match("1v1", "random").await.unwrap();
+    let m = db.create_match("1v1", "random", true).await.unwrap();
     db.enqueue_game(m.id, None, 0, None).await.unwrap();
 
     let job = db.claim_queue_job("w1").await.unwrap().unwrap();
@@ -192,7 +199,7 @@ async fn test_complete_queue_job() {
 #[tokio::test]
 async fn test_fail_queue_job_retries() {
     let db = test_db().await;
-    let m = db.create_match("1v1", "random").await.unwrap();
+    let m = db.create_match("1v1", "random", true).await.unwrap();
     db.enqueue_game(m.id, None, 0, None).await.unwrap();
 
     // Claim and fail
@@ -226,9 +233,9 @@ async fn test_fail_queue_job_retries() {
 async fn test_queue_priority_ordering() {
     let db = test_db().await;
 
-    let m1 = db.create_match("1v1", "random").await.unwrap();
-    let m2 = db.create_match("1v1", "random").await.unwrap();
-    let m3 = db.create_match("1v1", "random").await.unwrap();
+    let m1 = db.create_match("1v1", "random", true).await.unwrap();
+    let m2 = db.create_match("1v1", "random", true).await.unwrap();
+    let m3 = db.create_match("1v1", "random", true).await.unwrap();
 
     // Enqueue with different priorities
     db.enqueue_game(m1.id, None, 0, None).await.unwrap(); // low priority
@@ -253,9 +260,9 @@ async fn test_queue_status_counts() {
     let status = db.queue_status().await.unwrap();
     assert_eq!(status.total, 0);
 
-    let m1 = db.create_match("1v1", "random").await.unwrap();
-    let m2 = db.create_match("1v1", "random").await.unwrap();
-    let m3 = db.create_match("1v1", "random").await.unwrap();
+    let m1 = db.create_match("1v1", "random", true).await.unwrap();
+    let m2 = db.create_match("1v1", "random", true).await.unwrap();
+    let m3 = db.create_match("1v1", "random", true).await.unwrap();
 
     db.enqueue_game(m1.id, None, 0, None).await.unwrap();
     db.enqueue_game(m2.id, None, 0, None).await.unwrap();
@@ -291,10 +298,12 @@ async fn test_worker_pool_runs_game_to_completion() {
         PlayerEntry {
             name: "Bot A".into(),
             code: code.into(),
+            api: None,
         },
         PlayerEntry {
             name: "Bot B".into(),
             code: code.into(),
+            api: None,
         },
     ];
 
@@ -343,10 +352,12 @@ async fn test_worker_pool_parallel_games() {
             PlayerEntry {
                 name: format!("Bot A-{i}"),
                 code: code.into(),
+                api: None,
             },
             PlayerEntry {
                 name: format!("Bot B-{i}"),
                 code: code.into(),
+                api: None,
             },
         ];
         let spawned = pool.spawn_game(
@@ -397,8 +408,8 @@ fn test_worker_pool_rejects_when_at_capacity() {
     let spawned = pool.spawn_game(
         create_test_world(),
         vec![
-            PlayerEntry { name: "A".into(), code: code.into() },
-            PlayerEntry { name: "B".into(), code: code.into() },
+            PlayerEntry { name: "A".into(), code: code.into(), api: None },
+            PlayerEntry { name: "B".into(), code: code.into(), api: None },
         ],
         100,
         Some(1),
@@ -437,7 +448,7 @@ async fn test_end_to_end_enqueue_and_process() {
         .unwrap();
 
     // Create a match and add participants
-    let m = db.create_match("1v1", "random").await.unwrap();
+    let m = db.create_match("1v1", "random", true).await.unwrap();
     db.add_match_participant(m.id, va.id, 0).await.unwrap();
     db.add_match_participant(m.id, vb.id, 1).await.unwrap();
 
@@ -455,7 +466,7 @@ async fn test_end_to_end_enqueue_and_process() {
     for p in &participants {
         let v = db.get_bot_version_by_id(p.bot_version_id).await.unwrap().unwrap();
         let name = p.bot_name.clone().unwrap_or_else(|| format!("Bot v{}", v.version));
-        players.push(PlayerEntry { name, code: v.code });
+        players.push(PlayerEntry { name, code: v.code, api: None });
         version_ids.push(p.bot_version_id);
     }
 
@@ -495,8 +506,440 @@ async fn test_end_to_end_enqueue_and_process() {
     assert_eq!(updated_va.wins + updated_va.losses + updated_va.draws, 1);
     assert_eq!(updated_vb.wins + updated_vb.losses + updated_vb.draws, 1);
 
+    // Each side got one rating history row matching its new Elo
+    let history = db.list_rating_history(va.id, 10, 0).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].match_id, Some(m.id));
+    assert_eq!(history[0].rating_after, updated_va.elo_1v1);
+    assert_eq!(history[0].opponent_version_id, Some(vb.id));
+
     // Verify queue status
     let status = db.queue_status().await.unwrap();
     assert_eq!(status.completed, 1);
     assert_eq!(status.pending, 0);
 }
+
+// ── Skill ratings from game completion ───────────────────────────────
+
+/// A finished game with the given final scores, one per version id.
+fn scored_result(match_id: i64, version_ids: &[i64], scores: &[i32]) -> GameResult {
+    let best = (0..scores.len()).max_by_key(|&i| scores[i]);
+    GameResult {
+        match_id: Some(match_id),
+        winner_player_index: best,
+        player_scores: version_ids
+            .iter()
+            .zip(scores)
+            .enumerate()
+            .map(|(i, (&bot_version_id, &score))| PlayerScore {
+                player_index: i,
+                bot_version_id,
+                score,
+                creatures_spawned: 0,
+                creatures_killed: 0,
+                creatures_lost: 0,
+            })
+            .collect(),
+        replay_data: Vec::new(),
+        tick_count: 1,
+        failed_bot_version_ids: Vec::new(),
+    }
+}
+
+async fn create_versions(db: &Database, names: &[&str]) -> Vec<i64> {
+    let user = db
+        .create_user("skill", "skill@example.com", "hash", "Skill")
+        .await
+        .unwrap();
+    let mut ids = Vec::new();
+    for name in names {
+        let bot = db.create_bot(name, "", Some(user.id)).await.unwrap();
+        ids.push(db.create_bot_version(bot.id, "").await.unwrap().id);
+    }
+    ids
+}
+
+#[tokio::test]
+async fn test_ffa_completion_rates_full_ranking() {
+    let db = test_db().await;
+    let ids = create_versions(&db, &["A", "B", "C", "D"]).await;
+
+    let m = db.create_match("ffa", "random", true).await.unwrap();
+    for (slot, &id) in ids.iter().enumerate() {
+        db.add_match_participant(m.id, id, slot as i32).await.unwrap();
+    }
+    let result = scored_result(m.id, &ids, &[300, 900, 300, 100]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids, "ffa", &result).await;
+
+    // Placements follow the score ranking, ties sharing a place
+    let participants = db.get_match_participants(m.id).await.unwrap();
+    let placement = |id: i64| {
+        participants
+            .iter()
+            .find(|p| p.bot_version_id == id)
+            .and_then(|p| p.placement)
+    };
+    assert_eq!(
+        ids.iter().map(|&id| placement(id)).collect::<Vec<_>>(),
+        vec![Some(2), Some(1), Some(2), Some(4)]
+    );
+    // ...and so do the versions' placement points
+    let mut version_points = Vec::new();
+    for &id in &ids {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        version_points.push(v.ffa_placement_points);
+    }
+    assert_eq!(version_points, vec![3, 4, 3, 1]);
+
+    let mut mus = Vec::new();
+    for &id in &ids {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        assert!(v.ffa_sigma < infon_backend::openskill::STARTING_SIGMA);
+        mus.push(v.ffa_mu);
+    }
+    assert!(mus[1] > mus[0] && mus[0] > mus[3]);
+    assert!((mus[0] - mus[2]).abs() < 1e-6);
+
+    let board = db.leaderboard_ffa(50, 0, "skill", &LeaderboardFilter::default()).await.unwrap();
+    assert_eq!(board[0].bot_version_id, ids[1]);
+    assert_eq!(board.last().unwrap().bot_version_id, ids[3]);
+    assert!(board.iter().all(|e| e.provisional == 1 && e.confidence.is_some()));
+
+    // Completion fills the windowed board's stats; a rebuild agrees with them
+    let week = LeaderboardFilter { period_days: Some(7), ..Default::default() };
+    let windowed = db.leaderboard_ffa(50, 0, "points", &week).await.unwrap();
+    let points = |board: &[infon_backend::db::LeaderboardEntry]| {
+        board.iter().map(|e| (e.bot_version_id, e.rating)).collect::<Vec<_>>()
+    };
+    // Tied placements earn the same points
+    assert_eq!(points(&windowed), vec![(ids[1], 4), (ids[0], 3), (ids[2], 3), (ids[3], 1)]);
+    assert_eq!(windowed[1].rank, windowed[2].rank);
+    db.rebuild_leaderboard_stats().await.unwrap();
+    let rebuilt = db.leaderboard_ffa(50, 0, "points", &week).await.unwrap();
+    assert_eq!(points(&rebuilt), points(&windowed));
+}
+
+#[tokio::test]
+async fn test_2v2_completion_rates_teams() {
+    let db = test_db().await;
+    let ids = create_versions(&db, &["A1", "A2", "B1", "B2"]).await;
+    let owner = db.get_user_by_username("skill").await.unwrap().unwrap();
+    let team_a = db.create_team(owner.id, "Team A").await.unwrap();
+    let team_b = db.create_team(owner.id, "Team B").await.unwrap();
+    let tv_a = db.create_team_version(team_a.id, ids[0], ids[1]).await.unwrap();
+    let tv_b = db.create_team_version(team_b.id, ids[3], ids[2]).await.unwrap();
+
+    let m = db.create_match("2v2", "random", true).await.unwrap();
+    for (slot, &id) in ids.iter().enumerate() {
+        db.add_match_participant(m.id, id, slot as i32).await.unwrap();
+    }
+    // Team A wins on summed score even though B1 is the top scorer
+    let result = scored_result(m.id, &ids, &[400, 400, 500, 100]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids, "2v2", &result).await;
+
+    // Every member's record follows its team's result, not the top scorer
+    for (i, &id) in ids.iter().enumerate() {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        if i < 2 {
+            assert!(v.team_mu > infon_backend::openskill::STARTING_MU);
+            assert_eq!((v.wins, v.losses, v.draws), (1, 0, 0));
+        } else {
+            assert!(v.team_mu < infon_backend::openskill::STARTING_MU);
+            assert_eq!((v.wins, v.losses, v.draws), (0, 1, 0));
+        }
+        assert_eq!(v.ffa_mu, infon_backend::openskill::STARTING_MU);
+    }
+    let placements: Vec<Option<i32>> = db
+        .get_match_participants(m.id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.placement)
+        .collect();
+    assert_eq!(placements, vec![Some(1), Some(1), Some(2), Some(2)]);
+
+    let board = db.leaderboard_2v2(50, 0, "skill", &LeaderboardFilter::default()).await.unwrap();
+    assert_eq!(board.len(), 2);
+    assert_eq!(board[0].bot_version_id, tv_a.id);
+    assert_eq!((board[0].wins, board[0].losses), (1, 0));
+    assert_eq!(board[1].bot_version_id, tv_b.id);
+    assert_eq!((board[1].wins, board[1].losses), (0, 1));
+    assert!(board[0].rating > board[1].rating);
+
+    let on_map = LeaderboardFilter { map: Some("random".into()), ..Default::default() };
+    let board = db.leaderboard_2v2(50, 0, "elo", &on_map).await.unwrap();
+    let records: Vec<(i64, i32, i32)> =
+        board.iter().map(|e| (e.bot_version_id, e.wins, e.losses)).collect();
+    assert_eq!(records, vec![(tv_a.id, 1, 0), (tv_b.id, 0, 1)]);
+}
+
+#[tokio::test]
+async fn test_unranked_completion_leaves_ratings_alone() {
+    let db = test_db().await;
+    let ids = create_versions(&db, &["A", "B"]).await;
+
+    let m = db.create_match("1v1", "random", false).await.unwrap();
+    db.add_match_participant(m.id, ids[0], 0).await.unwrap();
+    db.add_match_participant(m.id, ids[1], 1).await.unwrap();
+    let result = scored_result(m.id, &ids, &[500, 100]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids, "1v1", &result).await;
+
+    let finished = db.get_match(m.id).await.unwrap().unwrap();
+    assert_eq!((finished.status.as_str(), finished.ranked), ("finished", 0));
+    let a = db.get_bot_version_by_id(ids[0]).await.unwrap().unwrap();
+    let b = db.get_bot_version_by_id(ids[1]).await.unwrap().unwrap();
+    assert_eq!((a.elo_1v1, b.elo_1v1), (1500, 1500));
+    assert_eq!((a.games_played, a.wins), (0, 0));
+    assert_eq!((a.unranked_games, a.unranked_wins), (1, 1));
+    assert_eq!((b.unranked_games, b.unranked_losses), (1, 1));
+    assert!(db.list_rating_history(ids[0], 10, 0).await.unwrap().is_empty());
+    let participants = db.get_match_participants(m.id).await.unwrap();
+    assert_eq!(participants[0].placement, Some(1));
+    assert_eq!(participants[0].elo_after, None);
+
+    // Ignored by the leaderboard and by a full recompute
+    let board = db.leaderboard_1v1(10, 0, "elo", &LeaderboardFilter::default()).await.unwrap();
+    assert!(board.is_empty());
+    assert_eq!(db.recompute_ratings(true).await.unwrap().matches_replayed, 0);
+}
+
+#[tokio::test]
+async fn test_cross_league_challenge_rates_only_the_newcomer() {
+    let db = test_db().await;
+    let ids = create_versions(&db, &["Newcomer", "Open"]).await;
+
+    let participants = [(ids[0], true), (ids[1], false)];
+    let m = db
+        .create_match_with_participants("1v1", "random", true, &participants, None)
+        .await
+        .unwrap();
+    let result = scored_result(m.id, &ids, &[100, 500]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids, "1v1", &result).await;
+
+    let a = db.get_bot_version_by_id(ids[0]).await.unwrap().unwrap();
+    let b = db.get_bot_version_by_id(ids[1]).await.unwrap().unwrap();
+    assert!(a.elo_1v1 < 1500);
+    assert_eq!((a.games_played, a.losses), (1, 1));
+    assert_eq!(b.elo_1v1, 1500);
+    assert_eq!((b.games_played, b.unranked_wins), (0, 1));
+    assert_eq!(db.list_rating_history(ids[0], 10, 0).await.unwrap().len(), 1);
+    assert!(db.list_rating_history(ids[1], 10, 0).await.unwrap().is_empty());
+    let participants = db.get_match_participants(m.id).await.unwrap();
+    assert_eq!(participants[1].elo_after, None);
+
+    // A full recompute agrees
+    let report = db.recompute_ratings(true).await.unwrap();
+    assert_eq!(report.matches_replayed, 1);
+    assert!(report.changes.is_empty(), "{:?}", report.changes);
+}
+
+#[tokio::test]
+async fn test_unrated_participants_keep_ffa_and_team_skill() {
+    use infon_backend::openskill::STARTING_MU;
+
+    let db = test_db().await;
+    let ids = create_versions(&db, &["A", "B", "C", "D"]).await;
+
+    // FFA: the unrated winner keeps its skill and points
+    let participants = [(ids[0], false), (ids[1], true), (ids[2], true)];
+    let m = db
+        .create_match_with_participants("ffa", "random", true, &participants, None)
+        .await
+        .unwrap();
+    let result = scored_result(m.id, &ids[..3], &[900, 300, 100]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids[..3], "ffa", &result).await;
+    let a = db.get_bot_version_by_id(ids[0]).await.unwrap().unwrap();
+    assert_eq!((a.ffa_mu, a.ffa_placement_points), (STARTING_MU, 0));
+    let c = db.get_bot_version_by_id(ids[2]).await.unwrap().unwrap();
+    assert!(c.ffa_mu < STARTING_MU);
+    assert_eq!(c.ffa_placement_points, 1);
+
+    // 2v2: only the rated team's members move
+    let participants = [(ids[0], true), (ids[1], true), (ids[2], false), (ids[3], false)];
+    let m = db
+        .create_match_with_participants("2v2", "random", true, &participants, None)
+        .await
+        .unwrap();
+    let result = scored_result(m.id, &ids, &[100, 100, 500, 500]);
+    infon_backend::queue::run_game_completion(&db, m.id, &ids, "2v2", &result).await;
+    for (i, &id) in ids.iter().enumerate() {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        assert_eq!(v.team_mu == STARTING_MU, i >= 2, "version {i}");
+    }
+
+    // A full recompute agrees
+    let report = db.recompute_ratings(true).await.unwrap();
+    assert!(report.changes.is_empty(), "{:?}", report.changes);
+}
+
+/// Start a tournament of `ids` through the real `POST /api/tournaments/{id}/run`
+/// handler, then play every queued game until it finishes: the earlier-entered
+/// bot wins. Returns the tournament id and the number of games played.
+async fn run_tournament_to_the_end(
+    db: &Arc<Database>,
+    format: &str,
+    ids: &[i64],
+) -> (i64, usize) {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    let t = db.create_tournament(format, "random").await.unwrap();
+    db.update_tournament(t.id, None, None, Some(format), None)
+        .await
+        .unwrap();
+    for &id in ids {
+        db.add_tournament_entry(t.id, id, "").await.unwrap();
+    }
+
+    let app = infon_backend::api::router(
+        db.clone(),
+        Arc::new(infon_backend::engine::server::GameServer::new()),
+        infon_backend::rate_limit::RateLimiter::new(),
+        std::path::PathBuf::from("../data/maps"),
+    );
+    let token = infon_backend::auth::create_token(1, "organizer", "user").unwrap();
+    // Runs once; a second run doesn't queue round 1 again
+    for status in [StatusCode::OK, StatusCode::CONFLICT] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::post(format!("/api/tournaments/{}/run", t.id))
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), status);
+    }
+
+    let mut played = 0;
+    while db.get_tournament(t.id).await.unwrap().unwrap().status == "running" {
+        let pending: Vec<_> = db
+            .get_tournament_matches_detail(t.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.status != "finished")
+            .collect();
+        assert!(!pending.is_empty() && played < 20, "tournament stalled");
+        for d in pending {
+            let slots: Vec<i64> = d.participants.iter().map(|p| p.bot_version_id).collect();
+            let scores = if slots[0] < slots[1] { [200, 100] } else { [100, 200] };
+            let result = scored_result(d.match_id, &slots, &scores);
+            infon_backend::queue::run_game_completion(db, d.match_id, &slots, "1v1", &result)
+                .await;
+            played += 1;
+        }
+    }
+    (t.id, played)
+}
+
+#[tokio::test]
+async fn test_double_elimination_tournament_runs_to_the_end() {
+    use infon_backend::tournament::{bracket_games, double_elimination_state, Bracket};
+
+    let db = Arc::new(test_db().await);
+    let ids = create_versions(&db, &["A", "B", "C", "D", "E"]).await;
+    let (t, played) = run_tournament_to_the_end(&db, "double_elimination", &ids).await;
+
+    let details = db.get_tournament_matches_detail(t).await.unwrap();
+    let bracket = double_elimination_state(&ids, &bracket_games(&details), true);
+    assert_eq!(bracket.champion, Some(ids[0]));
+    assert_eq!(bracket.matches.last().unwrap().bracket, Bracket::GrandFinal);
+    // Each game costs one bot a life; everyone but the unbeaten champion used both
+    assert_eq!(played, 2 * (ids.len() - 1));
+    assert!(bracket.entrants.iter().all(|e| e.placement.is_some()));
+
+    // Tournament games are unranked
+    for id in ids {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        assert_eq!((v.elo_1v1, v.games_played), (1500, 0));
+    }
+}
+
+#[tokio::test]
+async fn test_ranked_challenge_needs_the_challengers_owner() {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    let db = Arc::new(test_db().await);
+    let ids = create_versions(&db, &["Mine", "Theirs"]).await;
+    for &id in &ids {
+        let v = db.get_bot_version_by_id(id).await.unwrap().unwrap();
+        db.set_active_version(v.bot_id, id).await.unwrap();
+    }
+    let owner = db.get_user_by_username("skill").await.unwrap().unwrap();
+    let other = db
+        .create_user("other", "other@example.com", "hash", "Other")
+        .await
+        .unwrap();
+
+    let app = infon_backend::api::router(
+        db.clone(),
+        Arc::new(infon_backend::engine::server::GameServer::new()),
+        infon_backend::rate_limit::RateLimiter::new(),
+        std::path::PathBuf::from("../data/maps"),
+    );
+    let body = json!({
+        "bot_version_id": ids[0],
+        "opponent_bot_version_id": ids[1],
+        "headless": true,
+        "ranked": true,
+    })
+    .to_string();
+    for (user, status) in [(other.id, StatusCode::FORBIDDEN), (owner.id, StatusCode::CREATED)] {
+        let token = infon_backend::auth::create_token(user, "player", "user").unwrap();
+        let response = app
+            .clone()
+            .oneshot(
+                Request::post("/api/matches/challenge")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.clone()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), status);
+    }
+}
+
+#[tokio::test]
+async fn test_swiss_tournament_rotates_byes_without_rematches() {
+    let db = Arc::new(test_db().await);
+    let ids = create_versions(&db, &["A", "B", "C", "D", "E"]).await;
+    let (t, played) = run_tournament_to_the_end(&db, "swiss_3", &ids).await;
+    assert_eq!(played, 6);
+
+    // Nobody meets the same opponent twice or sits out twice
+    let details = db.get_tournament_matches_detail(t).await.unwrap();
+    let mut pairs: Vec<(i64, i64)> = details
+        .iter()
+        .map(|d| {
+            let (a, b) = (d.participants[0].bot_version_id, d.participants[1].bot_version_id);
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    pairs.sort();
+    pairs.dedup();
+    assert_eq!(pairs.len(), 6);
+    let mut byes = db.list_tournament_byes(t).await.unwrap();
+    assert_eq!(byes.len(), 3);
+    byes.sort();
+    byes.dedup();
+    assert_eq!(byes.len(), 3);
+
+    // Standings carry the bye points and are ranked by them
+    let standings = db.get_tournament_standings(t).await.unwrap();
+    assert_eq!(standings.len(), ids.len());
+    assert_eq!(standings.iter().map(|s| s.points).sum::<f64>(), 9.0);
+    assert!(standings.windows(2).all(|w| w[0].points >= w[1].points));
+    assert_eq!(standings[0].bot_version_id, ids[0]);
+    for s in &standings {
+        assert_eq!(s.points, (s.wins + s.byes) as f64);
+    }
+}
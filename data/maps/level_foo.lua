@@ -0,0 +1,1215 @@
+-- Nachdem es einen Votebot gab, welcher nach neuen Levels gebettelt hat...
+-- Hier ist es :-)
+
+function level_size()
+    return 64, 46
+end
+
+function level_koth_pos()
+    return 32, 23
+end
+
+function level_init()
+    world_dig(32, 23, TILE_PLAIN)
+    world_dig(18, 21, TILE_PLAIN)
+    world_dig(19, 21, TILE_PLAIN)
+    world_dig(18, 22, TILE_PLAIN)
+    world_dig(19, 22, TILE_PLAIN)
+    world_dig(20, 21, TILE_PLAIN)
+    world_dig(20, 22, TILE_PLAIN)
+    world_dig(21, 21, TILE_PLAIN)
+    world_dig(21, 22, TILE_PLAIN)
+    world_dig(22, 21, TILE_PLAIN)
+    world_dig(22, 22, TILE_PLAIN)
+    world_dig(21, 23, TILE_PLAIN)
+    world_dig(22, 23, TILE_PLAIN)
+    world_dig(23, 22, TILE_PLAIN)
+    world_dig(23, 23, TILE_PLAIN)
+    world_dig(24, 22, TILE_PLAIN)
+    world_dig(24, 23, TILE_PLAIN)
+    world_dig(25, 22, TILE_PLAIN)
+    world_dig(25, 23, TILE_PLAIN)
+    world_dig(26, 22, TILE_PLAIN)
+    world_dig(26, 23, TILE_PLAIN)
+    world_dig(27, 22, TILE_PLAIN)
+    world_dig(27, 23, TILE_PLAIN)
+    world_dig(28, 22, TILE_PLAIN)
+    world_dig(28, 23, TILE_PLAIN)
+    world_dig(29, 22, TILE_PLAIN)
+    world_dig(29, 23, TILE_PLAIN)
+    world_dig(30, 22, TILE_PLAIN)
+    world_dig(30, 23, TILE_PLAIN)
+    world_dig(31, 22, TILE_PLAIN)
+    world_dig(31, 23, TILE_PLAIN)
+    world_dig(32, 22, TILE_PLAIN)
+    world_dig(33, 22, TILE_PLAIN)
+    world_dig(33, 23, TILE_PLAIN)
+    world_dig(34, 22, TILE_PLAIN)
+    world_dig(34, 23, TILE_PLAIN)
+    world_dig(35, 22, TILE_PLAIN)
+    world_dig(35, 23, TILE_PLAIN)
+    world_dig(36, 22, TILE_PLAIN)
+    world_dig(36, 23, TILE_PLAIN)
+    world_dig(35, 24, TILE_PLAIN)
+    world_dig(36, 24, TILE_PLAIN)
+    world_dig(37, 23, TILE_PLAIN)
+    world_dig(37, 24, TILE_PLAIN)
+    world_dig(38, 23, TILE_PLAIN)
+    world_dig(38, 24, TILE_PLAIN)
+    world_dig(39, 23, TILE_PLAIN)
+    world_dig(39, 24, TILE_PLAIN)
+    world_dig(40, 23, TILE_PLAIN)
+    world_dig(40, 24, TILE_PLAIN)
+    world_dig(41, 23, TILE_PLAIN)
+    world_dig(41, 24, TILE_PLAIN)
+    world_dig(42, 23, TILE_PLAIN)
+    world_dig(42, 24, TILE_PLAIN)
+    world_dig(43, 23, TILE_PLAIN)
+    world_dig(43, 24, TILE_PLAIN)
+    world_dig(44, 23, TILE_PLAIN)
+    world_dig(44, 24, TILE_PLAIN)
+    world_dig(45, 23, TILE_PLAIN)
+    world_dig(45, 24, TILE_PLAIN)
+    world_dig(46, 23, TILE_PLAIN)
+    world_dig(46, 24, TILE_PLAIN)
+    world_dig(45, 22, TILE_PLAIN)
+    world_dig(46, 22, TILE_PLAIN)
+    world_dig(47, 22, TILE_PLAIN)
+    world_dig(47, 23, TILE_PLAIN)
+    world_dig(46, 21, TILE_PLAIN)
+    world_dig(47, 21, TILE_PLAIN)
+    world_dig(48, 21, TILE_PLAIN)
+    world_dig(48, 22, TILE_PLAIN)
+    world_dig(47, 20, TILE_PLAIN)
+    world_dig(48, 20, TILE_PLAIN)
+    world_dig(49, 20, TILE_PLAIN)
+    world_dig(49, 21, TILE_PLAIN)
+    world_dig(48, 19, TILE_PLAIN)
+    world_dig(49, 19, TILE_PLAIN)
+    world_dig(50, 19, TILE_PLAIN)
+    world_dig(50, 20, TILE_PLAIN)
+    world_dig(49, 18, TILE_PLAIN)
+    world_dig(50, 18, TILE_PLAIN)
+    world_dig(51, 18, TILE_PLAIN)
+    world_dig(51, 19, TILE_PLAIN)
+    world_dig(51, 17, TILE_PLAIN)
+    world_dig(52, 17, TILE_PLAIN)
+    world_dig(52, 18, TILE_PLAIN)
+    world_dig(53, 17, TILE_PLAIN)
+    world_dig(53, 18, TILE_PLAIN)
+    world_dig(52, 16, TILE_PLAIN)
+    world_dig(53, 16, TILE_PLAIN)
+    world_dig(54, 16, TILE_PLAIN)
+    world_dig(54, 17, TILE_PLAIN)
+    world_dig(53, 15, TILE_PLAIN)
+    world_dig(54, 15, TILE_PLAIN)
+    world_dig(53, 14, TILE_PLAIN)
+    world_dig(54, 14, TILE_PLAIN)
+    world_dig(55, 14, TILE_PLAIN)
+    world_dig(55, 15, TILE_PLAIN)
+    world_dig(54, 13, TILE_PLAIN)
+    world_dig(55, 13, TILE_PLAIN)
+    world_dig(54, 12, TILE_PLAIN)
+    world_dig(55, 12, TILE_PLAIN)
+    world_dig(54, 11, TILE_PLAIN)
+    world_dig(55, 11, TILE_PLAIN)
+    world_dig(54, 10, TILE_PLAIN)
+    world_dig(55, 10, TILE_PLAIN)
+    world_dig(53, 10, TILE_PLAIN)
+    world_dig(53, 11, TILE_PLAIN)
+    world_dig(53, 9, TILE_PLAIN)
+    world_dig(54, 9, TILE_PLAIN)
+    world_dig(52, 9, TILE_PLAIN)
+    world_dig(52, 10, TILE_PLAIN)
+    world_dig(52, 8, TILE_PLAIN)
+    world_dig(53, 8, TILE_PLAIN)
+    world_dig(51, 8, TILE_PLAIN)
+    world_dig(51, 9, TILE_PLAIN)
+    world_dig(51, 7, TILE_PLAIN)
+    world_dig(52, 7, TILE_PLAIN)
+    world_dig(50, 7, TILE_PLAIN)
+    world_dig(50, 8, TILE_PLAIN)
+    world_dig(50, 6, TILE_PLAIN)
+    world_dig(51, 6, TILE_PLAIN)
+    world_dig(49, 6, TILE_PLAIN)
+    world_dig(49, 7, TILE_PLAIN)
+    world_dig(49, 5, TILE_PLAIN)
+    world_dig(50, 5, TILE_PLAIN)
+    world_dig(48, 5, TILE_PLAIN)
+    world_dig(48, 6, TILE_PLAIN)
+    world_dig(47, 5, TILE_PLAIN)
+    world_dig(47, 6, TILE_PLAIN)
+    world_dig(46, 5, TILE_PLAIN)
+    world_dig(46, 6, TILE_PLAIN)
+    world_dig(45, 5, TILE_PLAIN)
+    world_dig(45, 6, TILE_PLAIN)
+    world_dig(44, 5, TILE_PLAIN)
+    world_dig(44, 6, TILE_PLAIN)
+    world_dig(44, 7, TILE_PLAIN)
+    world_dig(45, 7, TILE_PLAIN)
+    world_dig(43, 6, TILE_PLAIN)
+    world_dig(43, 7, TILE_PLAIN)
+    world_dig(42, 6, TILE_PLAIN)
+    world_dig(42, 7, TILE_PLAIN)
+    world_dig(42, 8, TILE_PLAIN)
+    world_dig(43, 8, TILE_PLAIN)
+    world_dig(41, 7, TILE_PLAIN)
+    world_dig(41, 8, TILE_PLAIN)
+    world_dig(41, 9, TILE_PLAIN)
+    world_dig(42, 9, TILE_PLAIN)
+    world_dig(40, 8, TILE_PLAIN)
+    world_dig(40, 9, TILE_PLAIN)
+    world_dig(40, 10, TILE_PLAIN)
+    world_dig(41, 10, TILE_PLAIN)
+    world_dig(39, 9, TILE_PLAIN)
+    world_dig(39, 10, TILE_PLAIN)
+    world_dig(39, 11, TILE_PLAIN)
+    world_dig(40, 11, TILE_PLAIN)
+    world_dig(38, 10, TILE_PLAIN)
+    world_dig(38, 11, TILE_PLAIN)
+    world_dig(38, 12, TILE_PLAIN)
+    world_dig(39, 12, TILE_PLAIN)
+    world_dig(37, 11, TILE_PLAIN)
+    world_dig(37, 12, TILE_PLAIN)
+    world_dig(37, 13, TILE_PLAIN)
+    world_dig(38, 13, TILE_PLAIN)
+    world_dig(47, 19, TILE_PLAIN)
+    world_dig(47, 18, TILE_PLAIN)
+    world_dig(48, 18, TILE_PLAIN)
+    world_dig(46, 18, TILE_PLAIN)
+    world_dig(46, 19, TILE_PLAIN)
+    world_dig(46, 17, TILE_PLAIN)
+    world_dig(47, 17, TILE_PLAIN)
+    world_dig(45, 17, TILE_PLAIN)
+    world_dig(45, 18, TILE_PLAIN)
+    world_dig(45, 16, TILE_PLAIN)
+    world_dig(46, 16, TILE_PLAIN)
+    world_dig(44, 16, TILE_PLAIN)
+    world_dig(44, 17, TILE_PLAIN)
+    world_dig(44, 15, TILE_PLAIN)
+    world_dig(45, 15, TILE_PLAIN)
+    world_dig(43, 15, TILE_PLAIN)
+    world_dig(43, 16, TILE_PLAIN)
+    world_dig(43, 14, TILE_PLAIN)
+    world_dig(44, 14, TILE_PLAIN)
+    world_dig(42, 14, TILE_PLAIN)
+    world_dig(42, 15, TILE_PLAIN)
+    world_dig(42, 13, TILE_PLAIN)
+    world_dig(43, 13, TILE_PLAIN)
+    world_dig(41, 13, TILE_PLAIN)
+    world_dig(41, 14, TILE_PLAIN)
+    world_dig(40, 12, TILE_PLAIN)
+    world_dig(41, 12, TILE_PLAIN)
+    world_dig(40, 13, TILE_PLAIN)
+    world_dig(39, 13, TILE_PLAIN)
+    world_dig(36, 11, TILE_PLAIN)
+    world_dig(36, 12, TILE_PLAIN)
+    world_dig(36, 10, TILE_PLAIN)
+    world_dig(37, 10, TILE_PLAIN)
+    world_dig(35, 10, TILE_PLAIN)
+    world_dig(35, 11, TILE_PLAIN)
+    world_dig(35, 9, TILE_PLAIN)
+    world_dig(36, 9, TILE_PLAIN)
+    world_dig(34, 9, TILE_PLAIN)
+    world_dig(34, 10, TILE_PLAIN)
+    world_dig(34, 8, TILE_PLAIN)
+    world_dig(35, 8, TILE_PLAIN)
+    world_dig(34, 7, TILE_PLAIN)
+    world_dig(35, 7, TILE_PLAIN)
+    world_dig(33, 7, TILE_PLAIN)
+    world_dig(33, 8, TILE_PLAIN)
+    world_dig(32, 6, TILE_PLAIN)
+    world_dig(33, 6, TILE_PLAIN)
+    world_dig(32, 7, TILE_PLAIN)
+    world_dig(31, 5, TILE_PLAIN)
+    world_dig(32, 5, TILE_PLAIN)
+    world_dig(31, 6, TILE_PLAIN)
+    world_dig(30, 4, TILE_PLAIN)
+    world_dig(31, 4, TILE_PLAIN)
+    world_dig(30, 5, TILE_PLAIN)
+    world_dig(29, 3, TILE_PLAIN)
+    world_dig(30, 3, TILE_PLAIN)
+    world_dig(29, 4, TILE_PLAIN)
+    world_dig(28, 3, TILE_PLAIN)
+    world_dig(28, 4, TILE_PLAIN)
+    world_dig(27, 3, TILE_PLAIN)
+    world_dig(27, 4, TILE_PLAIN)
+    world_dig(26, 3, TILE_PLAIN)
+    world_dig(26, 4, TILE_PLAIN)
+    world_dig(26, 5, TILE_PLAIN)
+    world_dig(27, 5, TILE_PLAIN)
+    world_dig(25, 4, TILE_PLAIN)
+    world_dig(25, 5, TILE_PLAIN)
+    world_dig(25, 6, TILE_PLAIN)
+    world_dig(26, 6, TILE_PLAIN)
+    world_dig(24, 5, TILE_PLAIN)
+    world_dig(24, 6, TILE_PLAIN)
+    world_dig(24, 7, TILE_PLAIN)
+    world_dig(25, 7, TILE_PLAIN)
+    world_dig(26, 7, TILE_PLAIN)
+    world_dig(25, 8, TILE_PLAIN)
+    world_dig(26, 8, TILE_PLAIN)
+    world_dig(25, 9, TILE_PLAIN)
+    world_dig(26, 9, TILE_PLAIN)
+    world_dig(25, 10, TILE_PLAIN)
+    world_dig(26, 10, TILE_PLAIN)
+    world_dig(25, 11, TILE_PLAIN)
+    world_dig(26, 11, TILE_PLAIN)
+    world_dig(25, 12, TILE_PLAIN)
+    world_dig(26, 12, TILE_PLAIN)
+    world_dig(25, 13, TILE_PLAIN)
+    world_dig(26, 13, TILE_PLAIN)
+    world_dig(33, 9, TILE_PLAIN)
+    world_dig(33, 10, TILE_PLAIN)
+    world_dig(32, 10, TILE_PLAIN)
+    world_dig(32, 11, TILE_PLAIN)
+    world_dig(33, 11, TILE_PLAIN)
+    world_dig(31, 11, TILE_PLAIN)
+    world_dig(31, 12, TILE_PLAIN)
+    world_dig(32, 12, TILE_PLAIN)
+    world_dig(30, 12, TILE_PLAIN)
+    world_dig(30, 13, TILE_PLAIN)
+    world_dig(31, 13, TILE_PLAIN)
+    world_dig(29, 12, TILE_PLAIN)
+    world_dig(29, 13, TILE_PLAIN)
+    world_dig(29, 14, TILE_PLAIN)
+    world_dig(30, 14, TILE_PLAIN)
+    world_dig(28, 13, TILE_PLAIN)
+    world_dig(28, 14, TILE_PLAIN)
+    world_dig(27, 13, TILE_PLAIN)
+    world_dig(27, 14, TILE_PLAIN)
+    world_dig(27, 15, TILE_PLAIN)
+    world_dig(28, 15, TILE_PLAIN)
+    world_dig(26, 14, TILE_PLAIN)
+    world_dig(26, 15, TILE_PLAIN)
+    world_dig(25, 14, TILE_PLAIN)
+    world_dig(24, 13, TILE_PLAIN)
+    world_dig(24, 14, TILE_PLAIN)
+    world_dig(23, 13, TILE_PLAIN)
+    world_dig(23, 14, TILE_PLAIN)
+    world_dig(22, 12, TILE_PLAIN)
+    world_dig(23, 12, TILE_PLAIN)
+    world_dig(22, 13, TILE_PLAIN)
+    world_dig(21, 12, TILE_PLAIN)
+    world_dig(21, 13, TILE_PLAIN)
+    world_dig(21, 11, TILE_PLAIN)
+    world_dig(22, 11, TILE_PLAIN)
+    world_dig(20, 11, TILE_PLAIN)
+    world_dig(20, 12, TILE_PLAIN)
+    world_dig(19, 11, TILE_PLAIN)
+    world_dig(19, 12, TILE_PLAIN)
+    world_dig(19, 10, TILE_PLAIN)
+    world_dig(20, 10, TILE_PLAIN)
+    world_dig(18, 10, TILE_PLAIN)
+    world_dig(18, 11, TILE_PLAIN)
+    world_dig(18, 9, TILE_PLAIN)
+    world_dig(19, 9, TILE_PLAIN)
+    world_dig(17, 8, TILE_PLAIN)
+    world_dig(18, 8, TILE_PLAIN)
+    world_dig(17, 9, TILE_PLAIN)
+    world_dig(16, 7, TILE_PLAIN)
+    world_dig(17, 7, TILE_PLAIN)
+    world_dig(16, 8, TILE_PLAIN)
+    world_dig(15, 6, TILE_PLAIN)
+    world_dig(16, 6, TILE_PLAIN)
+    world_dig(15, 7, TILE_PLAIN)
+    world_dig(15, 5, TILE_PLAIN)
+    world_dig(16, 5, TILE_PLAIN)
+    world_dig(14, 4, TILE_PLAIN)
+    world_dig(15, 4, TILE_PLAIN)
+    world_dig(14, 5, TILE_PLAIN)
+    world_dig(14, 3, TILE_PLAIN)
+    world_dig(15, 3, TILE_PLAIN)
+    world_dig(16, 3, TILE_PLAIN)
+    world_dig(16, 4, TILE_PLAIN)
+    world_dig(17, 3, TILE_PLAIN)
+    world_dig(17, 4, TILE_PLAIN)
+    world_dig(18, 3, TILE_PLAIN)
+    world_dig(18, 4, TILE_PLAIN)
+    world_dig(19, 3, TILE_PLAIN)
+    world_dig(19, 4, TILE_PLAIN)
+    world_dig(20, 3, TILE_PLAIN)
+    world_dig(20, 4, TILE_PLAIN)
+    world_dig(21, 3, TILE_PLAIN)
+    world_dig(21, 4, TILE_PLAIN)
+    world_dig(20, 5, TILE_PLAIN)
+    world_dig(21, 5, TILE_PLAIN)
+    world_dig(22, 4, TILE_PLAIN)
+    world_dig(22, 5, TILE_PLAIN)
+    world_dig(21, 6, TILE_PLAIN)
+    world_dig(22, 6, TILE_PLAIN)
+    world_dig(21, 7, TILE_PLAIN)
+    world_dig(22, 7, TILE_PLAIN)
+    world_dig(21, 8, TILE_PLAIN)
+    world_dig(22, 8, TILE_PLAIN)
+    world_dig(20, 7, TILE_PLAIN)
+    world_dig(20, 8, TILE_PLAIN)
+    world_dig(20, 9, TILE_PLAIN)
+    world_dig(21, 9, TILE_PLAIN)
+    world_dig(21, 10, TILE_PLAIN)
+    world_dig(17, 10, TILE_PLAIN)
+    world_dig(17, 11, TILE_PLAIN)
+    world_dig(16, 10, TILE_PLAIN)
+    world_dig(16, 11, TILE_PLAIN)
+    world_dig(16, 12, TILE_PLAIN)
+    world_dig(17, 12, TILE_PLAIN)
+    world_dig(15, 11, TILE_PLAIN)
+    world_dig(15, 12, TILE_PLAIN)
+    world_dig(14, 11, TILE_PLAIN)
+    world_dig(14, 12, TILE_PLAIN)
+    world_dig(13, 11, TILE_PLAIN)
+    world_dig(13, 12, TILE_PLAIN)
+    world_dig(13, 13, TILE_PLAIN)
+    world_dig(14, 13, TILE_PLAIN)
+    world_dig(12, 12, TILE_PLAIN)
+    world_dig(12, 13, TILE_PLAIN)
+    world_dig(11, 12, TILE_PLAIN)
+    world_dig(11, 13, TILE_PLAIN)
+    world_dig(11, 14, TILE_PLAIN)
+    world_dig(12, 14, TILE_PLAIN)
+    world_dig(10, 13, TILE_PLAIN)
+    world_dig(10, 14, TILE_PLAIN)
+    world_dig(9, 13, TILE_PLAIN)
+    world_dig(9, 14, TILE_PLAIN)
+    world_dig(8, 13, TILE_PLAIN)
+    world_dig(8, 14, TILE_PLAIN)
+    world_dig(7, 13, TILE_PLAIN)
+    world_dig(7, 14, TILE_PLAIN)
+    world_dig(7, 12, TILE_PLAIN)
+    world_dig(8, 12, TILE_PLAIN)
+    world_dig(6, 12, TILE_PLAIN)
+    world_dig(6, 13, TILE_PLAIN)
+    world_dig(6, 11, TILE_PLAIN)
+    world_dig(7, 11, TILE_PLAIN)
+    world_dig(5, 11, TILE_PLAIN)
+    world_dig(5, 12, TILE_PLAIN)
+    world_dig(4, 10, TILE_PLAIN)
+    world_dig(5, 10, TILE_PLAIN)
+    world_dig(4, 11, TILE_PLAIN)
+    world_dig(4, 9, TILE_PLAIN)
+    world_dig(5, 9, TILE_PLAIN)
+    world_dig(3, 9, TILE_PLAIN)
+    world_dig(3, 10, TILE_PLAIN)
+    world_dig(3, 8, TILE_PLAIN)
+    world_dig(4, 8, TILE_PLAIN)
+    world_dig(3, 7, TILE_PLAIN)
+    world_dig(4, 7, TILE_PLAIN)
+    world_dig(3, 6, TILE_PLAIN)
+    world_dig(4, 6, TILE_PLAIN)
+    world_dig(3, 5, TILE_PLAIN)
+    world_dig(4, 5, TILE_PLAIN)
+    world_dig(5, 5, TILE_PLAIN)
+    world_dig(5, 6, TILE_PLAIN)
+    world_dig(4, 4, TILE_PLAIN)
+    world_dig(5, 4, TILE_PLAIN)
+    world_dig(6, 4, TILE_PLAIN)
+    world_dig(6, 5, TILE_PLAIN)
+    world_dig(5, 3, TILE_PLAIN)
+    world_dig(6, 3, TILE_PLAIN)
+    world_dig(7, 3, TILE_PLAIN)
+    world_dig(7, 4, TILE_PLAIN)
+    world_dig(7, 5, TILE_PLAIN)
+    world_dig(8, 4, TILE_PLAIN)
+    world_dig(8, 5, TILE_PLAIN)
+    world_dig(9, 5, TILE_PLAIN)
+    world_dig(8, 6, TILE_PLAIN)
+    world_dig(9, 6, TILE_PLAIN)
+    world_dig(10, 5, TILE_PLAIN)
+    world_dig(10, 6, TILE_PLAIN)
+    world_dig(9, 7, TILE_PLAIN)
+    world_dig(10, 7, TILE_PLAIN)
+    world_dig(11, 6, TILE_PLAIN)
+    world_dig(11, 7, TILE_PLAIN)
+    world_dig(10, 8, TILE_PLAIN)
+    world_dig(11, 8, TILE_PLAIN)
+    world_dig(12, 7, TILE_PLAIN)
+    world_dig(12, 8, TILE_PLAIN)
+    world_dig(11, 9, TILE_PLAIN)
+    world_dig(12, 9, TILE_PLAIN)
+    world_dig(13, 9, TILE_PLAIN)
+    world_dig(12, 10, TILE_PLAIN)
+    world_dig(13, 10, TILE_PLAIN)
+    world_dig(12, 11, TILE_PLAIN)
+    world_dig(13, 14, TILE_PLAIN)
+    world_dig(14, 14, TILE_PLAIN)
+    world_dig(13, 15, TILE_PLAIN)
+    world_dig(14, 15, TILE_PLAIN)
+    world_dig(13, 16, TILE_PLAIN)
+    world_dig(14, 16, TILE_PLAIN)
+    world_dig(13, 17, TILE_PLAIN)
+    world_dig(14, 17, TILE_PLAIN)
+    world_dig(12, 16, TILE_PLAIN)
+    world_dig(12, 17, TILE_PLAIN)
+    world_dig(12, 18, TILE_PLAIN)
+    world_dig(13, 18, TILE_PLAIN)
+    world_dig(12, 19, TILE_PLAIN)
+    world_dig(13, 19, TILE_PLAIN)
+    world_dig(12, 20, TILE_PLAIN)
+    world_dig(13, 20, TILE_PLAIN)
+    world_dig(12, 21, TILE_PLAIN)
+    world_dig(13, 21, TILE_PLAIN)
+    world_dig(12, 22, TILE_PLAIN)
+    world_dig(13, 22, TILE_PLAIN)
+    world_dig(3, 19, TILE_PLAIN)
+    world_dig(4, 19, TILE_PLAIN)
+    world_dig(3, 20, TILE_PLAIN)
+    world_dig(4, 20, TILE_PLAIN)
+    world_dig(5, 19, TILE_PLAIN)
+    world_dig(5, 20, TILE_PLAIN)
+    world_dig(4, 21, TILE_PLAIN)
+    world_dig(5, 21, TILE_PLAIN)
+    world_dig(6, 20, TILE_PLAIN)
+    world_dig(6, 21, TILE_PLAIN)
+    world_dig(7, 20, TILE_PLAIN)
+    world_dig(7, 21, TILE_PLAIN)
+    world_dig(8, 20, TILE_PLAIN)
+    world_dig(8, 21, TILE_PLAIN)
+    world_dig(9, 20, TILE_PLAIN)
+    world_dig(9, 21, TILE_PLAIN)
+    world_dig(10, 20, TILE_PLAIN)
+    world_dig(10, 21, TILE_PLAIN)
+    world_dig(11, 20, TILE_PLAIN)
+    world_dig(11, 21, TILE_PLAIN)
+    world_dig(11, 22, TILE_PLAIN)
+    world_dig(14, 21, TILE_PLAIN)
+    world_dig(14, 22, TILE_PLAIN)
+    world_dig(15, 21, TILE_PLAIN)
+    world_dig(15, 22, TILE_PLAIN)
+    world_dig(16, 21, TILE_PLAIN)
+    world_dig(16, 22, TILE_PLAIN)
+    world_dig(17, 22, TILE_PLAIN)
+    world_dig(16, 23, TILE_PLAIN)
+    world_dig(17, 23, TILE_PLAIN)
+    world_dig(18, 23, TILE_PLAIN)
+    world_dig(19, 23, TILE_PLAIN)
+    world_dig(20, 23, TILE_PLAIN)
+    world_dig(3, 21, TILE_PLAIN)
+    world_dig(4, 22, TILE_PLAIN)
+    world_dig(5, 22, TILE_PLAIN)
+    world_dig(4, 23, TILE_PLAIN)
+    world_dig(5, 23, TILE_PLAIN)
+    world_dig(6, 22, TILE_PLAIN)
+    world_dig(6, 23, TILE_PLAIN)
+    world_dig(5, 24, TILE_PLAIN)
+    world_dig(6, 24, TILE_PLAIN)
+    world_dig(7, 24, TILE_PLAIN)
+    world_dig(6, 25, TILE_PLAIN)
+    world_dig(7, 25, TILE_PLAIN)
+    world_dig(6, 26, TILE_PLAIN)
+    world_dig(7, 26, TILE_PLAIN)
+    world_dig(6, 27, TILE_PLAIN)
+    world_dig(7, 27, TILE_PLAIN)
+    world_dig(8, 26, TILE_PLAIN)
+    world_dig(8, 27, TILE_PLAIN)
+    world_dig(7, 28, TILE_PLAIN)
+    world_dig(8, 28, TILE_PLAIN)
+    world_dig(9, 28, TILE_PLAIN)
+    world_dig(8, 29, TILE_PLAIN)
+    world_dig(9, 29, TILE_PLAIN)
+    world_dig(8, 30, TILE_PLAIN)
+    world_dig(9, 30, TILE_PLAIN)
+    world_dig(8, 31, TILE_PLAIN)
+    world_dig(9, 31, TILE_PLAIN)
+    world_dig(10, 30, TILE_PLAIN)
+    world_dig(10, 31, TILE_PLAIN)
+    world_dig(11, 30, TILE_PLAIN)
+    world_dig(11, 31, TILE_PLAIN)
+    world_dig(12, 30, TILE_PLAIN)
+    world_dig(12, 31, TILE_PLAIN)
+    world_dig(13, 30, TILE_PLAIN)
+    world_dig(13, 31, TILE_PLAIN)
+    world_dig(14, 30, TILE_PLAIN)
+    world_dig(14, 31, TILE_PLAIN)
+    world_dig(15, 30, TILE_PLAIN)
+    world_dig(15, 31, TILE_PLAIN)
+    world_dig(15, 29, TILE_PLAIN)
+    world_dig(16, 29, TILE_PLAIN)
+    world_dig(16, 30, TILE_PLAIN)
+    world_dig(15, 28, TILE_PLAIN)
+    world_dig(16, 28, TILE_PLAIN)
+    world_dig(17, 28, TILE_PLAIN)
+    world_dig(17, 29, TILE_PLAIN)
+    world_dig(16, 27, TILE_PLAIN)
+    world_dig(17, 27, TILE_PLAIN)
+    world_dig(18, 27, TILE_PLAIN)
+    world_dig(18, 28, TILE_PLAIN)
+    world_dig(17, 26, TILE_PLAIN)
+    world_dig(18, 26, TILE_PLAIN)
+    world_dig(19, 26, TILE_PLAIN)
+    world_dig(19, 27, TILE_PLAIN)
+    world_dig(18, 25, TILE_PLAIN)
+    world_dig(19, 25, TILE_PLAIN)
+    world_dig(19, 24, TILE_PLAIN)
+    world_dig(20, 24, TILE_PLAIN)
+    world_dig(20, 25, TILE_PLAIN)
+    world_dig(21, 24, TILE_PLAIN)
+    world_dig(21, 25, TILE_PLAIN)
+    world_dig(26, 24, TILE_PLAIN)
+    world_dig(27, 24, TILE_PLAIN)
+    world_dig(28, 24, TILE_PLAIN)
+    world_dig(27, 25, TILE_PLAIN)
+    world_dig(28, 25, TILE_PLAIN)
+    world_dig(27, 26, TILE_PLAIN)
+    world_dig(28, 26, TILE_PLAIN)
+    world_dig(29, 25, TILE_PLAIN)
+    world_dig(29, 26, TILE_PLAIN)
+    world_dig(28, 27, TILE_PLAIN)
+    world_dig(29, 27, TILE_PLAIN)
+    world_dig(28, 28, TILE_PLAIN)
+    world_dig(29, 28, TILE_PLAIN)
+    world_dig(28, 29, TILE_PLAIN)
+    world_dig(29, 29, TILE_PLAIN)
+    world_dig(30, 28, TILE_PLAIN)
+    world_dig(30, 29, TILE_PLAIN)
+    world_dig(29, 30, TILE_PLAIN)
+    world_dig(30, 30, TILE_PLAIN)
+    world_dig(29, 31, TILE_PLAIN)
+    world_dig(30, 31, TILE_PLAIN)
+    world_dig(29, 32, TILE_PLAIN)
+    world_dig(30, 32, TILE_PLAIN)
+    world_dig(31, 32, TILE_PLAIN)
+    world_dig(30, 33, TILE_PLAIN)
+    world_dig(31, 33, TILE_PLAIN)
+    world_dig(30, 34, TILE_PLAIN)
+    world_dig(31, 34, TILE_PLAIN)
+    world_dig(30, 35, TILE_PLAIN)
+    world_dig(31, 35, TILE_PLAIN)
+    world_dig(30, 36, TILE_PLAIN)
+    world_dig(31, 36, TILE_PLAIN)
+    world_dig(32, 36, TILE_PLAIN)
+    world_dig(31, 37, TILE_PLAIN)
+    world_dig(32, 37, TILE_PLAIN)
+    world_dig(31, 38, TILE_PLAIN)
+    world_dig(32, 38, TILE_PLAIN)
+    world_dig(13, 32, TILE_PLAIN)
+    world_dig(14, 32, TILE_PLAIN)
+    world_dig(15, 32, TILE_PLAIN)
+    world_dig(16, 31, TILE_PLAIN)
+    world_dig(16, 32, TILE_PLAIN)
+    world_dig(15, 33, TILE_PLAIN)
+    world_dig(16, 33, TILE_PLAIN)
+    world_dig(17, 32, TILE_PLAIN)
+    world_dig(17, 33, TILE_PLAIN)
+    world_dig(18, 32, TILE_PLAIN)
+    world_dig(18, 33, TILE_PLAIN)
+    world_dig(19, 33, TILE_PLAIN)
+    world_dig(18, 34, TILE_PLAIN)
+    world_dig(19, 34, TILE_PLAIN)
+    world_dig(20, 33, TILE_PLAIN)
+    world_dig(20, 34, TILE_PLAIN)
+    world_dig(21, 33, TILE_PLAIN)
+    world_dig(21, 34, TILE_PLAIN)
+    world_dig(20, 35, TILE_PLAIN)
+    world_dig(21, 35, TILE_PLAIN)
+    world_dig(22, 34, TILE_PLAIN)
+    world_dig(22, 35, TILE_PLAIN)
+    world_dig(23, 34, TILE_PLAIN)
+    world_dig(23, 35, TILE_PLAIN)
+    world_dig(24, 34, TILE_PLAIN)
+    world_dig(24, 35, TILE_PLAIN)
+    world_dig(23, 36, TILE_PLAIN)
+    world_dig(24, 36, TILE_PLAIN)
+    world_dig(25, 35, TILE_PLAIN)
+    world_dig(25, 36, TILE_PLAIN)
+    world_dig(26, 35, TILE_PLAIN)
+    world_dig(26, 36, TILE_PLAIN)
+    world_dig(27, 35, TILE_PLAIN)
+    world_dig(27, 36, TILE_PLAIN)
+    world_dig(28, 35, TILE_PLAIN)
+    world_dig(28, 36, TILE_PLAIN)
+    world_dig(29, 35, TILE_PLAIN)
+    world_dig(29, 36, TILE_PLAIN)
+    world_dig(28, 34, TILE_PLAIN)
+    world_dig(29, 34, TILE_PLAIN)
+    world_dig(17, 34, TILE_PLAIN)
+    world_dig(17, 35, TILE_PLAIN)
+    world_dig(18, 35, TILE_PLAIN)
+    world_dig(19, 35, TILE_PLAIN)
+    world_dig(18, 36, TILE_PLAIN)
+    world_dig(19, 36, TILE_PLAIN)
+    world_dig(18, 37, TILE_PLAIN)
+    world_dig(19, 37, TILE_PLAIN)
+    world_dig(17, 36, TILE_PLAIN)
+    world_dig(17, 37, TILE_PLAIN)
+    world_dig(16, 37, TILE_PLAIN)
+    world_dig(16, 38, TILE_PLAIN)
+    world_dig(17, 38, TILE_PLAIN)
+    world_dig(15, 37, TILE_PLAIN)
+    world_dig(15, 38, TILE_PLAIN)
+    world_dig(15, 39, TILE_PLAIN)
+    world_dig(16, 39, TILE_PLAIN)
+    world_dig(14, 38, TILE_PLAIN)
+    world_dig(14, 39, TILE_PLAIN)
+    world_dig(13, 38, TILE_PLAIN)
+    world_dig(13, 39, TILE_PLAIN)
+    world_dig(12, 38, TILE_PLAIN)
+    world_dig(12, 39, TILE_PLAIN)
+    world_dig(11, 38, TILE_PLAIN)
+    world_dig(11, 39, TILE_PLAIN)
+    world_dig(10, 38, TILE_PLAIN)
+    world_dig(10, 39, TILE_PLAIN)
+    world_dig(9, 38, TILE_PLAIN)
+    world_dig(9, 39, TILE_PLAIN)
+    world_dig(8, 38, TILE_PLAIN)
+    world_dig(8, 39, TILE_PLAIN)
+    world_dig(7, 37, TILE_PLAIN)
+    world_dig(8, 37, TILE_PLAIN)
+    world_dig(7, 38, TILE_PLAIN)
+    world_dig(6, 37, TILE_PLAIN)
+    world_dig(6, 38, TILE_PLAIN)
+    world_dig(6, 36, TILE_PLAIN)
+    world_dig(7, 36, TILE_PLAIN)
+    world_dig(5, 35, TILE_PLAIN)
+    world_dig(6, 35, TILE_PLAIN)
+    world_dig(5, 36, TILE_PLAIN)
+    world_dig(5, 34, TILE_PLAIN)
+    world_dig(6, 34, TILE_PLAIN)
+    world_dig(5, 33, TILE_PLAIN)
+    world_dig(6, 33, TILE_PLAIN)
+    world_dig(4, 33, TILE_PLAIN)
+    world_dig(4, 34, TILE_PLAIN)
+    world_dig(4, 32, TILE_PLAIN)
+    world_dig(5, 32, TILE_PLAIN)
+    world_dig(4, 31, TILE_PLAIN)
+    world_dig(5, 31, TILE_PLAIN)
+    world_dig(4, 30, TILE_PLAIN)
+    world_dig(5, 30, TILE_PLAIN)
+    world_dig(4, 29, TILE_PLAIN)
+    world_dig(5, 29, TILE_PLAIN)
+    world_dig(6, 29, TILE_PLAIN)
+    world_dig(6, 30, TILE_PLAIN)
+    world_dig(5, 28, TILE_PLAIN)
+    world_dig(6, 28, TILE_PLAIN)
+    world_dig(7, 29, TILE_PLAIN)
+    world_dig(33, 38, TILE_PLAIN)
+    world_dig(32, 39, TILE_PLAIN)
+    world_dig(33, 39, TILE_PLAIN)
+    world_dig(34, 38, TILE_PLAIN)
+    world_dig(34, 39, TILE_PLAIN)
+    world_dig(35, 38, TILE_PLAIN)
+    world_dig(35, 39, TILE_PLAIN)
+    world_dig(36, 38, TILE_PLAIN)
+    world_dig(36, 39, TILE_PLAIN)
+    world_dig(35, 37, TILE_PLAIN)
+    world_dig(36, 37, TILE_PLAIN)
+    world_dig(37, 37, TILE_PLAIN)
+    world_dig(37, 38, TILE_PLAIN)
+    world_dig(36, 36, TILE_PLAIN)
+    world_dig(37, 36, TILE_PLAIN)
+    world_dig(38, 36, TILE_PLAIN)
+    world_dig(38, 37, TILE_PLAIN)
+    world_dig(37, 35, TILE_PLAIN)
+    world_dig(38, 35, TILE_PLAIN)
+    world_dig(37, 34, TILE_PLAIN)
+    world_dig(38, 34, TILE_PLAIN)
+    world_dig(37, 33, TILE_PLAIN)
+    world_dig(38, 33, TILE_PLAIN)
+    world_dig(39, 33, TILE_PLAIN)
+    world_dig(39, 34, TILE_PLAIN)
+    world_dig(38, 32, TILE_PLAIN)
+    world_dig(39, 32, TILE_PLAIN)
+    world_dig(38, 31, TILE_PLAIN)
+    world_dig(39, 31, TILE_PLAIN)
+    world_dig(40, 31, TILE_PLAIN)
+    world_dig(40, 32, TILE_PLAIN)
+    world_dig(39, 30, TILE_PLAIN)
+    world_dig(40, 30, TILE_PLAIN)
+    world_dig(39, 29, TILE_PLAIN)
+    world_dig(40, 29, TILE_PLAIN)
+    world_dig(41, 29, TILE_PLAIN)
+    world_dig(41, 30, TILE_PLAIN)
+    world_dig(40, 28, TILE_PLAIN)
+    world_dig(41, 28, TILE_PLAIN)
+    world_dig(40, 27, TILE_PLAIN)
+    world_dig(41, 27, TILE_PLAIN)
+    world_dig(42, 27, TILE_PLAIN)
+    world_dig(42, 28, TILE_PLAIN)
+    world_dig(41, 26, TILE_PLAIN)
+    world_dig(42, 26, TILE_PLAIN)
+    world_dig(41, 25, TILE_PLAIN)
+    world_dig(42, 25, TILE_PLAIN)
+    world_dig(43, 25, TILE_PLAIN)
+    world_dig(43, 26, TILE_PLAIN)
+    world_dig(40, 34, TILE_PLAIN)
+    world_dig(39, 35, TILE_PLAIN)
+    world_dig(40, 35, TILE_PLAIN)
+    world_dig(39, 36, TILE_PLAIN)
+    world_dig(40, 36, TILE_PLAIN)
+    world_dig(41, 35, TILE_PLAIN)
+    world_dig(41, 36, TILE_PLAIN)
+    world_dig(40, 37, TILE_PLAIN)
+    world_dig(41, 37, TILE_PLAIN)
+    world_dig(42, 36, TILE_PLAIN)
+    world_dig(42, 37, TILE_PLAIN)
+    world_dig(41, 38, TILE_PLAIN)
+    world_dig(42, 38, TILE_PLAIN)
+    world_dig(43, 37, TILE_PLAIN)
+    world_dig(43, 38, TILE_PLAIN)
+    world_dig(44, 38, TILE_PLAIN)
+    world_dig(43, 39, TILE_PLAIN)
+    world_dig(44, 39, TILE_PLAIN)
+    world_dig(45, 38, TILE_PLAIN)
+    world_dig(45, 39, TILE_PLAIN)
+    world_dig(44, 40, TILE_PLAIN)
+    world_dig(45, 40, TILE_PLAIN)
+    world_dig(46, 39, TILE_PLAIN)
+    world_dig(46, 40, TILE_PLAIN)
+    world_dig(47, 39, TILE_PLAIN)
+    world_dig(47, 40, TILE_PLAIN)
+    world_dig(46, 41, TILE_PLAIN)
+    world_dig(47, 41, TILE_PLAIN)
+    world_dig(48, 40, TILE_PLAIN)
+    world_dig(48, 41, TILE_PLAIN)
+    world_dig(49, 40, TILE_PLAIN)
+    world_dig(49, 41, TILE_PLAIN)
+    world_dig(49, 39, TILE_PLAIN)
+    world_dig(50, 39, TILE_PLAIN)
+    world_dig(50, 40, TILE_PLAIN)
+    world_dig(51, 39, TILE_PLAIN)
+    world_dig(51, 40, TILE_PLAIN)
+    world_dig(50, 38, TILE_PLAIN)
+    world_dig(51, 38, TILE_PLAIN)
+    world_dig(52, 38, TILE_PLAIN)
+    world_dig(52, 39, TILE_PLAIN)
+    world_dig(51, 37, TILE_PLAIN)
+    world_dig(52, 37, TILE_PLAIN)
+    world_dig(53, 37, TILE_PLAIN)
+    world_dig(53, 38, TILE_PLAIN)
+    world_dig(52, 36, TILE_PLAIN)
+    world_dig(53, 36, TILE_PLAIN)
+    world_dig(52, 35, TILE_PLAIN)
+    world_dig(53, 35, TILE_PLAIN)
+    world_dig(52, 34, TILE_PLAIN)
+    world_dig(53, 34, TILE_PLAIN)
+    world_dig(52, 33, TILE_PLAIN)
+    world_dig(53, 33, TILE_PLAIN)
+    world_dig(51, 33, TILE_PLAIN)
+    world_dig(51, 34, TILE_PLAIN)
+    world_dig(51, 32, TILE_PLAIN)
+    world_dig(52, 32, TILE_PLAIN)
+    world_dig(50, 31, TILE_PLAIN)
+    world_dig(51, 31, TILE_PLAIN)
+    world_dig(50, 32, TILE_PLAIN)
+    world_dig(50, 30, TILE_PLAIN)
+    world_dig(51, 30, TILE_PLAIN)
+    world_dig(49, 30, TILE_PLAIN)
+    world_dig(49, 31, TILE_PLAIN)
+    world_dig(49, 29, TILE_PLAIN)
+    world_dig(50, 29, TILE_PLAIN)
+    world_dig(48, 29, TILE_PLAIN)
+    world_dig(48, 30, TILE_PLAIN)
+    world_dig(48, 28, TILE_PLAIN)
+    world_dig(49, 28, TILE_PLAIN)
+    world_dig(48, 27, TILE_PLAIN)
+    world_dig(49, 27, TILE_PLAIN)
+    world_dig(47, 27, TILE_PLAIN)
+    world_dig(47, 28, TILE_PLAIN)
+    world_dig(47, 26, TILE_PLAIN)
+    world_dig(48, 26, TILE_PLAIN)
+    world_dig(46, 26, TILE_PLAIN)
+    world_dig(46, 27, TILE_PLAIN)
+    world_dig(46, 25, TILE_PLAIN)
+    world_dig(47, 25, TILE_PLAIN)
+    world_dig(45, 25, TILE_PLAIN)
+    world_dig(45, 26, TILE_PLAIN)
+    world_dig(53, 32, TILE_PLAIN)
+    world_dig(54, 32, TILE_PLAIN)
+    world_dig(54, 33, TILE_PLAIN)
+    world_dig(53, 31, TILE_PLAIN)
+    world_dig(54, 31, TILE_PLAIN)
+    world_dig(55, 31, TILE_PLAIN)
+    world_dig(55, 32, TILE_PLAIN)
+    world_dig(56, 31, TILE_PLAIN)
+    world_dig(56, 32, TILE_PLAIN)
+    world_dig(56, 30, TILE_PLAIN)
+    world_dig(57, 30, TILE_PLAIN)
+    world_dig(57, 31, TILE_PLAIN)
+    world_dig(58, 30, TILE_PLAIN)
+    world_dig(58, 31, TILE_PLAIN)
+    world_dig(57, 29, TILE_PLAIN)
+    world_dig(58, 29, TILE_PLAIN)
+    world_dig(59, 29, TILE_PLAIN)
+    world_dig(59, 30, TILE_PLAIN)
+    world_dig(58, 28, TILE_PLAIN)
+    world_dig(59, 28, TILE_PLAIN)
+    world_dig(59, 27, TILE_PLAIN)
+    world_dig(60, 27, TILE_PLAIN)
+    world_dig(60, 28, TILE_PLAIN)
+    world_dig(59, 26, TILE_PLAIN)
+    world_dig(60, 26, TILE_PLAIN)
+    world_dig(59, 25, TILE_PLAIN)
+    world_dig(60, 25, TILE_PLAIN)
+    world_dig(61, 25, TILE_PLAIN)
+    world_dig(61, 26, TILE_PLAIN)
+    world_dig(60, 24, TILE_PLAIN)
+    world_dig(61, 24, TILE_PLAIN)
+    world_dig(59, 23, TILE_PLAIN)
+    world_dig(60, 23, TILE_PLAIN)
+    world_dig(59, 24, TILE_PLAIN)
+    world_dig(59, 22, TILE_PLAIN)
+    world_dig(60, 22, TILE_PLAIN)
+    world_dig(58, 21, TILE_PLAIN)
+    world_dig(59, 21, TILE_PLAIN)
+    world_dig(58, 22, TILE_PLAIN)
+    world_dig(58, 20, TILE_PLAIN)
+    world_dig(59, 20, TILE_PLAIN)
+    world_dig(57, 20, TILE_PLAIN)
+    world_dig(57, 21, TILE_PLAIN)
+    world_dig(57, 19, TILE_PLAIN)
+    world_dig(58, 19, TILE_PLAIN)
+    world_dig(56, 19, TILE_PLAIN)
+    world_dig(56, 20, TILE_PLAIN)
+    world_dig(55, 18, TILE_PLAIN)
+    world_dig(56, 18, TILE_PLAIN)
+    world_dig(55, 19, TILE_PLAIN)
+    world_dig(54, 18, TILE_PLAIN)
+    world_dig(54, 19, TILE_PLAIN)
+    world_dig(55, 17, TILE_PLAIN)
+    world_dig(17, 39, TILE_PLAIN)
+    world_dig(16, 40, TILE_PLAIN)
+    world_dig(17, 40, TILE_PLAIN)
+    world_dig(18, 39, TILE_PLAIN)
+    world_dig(18, 40, TILE_PLAIN)
+    world_dig(17, 41, TILE_PLAIN)
+    world_dig(18, 41, TILE_PLAIN)
+    world_dig(19, 40, TILE_PLAIN)
+    world_dig(19, 41, TILE_PLAIN)
+    world_dig(18, 42, TILE_PLAIN)
+    world_dig(19, 42, TILE_PLAIN)
+    world_dig(20, 41, TILE_PLAIN)
+    world_dig(20, 42, TILE_PLAIN)
+    world_dig(21, 41, TILE_PLAIN)
+    world_dig(21, 42, TILE_PLAIN)
+    world_dig(22, 42, TILE_PLAIN)
+    world_dig(21, 43, TILE_PLAIN)
+    world_dig(22, 43, TILE_PLAIN)
+    world_dig(23, 42, TILE_PLAIN)
+    world_dig(23, 43, TILE_PLAIN)
+    world_dig(24, 42, TILE_PLAIN)
+    world_dig(24, 43, TILE_PLAIN)
+    world_dig(25, 42, TILE_PLAIN)
+    world_dig(25, 43, TILE_PLAIN)
+    world_dig(26, 42, TILE_PLAIN)
+    world_dig(26, 43, TILE_PLAIN)
+    world_dig(27, 42, TILE_PLAIN)
+    world_dig(27, 43, TILE_PLAIN)
+    world_dig(28, 42, TILE_PLAIN)
+    world_dig(28, 43, TILE_PLAIN)
+    world_dig(29, 42, TILE_PLAIN)
+    world_dig(29, 43, TILE_PLAIN)
+    world_dig(30, 42, TILE_PLAIN)
+    world_dig(30, 43, TILE_PLAIN)
+    world_dig(30, 41, TILE_PLAIN)
+    world_dig(31, 41, TILE_PLAIN)
+    world_dig(31, 42, TILE_PLAIN)
+    world_dig(32, 41, TILE_PLAIN)
+    world_dig(32, 42, TILE_PLAIN)
+    world_dig(31, 40, TILE_PLAIN)
+    world_dig(32, 40, TILE_PLAIN)
+    world_dig(33, 40, TILE_PLAIN)
+    world_dig(33, 41, TILE_PLAIN)
+    world_dig(34, 40, TILE_PLAIN)
+    world_dig(34, 41, TILE_PLAIN)
+    world_dig(35, 40, TILE_PLAIN)
+    world_dig(37, 39, TILE_PLAIN)
+    world_dig(52, 40, TILE_PLAIN)
+    world_dig(51, 41, TILE_PLAIN)
+    world_dig(52, 41, TILE_PLAIN)
+    world_dig(53, 41, TILE_PLAIN)
+    world_dig(52, 42, TILE_PLAIN)
+    world_dig(53, 42, TILE_PLAIN)
+    world_dig(54, 41, TILE_PLAIN)
+    world_dig(54, 42, TILE_PLAIN)
+    world_dig(55, 41, TILE_PLAIN)
+    world_dig(55, 42, TILE_PLAIN)
+    world_dig(56, 41, TILE_PLAIN)
+    world_dig(56, 42, TILE_PLAIN)
+    world_dig(57, 41, TILE_PLAIN)
+    world_dig(57, 42, TILE_PLAIN)
+    world_dig(58, 41, TILE_PLAIN)
+    world_dig(58, 42, TILE_PLAIN)
+    world_dig(58, 40, TILE_PLAIN)
+    world_dig(59, 40, TILE_PLAIN)
+    world_dig(59, 41, TILE_PLAIN)
+    world_dig(60, 40, TILE_PLAIN)
+    world_dig(60, 41, TILE_PLAIN)
+    world_dig(59, 39, TILE_PLAIN)
+    world_dig(60, 39, TILE_PLAIN)
+    world_dig(61, 39, TILE_PLAIN)
+    world_dig(61, 40, TILE_PLAIN)
+    world_dig(60, 38, TILE_PLAIN)
+    world_dig(61, 38, TILE_PLAIN)
+    world_dig(61, 37, TILE_PLAIN)
+    world_dig(61, 36, TILE_PLAIN)
+    world_dig(61, 34, TILE_PLAIN)
+    world_dig(61, 35, TILE_PLAIN)
+    world_dig(61, 33, TILE_PLAIN)
+    world_dig(61, 32, TILE_PLAIN)
+    world_dig(61, 31, TILE_PLAIN)
+    world_dig(60, 31, TILE_PLAIN)
+    world_dig(60, 32, TILE_PLAIN)
+    world_dig(60, 30, TILE_PLAIN)
+    world_dig(61, 30, TILE_PLAIN)
+    world_dig(59, 31, TILE_PLAIN)
+    world_dig(3, 18, TILE_PLAIN)
+    world_dig(4, 18, TILE_PLAIN)
+    world_dig(3, 17, TILE_PLAIN)
+    world_dig(4, 17, TILE_PLAIN)
+    world_dig(4, 16, TILE_PLAIN)
+    world_dig(5, 16, TILE_PLAIN)
+    world_dig(5, 17, TILE_PLAIN)
+    world_dig(4, 15, TILE_PLAIN)
+    world_dig(5, 15, TILE_PLAIN)
+    world_dig(5, 14, TILE_PLAIN)
+    world_dig(6, 14, TILE_PLAIN)
+    world_dig(6, 15, TILE_PLAIN)
+    world_dig(5, 13, TILE_PLAIN)
+    world_dig(52, 6, TILE_PLAIN)
+    world_dig(51, 5, TILE_PLAIN)
+    world_dig(52, 5, TILE_PLAIN)
+    world_dig(52, 4, TILE_PLAIN)
+    world_dig(53, 4, TILE_PLAIN)
+    world_dig(53, 5, TILE_PLAIN)
+    world_dig(53, 3, TILE_PLAIN)
+    world_dig(54, 3, TILE_PLAIN)
+    world_dig(54, 4, TILE_PLAIN)
+    world_dig(55, 3, TILE_PLAIN)
+    world_dig(55, 4, TILE_PLAIN)
+    world_dig(56, 3, TILE_PLAIN)
+    world_dig(56, 4, TILE_PLAIN)
+    world_dig(55, 2, TILE_PLAIN)
+    world_dig(56, 2, TILE_PLAIN)
+    world_dig(57, 2, TILE_PLAIN)
+    world_dig(57, 3, TILE_PLAIN)
+    world_dig(58, 2, TILE_PLAIN)
+    world_dig(58, 3, TILE_PLAIN)
+    world_dig(59, 2, TILE_PLAIN)
+    world_dig(59, 3, TILE_PLAIN)
+    world_dig(58, 4, TILE_PLAIN)
+    world_dig(59, 4, TILE_PLAIN)
+    world_dig(60, 3, TILE_PLAIN)
+    world_dig(60, 4, TILE_PLAIN)
+    world_dig(61, 4, TILE_PLAIN)
+    world_dig(60, 5, TILE_PLAIN)
+    world_dig(61, 5, TILE_PLAIN)
+    world_dig(60, 6, TILE_PLAIN)
+    world_dig(61, 6, TILE_PLAIN)
+    world_dig(61, 7, TILE_PLAIN)
+    world_dig(61, 8, TILE_PLAIN)
+    world_dig(61, 9, TILE_PLAIN)
+    world_dig(61, 10, TILE_PLAIN)
+    world_dig(60, 10, TILE_PLAIN)
+    world_dig(60, 11, TILE_PLAIN)
+    world_dig(61, 11, TILE_PLAIN)
+    world_dig(60, 12, TILE_PLAIN)
+    world_dig(61, 12, TILE_PLAIN)
+    world_dig(59, 11, TILE_PLAIN)
+    world_dig(59, 12, TILE_PLAIN)
+    world_dig(59, 13, TILE_PLAIN)
+    world_dig(60, 13, TILE_PLAIN)
+    world_dig(58, 12, TILE_PLAIN)
+    world_dig(58, 13, TILE_PLAIN)
+    world_dig(58, 14, TILE_PLAIN)
+    world_dig(59, 14, TILE_PLAIN)
+    world_dig(57, 13, TILE_PLAIN)
+    world_dig(57, 14, TILE_PLAIN)
+    world_dig(57, 15, TILE_PLAIN)
+    world_dig(58, 15, TILE_PLAIN)
+    world_dig(56, 14, TILE_PLAIN)
+    world_dig(56, 15, TILE_PLAIN)
+    world_dig(33, 5, TILE_PLAIN)
+    world_dig(33, 4, TILE_PLAIN)
+    world_dig(34, 4, TILE_PLAIN)
+    world_dig(34, 5, TILE_PLAIN)
+    world_dig(33, 3, TILE_PLAIN)
+    world_dig(34, 3, TILE_PLAIN)
+    world_dig(35, 3, TILE_PLAIN)
+    world_dig(35, 4, TILE_PLAIN)
+    world_dig(36, 3, TILE_PLAIN)
+    world_dig(36, 4, TILE_PLAIN)
+    world_dig(35, 2, TILE_PLAIN)
+    world_dig(36, 2, TILE_PLAIN)
+    world_dig(37, 2, TILE_PLAIN)
+    world_dig(37, 3, TILE_PLAIN)
+    world_dig(38, 2, TILE_PLAIN)
+    world_dig(38, 3, TILE_PLAIN)
+    world_dig(39, 2, TILE_PLAIN)
+    world_dig(39, 3, TILE_PLAIN)
+    world_dig(40, 2, TILE_PLAIN)
+    world_dig(40, 3, TILE_PLAIN)
+    world_dig(41, 2, TILE_PLAIN)
+    world_dig(41, 3, TILE_PLAIN)
+    world_dig(42, 2, TILE_PLAIN)
+    world_dig(42, 3, TILE_PLAIN)
+    world_dig(41, 4, TILE_PLAIN)
+    world_dig(42, 4, TILE_PLAIN)
+    world_dig(43, 3, TILE_PLAIN)
+    world_dig(43, 4, TILE_PLAIN)
+    world_dig(42, 5, TILE_PLAIN)
+    world_dig(43, 5, TILE_PLAIN)
+    world_dig(36, 40, TILE_PLAIN)
+    world_dig(35, 41, TILE_PLAIN)
+    world_dig(36, 41, TILE_PLAIN)
+    world_dig(35, 42, TILE_PLAIN)
+    world_dig(36, 42, TILE_PLAIN)
+    world_dig(37, 41, TILE_PLAIN)
+    world_dig(37, 42, TILE_PLAIN)
+    world_dig(36, 43, TILE_PLAIN)
+    world_dig(37, 43, TILE_PLAIN)
+    world_dig(38, 42, TILE_PLAIN)
+    world_dig(38, 43, TILE_PLAIN)
+    world_dig(39, 42, TILE_PLAIN)
+    world_dig(39, 43, TILE_PLAIN)
+    world_dig(40, 42, TILE_PLAIN)
+    world_dig(40, 43, TILE_PLAIN)
+    world_dig(41, 42, TILE_PLAIN)
+    world_dig(41, 43, TILE_PLAIN)
+    world_dig(42, 42, TILE_PLAIN)
+    world_dig(42, 43, TILE_PLAIN)
+    world_dig(43, 42, TILE_PLAIN)
+    world_dig(43, 43, TILE_PLAIN)
+    world_dig(44, 42, TILE_PLAIN)
+    world_dig(44, 43, TILE_PLAIN)
+    world_dig(43, 41, TILE_PLAIN)
+    world_dig(44, 41, TILE_PLAIN)
+    world_dig(45, 41, TILE_PLAIN)
+    world_dig(5, 37, TILE_PLAIN)
+    world_dig(5, 38, TILE_PLAIN)
+    world_dig(5, 39, TILE_PLAIN)
+    world_dig(6, 39, TILE_PLAIN)
+    world_dig(4, 38, TILE_PLAIN)
+    world_dig(4, 39, TILE_PLAIN)
+    world_dig(4, 40, TILE_PLAIN)
+    world_dig(5, 40, TILE_PLAIN)
+    world_dig(3, 39, TILE_PLAIN)
+    world_dig(3, 40, TILE_PLAIN)
+    world_dig(3, 41, TILE_PLAIN)
+    world_dig(4, 41, TILE_PLAIN)
+    world_dig(5, 41, TILE_PLAIN)
+    world_dig(4, 42, TILE_PLAIN)
+    world_dig(5, 42, TILE_PLAIN)
+    world_dig(6, 42, TILE_PLAIN)
+    world_dig(5, 43, TILE_PLAIN)
+    world_dig(6, 43, TILE_PLAIN)
+    world_dig(7, 42, TILE_PLAIN)
+    world_dig(7, 43, TILE_PLAIN)
+    world_dig(8, 43, TILE_PLAIN)
+    world_dig(9, 43, TILE_PLAIN)
+    world_dig(10, 43, TILE_PLAIN)
+    world_dig(11, 43, TILE_PLAIN)
+    world_dig(12, 43, TILE_PLAIN)
+    world_dig(11, 42, TILE_PLAIN)
+    world_dig(12, 42, TILE_PLAIN)
+    world_dig(11, 41, TILE_PLAIN)
+    world_dig(12, 41, TILE_PLAIN)
+    world_dig(11, 40, TILE_PLAIN)
+    world_dig(12, 40, TILE_PLAIN)
+    world_dig(13, 40, TILE_PLAIN)
+    world_dig(13, 41, TILE_PLAIN)
+    world_dig(31, 21, TILE_PLAIN)
+    world_dig(32, 21, TILE_PLAIN)
+    world_dig(31, 20, TILE_PLAIN)
+    world_dig(32, 20, TILE_PLAIN)
+    world_dig(33, 20, TILE_PLAIN)
+    world_dig(33, 21, TILE_PLAIN)
+    world_dig(34, 20, TILE_PLAIN)
+    world_dig(34, 21, TILE_PLAIN)
+    world_dig(30, 21, TILE_PLAIN)
+    world_dig(30, 20, TILE_PLAIN)
+    world_dig(30, 24, TILE_PLAIN)
+    world_dig(31, 24, TILE_PLAIN)
+    world_dig(32, 24, TILE_PLAIN)
+    world_dig(31, 25, TILE_PLAIN)
+    world_dig(32, 25, TILE_PLAIN)
+    world_dig(31, 26, TILE_PLAIN)
+    world_dig(32, 26, TILE_PLAIN)
+    world_dig(33, 25, TILE_PLAIN)
+    world_dig(33, 26, TILE_PLAIN)
+    world_dig(34, 25, TILE_PLAIN)
+    world_dig(34, 26, TILE_PLAIN)
+    world_dig(33, 24, TILE_PLAIN)
+    world_dig(34, 24, TILE_PLAIN)
+    world_dig(35, 21, TILE_PLAIN)
+    world_dig(36, 21, TILE_PLAIN)
+    world_dig(35, 20, TILE_PLAIN)
+    world_dig(36, 20, TILE_PLAIN)
+    world_dig(35, 25, TILE_PLAIN)
+    world_dig(36, 25, TILE_PLAIN)
+    world_dig(35, 26, TILE_PLAIN)
+    world_dig(36, 26, TILE_PLAIN)
+    world_dig(31, 19, TILE_PLAIN)
+    world_dig(32, 19, TILE_PLAIN)
+    world_dig(33, 19, TILE_PLAIN)
+    world_dig(34, 19, TILE_PLAIN)
+    world_dig(35, 19, TILE_PLAIN)
+    world_dig(26, 16, TILE_PLAIN)
+    world_dig(27, 16, TILE_PLAIN)
+    world_dig(26, 17, TILE_PLAIN)
+    world_dig(27, 17, TILE_PLAIN)
+    world_dig(25, 16, TILE_PLAIN)
+    world_dig(25, 17, TILE_PLAIN)
+    world_dig(25, 18, TILE_PLAIN)
+    world_dig(26, 18, TILE_PLAIN)
+    world_dig(24, 17, TILE_PLAIN)
+    world_dig(24, 18, TILE_PLAIN)
+    world_dig(24, 19, TILE_PLAIN)
+    world_dig(25, 19, TILE_PLAIN)
+    world_dig(23, 18, TILE_PLAIN)
+    world_dig(23, 19, TILE_PLAIN)
+    world_dig(23, 20, TILE_PLAIN)
+    world_dig(24, 20, TILE_PLAIN)
+    world_dig(22, 19, TILE_PLAIN)
+    world_dig(22, 20, TILE_PLAIN)
+    world_dig(23, 21, TILE_PLAIN)
+    
+    local w, h = level_size()
+    local offsets = { { -1, -1 }, { 0, -1 }, { 1, -1 },
+                      { -1,  0 },            { 1,  0 },
+                      { -1,  1 }, { 0,  1 }, { 1,  1 } }
+    for x = 1, w-2 do
+        for y = 1, h-2 do
+            local change = true
+            for n, offset in pairs(offsets) do 
+                local xx, yy = unpack(offset)
+                if world_get_type(x + xx, y + yy) ~= TILE_SOLID then
+                    change = false
+                    break
+                end
+            end
+            if change then
+               world_set_gfx(x, y, TILE_GFX_LAVA)
+           end
+        end
+    end
+
+    world_make_border(TILE_GFX_LAVA)
+
+    food_spawner = {}
+    for s = 0, 15 do
+        local dx, dy = world_find_digged()
+        food_spawner[s] = { x = dx,
+                            y = dy,
+                            r = math.random(3),
+                            a = math.random(100) + 30,
+                            i = math.random(1000) + 1000,
+                            n = game_time() }
+        world_add_food(food_spawner[s].x, 
+                       food_spawner[s].y, 
+                       10000)
+    end
+
+    last_food = game_time()
+end
+
+function level_tick()
+    if game_time() > last_food + 10000 then
+        for n, spawner in pairs(food_spawner) do
+            if game_time() > spawner.n then 
+                world_add_food(spawner.x + math.random(spawner.r * 2 + 1) - spawner.r,
+                               spawner.y + math.random(spawner.r * 2 + 1) - spawner.r,
+                               spawner.a)
+                spawner.n = spawner.n + spawner.i                               
+            end
+        end
+    end
+end
@@ -0,0 +1,2080 @@
+-- Nachdem es einen Votebot gab, welcher nach neuen Levels gebettelt hat...
+-- Hier ist es :-)
+
+function level_size()
+    return 64, 46
+end
+
+function level_koth_pos()
+    return 56, 22
+end
+
+-- function level_spawn_point(player)
+--    return world_tile_center(11, 11)
+-- end
+
+function level_init()
+    world_dig(32, 23, TILE_WATER)
+    world_dig(61, 16, TILE_WATER)
+    world_dig(62, 16, TILE_WATER)
+    world_dig(61, 17, TILE_WATER)
+    world_dig(62, 17, TILE_WATER)
+    world_dig(60, 15, TILE_WATER)
+    world_dig(61, 15, TILE_WATER)
+    world_dig(60, 16, TILE_WATER)
+    world_dig(59, 15, TILE_WATER)
+    world_dig(59, 16, TILE_WATER)
+    world_dig(58, 15, TILE_WATER)
+    world_dig(58, 16, TILE_WATER)
+    world_dig(57, 15, TILE_WATER)
+    world_dig(57, 16, TILE_WATER)
+    world_dig(56, 15, TILE_WATER)
+    world_dig(56, 16, TILE_WATER)
+    world_dig(55, 15, TILE_WATER)
+    world_dig(55, 16, TILE_WATER)
+    world_dig(54, 15, TILE_WATER)
+    world_dig(54, 16, TILE_WATER)
+    world_dig(53, 15, TILE_WATER)
+    world_dig(53, 16, TILE_WATER)
+    world_dig(52, 15, TILE_WATER)
+    world_dig(52, 16, TILE_WATER)
+    world_dig(51, 15, TILE_WATER)
+    world_dig(51, 16, TILE_WATER)
+    world_dig(50, 15, TILE_WATER)
+    world_dig(50, 16, TILE_WATER)
+    world_dig(50, 17, TILE_WATER)
+    world_dig(51, 17, TILE_WATER)
+    world_dig(49, 16, TILE_WATER)
+    world_dig(49, 17, TILE_WATER)
+    world_dig(48, 16, TILE_WATER)
+    world_dig(48, 17, TILE_WATER)
+    world_dig(52, 17, TILE_WATER)
+    world_dig(53, 17, TILE_WATER)
+    world_dig(54, 17, TILE_WATER)
+    world_dig(55, 17, TILE_WATER)
+    world_dig(56, 17, TILE_WATER)
+    world_dig(57, 17, TILE_WATER)
+    world_dig(58, 17, TILE_WATER)
+    world_dig(59, 17, TILE_WATER)
+    world_dig(60, 17, TILE_WATER)
+    world_dig(48, 15, TILE_WATER)
+    world_dig(49, 15, TILE_WATER)
+    world_dig(47, 14, TILE_WATER)
+    world_dig(48, 14, TILE_WATER)
+    world_dig(47, 15, TILE_WATER)
+    world_dig(47, 13, TILE_WATER)
+    world_dig(48, 13, TILE_WATER)
+    world_dig(47, 12, TILE_WATER)
+    world_dig(48, 12, TILE_WATER)
+    world_dig(47, 11, TILE_WATER)
+    world_dig(48, 11, TILE_WATER)
+    world_dig(47, 10, TILE_WATER)
+    world_dig(48, 10, TILE_WATER)
+    world_dig(47, 9, TILE_WATER)
+    world_dig(48, 9, TILE_WATER)
+    world_dig(47, 8, TILE_WATER)
+    world_dig(48, 8, TILE_WATER)
+    world_dig(47, 7, TILE_WATER)
+    world_dig(48, 7, TILE_WATER)
+    world_dig(47, 6, TILE_WATER)
+    world_dig(48, 6, TILE_WATER)
+    world_dig(47, 5, TILE_WATER)
+    world_dig(48, 5, TILE_WATER)
+    world_dig(47, 4, TILE_WATER)
+    world_dig(48, 4, TILE_WATER)
+    world_dig(47, 3, TILE_WATER)
+    world_dig(48, 3, TILE_WATER)
+    world_dig(47, 2, TILE_WATER)
+    world_dig(48, 2, TILE_WATER)
+    world_dig(47, 1, TILE_WATER)
+    world_dig(48, 1, TILE_WATER)
+    world_dig(49, 1, TILE_WATER)
+    world_dig(50, 1, TILE_WATER)
+    world_dig(51, 1, TILE_WATER)
+    world_dig(52, 1, TILE_WATER)
+    world_dig(53, 1, TILE_WATER)
+    world_dig(54, 1, TILE_WATER)
+    world_dig(55, 1, TILE_WATER)
+    world_dig(54, 2, TILE_WATER)
+    world_dig(55, 2, TILE_WATER)
+    world_dig(56, 1, TILE_WATER)
+    world_dig(56, 2, TILE_WATER)
+    world_dig(57, 1, TILE_WATER)
+    world_dig(57, 2, TILE_WATER)
+    world_dig(58, 1, TILE_WATER)
+    world_dig(59, 1, TILE_WATER)
+    world_dig(60, 1, TILE_WATER)
+    world_dig(61, 1, TILE_WATER)
+    world_dig(62, 1, TILE_WATER)
+    world_dig(61, 2, TILE_WATER)
+    world_dig(62, 2, TILE_WATER)
+    world_dig(61, 3, TILE_WATER)
+    world_dig(62, 3, TILE_WATER)
+    world_dig(62, 4, TILE_WATER)
+    world_dig(62, 5, TILE_WATER)
+    world_dig(62, 6, TILE_WATER)
+    world_dig(62, 7, TILE_WATER)
+    world_dig(62, 8, TILE_WATER)
+    world_dig(62, 9, TILE_WATER)
+    world_dig(62, 10, TILE_WATER)
+    world_dig(62, 11, TILE_WATER)
+    world_dig(62, 12, TILE_WATER)
+    world_dig(62, 13, TILE_WATER)
+    world_dig(61, 12, TILE_WATER)
+    world_dig(61, 13, TILE_WATER)
+    world_dig(61, 14, TILE_WATER)
+    world_dig(62, 14, TILE_WATER)
+    world_dig(62, 15, TILE_WATER)
+    world_dig(60, 14, TILE_WATER)
+    world_dig(59, 14, TILE_WATER)
+    world_dig(58, 14, TILE_WATER)
+    world_dig(57, 14, TILE_WATER)
+    world_dig(56, 14, TILE_WATER)
+    world_dig(55, 14, TILE_WATER)
+    world_dig(54, 14, TILE_WATER)
+    world_dig(53, 14, TILE_WATER)
+    world_dig(52, 14, TILE_WATER)
+    world_dig(51, 14, TILE_WATER)
+    world_dig(50, 14, TILE_WATER)
+    world_dig(49, 14, TILE_WATER)
+    world_dig(49, 13, TILE_WATER)
+    world_dig(49, 9, TILE_WATER)
+    world_dig(49, 10, TILE_WATER)
+    world_dig(49, 8, TILE_WATER)
+    world_dig(49, 7, TILE_WATER)
+    world_dig(50, 7, TILE_WATER)
+    world_dig(50, 8, TILE_WATER)
+    world_dig(49, 6, TILE_WATER)
+    world_dig(50, 6, TILE_WATER)
+    world_dig(49, 5, TILE_WATER)
+    world_dig(50, 5, TILE_WATER)
+    world_dig(49, 4, TILE_WATER)
+    world_dig(49, 3, TILE_WATER)
+    world_dig(49, 2, TILE_WATER)
+    world_dig(50, 2, TILE_WATER)
+    world_dig(50, 3, TILE_WATER)
+    world_dig(51, 2, TILE_WATER)
+    world_dig(52, 2, TILE_WATER)
+    world_dig(53, 2, TILE_WATER)
+    world_dig(53, 3, TILE_WATER)
+    world_dig(54, 3, TILE_WATER)
+    world_dig(55, 3, TILE_WATER)
+    world_dig(56, 3, TILE_WATER)
+    world_dig(55, 4, TILE_WATER)
+    world_dig(56, 4, TILE_WATER)
+    world_dig(55, 5, TILE_WATER)
+    world_dig(56, 5, TILE_WATER)
+    world_dig(54, 4, TILE_WATER)
+    world_dig(54, 5, TILE_WATER)
+    world_dig(53, 4, TILE_WATER)
+    world_dig(53, 5, TILE_WATER)
+    world_dig(53, 6, TILE_WATER)
+    world_dig(54, 6, TILE_WATER)
+    world_dig(52, 5, TILE_WATER)
+    world_dig(52, 6, TILE_WATER)
+    world_dig(51, 5, TILE_WATER)
+    world_dig(51, 6, TILE_WATER)
+    world_dig(51, 4, TILE_WATER)
+    world_dig(52, 4, TILE_WATER)
+    world_dig(57, 3, TILE_WATER)
+    world_dig(58, 2, TILE_WATER)
+    world_dig(58, 3, TILE_WATER)
+    world_dig(59, 2, TILE_WATER)
+    world_dig(59, 3, TILE_WATER)
+    world_dig(60, 2, TILE_WATER)
+    world_dig(60, 3, TILE_WATER)
+    world_dig(59, 4, TILE_WATER)
+    world_dig(60, 4, TILE_WATER)
+    world_dig(59, 5, TILE_WATER)
+    world_dig(60, 5, TILE_WATER)
+    world_dig(58, 5, TILE_WATER)
+    world_dig(58, 6, TILE_WATER)
+    world_dig(59, 6, TILE_WATER)
+    world_dig(58, 7, TILE_WATER)
+    world_dig(59, 7, TILE_WATER)
+    world_dig(57, 6, TILE_WATER)
+    world_dig(57, 7, TILE_WATER)
+    world_dig(56, 6, TILE_WATER)
+    world_dig(56, 7, TILE_WATER)
+    world_dig(55, 6, TILE_WATER)
+    world_dig(55, 7, TILE_WATER)
+    world_dig(54, 7, TILE_WATER)
+    world_dig(61, 4, TILE_WATER)
+    world_dig(61, 8, TILE_WATER)
+    world_dig(61, 9, TILE_WATER)
+    world_dig(60, 8, TILE_WATER)
+    world_dig(60, 9, TILE_WATER)
+    world_dig(59, 9, TILE_WATER)
+    world_dig(59, 10, TILE_WATER)
+    world_dig(60, 10, TILE_WATER)
+    world_dig(57, 9, TILE_WATER)
+    world_dig(58, 9, TILE_WATER)
+    world_dig(57, 10, TILE_WATER)
+    world_dig(58, 10, TILE_WATER)
+    world_dig(56, 9, TILE_WATER)
+    world_dig(56, 10, TILE_WATER)
+    world_dig(56, 8, TILE_WATER)
+    world_dig(57, 8, TILE_WATER)
+    world_dig(57, 5, TILE_WATER)
+    world_dig(60, 6, TILE_WATER)
+    world_dig(61, 5, TILE_WATER)
+    world_dig(61, 7, TILE_WATER)
+    world_dig(57, 11, TILE_WATER)
+    world_dig(58, 11, TILE_WATER)
+    world_dig(56, 11, TILE_WATER)
+    world_dig(55, 10, TILE_WATER)
+    world_dig(55, 11, TILE_WATER)
+    world_dig(54, 10, TILE_WATER)
+    world_dig(54, 11, TILE_WATER)
+    world_dig(54, 9, TILE_WATER)
+    world_dig(55, 9, TILE_WATER)
+    world_dig(54, 8, TILE_WATER)
+    world_dig(55, 8, TILE_WATER)
+    world_dig(61, 6, TILE_WATER)
+    world_dig(61, 10, TILE_WATER)
+    world_dig(61, 11, TILE_WATER)
+    world_dig(60, 11, TILE_WATER)
+    world_dig(60, 12, TILE_WATER)
+    world_dig(58, 12, TILE_WATER)
+    world_dig(59, 12, TILE_WATER)
+    world_dig(58, 13, TILE_WATER)
+    world_dig(59, 13, TILE_WATER)
+    world_dig(57, 12, TILE_WATER)
+    world_dig(57, 13, TILE_WATER)
+    world_dig(56, 12, TILE_WATER)
+    world_dig(56, 13, TILE_WATER)
+    world_dig(55, 12, TILE_WATER)
+    world_dig(55, 13, TILE_WATER)
+    world_dig(59, 8, TILE_WATER)
+    world_dig(60, 13, TILE_WATER)
+    world_dig(54, 12, TILE_WATER)
+    world_dig(53, 10, TILE_WATER)
+    world_dig(53, 11, TILE_WATER)
+    world_dig(53, 9, TILE_WATER)
+    world_dig(58, 8, TILE_WATER)
+    world_dig(59, 11, TILE_WATER)
+    world_dig(53, 12, TILE_WATER)
+    world_dig(53, 13, TILE_WATER)
+    world_dig(54, 13, TILE_WATER)
+    world_dig(52, 12, TILE_WATER)
+    world_dig(52, 13, TILE_WATER)
+    world_dig(51, 12, TILE_WATER)
+    world_dig(51, 13, TILE_WATER)
+    world_dig(52, 11, TILE_WATER)
+    world_dig(51, 11, TILE_WATER)
+    world_dig(51, 10, TILE_WATER)
+    world_dig(52, 10, TILE_WATER)
+    world_dig(51, 9, TILE_WATER)
+    world_dig(52, 9, TILE_WATER)
+    world_dig(51, 8, TILE_WATER)
+    world_dig(52, 8, TILE_WATER)
+    world_dig(52, 7, TILE_WATER)
+    world_dig(53, 7, TILE_WATER)
+    world_dig(53, 8, TILE_WATER)
+    world_dig(50, 12, TILE_WATER)
+    world_dig(50, 13, TILE_WATER)
+    world_dig(50, 11, TILE_WATER)
+    world_dig(50, 10, TILE_WATER)
+    world_dig(50, 9, TILE_WATER)
+    world_dig(51, 7, TILE_WATER)
+    world_dig(50, 4, TILE_WATER)
+    world_dig(52, 3, TILE_WATER)
+    world_dig(57, 4, TILE_WATER)
+    world_dig(58, 4, TILE_WATER)
+    world_dig(60, 7, TILE_WATER)
+    world_dig(49, 11, TILE_WATER)
+    world_dig(49, 12, TILE_WATER)
+    world_dig(51, 3, TILE_WATER)
+    world_dig(47, 16, TILE_WATER)
+    world_dig(47, 17, TILE_WATER)
+    world_dig(47, 18, TILE_WATER)
+    world_dig(48, 18, TILE_WATER)
+    world_dig(47, 19, TILE_WATER)
+    world_dig(48, 19, TILE_WATER)
+    world_dig(49, 18, TILE_WATER)
+    world_dig(49, 19, TILE_WATER)
+    world_dig(50, 18, TILE_WATER)
+    world_dig(50, 19, TILE_WATER)
+    world_dig(51, 18, TILE_WATER)
+    world_dig(51, 19, TILE_WATER)
+    world_dig(49, 20, TILE_WATER)
+    world_dig(50, 20, TILE_WATER)
+    world_dig(49, 21, TILE_WATER)
+    world_dig(50, 21, TILE_WATER)
+    world_dig(49, 22, TILE_WATER)
+    world_dig(50, 22, TILE_WATER)
+    world_dig(48, 21, TILE_WATER)
+    world_dig(48, 22, TILE_WATER)
+    world_dig(48, 20, TILE_WATER)
+    world_dig(51, 20, TILE_WATER)
+    world_dig(51, 21, TILE_WATER)
+    world_dig(52, 20, TILE_WATER)
+    world_dig(52, 21, TILE_WATER)
+    world_dig(52, 18, TILE_WATER)
+    world_dig(52, 19, TILE_WATER)
+    world_dig(47, 20, TILE_WATER)
+    world_dig(47, 21, TILE_WATER)
+    world_dig(48, 23, TILE_WATER)
+    world_dig(49, 23, TILE_WATER)
+    world_dig(48, 24, TILE_WATER)
+    world_dig(49, 24, TILE_WATER)
+    world_dig(47, 24, TILE_WATER)
+    world_dig(47, 25, TILE_WATER)
+    world_dig(48, 25, TILE_WATER)
+    world_dig(47, 26, TILE_WATER)
+    world_dig(48, 26, TILE_WATER)
+    world_dig(47, 27, TILE_WATER)
+    world_dig(48, 27, TILE_WATER)
+    world_dig(47, 28, TILE_WATER)
+    world_dig(48, 28, TILE_WATER)
+    world_dig(47, 29, TILE_WATER)
+    world_dig(48, 29, TILE_WATER)
+    world_dig(46, 25, TILE_WATER)
+    world_dig(46, 26, TILE_WATER)
+    world_dig(46, 24, TILE_WATER)
+    world_dig(46, 23, TILE_WATER)
+    world_dig(47, 23, TILE_WATER)
+    world_dig(46, 22, TILE_WATER)
+    world_dig(47, 22, TILE_WATER)
+    world_dig(46, 21, TILE_WATER)
+    world_dig(46, 20, TILE_WATER)
+    world_dig(46, 19, TILE_WATER)
+    world_dig(46, 18, TILE_WATER)
+    world_dig(46, 17, TILE_WATER)
+    world_dig(46, 16, TILE_WATER)
+    world_dig(46, 15, TILE_WATER)
+    world_dig(46, 14, TILE_WATER)
+    world_dig(46, 13, TILE_WATER)
+    world_dig(46, 12, TILE_WATER)
+    world_dig(46, 11, TILE_WATER)
+    world_dig(46, 10, TILE_WATER)
+    world_dig(46, 4, TILE_WATER)
+    world_dig(46, 5, TILE_WATER)
+    world_dig(46, 3, TILE_WATER)
+    world_dig(46, 2, TILE_WATER)
+    world_dig(45, 1, TILE_WATER)
+    world_dig(46, 1, TILE_WATER)
+    world_dig(45, 2, TILE_WATER)
+    world_dig(45, 3, TILE_WATER)
+    world_dig(45, 4, TILE_WATER)
+    world_dig(45, 5, TILE_WATER)
+    world_dig(45, 6, TILE_WATER)
+    world_dig(46, 6, TILE_WATER)
+    world_dig(45, 7, TILE_WATER)
+    world_dig(46, 7, TILE_WATER)
+    world_dig(45, 8, TILE_WATER)
+    world_dig(46, 8, TILE_WATER)
+    world_dig(45, 9, TILE_WATER)
+    world_dig(46, 9, TILE_WATER)
+    world_dig(45, 10, TILE_WATER)
+    world_dig(45, 11, TILE_WATER)
+    world_dig(45, 12, TILE_WATER)
+    world_dig(45, 13, TILE_WATER)
+    world_dig(45, 14, TILE_WATER)
+    world_dig(45, 15, TILE_WATER)
+    world_dig(45, 16, TILE_WATER)
+    world_dig(45, 17, TILE_WATER)
+    world_dig(45, 18, TILE_WATER)
+    world_dig(45, 19, TILE_WATER)
+    world_dig(45, 20, TILE_WATER)
+    world_dig(45, 21, TILE_WATER)
+    world_dig(45, 22, TILE_WATER)
+    world_dig(45, 23, TILE_WATER)
+    world_dig(45, 24, TILE_WATER)
+    world_dig(45, 25, TILE_WATER)
+    world_dig(45, 26, TILE_WATER)
+    world_dig(45, 27, TILE_WATER)
+    world_dig(46, 27, TILE_WATER)
+    world_dig(45, 28, TILE_WATER)
+    world_dig(46, 28, TILE_WATER)
+    world_dig(45, 29, TILE_WATER)
+    world_dig(46, 29, TILE_WATER)
+    world_dig(45, 30, TILE_WATER)
+    world_dig(46, 30, TILE_WATER)
+    world_dig(45, 31, TILE_WATER)
+    world_dig(46, 31, TILE_WATER)
+    world_dig(45, 32, TILE_WATER)
+    world_dig(46, 32, TILE_WATER)
+    world_dig(45, 33, TILE_WATER)
+    world_dig(46, 33, TILE_WATER)
+    world_dig(45, 34, TILE_WATER)
+    world_dig(46, 34, TILE_WATER)
+    world_dig(45, 35, TILE_WATER)
+    world_dig(46, 35, TILE_WATER)
+    world_dig(45, 36, TILE_WATER)
+    world_dig(46, 36, TILE_WATER)
+    world_dig(45, 37, TILE_WATER)
+    world_dig(46, 37, TILE_WATER)
+    world_dig(45, 38, TILE_WATER)
+    world_dig(46, 38, TILE_WATER)
+    world_dig(45, 39, TILE_WATER)
+    world_dig(46, 39, TILE_WATER)
+    world_dig(45, 40, TILE_WATER)
+    world_dig(46, 40, TILE_WATER)
+    world_dig(45, 41, TILE_WATER)
+    world_dig(46, 41, TILE_WATER)
+    world_dig(45, 42, TILE_WATER)
+    world_dig(46, 42, TILE_WATER)
+    world_dig(45, 43, TILE_WATER)
+    world_dig(46, 43, TILE_WATER)
+    world_dig(45, 44, TILE_WATER)
+    world_dig(46, 44, TILE_WATER)
+    world_dig(47, 44, TILE_WATER)
+    world_dig(48, 44, TILE_WATER)
+    world_dig(47, 43, TILE_WATER)
+    world_dig(48, 43, TILE_WATER)
+    world_dig(47, 42, TILE_WATER)
+    world_dig(48, 42, TILE_WATER)
+    world_dig(47, 41, TILE_WATER)
+    world_dig(48, 41, TILE_WATER)
+    world_dig(47, 40, TILE_WATER)
+    world_dig(48, 40, TILE_WATER)
+    world_dig(47, 39, TILE_WATER)
+    world_dig(48, 39, TILE_WATER)
+    world_dig(47, 38, TILE_WATER)
+    world_dig(47, 37, TILE_WATER)
+    world_dig(47, 36, TILE_WATER)
+    world_dig(47, 35, TILE_WATER)
+    world_dig(47, 34, TILE_WATER)
+    world_dig(51, 22, TILE_WATER)
+    world_dig(52, 22, TILE_WATER)
+    world_dig(51, 23, TILE_WATER)
+    world_dig(52, 23, TILE_WATER)
+    world_dig(51, 24, TILE_WATER)
+    world_dig(52, 24, TILE_WATER)
+    world_dig(51, 25, TILE_WATER)
+    world_dig(52, 25, TILE_WATER)
+    world_dig(51, 26, TILE_WATER)
+    world_dig(52, 26, TILE_WATER)
+    world_dig(51, 27, TILE_WATER)
+    world_dig(52, 27, TILE_WATER)
+    world_dig(51, 28, TILE_WATER)
+    world_dig(52, 28, TILE_WATER)
+    world_dig(53, 27, TILE_WATER)
+    world_dig(53, 28, TILE_WATER)
+    world_dig(54, 27, TILE_WATER)
+    world_dig(54, 28, TILE_WATER)
+    world_dig(55, 27, TILE_WATER)
+    world_dig(55, 28, TILE_WATER)
+    world_dig(56, 27, TILE_WATER)
+    world_dig(56, 28, TILE_WATER)
+    world_dig(57, 27, TILE_WATER)
+    world_dig(57, 28, TILE_WATER)
+    world_dig(58, 27, TILE_WATER)
+    world_dig(58, 28, TILE_WATER)
+    world_dig(59, 27, TILE_WATER)
+    world_dig(59, 28, TILE_WATER)
+    world_dig(60, 27, TILE_WATER)
+    world_dig(60, 28, TILE_WATER)
+    world_dig(61, 27, TILE_WATER)
+    world_dig(61, 28, TILE_WATER)
+    world_dig(62, 27, TILE_WATER)
+    world_dig(62, 28, TILE_WATER)
+    world_dig(61, 26, TILE_WATER)
+    world_dig(62, 26, TILE_WATER)
+    world_dig(61, 25, TILE_WATER)
+    world_dig(62, 25, TILE_WATER)
+    world_dig(61, 24, TILE_WATER)
+    world_dig(62, 24, TILE_WATER)
+    world_dig(61, 23, TILE_WATER)
+    world_dig(62, 23, TILE_WATER)
+    world_dig(61, 22, TILE_WATER)
+    world_dig(62, 22, TILE_WATER)
+    world_dig(61, 21, TILE_WATER)
+    world_dig(62, 21, TILE_WATER)
+    world_dig(61, 20, TILE_WATER)
+    world_dig(62, 20, TILE_WATER)
+    world_dig(61, 19, TILE_WATER)
+    world_dig(62, 19, TILE_WATER)
+    world_dig(61, 18, TILE_WATER)
+    world_dig(62, 18, TILE_WATER)
+    world_dig(50, 23, TILE_WATER)
+    world_dig(50, 24, TILE_WATER)
+    world_dig(49, 25, TILE_WATER)
+    world_dig(50, 25, TILE_WATER)
+    world_dig(49, 26, TILE_WATER)
+    world_dig(50, 26, TILE_WATER)
+    world_dig(50, 27, TILE_WATER)
+    world_dig(50, 28, TILE_WATER)
+    world_dig(50, 29, TILE_WATER)
+    world_dig(51, 29, TILE_WATER)
+    world_dig(50, 30, TILE_WATER)
+    world_dig(51, 30, TILE_WATER)
+    world_dig(49, 29, TILE_WATER)
+    world_dig(49, 30, TILE_WATER)
+    world_dig(49, 31, TILE_WATER)
+    world_dig(50, 31, TILE_WATER)
+    world_dig(48, 30, TILE_WATER)
+    world_dig(47, 30, TILE_WATER)
+    world_dig(49, 27, TILE_WATER)
+    world_dig(49, 28, TILE_WATER)
+    world_dig(48, 31, TILE_WATER)
+    world_dig(48, 32, TILE_WATER)
+    world_dig(49, 32, TILE_WATER)
+    world_dig(48, 33, TILE_WATER)
+    world_dig(49, 33, TILE_WATER)
+    world_dig(47, 32, TILE_WATER)
+    world_dig(47, 33, TILE_WATER)
+    world_dig(48, 34, TILE_WATER)
+    world_dig(48, 35, TILE_WATER)
+    world_dig(47, 31, TILE_WATER)
+    world_dig(50, 32, TILE_WATER)
+    world_dig(50, 33, TILE_WATER)
+    world_dig(51, 32, TILE_WATER)
+    world_dig(51, 33, TILE_WATER)
+    world_dig(50, 34, TILE_WATER)
+    world_dig(51, 34, TILE_WATER)
+    world_dig(52, 34, TILE_WATER)
+    world_dig(51, 35, TILE_WATER)
+    world_dig(52, 35, TILE_WATER)
+    world_dig(51, 36, TILE_WATER)
+    world_dig(52, 36, TILE_WATER)
+    world_dig(53, 34, TILE_WATER)
+    world_dig(53, 35, TILE_WATER)
+    world_dig(54, 34, TILE_WATER)
+    world_dig(54, 35, TILE_WATER)
+    world_dig(53, 33, TILE_WATER)
+    world_dig(54, 33, TILE_WATER)
+    world_dig(55, 33, TILE_WATER)
+    world_dig(55, 34, TILE_WATER)
+    world_dig(54, 32, TILE_WATER)
+    world_dig(55, 32, TILE_WATER)
+    world_dig(56, 32, TILE_WATER)
+    world_dig(56, 33, TILE_WATER)
+    world_dig(57, 32, TILE_WATER)
+    world_dig(57, 33, TILE_WATER)
+    world_dig(58, 32, TILE_WATER)
+    world_dig(58, 33, TILE_WATER)
+    world_dig(57, 31, TILE_WATER)
+    world_dig(58, 31, TILE_WATER)
+    world_dig(59, 31, TILE_WATER)
+    world_dig(59, 32, TILE_WATER)
+    world_dig(60, 31, TILE_WATER)
+    world_dig(60, 32, TILE_WATER)
+    world_dig(60, 30, TILE_WATER)
+    world_dig(61, 30, TILE_WATER)
+    world_dig(61, 31, TILE_WATER)
+    world_dig(62, 30, TILE_WATER)
+    world_dig(62, 31, TILE_WATER)
+    world_dig(62, 29, TILE_WATER)
+    world_dig(61, 29, TILE_WATER)
+    world_dig(60, 29, TILE_WATER)
+    world_dig(59, 29, TILE_WATER)
+    world_dig(59, 30, TILE_WATER)
+    world_dig(58, 29, TILE_WATER)
+    world_dig(58, 30, TILE_WATER)
+    world_dig(57, 29, TILE_WATER)
+    world_dig(57, 30, TILE_WATER)
+    world_dig(56, 29, TILE_WATER)
+    world_dig(56, 30, TILE_WATER)
+    world_dig(55, 29, TILE_WATER)
+    world_dig(55, 30, TILE_WATER)
+    world_dig(54, 29, TILE_WATER)
+    world_dig(54, 30, TILE_WATER)
+    world_dig(53, 29, TILE_WATER)
+    world_dig(53, 30, TILE_WATER)
+    world_dig(52, 29, TILE_WATER)
+    world_dig(52, 30, TILE_WATER)
+    world_dig(52, 31, TILE_WATER)
+    world_dig(53, 31, TILE_WATER)
+    world_dig(51, 31, TILE_WATER)
+    world_dig(52, 32, TILE_WATER)
+    world_dig(53, 32, TILE_WATER)
+    world_dig(52, 33, TILE_WATER)
+    world_dig(54, 31, TILE_WATER)
+    world_dig(55, 31, TILE_WATER)
+    world_dig(56, 31, TILE_WATER)
+    world_dig(59, 33, TILE_WATER)
+    world_dig(60, 33, TILE_WATER)
+    world_dig(61, 32, TILE_WATER)
+    world_dig(61, 33, TILE_WATER)
+    world_dig(62, 32, TILE_WATER)
+    world_dig(62, 33, TILE_WATER)
+    world_dig(61, 34, TILE_WATER)
+    world_dig(62, 34, TILE_WATER)
+    world_dig(61, 35, TILE_WATER)
+    world_dig(62, 35, TILE_WATER)
+    world_dig(61, 36, TILE_WATER)
+    world_dig(62, 36, TILE_WATER)
+    world_dig(61, 37, TILE_WATER)
+    world_dig(62, 37, TILE_WATER)
+    world_dig(60, 36, TILE_WATER)
+    world_dig(60, 37, TILE_WATER)
+    world_dig(60, 35, TILE_WATER)
+    world_dig(59, 35, TILE_WATER)
+    world_dig(59, 36, TILE_WATER)
+    world_dig(58, 35, TILE_WATER)
+    world_dig(58, 36, TILE_WATER)
+    world_dig(58, 34, TILE_WATER)
+    world_dig(59, 34, TILE_WATER)
+    world_dig(60, 34, TILE_WATER)
+    world_dig(58, 37, TILE_WATER)
+    world_dig(59, 37, TILE_WATER)
+    world_dig(57, 36, TILE_WATER)
+    world_dig(57, 37, TILE_WATER)
+    world_dig(57, 38, TILE_WATER)
+    world_dig(58, 38, TILE_WATER)
+    world_dig(56, 37, TILE_WATER)
+    world_dig(56, 38, TILE_WATER)
+    world_dig(55, 37, TILE_WATER)
+    world_dig(55, 38, TILE_WATER)
+    world_dig(55, 36, TILE_WATER)
+    world_dig(56, 36, TILE_WATER)
+    world_dig(54, 36, TILE_WATER)
+    world_dig(54, 37, TILE_WATER)
+    world_dig(55, 35, TILE_WATER)
+    world_dig(56, 35, TILE_WATER)
+    world_dig(56, 34, TILE_WATER)
+    world_dig(57, 34, TILE_WATER)
+    world_dig(57, 35, TILE_WATER)
+    world_dig(53, 36, TILE_WATER)
+    world_dig(53, 37, TILE_WATER)
+    world_dig(52, 37, TILE_WATER)
+    world_dig(52, 38, TILE_WATER)
+    world_dig(53, 38, TILE_WATER)
+    world_dig(51, 37, TILE_WATER)
+    world_dig(51, 38, TILE_WATER)
+    world_dig(50, 37, TILE_WATER)
+    world_dig(50, 38, TILE_WATER)
+    world_dig(49, 37, TILE_WATER)
+    world_dig(49, 38, TILE_WATER)
+    world_dig(48, 37, TILE_WATER)
+    world_dig(48, 38, TILE_WATER)
+    world_dig(48, 36, TILE_WATER)
+    world_dig(49, 36, TILE_WATER)
+    world_dig(49, 35, TILE_WATER)
+    world_dig(49, 34, TILE_WATER)
+    world_dig(50, 35, TILE_WATER)
+    world_dig(50, 36, TILE_WATER)
+    world_dig(50, 39, TILE_WATER)
+    world_dig(51, 39, TILE_WATER)
+    world_dig(49, 39, TILE_WATER)
+    world_dig(49, 40, TILE_WATER)
+    world_dig(50, 40, TILE_WATER)
+    world_dig(49, 41, TILE_WATER)
+    world_dig(50, 41, TILE_WATER)
+    world_dig(49, 42, TILE_WATER)
+    world_dig(50, 42, TILE_WATER)
+    world_dig(51, 41, TILE_WATER)
+    world_dig(51, 42, TILE_WATER)
+    world_dig(52, 41, TILE_WATER)
+    world_dig(52, 42, TILE_WATER)
+    world_dig(53, 41, TILE_WATER)
+    world_dig(53, 42, TILE_WATER)
+    world_dig(54, 41, TILE_WATER)
+    world_dig(54, 42, TILE_WATER)
+    world_dig(53, 40, TILE_WATER)
+    world_dig(54, 40, TILE_WATER)
+    world_dig(55, 40, TILE_WATER)
+    world_dig(55, 41, TILE_WATER)
+    world_dig(56, 40, TILE_WATER)
+    world_dig(56, 41, TILE_WATER)
+    world_dig(55, 42, TILE_WATER)
+    world_dig(56, 42, TILE_WATER)
+    world_dig(57, 41, TILE_WATER)
+    world_dig(57, 42, TILE_WATER)
+    world_dig(58, 41, TILE_WATER)
+    world_dig(58, 42, TILE_WATER)
+    world_dig(57, 43, TILE_WATER)
+    world_dig(58, 43, TILE_WATER)
+    world_dig(57, 44, TILE_WATER)
+    world_dig(58, 44, TILE_WATER)
+    world_dig(56, 44, TILE_WATER)
+    world_dig(50, 44, TILE_WATER)
+    world_dig(51, 44, TILE_WATER)
+    world_dig(49, 44, TILE_WATER)
+    world_dig(52, 44, TILE_WATER)
+    world_dig(53, 44, TILE_WATER)
+    world_dig(54, 44, TILE_WATER)
+    world_dig(55, 44, TILE_WATER)
+    world_dig(59, 44, TILE_WATER)
+    world_dig(60, 44, TILE_WATER)
+    world_dig(61, 44, TILE_WATER)
+    world_dig(51, 43, TILE_WATER)
+    world_dig(52, 43, TILE_WATER)
+    world_dig(53, 43, TILE_WATER)
+    world_dig(54, 43, TILE_WATER)
+    world_dig(55, 43, TILE_WATER)
+    world_dig(56, 43, TILE_WATER)
+    world_dig(59, 42, TILE_WATER)
+    world_dig(59, 43, TILE_WATER)
+    world_dig(60, 42, TILE_WATER)
+    world_dig(60, 43, TILE_WATER)
+    world_dig(49, 43, TILE_WATER)
+    world_dig(50, 43, TILE_WATER)
+    world_dig(61, 43, TILE_WATER)
+    world_dig(62, 43, TILE_WATER)
+    world_dig(62, 44, TILE_WATER)
+    world_dig(60, 41, TILE_WATER)
+    world_dig(61, 41, TILE_WATER)
+    world_dig(61, 42, TILE_WATER)
+    world_dig(62, 41, TILE_WATER)
+    world_dig(62, 42, TILE_WATER)
+    world_dig(59, 41, TILE_WATER)
+    world_dig(52, 40, TILE_WATER)
+    world_dig(57, 40, TILE_WATER)
+    world_dig(58, 40, TILE_WATER)
+    world_dig(59, 40, TILE_WATER)
+    world_dig(60, 40, TILE_WATER)
+    world_dig(61, 40, TILE_WATER)
+    world_dig(51, 40, TILE_WATER)
+    world_dig(52, 39, TILE_WATER)
+    world_dig(53, 39, TILE_WATER)
+    world_dig(54, 38, TILE_WATER)
+    world_dig(54, 39, TILE_WATER)
+    world_dig(55, 39, TILE_WATER)
+    world_dig(56, 39, TILE_WATER)
+    world_dig(57, 39, TILE_WATER)
+    world_dig(59, 38, TILE_WATER)
+    world_dig(58, 39, TILE_WATER)
+    world_dig(59, 39, TILE_WATER)
+    world_dig(60, 38, TILE_WATER)
+    world_dig(60, 39, TILE_WATER)
+    world_dig(61, 38, TILE_WATER)
+    world_dig(61, 39, TILE_WATER)
+    world_dig(62, 38, TILE_WATER)
+    world_dig(62, 39, TILE_WATER)
+    world_dig(62, 40, TILE_WATER)
+    world_dig(44, 26, TILE_WATER)
+    world_dig(44, 27, TILE_WATER)
+    world_dig(43, 26, TILE_WATER)
+    world_dig(43, 27, TILE_WATER)
+    world_dig(42, 26, TILE_WATER)
+    world_dig(42, 27, TILE_WATER)
+    world_dig(41, 26, TILE_WATER)
+    world_dig(41, 27, TILE_WATER)
+    world_dig(40, 26, TILE_WATER)
+    world_dig(40, 27, TILE_WATER)
+    world_dig(40, 28, TILE_WATER)
+    world_dig(41, 28, TILE_WATER)
+    world_dig(38, 27, TILE_WATER)
+    world_dig(39, 27, TILE_WATER)
+    world_dig(38, 28, TILE_WATER)
+    world_dig(39, 28, TILE_WATER)
+    world_dig(37, 27, TILE_WATER)
+    world_dig(37, 28, TILE_WATER)
+    world_dig(36, 27, TILE_WATER)
+    world_dig(36, 28, TILE_WATER)
+    world_dig(34, 27, TILE_WATER)
+    world_dig(35, 27, TILE_WATER)
+    world_dig(34, 28, TILE_WATER)
+    world_dig(35, 28, TILE_WATER)
+    world_dig(33, 27, TILE_WATER)
+    world_dig(33, 28, TILE_WATER)
+    world_dig(31, 27, TILE_WATER)
+    world_dig(32, 27, TILE_WATER)
+    world_dig(31, 28, TILE_WATER)
+    world_dig(32, 28, TILE_WATER)
+    world_dig(30, 27, TILE_WATER)
+    world_dig(30, 28, TILE_WATER)
+    world_dig(28, 27, TILE_WATER)
+    world_dig(29, 27, TILE_WATER)
+    world_dig(28, 28, TILE_WATER)
+    world_dig(29, 28, TILE_WATER)
+    world_dig(26, 27, TILE_WATER)
+    world_dig(27, 27, TILE_WATER)
+    world_dig(26, 28, TILE_WATER)
+    world_dig(27, 28, TILE_WATER)
+    world_dig(25, 27, TILE_WATER)
+    world_dig(25, 28, TILE_WATER)
+    world_dig(23, 27, TILE_WATER)
+    world_dig(24, 27, TILE_WATER)
+    world_dig(23, 28, TILE_WATER)
+    world_dig(24, 28, TILE_WATER)
+    world_dig(22, 27, TILE_WATER)
+    world_dig(22, 28, TILE_WATER)
+    world_dig(20, 27, TILE_WATER)
+    world_dig(21, 27, TILE_WATER)
+    world_dig(20, 28, TILE_WATER)
+    world_dig(21, 28, TILE_WATER)
+    world_dig(19, 27, TILE_WATER)
+    world_dig(19, 28, TILE_WATER)
+    world_dig(18, 27, TILE_WATER)
+    world_dig(18, 28, TILE_WATER)
+    world_dig(17, 27, TILE_WATER)
+    world_dig(17, 28, TILE_WATER)
+    world_dig(16, 27, TILE_WATER)
+    world_dig(16, 28, TILE_WATER)
+    world_dig(15, 27, TILE_WATER)
+    world_dig(15, 28, TILE_WATER)
+    world_dig(14, 27, TILE_WATER)
+    world_dig(14, 28, TILE_WATER)
+    world_dig(13, 27, TILE_WATER)
+    world_dig(13, 28, TILE_WATER)
+    world_dig(12, 27, TILE_WATER)
+    world_dig(12, 28, TILE_WATER)
+    world_dig(11, 27, TILE_WATER)
+    world_dig(11, 28, TILE_WATER)
+    world_dig(10, 27, TILE_WATER)
+    world_dig(10, 28, TILE_WATER)
+    world_dig(9, 27, TILE_WATER)
+    world_dig(9, 28, TILE_WATER)
+    world_dig(8, 27, TILE_WATER)
+    world_dig(8, 28, TILE_WATER)
+    world_dig(7, 27, TILE_WATER)
+    world_dig(7, 28, TILE_WATER)
+    world_dig(6, 27, TILE_WATER)
+    world_dig(6, 28, TILE_WATER)
+    world_dig(5, 27, TILE_WATER)
+    world_dig(5, 28, TILE_WATER)
+    world_dig(4, 27, TILE_WATER)
+    world_dig(4, 28, TILE_WATER)
+    world_dig(3, 27, TILE_WATER)
+    world_dig(3, 28, TILE_WATER)
+    world_dig(2, 27, TILE_WATER)
+    world_dig(2, 28, TILE_WATER)
+    world_dig(1, 27, TILE_WATER)
+    world_dig(1, 28, TILE_WATER)
+    world_dig(1, 26, TILE_WATER)
+    world_dig(2, 26, TILE_WATER)
+    world_dig(1, 25, TILE_WATER)
+    world_dig(2, 25, TILE_WATER)
+    world_dig(3, 25, TILE_WATER)
+    world_dig(3, 26, TILE_WATER)
+    world_dig(2, 24, TILE_WATER)
+    world_dig(3, 24, TILE_WATER)
+    world_dig(4, 24, TILE_WATER)
+    world_dig(4, 25, TILE_WATER)
+    world_dig(3, 23, TILE_WATER)
+    world_dig(4, 23, TILE_WATER)
+    world_dig(5, 23, TILE_WATER)
+    world_dig(5, 24, TILE_WATER)
+    world_dig(4, 22, TILE_WATER)
+    world_dig(5, 22, TILE_WATER)
+    world_dig(3, 22, TILE_WATER)
+    world_dig(2, 22, TILE_WATER)
+    world_dig(2, 23, TILE_WATER)
+    world_dig(1, 22, TILE_WATER)
+    world_dig(1, 23, TILE_WATER)
+    world_dig(1, 21, TILE_WATER)
+    world_dig(2, 21, TILE_WATER)
+    world_dig(1, 20, TILE_WATER)
+    world_dig(2, 20, TILE_WATER)
+    world_dig(1, 19, TILE_WATER)
+    world_dig(2, 19, TILE_WATER)
+    world_dig(1, 18, TILE_WATER)
+    world_dig(2, 18, TILE_WATER)
+    world_dig(1, 17, TILE_WATER)
+    world_dig(2, 17, TILE_WATER)
+    world_dig(3, 17, TILE_WATER)
+    world_dig(3, 18, TILE_WATER)
+    world_dig(1, 16, TILE_WATER)
+    world_dig(2, 16, TILE_WATER)
+    world_dig(3, 16, TILE_WATER)
+    world_dig(4, 16, TILE_WATER)
+    world_dig(4, 17, TILE_WATER)
+    world_dig(5, 16, TILE_WATER)
+    world_dig(5, 17, TILE_WATER)
+    world_dig(6, 16, TILE_WATER)
+    world_dig(6, 17, TILE_WATER)
+    world_dig(7, 16, TILE_WATER)
+    world_dig(7, 17, TILE_WATER)
+    world_dig(8, 16, TILE_WATER)
+    world_dig(8, 17, TILE_WATER)
+    world_dig(9, 16, TILE_WATER)
+    world_dig(9, 17, TILE_WATER)
+    world_dig(10, 16, TILE_WATER)
+    world_dig(10, 17, TILE_WATER)
+    world_dig(11, 16, TILE_WATER)
+    world_dig(11, 17, TILE_WATER)
+    world_dig(12, 16, TILE_WATER)
+    world_dig(12, 17, TILE_WATER)
+    world_dig(13, 16, TILE_WATER)
+    world_dig(13, 17, TILE_WATER)
+    world_dig(14, 16, TILE_WATER)
+    world_dig(14, 17, TILE_WATER)
+    world_dig(15, 16, TILE_WATER)
+    world_dig(15, 17, TILE_WATER)
+    world_dig(16, 16, TILE_WATER)
+    world_dig(16, 17, TILE_WATER)
+    world_dig(17, 16, TILE_WATER)
+    world_dig(17, 17, TILE_WATER)
+    world_dig(18, 16, TILE_WATER)
+    world_dig(18, 17, TILE_WATER)
+    world_dig(19, 16, TILE_WATER)
+    world_dig(19, 17, TILE_WATER)
+    world_dig(20, 16, TILE_WATER)
+    world_dig(20, 17, TILE_WATER)
+    world_dig(21, 16, TILE_WATER)
+    world_dig(21, 17, TILE_WATER)
+    world_dig(22, 16, TILE_WATER)
+    world_dig(22, 17, TILE_WATER)
+    world_dig(23, 16, TILE_WATER)
+    world_dig(23, 17, TILE_WATER)
+    world_dig(24, 16, TILE_WATER)
+    world_dig(24, 17, TILE_WATER)
+    world_dig(25, 16, TILE_WATER)
+    world_dig(25, 17, TILE_WATER)
+    world_dig(26, 16, TILE_WATER)
+    world_dig(26, 17, TILE_WATER)
+    world_dig(27, 16, TILE_WATER)
+    world_dig(27, 17, TILE_WATER)
+    world_dig(28, 16, TILE_WATER)
+    world_dig(28, 17, TILE_WATER)
+    world_dig(29, 16, TILE_WATER)
+    world_dig(29, 17, TILE_WATER)
+    world_dig(30, 16, TILE_WATER)
+    world_dig(30, 17, TILE_WATER)
+    world_dig(31, 16, TILE_WATER)
+    world_dig(31, 17, TILE_WATER)
+    world_dig(32, 16, TILE_WATER)
+    world_dig(32, 17, TILE_WATER)
+    world_dig(33, 16, TILE_WATER)
+    world_dig(33, 17, TILE_WATER)
+    world_dig(34, 16, TILE_WATER)
+    world_dig(34, 17, TILE_WATER)
+    world_dig(35, 16, TILE_WATER)
+    world_dig(35, 17, TILE_WATER)
+    world_dig(36, 16, TILE_WATER)
+    world_dig(36, 17, TILE_WATER)
+    world_dig(37, 16, TILE_WATER)
+    world_dig(37, 17, TILE_WATER)
+    world_dig(38, 16, TILE_WATER)
+    world_dig(38, 17, TILE_WATER)
+    world_dig(39, 16, TILE_WATER)
+    world_dig(39, 17, TILE_WATER)
+    world_dig(40, 16, TILE_WATER)
+    world_dig(40, 17, TILE_WATER)
+    world_dig(41, 16, TILE_WATER)
+    world_dig(41, 17, TILE_WATER)
+    world_dig(42, 16, TILE_WATER)
+    world_dig(42, 17, TILE_WATER)
+    world_dig(43, 16, TILE_WATER)
+    world_dig(43, 17, TILE_WATER)
+    world_dig(44, 16, TILE_WATER)
+    world_dig(44, 17, TILE_WATER)
+    world_dig(43, 18, TILE_WATER)
+    world_dig(44, 18, TILE_WATER)
+    world_dig(43, 19, TILE_WATER)
+    world_dig(44, 19, TILE_WATER)
+    world_dig(44, 20, TILE_WATER)
+    world_dig(44, 21, TILE_WATER)
+    world_dig(44, 22, TILE_WATER)
+    world_dig(44, 23, TILE_WATER)
+    world_dig(44, 24, TILE_WATER)
+    world_dig(44, 25, TILE_WATER)
+    world_dig(43, 24, TILE_WATER)
+    world_dig(43, 25, TILE_WATER)
+    world_dig(42, 25, TILE_WATER)
+    world_dig(41, 25, TILE_WATER)
+    world_dig(40, 25, TILE_WATER)
+    world_dig(39, 25, TILE_WATER)
+    world_dig(39, 26, TILE_WATER)
+    world_dig(38, 25, TILE_WATER)
+    world_dig(38, 26, TILE_WATER)
+    world_dig(37, 25, TILE_WATER)
+    world_dig(37, 26, TILE_WATER)
+    world_dig(36, 25, TILE_WATER)
+    world_dig(36, 26, TILE_WATER)
+    world_dig(35, 26, TILE_WATER)
+    world_dig(34, 26, TILE_WATER)
+    world_dig(33, 26, TILE_WATER)
+    world_dig(32, 26, TILE_WATER)
+    world_dig(31, 26, TILE_WATER)
+    world_dig(30, 25, TILE_WATER)
+    world_dig(31, 25, TILE_WATER)
+    world_dig(30, 26, TILE_WATER)
+    world_dig(29, 25, TILE_WATER)
+    world_dig(29, 26, TILE_WATER)
+    world_dig(28, 25, TILE_WATER)
+    world_dig(28, 26, TILE_WATER)
+    world_dig(27, 25, TILE_WATER)
+    world_dig(27, 26, TILE_WATER)
+    world_dig(26, 25, TILE_WATER)
+    world_dig(26, 26, TILE_WATER)
+    world_dig(25, 25, TILE_WATER)
+    world_dig(25, 26, TILE_WATER)
+    world_dig(24, 25, TILE_WATER)
+    world_dig(24, 26, TILE_WATER)
+    world_dig(23, 25, TILE_WATER)
+    world_dig(23, 26, TILE_WATER)
+    world_dig(22, 25, TILE_WATER)
+    world_dig(22, 26, TILE_WATER)
+    world_dig(21, 25, TILE_WATER)
+    world_dig(21, 26, TILE_WATER)
+    world_dig(20, 25, TILE_WATER)
+    world_dig(20, 26, TILE_WATER)
+    world_dig(19, 25, TILE_WATER)
+    world_dig(19, 26, TILE_WATER)
+    world_dig(18, 25, TILE_WATER)
+    world_dig(18, 26, TILE_WATER)
+    world_dig(17, 25, TILE_WATER)
+    world_dig(17, 26, TILE_WATER)
+    world_dig(16, 25, TILE_WATER)
+    world_dig(16, 26, TILE_WATER)
+    world_dig(15, 25, TILE_WATER)
+    world_dig(15, 26, TILE_WATER)
+    world_dig(14, 25, TILE_WATER)
+    world_dig(14, 26, TILE_WATER)
+    world_dig(12, 25, TILE_WATER)
+    world_dig(13, 25, TILE_WATER)
+    world_dig(12, 26, TILE_WATER)
+    world_dig(13, 26, TILE_WATER)
+    world_dig(11, 25, TILE_WATER)
+    world_dig(11, 26, TILE_WATER)
+    world_dig(10, 25, TILE_WATER)
+    world_dig(10, 26, TILE_WATER)
+    world_dig(9, 24, TILE_WATER)
+    world_dig(10, 24, TILE_WATER)
+    world_dig(9, 25, TILE_WATER)
+    world_dig(7, 24, TILE_WATER)
+    world_dig(8, 24, TILE_WATER)
+    world_dig(7, 25, TILE_WATER)
+    world_dig(8, 25, TILE_WATER)
+    world_dig(6, 24, TILE_WATER)
+    world_dig(6, 25, TILE_WATER)
+    world_dig(5, 25, TILE_WATER)
+    world_dig(1, 24, TILE_WATER)
+    world_dig(6, 22, TILE_WATER)
+    world_dig(6, 23, TILE_WATER)
+    world_dig(6, 21, TILE_WATER)
+    world_dig(7, 21, TILE_WATER)
+    world_dig(7, 22, TILE_WATER)
+    world_dig(8, 21, TILE_WATER)
+    world_dig(9, 21, TILE_WATER)
+    world_dig(8, 22, TILE_WATER)
+    world_dig(9, 22, TILE_WATER)
+    world_dig(10, 22, TILE_WATER)
+    world_dig(11, 22, TILE_WATER)
+    world_dig(10, 23, TILE_WATER)
+    world_dig(11, 23, TILE_WATER)
+    world_dig(12, 22, TILE_WATER)
+    world_dig(12, 23, TILE_WATER)
+    world_dig(11, 24, TILE_WATER)
+    world_dig(12, 24, TILE_WATER)
+    world_dig(13, 24, TILE_WATER)
+    world_dig(9, 26, TILE_WATER)
+    world_dig(8, 26, TILE_WATER)
+    world_dig(6, 26, TILE_WATER)
+    world_dig(7, 26, TILE_WATER)
+    world_dig(3, 21, TILE_WATER)
+    world_dig(4, 21, TILE_WATER)
+    world_dig(5, 21, TILE_WATER)
+    world_dig(10, 21, TILE_WATER)
+    world_dig(11, 21, TILE_WATER)
+    world_dig(7, 23, TILE_WATER)
+    world_dig(8, 23, TILE_WATER)
+    world_dig(3, 20, TILE_WATER)
+    world_dig(4, 20, TILE_WATER)
+    world_dig(5, 20, TILE_WATER)
+    world_dig(6, 20, TILE_WATER)
+    world_dig(7, 20, TILE_WATER)
+    world_dig(3, 19, TILE_WATER)
+    world_dig(4, 19, TILE_WATER)
+    world_dig(4, 18, TILE_WATER)
+    world_dig(5, 18, TILE_WATER)
+    world_dig(5, 19, TILE_WATER)
+    world_dig(6, 18, TILE_WATER)
+    world_dig(6, 19, TILE_WATER)
+    world_dig(7, 18, TILE_WATER)
+    world_dig(7, 19, TILE_WATER)
+    world_dig(8, 18, TILE_WATER)
+    world_dig(9, 18, TILE_WATER)
+    world_dig(8, 19, TILE_WATER)
+    world_dig(9, 19, TILE_WATER)
+    world_dig(10, 18, TILE_WATER)
+    world_dig(11, 18, TILE_WATER)
+    world_dig(10, 19, TILE_WATER)
+    world_dig(11, 19, TILE_WATER)
+    world_dig(12, 19, TILE_WATER)
+    world_dig(13, 19, TILE_WATER)
+    world_dig(12, 20, TILE_WATER)
+    world_dig(13, 20, TILE_WATER)
+    world_dig(14, 19, TILE_WATER)
+    world_dig(15, 19, TILE_WATER)
+    world_dig(14, 20, TILE_WATER)
+    world_dig(15, 20, TILE_WATER)
+    world_dig(16, 19, TILE_WATER)
+    world_dig(16, 20, TILE_WATER)
+    world_dig(17, 19, TILE_WATER)
+    world_dig(17, 20, TILE_WATER)
+    world_dig(15, 21, TILE_WATER)
+    world_dig(16, 21, TILE_WATER)
+    world_dig(14, 21, TILE_WATER)
+    world_dig(12, 21, TILE_WATER)
+    world_dig(13, 21, TILE_WATER)
+    world_dig(10, 20, TILE_WATER)
+    world_dig(11, 20, TILE_WATER)
+    world_dig(9, 20, TILE_WATER)
+    world_dig(8, 20, TILE_WATER)
+    world_dig(13, 18, TILE_WATER)
+    world_dig(14, 18, TILE_WATER)
+    world_dig(15, 18, TILE_WATER)
+    world_dig(16, 18, TILE_WATER)
+    world_dig(17, 18, TILE_WATER)
+    world_dig(18, 18, TILE_WATER)
+    world_dig(18, 19, TILE_WATER)
+    world_dig(19, 18, TILE_WATER)
+    world_dig(20, 18, TILE_WATER)
+    world_dig(19, 19, TILE_WATER)
+    world_dig(20, 19, TILE_WATER)
+    world_dig(21, 18, TILE_WATER)
+    world_dig(21, 19, TILE_WATER)
+    world_dig(22, 18, TILE_WATER)
+    world_dig(22, 19, TILE_WATER)
+    world_dig(20, 20, TILE_WATER)
+    world_dig(21, 20, TILE_WATER)
+    world_dig(19, 20, TILE_WATER)
+    world_dig(18, 20, TILE_WATER)
+    world_dig(17, 21, TILE_WATER)
+    world_dig(18, 21, TILE_WATER)
+    world_dig(19, 21, TILE_WATER)
+    world_dig(20, 21, TILE_WATER)
+    world_dig(22, 20, TILE_WATER)
+    world_dig(21, 21, TILE_WATER)
+    world_dig(22, 21, TILE_WATER)
+    world_dig(23, 20, TILE_WATER)
+    world_dig(24, 20, TILE_WATER)
+    world_dig(23, 21, TILE_WATER)
+    world_dig(24, 21, TILE_WATER)
+    world_dig(25, 20, TILE_WATER)
+    world_dig(26, 20, TILE_WATER)
+    world_dig(25, 21, TILE_WATER)
+    world_dig(26, 21, TILE_WATER)
+    world_dig(24, 22, TILE_WATER)
+    world_dig(25, 22, TILE_WATER)
+    world_dig(23, 22, TILE_WATER)
+    world_dig(23, 23, TILE_WATER)
+    world_dig(24, 23, TILE_WATER)
+    world_dig(21, 22, TILE_WATER)
+    world_dig(22, 22, TILE_WATER)
+    world_dig(21, 23, TILE_WATER)
+    world_dig(22, 23, TILE_WATER)
+    world_dig(19, 23, TILE_WATER)
+    world_dig(20, 23, TILE_WATER)
+    world_dig(19, 24, TILE_WATER)
+    world_dig(20, 24, TILE_WATER)
+    world_dig(16, 23, TILE_WATER)
+    world_dig(17, 23, TILE_WATER)
+    world_dig(16, 24, TILE_WATER)
+    world_dig(17, 24, TILE_WATER)
+    world_dig(14, 23, TILE_WATER)
+    world_dig(15, 23, TILE_WATER)
+    world_dig(14, 24, TILE_WATER)
+    world_dig(15, 24, TILE_WATER)
+    world_dig(13, 23, TILE_WATER)
+    world_dig(13, 22, TILE_WATER)
+    world_dig(14, 22, TILE_WATER)
+    world_dig(15, 22, TILE_WATER)
+    world_dig(16, 22, TILE_WATER)
+    world_dig(18, 22, TILE_WATER)
+    world_dig(19, 22, TILE_WATER)
+    world_dig(18, 23, TILE_WATER)
+    world_dig(26, 22, TILE_WATER)
+    world_dig(27, 21, TILE_WATER)
+    world_dig(28, 21, TILE_WATER)
+    world_dig(27, 22, TILE_WATER)
+    world_dig(28, 22, TILE_WATER)
+    world_dig(29, 21, TILE_WATER)
+    world_dig(29, 22, TILE_WATER)
+    world_dig(30, 22, TILE_WATER)
+    world_dig(29, 23, TILE_WATER)
+    world_dig(30, 23, TILE_WATER)
+    world_dig(28, 23, TILE_WATER)
+    world_dig(27, 23, TILE_WATER)
+    world_dig(27, 24, TILE_WATER)
+    world_dig(28, 24, TILE_WATER)
+    world_dig(26, 24, TILE_WATER)
+    world_dig(24, 24, TILE_WATER)
+    world_dig(25, 24, TILE_WATER)
+    world_dig(21, 24, TILE_WATER)
+    world_dig(22, 24, TILE_WATER)
+    world_dig(18, 24, TILE_WATER)
+    world_dig(25, 23, TILE_WATER)
+    world_dig(26, 23, TILE_WATER)
+    world_dig(32, 22, TILE_WATER)
+    world_dig(33, 22, TILE_WATER)
+    world_dig(33, 23, TILE_WATER)
+    world_dig(34, 22, TILE_WATER)
+    world_dig(35, 22, TILE_WATER)
+    world_dig(34, 23, TILE_WATER)
+    world_dig(35, 23, TILE_WATER)
+    world_dig(36, 22, TILE_WATER)
+    world_dig(37, 22, TILE_WATER)
+    world_dig(36, 23, TILE_WATER)
+    world_dig(37, 23, TILE_WATER)
+    world_dig(34, 24, TILE_WATER)
+    world_dig(35, 24, TILE_WATER)
+    world_dig(32, 24, TILE_WATER)
+    world_dig(33, 24, TILE_WATER)
+    world_dig(30, 24, TILE_WATER)
+    world_dig(31, 24, TILE_WATER)
+    world_dig(29, 24, TILE_WATER)
+    world_dig(31, 23, TILE_WATER)
+    world_dig(36, 24, TILE_WATER)
+    world_dig(37, 24, TILE_WATER)
+    world_dig(38, 23, TILE_WATER)
+    world_dig(38, 24, TILE_WATER)
+    world_dig(39, 23, TILE_WATER)
+    world_dig(39, 24, TILE_WATER)
+    world_dig(32, 25, TILE_WATER)
+    world_dig(33, 25, TILE_WATER)
+    world_dig(35, 25, TILE_WATER)
+    world_dig(34, 25, TILE_WATER)
+    world_dig(38, 22, TILE_WATER)
+    world_dig(39, 22, TILE_WATER)
+    world_dig(40, 22, TILE_WATER)
+    world_dig(41, 22, TILE_WATER)
+    world_dig(40, 23, TILE_WATER)
+    world_dig(41, 23, TILE_WATER)
+    world_dig(40, 21, TILE_WATER)
+    world_dig(41, 21, TILE_WATER)
+    world_dig(42, 21, TILE_WATER)
+    world_dig(42, 22, TILE_WATER)
+    world_dig(42, 20, TILE_WATER)
+    world_dig(43, 20, TILE_WATER)
+    world_dig(43, 21, TILE_WATER)
+    world_dig(43, 22, TILE_WATER)
+    world_dig(42, 23, TILE_WATER)
+    world_dig(40, 24, TILE_WATER)
+    world_dig(41, 24, TILE_WATER)
+    world_dig(41, 20, TILE_WATER)
+    world_dig(38, 21, TILE_WATER)
+    world_dig(39, 21, TILE_WATER)
+    world_dig(37, 21, TILE_WATER)
+    world_dig(36, 21, TILE_WATER)
+    world_dig(35, 21, TILE_WATER)
+    world_dig(35, 20, TILE_WATER)
+    world_dig(36, 20, TILE_WATER)
+    world_dig(36, 19, TILE_WATER)
+    world_dig(37, 19, TILE_WATER)
+    world_dig(37, 20, TILE_WATER)
+    world_dig(37, 18, TILE_WATER)
+    world_dig(38, 18, TILE_WATER)
+    world_dig(38, 19, TILE_WATER)
+    world_dig(39, 18, TILE_WATER)
+    world_dig(40, 18, TILE_WATER)
+    world_dig(39, 19, TILE_WATER)
+    world_dig(40, 19, TILE_WATER)
+    world_dig(41, 18, TILE_WATER)
+    world_dig(42, 18, TILE_WATER)
+    world_dig(41, 19, TILE_WATER)
+    world_dig(42, 19, TILE_WATER)
+    world_dig(40, 20, TILE_WATER)
+    world_dig(39, 20, TILE_WATER)
+    world_dig(34, 21, TILE_WATER)
+    world_dig(33, 20, TILE_WATER)
+    world_dig(34, 20, TILE_WATER)
+    world_dig(33, 21, TILE_WATER)
+    world_dig(32, 20, TILE_WATER)
+    world_dig(32, 21, TILE_WATER)
+    world_dig(32, 19, TILE_WATER)
+    world_dig(33, 19, TILE_WATER)
+    world_dig(34, 19, TILE_WATER)
+    world_dig(34, 18, TILE_WATER)
+    world_dig(35, 18, TILE_WATER)
+    world_dig(35, 19, TILE_WATER)
+    world_dig(36, 18, TILE_WATER)
+    world_dig(38, 20, TILE_WATER)
+    world_dig(31, 21, TILE_WATER)
+    world_dig(31, 22, TILE_WATER)
+    world_dig(30, 21, TILE_WATER)
+    world_dig(27, 20, TILE_WATER)
+    world_dig(28, 20, TILE_WATER)
+    world_dig(27, 19, TILE_WATER)
+    world_dig(28, 19, TILE_WATER)
+    world_dig(29, 19, TILE_WATER)
+    world_dig(29, 20, TILE_WATER)
+    world_dig(30, 19, TILE_WATER)
+    world_dig(30, 20, TILE_WATER)
+    world_dig(31, 18, TILE_WATER)
+    world_dig(32, 18, TILE_WATER)
+    world_dig(31, 19, TILE_WATER)
+    world_dig(33, 18, TILE_WATER)
+    world_dig(31, 20, TILE_WATER)
+    world_dig(25, 19, TILE_WATER)
+    world_dig(26, 19, TILE_WATER)
+    world_dig(27, 18, TILE_WATER)
+    world_dig(28, 18, TILE_WATER)
+    world_dig(29, 18, TILE_WATER)
+    world_dig(30, 18, TILE_WATER)
+    world_dig(24, 19, TILE_WATER)
+    world_dig(23, 19, TILE_WATER)
+    world_dig(24, 18, TILE_WATER)
+    world_dig(25, 18, TILE_WATER)
+    world_dig(26, 18, TILE_WATER)
+    world_dig(23, 18, TILE_WATER)
+    world_dig(12, 18, TILE_WATER)
+    world_dig(9, 23, TILE_WATER)
+    world_dig(5, 26, TILE_WATER)
+    world_dig(4, 26, TILE_WATER)
+    world_dig(20, 22, TILE_WATER)
+    world_dig(17, 22, TILE_WATER)
+    world_dig(23, 24, TILE_WATER)
+    world_dig(42, 24, TILE_WATER)
+    world_dig(43, 23, TILE_WATER)
+    world_dig(42, 28, TILE_WATER)
+    world_dig(43, 28, TILE_WATER)
+    world_dig(44, 28, TILE_WATER)
+
+    world_dig(1, 1, TILE_PLAIN)
+    world_dig(1, 2, TILE_PLAIN)
+    world_dig(1, 3, TILE_PLAIN)
+    world_dig(1, 6, TILE_PLAIN)
+    world_dig(1, 7, TILE_PLAIN)
+    world_dig(1, 8, TILE_PLAIN)
+    world_dig(1, 5, TILE_PLAIN)
+    world_dig(1, 4, TILE_PLAIN)
+    world_dig(1, 9, TILE_PLAIN)
+    world_dig(1, 10, TILE_PLAIN)
+    world_dig(1, 11, TILE_PLAIN)
+    world_dig(1, 12, TILE_PLAIN)
+    world_dig(1, 13, TILE_PLAIN)
+    world_dig(2, 13, TILE_PLAIN)
+    world_dig(3, 13, TILE_PLAIN)
+    world_dig(3, 12, TILE_PLAIN)
+    world_dig(3, 11, TILE_PLAIN)
+    world_dig(3, 10, TILE_PLAIN)
+    world_dig(2, 9, TILE_PLAIN)
+    world_dig(2, 8, TILE_PLAIN)
+    world_dig(3, 8, TILE_PLAIN)
+    world_dig(3, 7, TILE_PLAIN)
+    world_dig(2, 7, TILE_PLAIN)
+    world_dig(2, 6, TILE_PLAIN)
+    world_dig(2, 5, TILE_PLAIN)
+    world_dig(2, 4, TILE_PLAIN)
+    world_dig(3, 4, TILE_PLAIN)
+    world_dig(4, 4, TILE_PLAIN)
+    world_dig(4, 5, TILE_PLAIN)
+    world_dig(5, 5, TILE_PLAIN)
+    world_dig(5, 6, TILE_PLAIN)
+    world_dig(6, 6, TILE_PLAIN)
+    world_dig(6, 5, TILE_PLAIN)
+    world_dig(7, 5, TILE_PLAIN)
+    world_dig(7, 4, TILE_PLAIN)
+    world_dig(6, 4, TILE_PLAIN)
+    world_dig(5, 4, TILE_PLAIN)
+    world_dig(4, 3, TILE_PLAIN)
+    world_dig(5, 3, TILE_PLAIN)
+    world_dig(8, 6, TILE_PLAIN)
+    world_dig(8, 7, TILE_PLAIN)
+    world_dig(8, 8, TILE_PLAIN)
+    world_dig(9, 8, TILE_PLAIN)
+    world_dig(7, 7, TILE_PLAIN)
+    world_dig(7, 6, TILE_PLAIN)
+    world_dig(9, 9, TILE_PLAIN)
+    world_dig(9, 10, TILE_PLAIN)
+    world_dig(8, 10, TILE_PLAIN)
+    world_dig(7, 10, TILE_PLAIN)
+    world_dig(7, 11, TILE_PLAIN)
+    world_dig(6, 11, TILE_PLAIN)
+    world_dig(5, 11, TILE_PLAIN)
+    world_dig(4, 11, TILE_PLAIN)
+    world_dig(4, 12, TILE_PLAIN)
+    world_dig(5, 12, TILE_PLAIN)
+    world_dig(6, 12, TILE_PLAIN)
+    world_dig(8, 11, TILE_PLAIN)
+    world_dig(10, 10, TILE_PLAIN)
+    world_dig(8, 5, TILE_PLAIN)
+    world_dig(9, 5, TILE_PLAIN)
+    world_dig(9, 6, TILE_PLAIN)
+    world_dig(8, 4, TILE_PLAIN)
+    world_dig(9, 4, TILE_PLAIN)
+    world_dig(10, 4, TILE_PLAIN)
+    world_dig(10, 5, TILE_PLAIN)
+    world_dig(9, 3, TILE_PLAIN)
+    world_dig(10, 3, TILE_PLAIN)
+    world_dig(11, 3, TILE_PLAIN)
+    world_dig(11, 4, TILE_PLAIN)
+    world_dig(12, 3, TILE_PLAIN)
+    world_dig(12, 4, TILE_PLAIN)
+    world_dig(13, 3, TILE_PLAIN)
+    world_dig(13, 4, TILE_PLAIN)
+    world_dig(14, 3, TILE_PLAIN)
+    world_dig(14, 4, TILE_PLAIN)
+    world_dig(15, 3, TILE_PLAIN)
+    world_dig(15, 4, TILE_PLAIN)
+    world_dig(16, 4, TILE_PLAIN)
+    world_dig(15, 5, TILE_PLAIN)
+    world_dig(16, 5, TILE_PLAIN)
+    world_dig(15, 6, TILE_PLAIN)
+    world_dig(16, 6, TILE_PLAIN)
+    world_dig(17, 5, TILE_PLAIN)
+    world_dig(17, 6, TILE_PLAIN)
+    world_dig(16, 7, TILE_PLAIN)
+    world_dig(17, 7, TILE_PLAIN)
+    world_dig(16, 8, TILE_PLAIN)
+    world_dig(17, 8, TILE_PLAIN)
+    world_dig(11, 11, TILE_PLAIN)
+    world_dig(12, 11, TILE_PLAIN)
+    world_dig(11, 12, TILE_PLAIN)
+    world_dig(12, 12, TILE_PLAIN)
+    world_dig(10, 11, TILE_PLAIN)
+    world_dig(10, 12, TILE_PLAIN)
+    world_dig(13, 12, TILE_PLAIN)
+    world_dig(12, 13, TILE_PLAIN)
+    world_dig(13, 13, TILE_PLAIN)
+    world_dig(14, 12, TILE_PLAIN)
+    world_dig(14, 13, TILE_PLAIN)
+    world_dig(13, 14, TILE_PLAIN)
+    world_dig(14, 14, TILE_PLAIN)
+    world_dig(15, 13, TILE_PLAIN)
+    world_dig(15, 14, TILE_PLAIN)
+    world_dig(16, 13, TILE_PLAIN)
+    world_dig(17, 13, TILE_PLAIN)
+    world_dig(16, 14, TILE_PLAIN)
+    world_dig(17, 14, TILE_PLAIN)
+    world_dig(16, 12, TILE_PLAIN)
+    world_dig(17, 12, TILE_PLAIN)
+    world_dig(18, 12, TILE_PLAIN)
+    world_dig(18, 13, TILE_PLAIN)
+    world_dig(17, 11, TILE_PLAIN)
+    world_dig(18, 11, TILE_PLAIN)
+    world_dig(17, 10, TILE_PLAIN)
+    world_dig(18, 10, TILE_PLAIN)
+    world_dig(19, 10, TILE_PLAIN)
+    world_dig(19, 11, TILE_PLAIN)
+    world_dig(18, 9, TILE_PLAIN)
+    world_dig(19, 9, TILE_PLAIN)
+    world_dig(20, 9, TILE_PLAIN)
+    world_dig(20, 10, TILE_PLAIN)
+    world_dig(21, 9, TILE_PLAIN)
+    world_dig(21, 10, TILE_PLAIN)
+    world_dig(17, 4, TILE_PLAIN)
+    world_dig(18, 4, TILE_PLAIN)
+    world_dig(18, 5, TILE_PLAIN)
+    world_dig(19, 4, TILE_PLAIN)
+    world_dig(19, 5, TILE_PLAIN)
+    world_dig(20, 4, TILE_PLAIN)
+    world_dig(20, 5, TILE_PLAIN)
+    world_dig(21, 4, TILE_PLAIN)
+    world_dig(21, 5, TILE_PLAIN)
+    world_dig(22, 4, TILE_PLAIN)
+    world_dig(22, 5, TILE_PLAIN)
+    world_dig(23, 4, TILE_PLAIN)
+    world_dig(23, 5, TILE_PLAIN)
+    world_dig(22, 3, TILE_PLAIN)
+    world_dig(23, 3, TILE_PLAIN)
+    world_dig(22, 2, TILE_PLAIN)
+    world_dig(23, 2, TILE_PLAIN)
+    world_dig(24, 2, TILE_PLAIN)
+    world_dig(24, 3, TILE_PLAIN)
+    world_dig(23, 1, TILE_PLAIN)
+    world_dig(24, 1, TILE_PLAIN)
+    world_dig(25, 1, TILE_PLAIN)
+    world_dig(26, 1, TILE_PLAIN)
+    world_dig(25, 2, TILE_PLAIN)
+    world_dig(26, 2, TILE_PLAIN)
+    world_dig(27, 2, TILE_PLAIN)
+    world_dig(26, 3, TILE_PLAIN)
+    world_dig(27, 3, TILE_PLAIN)
+    world_dig(28, 2, TILE_PLAIN)
+    world_dig(28, 3, TILE_PLAIN)
+    world_dig(27, 4, TILE_PLAIN)
+    world_dig(28, 4, TILE_PLAIN)
+    world_dig(29, 4, TILE_PLAIN)
+    world_dig(28, 5, TILE_PLAIN)
+    world_dig(29, 5, TILE_PLAIN)
+    world_dig(28, 6, TILE_PLAIN)
+    world_dig(29, 6, TILE_PLAIN)
+    world_dig(30, 5, TILE_PLAIN)
+    world_dig(30, 6, TILE_PLAIN)
+    world_dig(29, 7, TILE_PLAIN)
+    world_dig(30, 7, TILE_PLAIN)
+    world_dig(29, 8, TILE_PLAIN)
+    world_dig(30, 8, TILE_PLAIN)
+    world_dig(21, 11, TILE_PLAIN)
+    world_dig(22, 11, TILE_PLAIN)
+    world_dig(21, 12, TILE_PLAIN)
+    world_dig(22, 12, TILE_PLAIN)
+    world_dig(23, 11, TILE_PLAIN)
+    world_dig(23, 12, TILE_PLAIN)
+    world_dig(24, 11, TILE_PLAIN)
+    world_dig(24, 12, TILE_PLAIN)
+    world_dig(25, 11, TILE_PLAIN)
+    world_dig(25, 12, TILE_PLAIN)
+    world_dig(24, 13, TILE_PLAIN)
+    world_dig(25, 13, TILE_PLAIN)
+    world_dig(26, 12, TILE_PLAIN)
+    world_dig(26, 13, TILE_PLAIN)
+    world_dig(29, 12, TILE_PLAIN)
+    world_dig(30, 12, TILE_PLAIN)
+    world_dig(29, 13, TILE_PLAIN)
+    world_dig(30, 13, TILE_PLAIN)
+    world_dig(31, 12, TILE_PLAIN)
+    world_dig(31, 13, TILE_PLAIN)
+    world_dig(30, 11, TILE_PLAIN)
+    world_dig(31, 11, TILE_PLAIN)
+    world_dig(31, 10, TILE_PLAIN)
+    world_dig(32, 10, TILE_PLAIN)
+    world_dig(32, 11, TILE_PLAIN)
+    world_dig(31, 9, TILE_PLAIN)
+    world_dig(32, 9, TILE_PLAIN)
+    world_dig(33, 9, TILE_PLAIN)
+    world_dig(33, 10, TILE_PLAIN)
+    world_dig(32, 8, TILE_PLAIN)
+    world_dig(33, 8, TILE_PLAIN)
+    world_dig(32, 7, TILE_PLAIN)
+    world_dig(33, 7, TILE_PLAIN)
+    world_dig(31, 7, TILE_PLAIN)
+    world_dig(31, 8, TILE_PLAIN)
+    world_dig(31, 6, TILE_PLAIN)
+    world_dig(32, 6, TILE_PLAIN)
+    world_dig(33, 6, TILE_PLAIN)
+    world_dig(32, 5, TILE_PLAIN)
+    world_dig(33, 5, TILE_PLAIN)
+    world_dig(32, 4, TILE_PLAIN)
+    world_dig(33, 4, TILE_PLAIN)
+    world_dig(34, 4, TILE_PLAIN)
+    world_dig(34, 5, TILE_PLAIN)
+    world_dig(35, 4, TILE_PLAIN)
+    world_dig(35, 5, TILE_PLAIN)
+    world_dig(36, 4, TILE_PLAIN)
+    world_dig(36, 5, TILE_PLAIN)
+    world_dig(37, 4, TILE_PLAIN)
+    world_dig(37, 5, TILE_PLAIN)
+    world_dig(36, 6, TILE_PLAIN)
+    world_dig(37, 6, TILE_PLAIN)
+    world_dig(38, 5, TILE_PLAIN)
+    world_dig(38, 6, TILE_PLAIN)
+    world_dig(37, 7, TILE_PLAIN)
+    world_dig(38, 7, TILE_PLAIN)
+    world_dig(39, 6, TILE_PLAIN)
+    world_dig(39, 7, TILE_PLAIN)
+    world_dig(38, 8, TILE_PLAIN)
+    world_dig(39, 8, TILE_PLAIN)
+    world_dig(38, 9, TILE_PLAIN)
+    world_dig(39, 9, TILE_PLAIN)
+    world_dig(40, 8, TILE_PLAIN)
+    world_dig(40, 9, TILE_PLAIN)
+    world_dig(39, 10, TILE_PLAIN)
+    world_dig(40, 10, TILE_PLAIN)
+    world_dig(39, 11, TILE_PLAIN)
+    world_dig(40, 11, TILE_PLAIN)
+    world_dig(38, 14, TILE_PLAIN)
+    world_dig(39, 14, TILE_PLAIN)
+    world_dig(38, 15, TILE_PLAIN)
+    world_dig(39, 15, TILE_PLAIN)
+    world_dig(37, 13, TILE_PLAIN)
+    world_dig(38, 13, TILE_PLAIN)
+    world_dig(37, 14, TILE_PLAIN)
+    world_dig(36, 13, TILE_PLAIN)
+    world_dig(36, 14, TILE_PLAIN)
+    world_dig(36, 12, TILE_PLAIN)
+    world_dig(37, 12, TILE_PLAIN)
+    world_dig(35, 12, TILE_PLAIN)
+    world_dig(35, 13, TILE_PLAIN)
+    world_dig(35, 11, TILE_PLAIN)
+    world_dig(36, 11, TILE_PLAIN)
+    world_dig(34, 11, TILE_PLAIN)
+    world_dig(34, 12, TILE_PLAIN)
+    world_dig(34, 10, TILE_PLAIN)
+    world_dig(35, 10, TILE_PLAIN)
+    world_dig(40, 13, TILE_PLAIN)
+    world_dig(41, 13, TILE_PLAIN)
+    world_dig(40, 14, TILE_PLAIN)
+    world_dig(41, 14, TILE_PLAIN)
+    world_dig(42, 13, TILE_PLAIN)
+    world_dig(42, 14, TILE_PLAIN)
+    world_dig(43, 13, TILE_PLAIN)
+    world_dig(43, 14, TILE_PLAIN)
+    world_dig(42, 12, TILE_PLAIN)
+    world_dig(43, 12, TILE_PLAIN)
+    world_dig(42, 11, TILE_PLAIN)
+    world_dig(43, 11, TILE_PLAIN)
+    world_dig(39, 5, TILE_PLAIN)
+    world_dig(40, 5, TILE_PLAIN)
+    world_dig(40, 6, TILE_PLAIN)
+    world_dig(41, 5, TILE_PLAIN)
+    world_dig(41, 6, TILE_PLAIN)
+    world_dig(40, 4, TILE_PLAIN)
+    world_dig(41, 4, TILE_PLAIN)
+    world_dig(42, 4, TILE_PLAIN)
+    world_dig(42, 5, TILE_PLAIN)
+    world_dig(41, 3, TILE_PLAIN)
+    world_dig(42, 3, TILE_PLAIN)
+    world_dig(43, 3, TILE_PLAIN)
+    world_dig(43, 4, TILE_PLAIN)
+    world_dig(29, 11, TILE_PLAIN)
+    world_dig(28, 11, TILE_PLAIN)
+    world_dig(28, 10, TILE_PLAIN)
+    world_dig(28, 9, TILE_PLAIN)
+    world_dig(27, 9, TILE_PLAIN)
+    world_dig(26, 9, TILE_PLAIN)
+    world_dig(25, 9, TILE_PLAIN)
+    world_dig(25, 10, TILE_PLAIN)
+    world_dig(19, 8, TILE_PLAIN)
+    world_dig(19, 7, TILE_PLAIN)
+    world_dig(18, 7, TILE_PLAIN)
+    world_dig(2, 44, TILE_PLAIN)
+    world_dig(3, 44, TILE_PLAIN)
+    world_dig(2, 43, TILE_PLAIN)
+    world_dig(3, 43, TILE_PLAIN)
+    world_dig(4, 43, TILE_PLAIN)
+    world_dig(4, 44, TILE_PLAIN)
+    world_dig(4, 42, TILE_PLAIN)
+    world_dig(5, 42, TILE_PLAIN)
+    world_dig(5, 43, TILE_PLAIN)
+    world_dig(6, 42, TILE_PLAIN)
+    world_dig(6, 43, TILE_PLAIN)
+    world_dig(5, 41, TILE_PLAIN)
+    world_dig(6, 41, TILE_PLAIN)
+    world_dig(5, 40, TILE_PLAIN)
+    world_dig(6, 40, TILE_PLAIN)
+    world_dig(5, 39, TILE_PLAIN)
+    world_dig(6, 39, TILE_PLAIN)
+    world_dig(4, 39, TILE_PLAIN)
+    world_dig(4, 40, TILE_PLAIN)
+    world_dig(4, 38, TILE_PLAIN)
+    world_dig(5, 38, TILE_PLAIN)
+    world_dig(3, 37, TILE_PLAIN)
+    world_dig(4, 37, TILE_PLAIN)
+    world_dig(3, 38, TILE_PLAIN)
+    world_dig(3, 36, TILE_PLAIN)
+    world_dig(4, 36, TILE_PLAIN)
+    world_dig(2, 36, TILE_PLAIN)
+    world_dig(2, 37, TILE_PLAIN)
+    world_dig(1, 36, TILE_PLAIN)
+    world_dig(1, 37, TILE_PLAIN)
+    world_dig(1, 35, TILE_PLAIN)
+    world_dig(2, 35, TILE_PLAIN)
+    world_dig(1, 34, TILE_PLAIN)
+    world_dig(2, 34, TILE_PLAIN)
+    world_dig(1, 33, TILE_PLAIN)
+    world_dig(2, 33, TILE_PLAIN)
+    world_dig(3, 33, TILE_PLAIN)
+    world_dig(3, 34, TILE_PLAIN)
+    world_dig(2, 32, TILE_PLAIN)
+    world_dig(3, 32, TILE_PLAIN)
+    world_dig(2, 31, TILE_PLAIN)
+    world_dig(3, 31, TILE_PLAIN)
+    world_dig(4, 31, TILE_PLAIN)
+    world_dig(4, 32, TILE_PLAIN)
+    world_dig(5, 31, TILE_PLAIN)
+    world_dig(6, 31, TILE_PLAIN)
+    world_dig(5, 32, TILE_PLAIN)
+    world_dig(6, 32, TILE_PLAIN)
+    world_dig(7, 31, TILE_PLAIN)
+    world_dig(7, 32, TILE_PLAIN)
+    world_dig(8, 31, TILE_PLAIN)
+    world_dig(8, 32, TILE_PLAIN)
+    world_dig(9, 31, TILE_PLAIN)
+    world_dig(9, 32, TILE_PLAIN)
+    world_dig(8, 33, TILE_PLAIN)
+    world_dig(9, 33, TILE_PLAIN)
+    world_dig(10, 32, TILE_PLAIN)
+    world_dig(10, 33, TILE_PLAIN)
+    world_dig(11, 32, TILE_PLAIN)
+    world_dig(11, 33, TILE_PLAIN)
+    world_dig(10, 34, TILE_PLAIN)
+    world_dig(11, 34, TILE_PLAIN)
+    world_dig(10, 35, TILE_PLAIN)
+    world_dig(11, 35, TILE_PLAIN)
+    world_dig(12, 34, TILE_PLAIN)
+    world_dig(12, 35, TILE_PLAIN)
+    world_dig(11, 36, TILE_PLAIN)
+    world_dig(12, 36, TILE_PLAIN)
+    world_dig(11, 37, TILE_PLAIN)
+    world_dig(12, 37, TILE_PLAIN)
+    world_dig(11, 38, TILE_PLAIN)
+    world_dig(12, 38, TILE_PLAIN)
+    world_dig(9, 38, TILE_PLAIN)
+    world_dig(10, 38, TILE_PLAIN)
+    world_dig(9, 39, TILE_PLAIN)
+    world_dig(10, 39, TILE_PLAIN)
+    world_dig(8, 38, TILE_PLAIN)
+    world_dig(8, 39, TILE_PLAIN)
+    world_dig(7, 39, TILE_PLAIN)
+    world_dig(7, 40, TILE_PLAIN)
+    world_dig(8, 40, TILE_PLAIN)
+    world_dig(7, 38, TILE_PLAIN)
+    world_dig(7, 37, TILE_PLAIN)
+    world_dig(8, 37, TILE_PLAIN)
+    world_dig(7, 36, TILE_PLAIN)
+    world_dig(8, 36, TILE_PLAIN)
+    world_dig(9, 36, TILE_PLAIN)
+    world_dig(9, 37, TILE_PLAIN)
+    world_dig(10, 36, TILE_PLAIN)
+    world_dig(10, 37, TILE_PLAIN)
+    world_dig(14, 41, TILE_PLAIN)
+    world_dig(15, 41, TILE_PLAIN)
+    world_dig(14, 42, TILE_PLAIN)
+    world_dig(15, 42, TILE_PLAIN)
+    world_dig(14, 43, TILE_PLAIN)
+    world_dig(15, 43, TILE_PLAIN)
+    world_dig(16, 43, TILE_PLAIN)
+    world_dig(15, 44, TILE_PLAIN)
+    world_dig(16, 44, TILE_PLAIN)
+    world_dig(16, 42, TILE_PLAIN)
+    world_dig(13, 41, TILE_PLAIN)
+    world_dig(13, 42, TILE_PLAIN)
+    world_dig(13, 40, TILE_PLAIN)
+    world_dig(14, 40, TILE_PLAIN)
+    world_dig(12, 40, TILE_PLAIN)
+    world_dig(12, 41, TILE_PLAIN)
+    world_dig(12, 39, TILE_PLAIN)
+    world_dig(13, 39, TILE_PLAIN)
+    world_dig(11, 41, TILE_PLAIN)
+    world_dig(11, 42, TILE_PLAIN)
+    world_dig(12, 42, TILE_PLAIN)
+    world_dig(11, 43, TILE_PLAIN)
+    world_dig(12, 43, TILE_PLAIN)
+    world_dig(13, 43, TILE_PLAIN)
+    world_dig(15, 37, TILE_PLAIN)
+    world_dig(16, 37, TILE_PLAIN)
+    world_dig(15, 38, TILE_PLAIN)
+    world_dig(16, 38, TILE_PLAIN)
+    world_dig(16, 36, TILE_PLAIN)
+    world_dig(17, 36, TILE_PLAIN)
+    world_dig(17, 37, TILE_PLAIN)
+    world_dig(16, 35, TILE_PLAIN)
+    world_dig(17, 35, TILE_PLAIN)
+    world_dig(18, 35, TILE_PLAIN)
+    world_dig(18, 36, TILE_PLAIN)
+    world_dig(17, 34, TILE_PLAIN)
+    world_dig(18, 34, TILE_PLAIN)
+    world_dig(19, 34, TILE_PLAIN)
+    world_dig(19, 35, TILE_PLAIN)
+    world_dig(20, 34, TILE_PLAIN)
+    world_dig(20, 35, TILE_PLAIN)
+    world_dig(21, 34, TILE_PLAIN)
+    world_dig(21, 35, TILE_PLAIN)
+    world_dig(22, 34, TILE_PLAIN)
+    world_dig(22, 35, TILE_PLAIN)
+    world_dig(12, 33, TILE_PLAIN)
+    world_dig(13, 33, TILE_PLAIN)
+    world_dig(13, 34, TILE_PLAIN)
+    world_dig(12, 32, TILE_PLAIN)
+    world_dig(13, 32, TILE_PLAIN)
+    world_dig(14, 32, TILE_PLAIN)
+    world_dig(14, 33, TILE_PLAIN)
+    world_dig(15, 33, TILE_PLAIN)
+    world_dig(14, 34, TILE_PLAIN)
+    world_dig(15, 34, TILE_PLAIN)
+    world_dig(16, 33, TILE_PLAIN)
+    world_dig(16, 34, TILE_PLAIN)
+    world_dig(17, 33, TILE_PLAIN)
+    world_dig(18, 33, TILE_PLAIN)
+    world_dig(19, 33, TILE_PLAIN)
+    world_dig(23, 33, TILE_PLAIN)
+    world_dig(24, 33, TILE_PLAIN)
+    world_dig(23, 34, TILE_PLAIN)
+    world_dig(24, 34, TILE_PLAIN)
+    world_dig(25, 33, TILE_PLAIN)
+    world_dig(25, 34, TILE_PLAIN)
+    world_dig(24, 35, TILE_PLAIN)
+    world_dig(25, 35, TILE_PLAIN)
+    world_dig(26, 34, TILE_PLAIN)
+    world_dig(26, 35, TILE_PLAIN)
+    world_dig(27, 35, TILE_PLAIN)
+    world_dig(26, 36, TILE_PLAIN)
+    world_dig(27, 36, TILE_PLAIN)
+    world_dig(28, 36, TILE_PLAIN)
+    world_dig(27, 37, TILE_PLAIN)
+    world_dig(28, 37, TILE_PLAIN)
+    world_dig(27, 38, TILE_PLAIN)
+    world_dig(28, 38, TILE_PLAIN)
+    world_dig(29, 37, TILE_PLAIN)
+    world_dig(29, 38, TILE_PLAIN)
+    world_dig(28, 39, TILE_PLAIN)
+    world_dig(29, 39, TILE_PLAIN)
+    world_dig(30, 38, TILE_PLAIN)
+    world_dig(30, 39, TILE_PLAIN)
+    world_dig(29, 40, TILE_PLAIN)
+    world_dig(30, 40, TILE_PLAIN)
+    world_dig(27, 39, TILE_PLAIN)
+    world_dig(26, 38, TILE_PLAIN)
+    world_dig(26, 39, TILE_PLAIN)
+    world_dig(25, 38, TILE_PLAIN)
+    world_dig(25, 39, TILE_PLAIN)
+    world_dig(24, 39, TILE_PLAIN)
+    world_dig(24, 40, TILE_PLAIN)
+    world_dig(25, 40, TILE_PLAIN)
+    world_dig(23, 39, TILE_PLAIN)
+    world_dig(23, 40, TILE_PLAIN)
+    world_dig(22, 39, TILE_PLAIN)
+    world_dig(22, 40, TILE_PLAIN)
+    world_dig(21, 40, TILE_PLAIN)
+    world_dig(21, 41, TILE_PLAIN)
+    world_dig(22, 41, TILE_PLAIN)
+    world_dig(20, 40, TILE_PLAIN)
+    world_dig(20, 41, TILE_PLAIN)
+    world_dig(18, 37, TILE_PLAIN)
+    world_dig(17, 38, TILE_PLAIN)
+    world_dig(18, 38, TILE_PLAIN)
+    world_dig(17, 39, TILE_PLAIN)
+    world_dig(18, 39, TILE_PLAIN)
+    world_dig(19, 39, TILE_PLAIN)
+    world_dig(18, 40, TILE_PLAIN)
+    world_dig(19, 40, TILE_PLAIN)
+    world_dig(18, 41, TILE_PLAIN)
+    world_dig(19, 41, TILE_PLAIN)
+    world_dig(19, 42, TILE_PLAIN)
+    world_dig(20, 42, TILE_PLAIN)
+    world_dig(21, 42, TILE_PLAIN)
+    world_dig(20, 43, TILE_PLAIN)
+    world_dig(21, 43, TILE_PLAIN)
+    world_dig(22, 42, TILE_PLAIN)
+    world_dig(22, 43, TILE_PLAIN)
+    world_dig(27, 43, TILE_PLAIN)
+    world_dig(28, 43, TILE_PLAIN)
+    world_dig(27, 44, TILE_PLAIN)
+    world_dig(28, 44, TILE_PLAIN)
+    world_dig(29, 44, TILE_PLAIN)
+    world_dig(30, 44, TILE_PLAIN)
+    world_dig(31, 44, TILE_PLAIN)
+    world_dig(32, 44, TILE_PLAIN)
+    world_dig(33, 44, TILE_PLAIN)
+    world_dig(34, 44, TILE_PLAIN)
+    world_dig(33, 43, TILE_PLAIN)
+    world_dig(34, 43, TILE_PLAIN)
+    world_dig(33, 42, TILE_PLAIN)
+    world_dig(34, 42, TILE_PLAIN)
+    world_dig(33, 41, TILE_PLAIN)
+    world_dig(34, 41, TILE_PLAIN)
+    world_dig(32, 41, TILE_PLAIN)
+    world_dig(32, 42, TILE_PLAIN)
+    world_dig(32, 40, TILE_PLAIN)
+    world_dig(33, 40, TILE_PLAIN)
+    world_dig(31, 40, TILE_PLAIN)
+    world_dig(31, 41, TILE_PLAIN)
+    world_dig(31, 39, TILE_PLAIN)
+    world_dig(32, 39, TILE_PLAIN)
+    world_dig(31, 38, TILE_PLAIN)
+    world_dig(31, 36, TILE_PLAIN)
+    world_dig(32, 36, TILE_PLAIN)
+    world_dig(31, 37, TILE_PLAIN)
+    world_dig(32, 37, TILE_PLAIN)
+    world_dig(31, 35, TILE_PLAIN)
+    world_dig(32, 35, TILE_PLAIN)
+    world_dig(33, 35, TILE_PLAIN)
+    world_dig(33, 36, TILE_PLAIN)
+    world_dig(33, 34, TILE_PLAIN)
+    world_dig(34, 34, TILE_PLAIN)
+    world_dig(34, 35, TILE_PLAIN)
+    world_dig(35, 34, TILE_PLAIN)
+    world_dig(35, 35, TILE_PLAIN)
+    world_dig(35, 33, TILE_PLAIN)
+    world_dig(36, 33, TILE_PLAIN)
+    world_dig(36, 34, TILE_PLAIN)
+    world_dig(35, 32, TILE_PLAIN)
+    world_dig(36, 32, TILE_PLAIN)
+    world_dig(34, 32, TILE_PLAIN)
+    world_dig(34, 33, TILE_PLAIN)
+    world_dig(33, 32, TILE_PLAIN)
+    world_dig(33, 33, TILE_PLAIN)
+    world_dig(32, 31, TILE_PLAIN)
+    world_dig(33, 31, TILE_PLAIN)
+    world_dig(32, 32, TILE_PLAIN)
+    world_dig(31, 31, TILE_PLAIN)
+    world_dig(31, 32, TILE_PLAIN)
+    world_dig(30, 31, TILE_PLAIN)
+    world_dig(30, 32, TILE_PLAIN)
+    world_dig(30, 30, TILE_PLAIN)
+    world_dig(31, 30, TILE_PLAIN)
+    world_dig(36, 30, TILE_PLAIN)
+    world_dig(37, 30, TILE_PLAIN)
+    world_dig(36, 31, TILE_PLAIN)
+    world_dig(37, 31, TILE_PLAIN)
+    world_dig(38, 30, TILE_PLAIN)
+    world_dig(38, 31, TILE_PLAIN)
+    world_dig(39, 30, TILE_PLAIN)
+    world_dig(39, 31, TILE_PLAIN)
+    world_dig(40, 30, TILE_PLAIN)
+    world_dig(40, 31, TILE_PLAIN)
+    world_dig(39, 29, TILE_PLAIN)
+    world_dig(40, 29, TILE_PLAIN)
+    world_dig(40, 15, TILE_PLAIN)
+    world_dig(41, 15, TILE_PLAIN)
+    world_dig(42, 15, TILE_PLAIN)
+    world_dig(43, 15, TILE_PLAIN)
+    world_dig(44, 14, TILE_PLAIN)
+    world_dig(44, 15, TILE_PLAIN)
+    world_dig(44, 13, TILE_PLAIN)
+    world_dig(44, 12, TILE_PLAIN)
+    world_dig(44, 11, TILE_PLAIN)
+    world_dig(41, 31, TILE_PLAIN)
+    world_dig(40, 32, TILE_PLAIN)
+    world_dig(41, 32, TILE_PLAIN)
+    world_dig(42, 32, TILE_PLAIN)
+    world_dig(41, 33, TILE_PLAIN)
+    world_dig(42, 33, TILE_PLAIN)
+    world_dig(43, 32, TILE_PLAIN)
+    world_dig(43, 33, TILE_PLAIN)
+    world_dig(42, 31, TILE_PLAIN)
+    world_dig(43, 31, TILE_PLAIN)
+    world_dig(42, 30, TILE_PLAIN)
+    world_dig(43, 30, TILE_PLAIN)
+    world_dig(41, 30, TILE_PLAIN)
+    world_dig(41, 29, TILE_PLAIN)
+    world_dig(42, 29, TILE_PLAIN)
+    world_dig(43, 29, TILE_PLAIN)
+    world_dig(44, 29, TILE_PLAIN)
+    world_dig(44, 30, TILE_PLAIN)
+    world_dig(44, 31, TILE_PLAIN)
+    world_dig(44, 32, TILE_PLAIN)
+    world_dig(44, 33, TILE_PLAIN)
+    world_dig(37, 36, TILE_PLAIN)
+    world_dig(38, 36, TILE_PLAIN)
+    world_dig(37, 37, TILE_PLAIN)
+    world_dig(38, 37, TILE_PLAIN)
+    world_dig(37, 35, TILE_PLAIN)
+    world_dig(38, 35, TILE_PLAIN)
+    world_dig(39, 35, TILE_PLAIN)
+    world_dig(39, 36, TILE_PLAIN)
+    world_dig(40, 35, TILE_PLAIN)
+    world_dig(40, 36, TILE_PLAIN)
+    world_dig(39, 37, TILE_PLAIN)
+    world_dig(40, 37, TILE_PLAIN)
+    world_dig(41, 36, TILE_PLAIN)
+    world_dig(41, 37, TILE_PLAIN)
+    world_dig(40, 38, TILE_PLAIN)
+    world_dig(41, 38, TILE_PLAIN)
+    world_dig(40, 39, TILE_PLAIN)
+    world_dig(41, 39, TILE_PLAIN)
+    world_dig(40, 40, TILE_PLAIN)
+    world_dig(41, 40, TILE_PLAIN)
+    world_dig(40, 41, TILE_PLAIN)
+    world_dig(41, 41, TILE_PLAIN)
+    world_dig(42, 40, TILE_PLAIN)
+    world_dig(42, 41, TILE_PLAIN)
+    world_dig(41, 42, TILE_PLAIN)
+    world_dig(42, 42, TILE_PLAIN)
+    world_dig(43, 41, TILE_PLAIN)
+    world_dig(43, 42, TILE_PLAIN)
+    world_dig(42, 43, TILE_PLAIN)
+    world_dig(43, 43, TILE_PLAIN)
+    world_dig(41, 43, TILE_PLAIN)
+    world_dig(41, 44, TILE_PLAIN)
+    world_dig(42, 44, TILE_PLAIN)
+    world_dig(40, 44, TILE_PLAIN)
+    world_dig(40, 43, TILE_PLAIN)
+    world_dig(39, 43, TILE_PLAIN)
+    world_dig(39, 44, TILE_PLAIN)
+    world_dig(39, 42, TILE_PLAIN)
+    world_dig(40, 42, TILE_PLAIN)
+    world_dig(39, 41, TILE_PLAIN)
+    world_dig(39, 40, TILE_PLAIN)
+    world_dig(38, 40, TILE_PLAIN)
+    world_dig(38, 41, TILE_PLAIN)
+    world_dig(38, 39, TILE_PLAIN)
+    world_dig(39, 39, TILE_PLAIN)
+    world_dig(37, 39, TILE_PLAIN)
+    world_dig(37, 40, TILE_PLAIN)
+    world_dig(36, 39, TILE_PLAIN)
+    world_dig(36, 40, TILE_PLAIN)
+    world_dig(35, 39, TILE_PLAIN)
+    world_dig(35, 40, TILE_PLAIN)
+    world_dig(34, 39, TILE_PLAIN)
+    world_dig(34, 40, TILE_PLAIN)
+    world_dig(33, 39, TILE_PLAIN)
+    world_dig(33, 37, TILE_PLAIN)
+    world_dig(34, 37, TILE_PLAIN)
+    world_dig(33, 38, TILE_PLAIN)
+    world_dig(34, 38, TILE_PLAIN)
+    world_dig(35, 37, TILE_PLAIN)
+    world_dig(35, 38, TILE_PLAIN)
+    world_dig(36, 38, TILE_PLAIN)
+    world_dig(29, 31, TILE_PLAIN)
+    world_dig(28, 31, TILE_PLAIN)
+    world_dig(27, 31, TILE_PLAIN)
+    world_dig(27, 30, TILE_PLAIN)
+    world_dig(26, 30, TILE_PLAIN)
+    world_dig(25, 30, TILE_PLAIN)
+    world_dig(25, 31, TILE_PLAIN)
+    world_dig(25, 32, TILE_PLAIN)
+    world_dig(53, 18, TILE_PLAIN)
+    world_dig(54, 18, TILE_PLAIN)
+    world_dig(53, 19, TILE_PLAIN)
+    world_dig(54, 19, TILE_PLAIN)
+    world_dig(55, 18, TILE_PLAIN)
+    world_dig(55, 19, TILE_PLAIN)
+    world_dig(56, 18, TILE_PLAIN)
+    world_dig(56, 19, TILE_PLAIN)
+    world_dig(57, 18, TILE_PLAIN)
+    world_dig(57, 19, TILE_PLAIN)
+    world_dig(58, 18, TILE_PLAIN)
+    world_dig(58, 19, TILE_PLAIN)
+    world_dig(59, 18, TILE_PLAIN)
+    world_dig(59, 19, TILE_PLAIN)
+    world_dig(58, 20, TILE_PLAIN)
+    world_dig(59, 20, TILE_PLAIN)
+    world_dig(60, 19, TILE_PLAIN)
+    world_dig(60, 20, TILE_PLAIN)
+    world_dig(60, 18, TILE_PLAIN)
+    world_dig(57, 20, TILE_PLAIN)
+    world_dig(56, 20, TILE_PLAIN)
+    world_dig(55, 20, TILE_PLAIN)
+    world_dig(54, 20, TILE_PLAIN)
+    world_dig(53, 20, TILE_PLAIN)
+    world_dig(53, 21, TILE_PLAIN)
+    world_dig(54, 21, TILE_PLAIN)
+    world_dig(55, 21, TILE_PLAIN)
+    world_dig(56, 21, TILE_PLAIN)
+    world_dig(57, 21, TILE_PLAIN)
+    world_dig(58, 21, TILE_PLAIN)
+    world_dig(57, 22, TILE_PLAIN)
+    world_dig(58, 22, TILE_PLAIN)
+    world_dig(59, 21, TILE_PLAIN)
+    world_dig(59, 22, TILE_PLAIN)
+    world_dig(60, 21, TILE_PLAIN)
+    world_dig(60, 22, TILE_PLAIN)
+    world_dig(60, 23, TILE_PLAIN)
+    world_dig(59, 23, TILE_PLAIN)
+    world_dig(58, 23, TILE_PLAIN)
+    world_dig(58, 24, TILE_PLAIN)
+    world_dig(59, 24, TILE_PLAIN)
+    world_dig(57, 23, TILE_PLAIN)
+    world_dig(57, 24, TILE_PLAIN)
+    world_dig(56, 23, TILE_PLAIN)
+    world_dig(56, 24, TILE_PLAIN)
+    world_dig(55, 22, TILE_PLAIN)
+    world_dig(56, 22, TILE_PLAIN)
+    world_dig(55, 23, TILE_PLAIN)
+    world_dig(54, 22, TILE_PLAIN)
+    world_dig(54, 23, TILE_PLAIN)
+    world_dig(53, 22, TILE_PLAIN)
+    world_dig(53, 23, TILE_PLAIN)
+    world_dig(60, 24, TILE_PLAIN)
+    world_dig(60, 25, TILE_PLAIN)
+    world_dig(60, 26, TILE_PLAIN)
+    world_dig(59, 25, TILE_PLAIN)
+    world_dig(59, 26, TILE_PLAIN)
+    world_dig(58, 25, TILE_PLAIN)
+    world_dig(58, 26, TILE_PLAIN)
+    world_dig(57, 25, TILE_PLAIN)
+    world_dig(57, 26, TILE_PLAIN)
+    world_dig(56, 25, TILE_PLAIN)
+    world_dig(55, 24, TILE_PLAIN)
+    world_dig(55, 25, TILE_PLAIN)
+    world_dig(54, 24, TILE_PLAIN)
+    world_dig(54, 25, TILE_PLAIN)
+    world_dig(53, 24, TILE_PLAIN)
+    world_dig(53, 25, TILE_PLAIN)
+    world_dig(53, 26, TILE_PLAIN)
+    world_dig(54, 26, TILE_PLAIN)
+    world_dig(55, 26, TILE_PLAIN)
+    world_dig(56, 26, TILE_PLAIN)
+
+    world_make_border(TILE_GFX_BORDER)
+
+    food_spawner = {
+        {
+            x = 5,
+            y = 5,
+            r = 5,
+            a = 300,
+            i = 200,
+            n = 0,
+        }, {
+            x = 5,
+            y = 40,
+            r = 5,
+            a = 300,
+            i = 200,
+            n = 0,
+        }
+    }
+    last_food = game_time()
+end
+
+function level_tick()
+    for n, spawner in pairs(food_spawner) do
+        if game_time() > spawner.n then 
+            world_add_food(spawner.x + math.random(spawner.r * 2 + 1) - spawner.r,
+                           spawner.y + math.random(spawner.r * 2 + 1) - spawner.r,
+                           spawner.a)
+            spawner.n = spawner.n + spawner.i                               
+        end
+    end
+end
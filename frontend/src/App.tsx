@@ -10,6 +10,7 @@ import { GameList } from './pages/GameList';
 import { Login } from './pages/Login';
 import { Register } from './pages/Register';
 import { Leaderboard } from './pages/Leaderboard';
+import { Seasons } from './pages/Seasons';
 import { ApiKeys } from './pages/ApiKeys';
 import { Challenge } from './pages/Challenge';
 import { MyMatches } from './pages/MyMatches';
@@ -83,6 +84,7 @@ function App() {
               <Route path="/editor" element={<ProtectedRoute><BotEditor /></ProtectedRoute>} />
               <Route path="/editor/:botId" element={<ProtectedRoute><BotEditor /></ProtectedRoute>} />
               <Route path="/leaderboard" element={<Leaderboard />} />
+              <Route path="/seasons" element={<Seasons />} />
               <Route path="/tournaments" element={<TournamentList />} />
               <Route path="/tournaments/:id" element={<TournamentDetail />} />
               <Route path="/games" element={<GameList />} />
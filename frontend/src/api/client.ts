@@ -16,6 +16,8 @@ export interface Bot {
   owner_username?: string | null;
   visibility: string;
   active_version_id?: number | null;
+  /** Opted into automatic ladder matches. */
+  ladder?: boolean;
   created_at: string;
   updated_at: string;
   version_count?: number;
@@ -28,6 +30,8 @@ export interface BotVersion {
   bot_id: number;
   version: number;
   code: string;
+  api_type: string;
+  api_level: number;
   is_archived: boolean;
   is_faulty: boolean;
   elo_rating: number;
@@ -39,6 +43,10 @@ export interface BotVersion {
   draws: number;
   ffa_placement_points: number;
   ffa_games: number;
+  unranked_games: number;
+  unranked_wins: number;
+  unranked_losses: number;
+  unranked_draws: number;
   creatures_spawned: number;
   creatures_killed: number;
   creatures_lost: number;
@@ -73,7 +81,33 @@ export interface TournamentStanding {
   total_score: number;
   matches_played: number;
   wins: number;
+  draws: number;
   losses: number;
+  byes: number;
+  points: number;
+  buchholz: number;
+  sonneborn_berger: number;
+  head_to_head: number;
+}
+
+export interface RatingHistoryEntry {
+  id: number;
+  bot_version_id: number;
+  /** Null for a season reset, which also has no opponent. */
+  match_id: number | null;
+  rating_before: number;
+  rating_after: number;
+  opponent_version_id: number | null;
+  opponent_rating: number | null;
+  created_at: string;
+}
+
+export interface RatingHistory {
+  version_id: number;
+  rating: number;
+  peak: number;
+  rating_at: number | null;
+  history: RatingHistoryEntry[];
 }
 
 export interface LeaderboardEntry {
@@ -82,13 +116,76 @@ export interface LeaderboardEntry {
   bot_name: string;
   version: number;
   owner_username: string;
+  league: League;
   rating: number;
   games_played: number;
   wins: number;
   losses: number;
   win_rate: number;
+  /** Half-width of the interval around the skill rating (Glicko-2 for 1v1, Weng-Lin for FFA/2v2). */
+  confidence?: number | null;
+  /** Too few games for the skill rating to be trusted. */
+  provisional?: boolean;
+  /** Elo gained or lost over the period, on windowed 1v1 Elo boards. */
+  rating_change?: number | null;
 }
 
+export type LeaderboardPeriod = 'all' | 'week' | 'month';
+
+export interface LeaderboardFilters {
+  league?: League;
+  period?: LeaderboardPeriod;
+  owner?: string;
+  map?: string;
+  activeOnly?: boolean;
+  /** 'bot' shows only each bot's (or team's) best version. */
+  group?: 'version' | 'bot';
+}
+
+function leaderboardQuery(f: LeaderboardFilters): string {
+  let q = '';
+  if (f.league) q += `&league=${f.league}`;
+  if (f.period && f.period !== 'all') q += `&period=${f.period}`;
+  if (f.owner) q += `&owner=${encodeURIComponent(f.owner)}`;
+  if (f.map) q += `&map=${encodeURIComponent(f.map)}`;
+  if (f.activeOnly) q += '&active_only=true';
+  if (f.group === 'bot') q += '&group=bot';
+  return q;
+}
+
+export type RatingSort = 'elo' | 'glicko' | 'points' | 'skill';
+
+export interface Season {
+  id: number;
+  name: string;
+  starts_at: string;
+  ends_at: string;
+  /** Null while the season is active. */
+  finished_at: string | null;
+  created_at: string;
+  champion_bot_name: string | null;
+  champion_owner: string | null;
+  champion_rating: number | null;
+}
+
+/** A version's place on a season's 1v1 board; games, wins and peak are season-only. */
+export interface SeasonStanding {
+  rank: number;
+  bot_version_id: number;
+  bot_name: string;
+  version: number;
+  owner_username: string;
+  rating: number;
+  peak_rating: number;
+  games_played: number;
+  wins: number;
+  losses: number;
+  draws: number;
+}
+
+/** Account-age league: newcomer for the first 14 days, open afterwards. */
+export type League = 'newcomer' | 'open';
+
 export interface MapInfo {
   name: string;
   width: number;
@@ -143,6 +240,7 @@ export interface MatchDetail {
     map: string;
     status: string;
     winner_bot_version_id: number | null;
+    ranked: boolean;
     created_at: string;
     finished_at: string | null;
   };
@@ -158,6 +256,8 @@ export interface MatchDetail {
     creatures_spawned: number;
     creatures_killed: number;
     creatures_lost: number;
+    /** False for the Open side of a ranked challenge across leagues. */
+    rated: boolean;
     bot_name: string | null;
     owner_name: string | null;
   }[];
@@ -228,6 +328,35 @@ export interface TournamentMatchesResponse {
   rounds: TournamentRound[];
 }
 
+export type BracketSide = 'winners' | 'losers' | 'grand_final' | 'grand_final_reset';
+
+export interface BracketMatch {
+  match_id: number;
+  round: number;
+  bracket: BracketSide;
+  a: number;
+  b: number;
+  /** Who advances once the match is finished; draws go to the better seed. */
+  winner: number | null;
+}
+
+export interface BracketStanding {
+  bot_version_id: number;
+  bot_name: string | null;
+  seed: number;
+  losses: number;
+  eliminated_round: number | null;
+  /** Final place, shared by bots knocked out in the same round. */
+  placement: number | null;
+}
+
+export interface DoubleEliminationBracket {
+  format: string;
+  champion: number | null;
+  standings: BracketStanding[];
+  matches: BracketMatch[];
+}
+
 // Broadcast events for event ticker
 export type BroadcastEvent =
   | { kind: 'Spawn'; creature_id: number; player_id: number; player_name: string; creature_type: number }
@@ -278,6 +407,9 @@ export interface PlayerLoadErrorMsg {
 export interface ValidateLuaResult {
   valid: boolean;
   error?: string;
+  api_type?: string;
+  api_level?: number;
+  warnings?: string[];
 }
 
 export interface SnapshotDeltaMsg {
@@ -357,11 +489,11 @@ export const api = {
   getBot: (id: number): Promise<Bot> =>
     fetch(`${BASE_URL}/api/bots/${id}`, { headers: authHeaders() }).then(r => handleResponse<Bot>(r)),
 
-  updateBot: (id: number, name: string, description?: string): Promise<Bot> =>
+  updateBot: (id: number, name: string, description?: string, ladder?: boolean): Promise<Bot> =>
     fetch(`${BASE_URL}/api/bots/${id}`, {
       method: 'PUT',
       headers: { 'Content-Type': 'application/json', ...authHeaders() },
-      body: JSON.stringify({ name, description }),
+      body: JSON.stringify({ name, description, ladder }),
     }).then(r => handleResponse<Bot>(r)),
 
   deleteBot: (id: number): Promise<void> =>
@@ -383,6 +515,10 @@ export const api = {
   getVersion: (botId: number, versionId: number): Promise<BotVersion> =>
     fetch(`${BASE_URL}/api/bots/${botId}/versions/${versionId}`, { headers: authHeaders() }).then(r => handleResponse<BotVersion>(r)),
 
+  getRatingHistory: (botId: number, versionId: number, at?: string): Promise<RatingHistory> =>
+    fetch(`${BASE_URL}/api/bots/${botId}/versions/${versionId}/rating-history${at ? `?at=${encodeURIComponent(at)}` : ''}`, { headers: authHeaders() })
+      .then(r => handleResponse<RatingHistory>(r)),
+
   // Tournaments
   listTournaments: (): Promise<Tournament[]> =>
     fetch(`${BASE_URL}/api/tournaments`, { headers: authHeaders() }).then(r => handleResponse<Tournament[]>(r)),
@@ -436,6 +572,9 @@ export const api = {
   getTournamentMatches: (tournamentId: number): Promise<TournamentMatchesResponse> =>
     fetch(`${BASE_URL}/api/tournaments/${tournamentId}/matches`, { headers: authHeaders() }).then(r => handleResponse<TournamentMatchesResponse>(r)),
 
+  getTournamentBracket: (tournamentId: number): Promise<DoubleEliminationBracket> =>
+    fetch(`${BASE_URL}/api/tournaments/${tournamentId}/bracket`, { headers: authHeaders() }).then(r => handleResponse<DoubleEliminationBracket>(r)),
+
   // Game
   gameStatus: (): Promise<{ running: boolean }> =>
     fetch(`${BASE_URL}/api/game/status`).then(r => handleResponse<{ running: boolean }>(r)),
@@ -443,7 +582,7 @@ export const api = {
   listMaps: (): Promise<MapInfo[]> =>
     fetch(`${BASE_URL}/api/maps`).then(r => handleResponse<MapInfo[]>(r)),
 
-  startGame: (players: { bot_version_id: number; name?: string }[], map?: string, headless?: boolean, map_params?: { width?: number; height?: number; num_food_spots?: number }): Promise<{ status: string; message: string; match_id?: number }> =>
+  startGame: (players: { bot_version_id: number; name?: string }[], map?: string, headless?: boolean, map_params?: { width?: number; height?: number; num_food_spots?: number; symmetry?: string; radial_folds?: number; max_unfairness?: number }): Promise<{ status: string; message: string; match_id?: number }> =>
     fetch(`${BASE_URL}/api/game/start`, {
       method: 'POST',
       headers: { 'Content-Type': 'application/json', ...authHeaders() },
@@ -456,18 +595,26 @@ export const api = {
     }),
 
   // Leaderboards
-  leaderboard1v1: (limit = 50, offset = 0): Promise<LeaderboardEntry[]> =>
-    fetch(`${BASE_URL}/api/leaderboards/1v1?limit=${limit}&offset=${offset}`, { headers: authHeaders() })
+  leaderboard1v1: (limit = 50, offset = 0, sort: RatingSort = 'elo', hideProvisional = false, filters: LeaderboardFilters = {}): Promise<LeaderboardEntry[]> =>
+    fetch(`${BASE_URL}/api/leaderboards/1v1?limit=${limit}&offset=${offset}&sort=${sort}&hide_provisional=${hideProvisional}${leaderboardQuery(filters)}`, { headers: authHeaders() })
       .then(r => handleResponse<LeaderboardEntry[]>(r)),
 
-  leaderboardFfa: (limit = 50, offset = 0): Promise<LeaderboardEntry[]> =>
-    fetch(`${BASE_URL}/api/leaderboards/ffa?limit=${limit}&offset=${offset}`, { headers: authHeaders() })
+  leaderboardFfa: (limit = 50, offset = 0, sort: RatingSort = 'points', hideProvisional = false, filters: LeaderboardFilters = {}): Promise<LeaderboardEntry[]> =>
+    fetch(`${BASE_URL}/api/leaderboards/ffa?limit=${limit}&offset=${offset}&sort=${sort}&hide_provisional=${hideProvisional}${leaderboardQuery(filters)}`, { headers: authHeaders() })
       .then(r => handleResponse<LeaderboardEntry[]>(r)),
 
-  leaderboard2v2: (limit = 50, offset = 0): Promise<LeaderboardEntry[]> =>
-    fetch(`${BASE_URL}/api/leaderboards/2v2?limit=${limit}&offset=${offset}`, { headers: authHeaders() })
+  leaderboard2v2: (limit = 50, offset = 0, sort: RatingSort = 'elo', hideProvisional = false, filters: LeaderboardFilters = {}): Promise<LeaderboardEntry[]> =>
+    fetch(`${BASE_URL}/api/leaderboards/2v2?limit=${limit}&offset=${offset}&sort=${sort}&hide_provisional=${hideProvisional}${leaderboardQuery(filters)}`, { headers: authHeaders() })
       .then(r => handleResponse<LeaderboardEntry[]>(r)),
 
+  // Seasons
+  listSeasons: (): Promise<Season[]> =>
+    fetch(`${BASE_URL}/api/seasons`).then(r => handleResponse<Season[]>(r)),
+
+  seasonLeaderboard: (id: number, limit = 50, offset = 0): Promise<SeasonStanding[]> =>
+    fetch(`${BASE_URL}/api/seasons/${id}/leaderboard?limit=${limit}&offset=${offset}`)
+      .then(r => handleResponse<SeasonStanding[]>(r)),
+
   // Match detail & replay
   getMatch: (id: number): Promise<MatchDetail> =>
     fetch(`${BASE_URL}/api/matches/${id}`, { headers: authHeaders() }).then(r => handleResponse<MatchDetail>(r)),
@@ -550,7 +697,7 @@ export const api = {
     }).then(r => handleResponse<ValidateLuaResult>(r)),
 
   // Challenges
-  createChallenge: (botVersionId: number, opponentBotVersionId: number, options?: { format?: string; headless?: boolean; map?: string }): Promise<ChallengeResult> =>
+  createChallenge: (botVersionId: number, opponentBotVersionId: number, options?: { format?: string; headless?: boolean; map?: string; ranked?: boolean }): Promise<ChallengeResult> =>
     fetch(`${BASE_URL}/api/matches/challenge`, {
       method: 'POST',
       headers: { 'Content-Type': 'application/json', ...authHeaders() },
@@ -560,6 +707,7 @@ export const api = {
         format: options?.format,
         headless: options?.headless,
         map: options?.map,
+        ranked: options?.ranked,
       }),
     }).then(r => handleResponse<ChallengeResult>(r)),
 
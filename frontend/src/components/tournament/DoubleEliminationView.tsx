@@ -0,0 +1,101 @@
+import type { BracketSide, DoubleEliminationBracket, TournamentMatchInfo, TournamentRound } from '../../api/client';
+import { MatchCard } from './MatchCard';
+
+interface Props {
+  rounds: TournamentRound[];
+  bracket: DoubleEliminationBracket;
+}
+
+const SECTIONS: [string, BracketSide[]][] = [
+  ['Winners Bracket', ['winners']],
+  ['Losers Bracket', ['losers']],
+  ['Grand Final', ['grand_final', 'grand_final_reset']],
+];
+
+export function DoubleEliminationView({ rounds, bracket }: Props) {
+  const matchById = new Map<number, TournamentMatchInfo>();
+  rounds.forEach(r => r.matches.forEach(m => matchById.set(m.match_id, m)));
+
+  return (
+    <div>
+      {SECTIONS.map(([title, sides]) => {
+        const inSection = bracket.matches.filter(m => sides.includes(m.bracket));
+        if (inSection.length === 0) return null;
+        const roundNumbers = [...new Set(inSection.map(m => m.round))];
+        return (
+          <div key={title} style={{ marginBottom: '24px' }}>
+            <h4 style={sectionStyle}>{title}</h4>
+            <div style={{ display: 'flex', gap: '16px', overflowX: 'auto', paddingBottom: '8px' }}>
+              {roundNumbers.map(round => (
+                <div key={round} style={{ display: 'flex', flexDirection: 'column', gap: '8px', minWidth: '220px' }}>
+                  <span style={{ color: '#666', fontSize: '12px' }}>Round {round}</span>
+                  {inSection.filter(m => m.round === round).map(m => {
+                    const match = matchById.get(m.match_id);
+                    return match ? (
+                      <div key={m.match_id}>
+                        {m.bracket === 'grand_final_reset' && (
+                          <span style={{ color: '#888', fontSize: '11px' }}>Bracket reset</span>
+                        )}
+                        <MatchCard match={match} />
+                      </div>
+                    ) : null;
+                  })}
+                </div>
+              ))}
+            </div>
+          </div>
+        );
+      })}
+
+      {/* Placements */}
+      <h4 style={sectionStyle}>Placements</h4>
+      <table style={{ borderCollapse: 'collapse', width: '100%', fontSize: '13px' }}>
+        <thead>
+          <tr style={{ borderBottom: '1px solid #333' }}>
+            <th style={thStyle}>Place</th>
+            <th style={thStyle}>Bot</th>
+            <th style={thStyle}>Seed</th>
+            <th style={thStyle}>Losses</th>
+            <th style={thStyle}>Out in</th>
+          </tr>
+        </thead>
+        <tbody>
+          {bracket.standings.map(s => (
+            <tr key={s.bot_version_id} style={{ borderBottom: '1px solid #222' }}>
+              <td style={tdStyle}>{s.placement ?? '–'}</td>
+              <td style={{ ...tdStyle, color: '#16c79a', fontWeight: 600 }}>
+                {s.bot_name ?? `#${s.bot_version_id}`}
+                {s.bot_version_id === bracket.champion && ' 🏆'}
+              </td>
+              <td style={tdStyle}>{s.seed}</td>
+              <td style={{ ...tdStyle, color: s.losses > 0 ? '#e94560' : '#e0e0e0' }}>{s.losses}</td>
+              <td style={tdStyle}>{s.eliminated_round != null ? `Round ${s.eliminated_round}` : '–'}</td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+}
+
+const sectionStyle: React.CSSProperties = {
+  color: '#aaa',
+  fontSize: '12px',
+  textTransform: 'uppercase',
+  letterSpacing: '0.5px',
+  margin: '0 0 12px 0',
+};
+
+const thStyle: React.CSSProperties = {
+  textAlign: 'left',
+  padding: '8px 10px',
+  color: '#aaa',
+  fontSize: '12px',
+  fontWeight: 600,
+  textTransform: 'uppercase',
+};
+
+const tdStyle: React.CSSProperties = {
+  padding: '8px 10px',
+  color: '#e0e0e0',
+};
@@ -39,9 +39,15 @@ export function SwissRoundsView({ rounds, standings }: Props) {
               <tr style={{ borderBottom: '1px solid #333' }}>
                 <th style={thStyle}>#</th>
                 <th style={thStyle}>Bot</th>
-                <th style={thStyle}>Score</th>
+                <th style={thStyle}>Pts</th>
                 <th style={thStyle}>W</th>
+                <th style={thStyle}>D</th>
                 <th style={thStyle}>L</th>
+                <th style={thStyle}>Byes</th>
+                <th style={thStyle} title="Buchholz: sum of opponents' points">Buch</th>
+                <th style={thStyle} title="Sonneborn-Berger: points of beaten opponents plus half of drawn ones'">SB</th>
+                <th style={thStyle} title="Points scored against bots still tied">H2H</th>
+                <th style={thStyle}>Score</th>
               </tr>
             </thead>
             <tbody>
@@ -49,9 +55,15 @@ export function SwissRoundsView({ rounds, standings }: Props) {
                 <tr key={s.bot_version_id} style={{ borderBottom: '1px solid #222' }}>
                   <td style={tdStyle}>{i + 1}</td>
                   <td style={{ ...tdStyle, color: '#16c79a', fontWeight: 600 }}>{s.bot_name}</td>
-                  <td style={tdStyle}>{s.total_score}</td>
+                  <td style={{ ...tdStyle, fontWeight: 600 }}>{s.points}</td>
                   <td style={{ ...tdStyle, color: '#4caf50' }}>{s.wins}</td>
+                  <td style={tdStyle}>{s.draws}</td>
                   <td style={{ ...tdStyle, color: '#e94560' }}>{s.losses}</td>
+                  <td style={tdStyle}>{s.byes}</td>
+                  <td style={tdStyle}>{s.buchholz}</td>
+                  <td style={tdStyle}>{s.sonneborn_berger}</td>
+                  <td style={tdStyle}>{s.head_to_head}</td>
+                  <td style={{ ...tdStyle, color: '#888' }}>{s.total_score}</td>
                 </tr>
               ))}
             </tbody>
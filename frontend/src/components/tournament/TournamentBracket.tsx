@@ -1,5 +1,6 @@
-import type { TournamentRound, TournamentStanding } from '../../api/client';
+import type { DoubleEliminationBracket, TournamentRound, TournamentStanding } from '../../api/client';
 import { SingleEliminationBracket } from './SingleEliminationBracket';
+import { DoubleEliminationView } from './DoubleEliminationView';
 import { RoundRobinView } from './RoundRobinView';
 import { SwissRoundsView } from './SwissRoundsView';
 import { MatchCard } from './MatchCard';
@@ -8,9 +9,10 @@ interface Props {
   format: string;
   rounds: TournamentRound[];
   standings: TournamentStanding[];
+  bracket?: DoubleEliminationBracket | null;
 }
 
-export function TournamentBracket({ format, rounds, standings }: Props) {
+export function TournamentBracket({ format, rounds, standings, bracket }: Props) {
   if (rounds.length === 0) {
     return <p style={{ color: '#666' }}>No matches yet.</p>;
   }
@@ -19,6 +21,10 @@ export function TournamentBracket({ format, rounds, standings }: Props) {
     return <SingleEliminationBracket rounds={rounds} />;
   }
 
+  if (format.startsWith('double_elimination') && bracket) {
+    return <DoubleEliminationView rounds={rounds} bracket={bracket} />;
+  }
+
   if (format === 'round_robin') {
     return <RoundRobinView rounds={rounds} />;
   }
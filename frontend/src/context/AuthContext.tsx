@@ -1,5 +1,6 @@
 import { createContext, useContext, useState, useEffect } from 'react';
 import type { ReactNode } from 'react';
+import type { League } from '../api/client';
 
 export interface AuthUser {
   id: number;
@@ -7,6 +8,7 @@ export interface AuthUser {
   email: string;
   display_name: string | null;
   role: string;
+  league: League;
   created_at: string;
 }
 
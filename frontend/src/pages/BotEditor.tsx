@@ -65,6 +65,18 @@ export function BotEditor() {
           endLineNumber: line,
           endColumn: model.getLineLength(line) + 1,
         }]);
+      } else if (result.warnings && result.warnings.length > 0) {
+        // Point API warnings at the needs_api() declaration, if any
+        const declIndex = codeToValidate.split('\n').findIndex((l) => l.includes('needs_api'));
+        const line = declIndex >= 0 ? declIndex + 1 : 1;
+        monaco.editor.setModelMarkers(model, 'lua', result.warnings.map((message) => ({
+          severity: monaco.MarkerSeverity.Warning,
+          message,
+          startLineNumber: line,
+          startColumn: 1,
+          endLineNumber: line,
+          endColumn: model.getLineLength(line) + 1,
+        })));
       } else {
         monaco.editor.setModelMarkers(model, 'lua', []);
       }
@@ -141,6 +153,16 @@ export function BotEditor() {
     }
   };
 
+  const handleLadderToggle = async (ladder: boolean) => {
+    if (!bot) return;
+    try {
+      const updated = await api.updateBot(bot.id, bot.name, bot.description, ladder);
+      setBot(updated);
+    } catch (err) {
+      setError(err instanceof Error ? err.message : 'Failed to update ladder setting');
+    }
+  };
+
   const handleVersionChange = async (versionId: number) => {
     if (!bot) return;
     try {
@@ -199,6 +221,18 @@ export function BotEditor() {
         <button onClick={handleSaveVersion} disabled={saving} style={btnPrimary}>
           {saving ? 'Saving...' : 'Save Version'}
         </button>
+        <label
+          style={{ display: 'flex', alignItems: 'center', gap: 6, color: '#aaa', fontSize: 13 }}
+          title="Enter the active version into automatic ranked ladder matches"
+        >
+          <input
+            type="checkbox"
+            checked={!!bot?.ladder}
+            disabled={!bot}
+            onChange={e => handleLadderToggle(e.target.checked)}
+          />
+          Ladder
+        </label>
       </div>
 
       {/* Messages */}
@@ -26,6 +26,7 @@ export function Challenge() {
   const [maps, setMaps] = useState<MapInfo[]>([]);
   const [selectedMap, setSelectedMap] = useState<string>('');
   const [headless, setHeadless] = useState(false);
+  const [ranked, setRanked] = useState(false);
 
   // State
   const [loading, setLoading] = useState(true);
@@ -88,6 +89,7 @@ export function Challenge() {
         format: '1v1',
         headless,
         map: selectedMap || undefined,
+        ranked,
       });
       setResult(res);
     } catch (err) {
@@ -95,7 +97,7 @@ export function Challenge() {
     } finally {
       setSubmitting(false);
     }
-  }, [selectedVersionId, opponentVersionId, headless, selectedMap]);
+  }, [selectedVersionId, opponentVersionId, headless, selectedMap, ranked]);
 
   if (loading) {
     return <div style={{ padding: 24, textAlign: 'center', color: '#888' }}>Loading...</div>;
@@ -319,6 +321,17 @@ export function Challenge() {
             />
             Headless
           </label>
+          <label
+            style={{ display: 'flex', alignItems: 'center', gap: 6, color: '#aaa', fontSize: 13, paddingBottom: 2 }}
+            title="Counts towards ratings. Both bots must use their active version."
+          >
+            <input
+              type="checkbox"
+              checked={ranked}
+              onChange={e => setRanked(e.target.checked)}
+            />
+            Ranked
+          </label>
         </div>
 
         {/* Submit */}
@@ -606,7 +606,9 @@ DELETE   /api/api-keys/{id}     - Revoke an API key
 GET/POST /api/teams             - List/create teams
 GET      /api/notifications     - Your notifications
 POST     /api/validate-lua      - Validate Lua code
-GET      /api/maps              - Available maps
+GET/POST /api/maps              - Available maps / upload custom map
+GET/PUT  /api/maps/{name}       - Get/update a custom map
+GET      /api/maps/{name}/analysis - Map fairness analysis
 POST     /api/feedback          - Submit feedback
 GET      /api/docs/lua-api      - Lua API reference (Markdown)
 WS       /ws/game               - Live game WebSocket`}</CodeBlock>
@@ -864,7 +866,7 @@ function FAQ() {
               You are not calling <code style={{ color: '#16c79a' }}>self:moveto(x, y)</code> or{' '}
               <code style={{ color: '#16c79a' }}>set_path(id, x, y)</code>.
             </li>
-            <li>The destination is inside a wall (TILE_SOLID). Check with <code style={{ color: '#16c79a' }}>get_tile_type()</code>.</li>
+            <li>The destination is inside a wall (TILE_SOLID) and the creature is not a Flyer. Check with <code style={{ color: '#16c79a' }}>get_tile_type()</code>.</li>
             <li>Your creature is in a different state (eating, healing, etc.). Set state to WALK first.</li>
             <li>Your main loop exited. Use <code style={{ color: '#16c79a' }}>while true do ... end</code>.</li>
           </ul>
@@ -1,12 +1,18 @@
 import { useEffect, useState, useCallback } from 'react';
-import { useNavigate } from 'react-router-dom';
+import { Link, useNavigate } from 'react-router-dom';
 import { api } from '../api/client';
-import type { LeaderboardEntry } from '../api/client';
+import type { LeaderboardEntry, LeaderboardFilters, LeaderboardPeriod, League, RatingSort } from '../api/client';
 
 type Tab = '1v1' | 'ffa' | '2v2';
 
 const PAGE_SIZE = 50;
 
+const SORT_OPTIONS: Record<Tab, [RatingSort, string][]> = {
+  '1v1': [['elo', 'Elo'], ['glicko', 'Glicko-2']],
+  ffa: [['points', 'Placement points'], ['skill', 'Skill']],
+  '2v2': [['elo', 'Elo'], ['skill', 'Skill']],
+};
+
 export function Leaderboard() {
   const navigate = useNavigate();
   const [tab, setTab] = useState<Tab>('1v1');
@@ -14,21 +20,37 @@ export function Leaderboard() {
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
   const [offset, setOffset] = useState(0);
+  const [sort, setSort] = useState<RatingSort>('elo');
+  const [hideProvisional, setHideProvisional] = useState(false);
+  const [league, setLeague] = useState<League | ''>('');
+  const [period, setPeriod] = useState<LeaderboardPeriod>('all');
+  const [group, setGroup] = useState<'version' | 'bot'>('version');
+  const [activeOnly, setActiveOnly] = useState(false);
+  const [owner, setOwner] = useState('');
+  const [map, setMap] = useState('');
 
   const loadData = useCallback(async () => {
     try {
       setLoading(true);
       setError(null);
+      const filters: LeaderboardFilters = {
+        league: league || undefined,
+        period,
+        group,
+        activeOnly,
+        owner: owner.trim(),
+        map: map.trim(),
+      };
       let data: LeaderboardEntry[];
       switch (tab) {
         case '1v1':
-          data = await api.leaderboard1v1(PAGE_SIZE, offset);
+          data = await api.leaderboard1v1(PAGE_SIZE, offset, sort, hideProvisional, filters);
           break;
         case 'ffa':
-          data = await api.leaderboardFfa(PAGE_SIZE, offset);
+          data = await api.leaderboardFfa(PAGE_SIZE, offset, sort, hideProvisional, filters);
           break;
         case '2v2':
-          data = await api.leaderboard2v2(PAGE_SIZE, offset);
+          data = await api.leaderboard2v2(PAGE_SIZE, offset, sort, hideProvisional, filters);
           break;
       }
       setEntries(data);
@@ -37,7 +59,7 @@ export function Leaderboard() {
     } finally {
       setLoading(false);
     }
-  }, [tab, offset]);
+  }, [tab, offset, sort, hideProvisional, league, period, group, activeOnly, owner, map]);
 
   useEffect(() => {
     loadData();
@@ -45,16 +67,20 @@ export function Leaderboard() {
 
   const switchTab = (t: Tab) => {
     setTab(t);
+    setSort(SORT_OPTIONS[t][0][0]);
     setOffset(0);
   };
 
   return (
     <div style={{ padding: '24px', maxWidth: '960px', margin: '0 auto' }}>
-      <h2 style={{ color: '#e0e0e0', marginBottom: '24px' }}>Leaderboards</h2>
+      <div style={{ display: 'flex', alignItems: 'baseline', justifyContent: 'space-between', marginBottom: '24px' }}>
+        <h2 style={{ color: '#e0e0e0', margin: 0 }}>Leaderboards</h2>
+        <Link to="/seasons" style={{ color: '#6a6aff', textDecoration: 'none', fontSize: '14px' }}>Seasons</Link>
+      </div>
 
       {/* Tabs */}
       <div style={{ display: 'flex', gap: '4px', marginBottom: '24px' }}>
-        {([['1v1', '1v1 Elo'], ['ffa', 'FFA'], ['2v2', '2v2 Teams']] as const).map(([key, label]) => (
+        {([['1v1', '1v1'], ['ffa', 'FFA'], ['2v2', '2v2 Teams']] as const).map(([key, label]) => (
           <button
             key={key}
             onClick={() => switchTab(key)}
@@ -74,6 +100,95 @@ export function Leaderboard() {
         ))}
       </div>
 
+      {/* Rating system */}
+      <div style={{ display: 'flex', alignItems: 'center', gap: '16px', marginBottom: '16px', color: '#aaa', fontSize: '14px' }}>
+        <label>
+          Rating{' '}
+          <select
+            value={sort}
+            onChange={e => { setSort(e.target.value as RatingSort); setOffset(0); }}
+            style={selectStyle}
+          >
+            {SORT_OPTIONS[tab].map(([value, label]) => (
+              <option key={value} value={value}>{label}</option>
+            ))}
+          </select>
+        </label>
+        <label>
+          League{' '}
+          <select
+            value={league}
+            onChange={e => { setLeague(e.target.value as League | ''); setOffset(0); }}
+            style={selectStyle}
+          >
+            <option value="">All</option>
+            <option value="open">Open</option>
+            <option value="newcomer">Newcomer</option>
+          </select>
+        </label>
+        <label style={{ cursor: 'pointer' }}>
+          <input
+            type="checkbox"
+            checked={hideProvisional}
+            onChange={e => { setHideProvisional(e.target.checked); setOffset(0); }}
+          />{' '}
+          Hide provisional
+        </label>
+      </div>
+
+      {/* Filters */}
+      <div style={{ display: 'flex', flexWrap: 'wrap', alignItems: 'center', gap: '16px', marginBottom: '16px', color: '#aaa', fontSize: '14px' }}>
+        <label>
+          Period{' '}
+          <select
+            value={period}
+            onChange={e => { setPeriod(e.target.value as LeaderboardPeriod); setOffset(0); }}
+            style={selectStyle}
+          >
+            <option value="all">All time</option>
+            <option value="month">Last 30 days</option>
+            <option value="week">Last 7 days</option>
+          </select>
+        </label>
+        <label>
+          Show{' '}
+          <select
+            value={group}
+            onChange={e => { setGroup(e.target.value as 'version' | 'bot'); setOffset(0); }}
+            style={selectStyle}
+          >
+            <option value="version">Every version</option>
+            <option value="bot">{tab === '2v2' ? 'Best per team' : 'Best per bot'}</option>
+          </select>
+        </label>
+        <label>
+          Owner{' '}
+          <input
+            value={owner}
+            onChange={e => { setOwner(e.target.value); setOffset(0); }}
+            placeholder="any"
+            style={{ ...selectStyle, width: '100px' }}
+          />
+        </label>
+        <label>
+          Map{' '}
+          <input
+            value={map}
+            onChange={e => { setMap(e.target.value); setOffset(0); }}
+            placeholder="any"
+            style={{ ...selectStyle, width: '100px' }}
+          />
+        </label>
+        <label style={{ cursor: 'pointer' }}>
+          <input
+            type="checkbox"
+            checked={activeOnly}
+            onChange={e => { setActiveOnly(e.target.checked); setOffset(0); }}
+          />{' '}
+          Active versions only
+        </label>
+      </div>
+
       {error && (
         <div style={{ padding: '12px', background: '#5c1a1a', border: '1px solid #e94560', borderRadius: '4px', marginBottom: '16px', color: '#ff8a8a' }}>
           {error}
@@ -106,11 +221,27 @@ export function Leaderboard() {
               {entries.map(e => (
                 <tr key={e.bot_version_id} style={{ borderBottom: '1px solid #222' }}>
                   <td style={{ ...tdStyle, color: '#888', fontWeight: 600 }}>{e.rank}</td>
-                  <td style={{ ...tdStyle, color: '#16c79a', fontWeight: 600 }}>{e.bot_name}</td>
+                  <td style={{ ...tdStyle, color: '#16c79a', fontWeight: 600 }}>
+                    {e.bot_name}
+                    {e.provisional && (
+                      <span title="Too few games for a reliable rating" style={provisionalBadge}>provisional</span>
+                    )}
+                    {!league && e.league === 'newcomer' && (
+                      <span title="Owner's account is less than 14 days old" style={provisionalBadge}>newcomer</span>
+                    )}
+                  </td>
                   <td style={{ ...tdStyle, color: '#888' }}>v{e.version}</td>
                   <td style={tdStyle}>{e.owner_username}</td>
                   <td style={{ ...tdStyle, textAlign: 'right', fontWeight: 600, fontVariantNumeric: 'tabular-nums' }}>
                     {e.rating}
+                    {e.rating_change != null && (
+                      <span style={{ color: e.rating_change >= 0 ? '#4caf50' : '#e94560', fontWeight: 400, fontSize: '12px' }}>
+                        {' '}{e.rating_change >= 0 ? '+' : ''}{e.rating_change}
+                      </span>
+                    )}
+                    {(sort === 'glicko' || sort === 'skill') && e.confidence != null && (
+                      <span style={{ color: '#888', fontWeight: 400, fontSize: '12px' }}> ±{e.confidence}</span>
+                    )}
                   </td>
                   <td style={{ ...tdStyle, textAlign: 'right', fontVariantNumeric: 'tabular-nums' }}>
                     {e.games_played}
@@ -162,6 +293,14 @@ export function Leaderboard() {
   );
 }
 
+const selectStyle: React.CSSProperties = {
+  background: '#1a1a2e',
+  color: '#e0e0e0',
+  border: '1px solid #333',
+  borderRadius: '4px',
+  padding: '4px 8px',
+};
+
 const thStyle: React.CSSProperties = {
   textAlign: 'left',
   padding: '10px 12px',
@@ -188,6 +327,16 @@ const challengeBtn: React.CSSProperties = {
   cursor: 'pointer',
 };
 
+const provisionalBadge: React.CSSProperties = {
+  marginLeft: '8px',
+  padding: '1px 6px',
+  borderRadius: '3px',
+  background: '#333',
+  color: '#aaa',
+  fontSize: '11px',
+  fontWeight: 400,
+};
+
 const paginationBtn: React.CSSProperties = {
   background: '#1a1a2e',
   color: '#e0e0e0',
@@ -0,0 +1,139 @@
+import { useEffect, useState } from 'react';
+import { api } from '../api/client';
+import type { Season, SeasonStanding } from '../api/client';
+
+export function Seasons() {
+  const [seasons, setSeasons] = useState<Season[]>([]);
+  const [selected, setSelected] = useState<number | null>(null);
+  const [standings, setStandings] = useState<SeasonStanding[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    api.listSeasons()
+      .then(list => {
+        setSeasons(list);
+        if (list.length > 0) setSelected(list[0].id);
+      })
+      .catch(err => setError(err instanceof Error ? err.message : 'Failed to load seasons'))
+      .finally(() => setLoading(false));
+  }, []);
+
+  useEffect(() => {
+    if (selected == null) return;
+    api.seasonLeaderboard(selected, 100)
+      .then(setStandings)
+      .catch(err => setError(err instanceof Error ? err.message : 'Failed to load standings'));
+  }, [selected]);
+
+  const current = seasons.find(s => s.id === selected);
+
+  return (
+    <div style={{ padding: '24px', maxWidth: '960px', margin: '0 auto' }}>
+      <h2 style={{ color: '#e0e0e0', marginBottom: '24px' }}>Seasons</h2>
+
+      {error && (
+        <div style={{ padding: '12px', background: '#5c1a1a', border: '1px solid #e94560', borderRadius: '4px', marginBottom: '16px', color: '#ff8a8a' }}>
+          {error}
+        </div>
+      )}
+
+      {loading ? (
+        <p style={{ color: '#888' }}>Loading...</p>
+      ) : seasons.length === 0 ? (
+        <p style={{ color: '#888', textAlign: 'center', padding: '48px' }}>No seasons yet.</p>
+      ) : (
+        <>
+          {/* Season picker */}
+          <div style={{ display: 'flex', flexWrap: 'wrap', gap: '8px', marginBottom: '24px' }}>
+            {seasons.map(s => (
+              <button
+                key={s.id}
+                onClick={() => setSelected(s.id)}
+                style={{
+                  padding: '8px 16px',
+                  borderRadius: '4px',
+                  border: 'none',
+                  cursor: 'pointer',
+                  textAlign: 'left',
+                  background: s.id === selected ? '#16c79a' : '#1a1a2e',
+                  color: s.id === selected ? '#fff' : '#aaa',
+                }}
+              >
+                <div style={{ fontWeight: 600, fontSize: '14px' }}>
+                  {s.name}{s.finished_at == null && ' (current)'}
+                </div>
+                {s.champion_bot_name && (
+                  <div style={{ fontSize: '12px' }}>
+                    Champion: {s.champion_bot_name} ({s.champion_owner}, {s.champion_rating})
+                  </div>
+                )}
+              </button>
+            ))}
+          </div>
+
+          {current && (
+            <p style={{ color: '#888', fontSize: '13px', marginBottom: '12px' }}>
+              {current.starts_at.slice(0, 10)} – {(current.finished_at ?? current.ends_at).slice(0, 10)}
+              {current.finished_at == null && ' · live standings, ranked 1v1 games this season'}
+            </p>
+          )}
+
+          {standings.length === 0 ? (
+            <p style={{ color: '#888', textAlign: 'center', padding: '48px' }}>No ranked games this season.</p>
+          ) : (
+            <table style={{ width: '100%', borderCollapse: 'collapse' }}>
+              <thead>
+                <tr style={{ borderBottom: '1px solid #333' }}>
+                  <th style={thStyle}>#</th>
+                  <th style={thStyle}>Bot</th>
+                  <th style={thStyle}>Owner</th>
+                  <th style={{ ...thStyle, textAlign: 'right' }}>Rating</th>
+                  <th style={{ ...thStyle, textAlign: 'right' }}>Peak</th>
+                  <th style={{ ...thStyle, textAlign: 'right' }}>Games</th>
+                  <th style={{ ...thStyle, textAlign: 'right' }}>W / L / D</th>
+                </tr>
+              </thead>
+              <tbody>
+                {standings.map(e => (
+                  <tr key={e.bot_version_id} style={{ borderBottom: '1px solid #222' }}>
+                    <td style={{ ...tdStyle, color: '#888', fontWeight: 600 }}>{e.rank}</td>
+                    <td style={{ ...tdStyle, color: '#16c79a', fontWeight: 600 }}>
+                      {e.bot_name} <span style={{ color: '#888', fontWeight: 400 }}>v{e.version}</span>
+                    </td>
+                    <td style={tdStyle}>{e.owner_username}</td>
+                    <td style={{ ...tdStyle, textAlign: 'right', fontWeight: 600, fontVariantNumeric: 'tabular-nums' }}>{e.rating}</td>
+                    <td style={{ ...tdStyle, textAlign: 'right', fontVariantNumeric: 'tabular-nums' }}>{e.peak_rating}</td>
+                    <td style={{ ...tdStyle, textAlign: 'right', fontVariantNumeric: 'tabular-nums' }}>{e.games_played}</td>
+                    <td style={{ ...tdStyle, textAlign: 'right', fontVariantNumeric: 'tabular-nums' }}>
+                      <span style={{ color: '#4caf50' }}>{e.wins}</span>
+                      {' / '}
+                      <span style={{ color: '#e94560' }}>{e.losses}</span>
+                      {' / '}
+                      {e.draws}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const thStyle: React.CSSProperties = {
+  textAlign: 'left',
+  padding: '10px 12px',
+  color: '#aaa',
+  fontSize: '13px',
+  fontWeight: 600,
+  textTransform: 'uppercase',
+  letterSpacing: '0.5px',
+};
+
+const tdStyle: React.CSSProperties = {
+  padding: '10px 12px',
+  color: '#e0e0e0',
+};
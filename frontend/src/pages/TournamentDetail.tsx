@@ -2,12 +2,14 @@ import { useEffect, useState, useCallback } from 'react';
 import { useParams, useNavigate } from 'react-router-dom';
 import { useAuth } from '../context/AuthContext';
 import { api } from '../api/client';
-import type { Tournament, TournamentEntry, TournamentResult, TournamentStanding, TournamentRound, Bot, BotVersion, MapInfo } from '../api/client';
+import type { Tournament, TournamentEntry, TournamentResult, TournamentStanding, TournamentRound, DoubleEliminationBracket, Bot, BotVersion, MapInfo } from '../api/client';
 import { TournamentBracket } from '../components/tournament/TournamentBracket';
 
 const FORMAT_OPTIONS = [
   { value: 'round_robin', label: 'Round Robin' },
   { value: 'single_elimination', label: 'Single Elimination' },
+  { value: 'double_elimination', label: 'Double Elimination' },
+  { value: 'double_elimination_no_reset', label: 'Double Elimination (no bracket reset)' },
   { value: 'swiss_3', label: 'Swiss (3 rounds)' },
   { value: 'swiss_5', label: 'Swiss (5 rounds)' },
 ];
@@ -42,6 +44,7 @@ export function TournamentDetail() {
   const [_results, setResults] = useState<TournamentResult[]>([]);
   const [standings, setStandings] = useState<TournamentStanding[]>([]);
   const [tournamentRounds, setTournamentRounds] = useState<TournamentRound[]>([]);
+  const [bracket, setBracket] = useState<DoubleEliminationBracket | null>(null);
   const [bots, setBots] = useState<Bot[]>([]);
   const [versions, setVersions] = useState<BotVersion[]>([]);
   const [selectedBotId, setSelectedBotId] = useState<number | ''>('');
@@ -98,6 +101,9 @@ export function TournamentDetail() {
           setResults(r);
           setStandings(s);
           setTournamentRounds(matchesResp.rounds);
+          if (t.format.startsWith('double_elimination')) {
+            setBracket(await api.getTournamentBracket(tournamentId));
+          }
         } catch {
           // Results/standings may not be available yet
         }
@@ -397,6 +403,7 @@ export function TournamentDetail() {
             format={tournament.format}
             rounds={tournamentRounds}
             standings={standings}
+            bracket={bracket}
           />
         </>
       )}